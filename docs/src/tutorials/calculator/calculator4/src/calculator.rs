@@ -472,9 +472,17 @@ pub enum Recognizer {
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct TokenRecognizer(TokenKind, Recognizer);
-impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
-    fn recognize(&self, input: &'i str) -> Option<&'i str> {
-        match &self {
+impl<'i> TokenRecognizerT<'i, Context<'i, Input>> for TokenRecognizer {
+    #[allow(unused_variables, clippy::only_used_in_recursion)]
+    fn recognize_value(
+        &self,
+        context: &Context<'i, Input>,
+        input: &'i str,
+        position: usize,
+    ) -> Option<(usize, &'i str)> {
+        let whole_input = input;
+        let input = &input[position..];
+        let recognized: Option<&'i str> = match &self {
             #[allow(unused_variables)]
             TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
                 logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
@@ -512,7 +520,8 @@ impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
                     None
                 }
             }
-        }
+        };
+        recognized.map(|x_str| (x_str.len(), x_str))
     }
 }
 pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
@@ -6,7 +6,7 @@
 use std::{collections::BTreeSet, path::PathBuf};
 
 use proc_macro2::{Ident, Span};
-use quote::format_ident;
+use quote::{format_ident, ToTokens};
 use syn::{self, parse_quote};
 
 use crate::{
@@ -16,28 +16,71 @@ use crate::{
     Error,
 };
 
-use super::ParserGenerator;
+use super::{item_vis, ParserGenerator};
 
 mod production;
 
 pub(crate) trait ActionsGenerator {
+    /// Does the type with the given name carry the input lifetime.
+    fn borrows(&self, type_name: &str) -> bool;
+
     fn terminal_type(&self, terminal: &Terminal) -> syn::Item {
         let type_name_ident = Ident::new(&terminal.name, Span::call_site());
+        if self.borrows(&terminal.name) {
+            return parse_quote! {
+                pub type #type_name_ident<'i> = &'i Input;
+            };
+        }
+        let output_type: syn::Type = match &terminal.output_type {
+            // Validity of the type is checked during grammar construction.
+            Some(output_type) => syn::parse_str(output_type).unwrap(),
+            None => parse_quote! { String },
+        };
         parse_quote! {
-            pub type #type_name_ident = String;
+            pub type #type_name_ident = #output_type;
         }
     }
     fn terminal_action(
         &self,
         terminal: &Terminal,
-        _settings: &Settings,
+        settings: &Settings,
     ) -> syn::Item {
+        let vis = item_vis(settings);
         let type_name_ident = Ident::new(&terminal.name, Span::call_site());
         let action_name = to_snake_case(&terminal.name);
         let action_name_ident = Ident::new(&action_name, Span::call_site());
-        parse_quote! {
-            pub fn #action_name_ident(_ctx: &Ctx, token: Token) -> #type_name_ident {
-                token.value.into()
+        // Borrowed terminals are slices of the input while terminals with
+        // inline type are converted using `FromStr`. Values which can't be
+        // converted (e.g. out of range numbers) are rejected by fallible
+        // actions and replaced by the default value otherwise.
+        if self.borrows(&terminal.name) {
+            parse_quote! {
+                #vis fn #action_name_ident<'i>(_ctx: &Ctx<'i>, token: Token<'i>) -> #type_name_ident<'i> {
+                    token.value
+                }
+            }
+        } else if terminal.output_type.is_some() && settings.fallible_actions {
+            let message = format!("Invalid {} value.", terminal.name);
+            parse_quote! {
+                #vis fn #action_name_ident(_ctx: &Ctx, token: Token) -> Result<#type_name_ident> {
+                    token.value.parse().map_err(|_| rustemo::Error::Error {
+                        message: #message.into(),
+                        file: None,
+                        location: None,
+                    })
+                }
+            }
+        } else if terminal.output_type.is_some() {
+            parse_quote! {
+                #vis fn #action_name_ident(_ctx: &Ctx, token: Token) -> #type_name_ident {
+                    token.value.parse().unwrap_or_default()
+                }
+            }
+        } else {
+            parse_quote! {
+                #vis fn #action_name_ident(_ctx: &Ctx, token: Token) -> #type_name_ident {
+                    token.value.into()
+                }
             }
         }
     }
@@ -73,26 +116,88 @@ pub(super) fn generate_parser_actions(
         log!("Creating: {:?}", action_file);
         let lexer_mod = format_ident!("{parser_mod}_lexer");
         let parser_mod = format_ident!("{}", parser_mod);
+        let vis = item_vis(generator.settings);
         let input_type: syn::Stmt = match generator.settings.lexer_type {
             LexerType::Default => parse_quote! {
-                pub type Input = str;
+                #vis type Input = str;
             },
             LexerType::Custom => parse_quote! {
                 use super::#lexer_mod::Input;
             },
         };
+        let token_attr = &generator.token_attr_type;
+        let alloc_imports: Vec<syn::Stmt> = if generator.settings.no_std {
+            parse_quote! {
+                extern crate alloc;
+                #[allow(unused_imports)]
+                use alloc::{boxed::Box, string::{String, ToString}, vec, vec::Vec};
+            }
+        } else {
+            vec![]
+        };
+        let result_import: Vec<syn::Stmt> = if generator.settings.fallible_actions
+        {
+            parse_quote! {
+                use rustemo::Result;
+            }
+        } else {
+            vec![]
+        };
+        // Actions of `@text` rules slice the input using the context.
+        let context_import: Vec<syn::Stmt> = if generator
+            .grammar
+            .nonterminals()
+            .iter()
+            .any(|nt| matches!(nt.annotation, Some(ref a) if a == "text"))
+        {
+            parse_quote! {
+                use rustemo::Context as ContextT;
+            }
+        } else {
+            vec![]
+        };
+        let file_header = &generator.file_header;
         parse_quote! {
+            #(#file_header)*
             /// This file is maintained by rustemo but can be modified manually.
             /// All manual changes will be preserved except non-doc comments.
+            #(#alloc_imports)*
             use rustemo::Token as RustemoToken;
+            #(#result_import)*
+            #(#context_import)*
             use super::#parser_mod::{TokenKind, Context};
             #input_type
-            pub type Ctx<'i> = Context<'i, Input>;
+            #vis type Ctx<'i> = Context<'i, Input>;
             #[allow(dead_code)]
-            pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+            #vis type Token<'i> = RustemoToken<'i, Input, TokenKind #(, #token_attr)*>;
         }
     };
 
+    // Splice the items of the actions code blocks after the header of the
+    // file. The items already in the file are kept as they are.
+    let header_end = ast
+        .items
+        .iter()
+        .position(
+            |item| matches!(item, syn::Item::Type(t) if t.ident == "Token"),
+        )
+        .map_or(0, |idx| idx + 1);
+    let existing = ast
+        .items
+        .iter()
+        .map(|item| item.to_token_stream().to_string())
+        .collect::<BTreeSet<_>>();
+    let mut code_items = vec![];
+    for code in &generator.grammar.actions_code {
+        // Validity of the code is checked during grammar construction.
+        for item in syn::parse_file(code)?.items {
+            if !existing.contains(&item.to_token_stream().to_string()) {
+                code_items.push(item);
+            }
+        }
+    }
+    ast.items.splice(header_end..header_end, code_items);
+
     // Collect function and type names
     let mut type_names = BTreeSet::new();
     let mut action_names = BTreeSet::new();
@@ -131,6 +236,8 @@ pub(super) fn generate_parser_actions(
         production::ProductionActionsGenerator::new(
             generator.grammar,
             generator.types.as_ref().unwrap(),
+            &generator.borrowed_types,
+            generator.settings,
         );
 
     // Generate types and actions for terminals
@@ -144,7 +251,11 @@ pub(super) fn generate_parser_actions(
             let type_name = &terminal.name;
             if !type_names.contains(type_name) {
                 log!("Create type for terminal '{type_name}'.");
-                ast.items.push(actions_generator.terminal_type(terminal));
+                ast.items.push(with_doc(
+                    actions_generator.terminal_type(terminal),
+                    type_name,
+                    &terminal.doc,
+                ));
             }
             // Add terminal actions
             let action_name = to_snake_case(&terminal.name);
@@ -162,13 +273,17 @@ pub(super) fn generate_parser_actions(
         .grammar
         .nonterminals()
         .iter()
-        .filter(|nt| nt.reachable.get())
+        .filter(|nt| nt.reachable.get() && !nt.no_content)
         .for_each(|nonterminal| {
             // Add non-terminal type
             if !type_names.contains(&nonterminal.name) {
                 log!("Creating types for non-terminal '{}'.", nonterminal.name);
                 for ty in actions_generator.nonterminal_types(nonterminal) {
-                    ast.items.push(ty);
+                    ast.items.push(with_doc(
+                        ty,
+                        &nonterminal.name,
+                        &nonterminal.doc,
+                    ));
                 }
             }
 
@@ -194,3 +309,22 @@ pub(super) fn generate_parser_actions(
 
     Ok(())
 }
+
+/// Adds the lines of the grammar doc comment as doc attributes if the given
+/// item is the type with the given name.
+fn with_doc(mut item: syn::Item, type_name: &str, doc: &[String]) -> syn::Item {
+    let (ident, attrs) = match &mut item {
+        syn::Item::Struct(item) => (&item.ident, &mut item.attrs),
+        syn::Item::Enum(item) => (&item.ident, &mut item.attrs),
+        syn::Item::Type(item) => (&item.ident, &mut item.attrs),
+        _ => return item,
+    };
+    if ident == type_name {
+        attrs.splice(
+            0..0,
+            doc.iter()
+                .map(|line| -> syn::Attribute { parse_quote!(#[doc = #line]) }),
+        );
+    }
+    item
+}
@@ -1,13 +1,15 @@
+use std::collections::BTreeSet;
+
 use proc_macro2::{Ident, Span};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse::Parser, parse_quote};
 
 use crate::{
-    generator::action_name,
+    generator::{action_name, item_vis},
     grammar::{
         types::{
-            to_snake_case, Choice, ChoiceKind, SymbolType, SymbolTypeKind,
-            SymbolTypes,
+            to_snake_case, Choice, ChoiceKind, Field, SymbolType,
+            SymbolTypeKind, SymbolTypes,
         },
         Grammar, NonTerminal,
     },
@@ -19,6 +21,41 @@ use super::ActionsGenerator;
 pub(crate) struct ProductionActionsGenerator<'t> {
     types: &'t SymbolTypes,
     term_len: usize,
+    borrowed: &'t BTreeSet<String>,
+    derives: Vec<syn::Path>,
+}
+
+/// The type with the given name. Types carrying the input lifetime are
+/// referenced with `'i`.
+fn type_ref(type_name: &str, borrowed: &BTreeSet<String>) -> syn::Type {
+    let type_ident = Ident::new(type_name, Span::call_site());
+    if borrowed.contains(type_name) {
+        parse_quote! { #type_ident<'i> }
+    } else {
+        parse_quote! { #type_ident }
+    }
+}
+
+/// Generics of the declaration of the type with the given name.
+fn type_generics(
+    type_name: &str,
+    borrowed: &BTreeSet<String>,
+) -> syn::Generics {
+    if borrowed.contains(type_name) {
+        parse_quote! { <'i> }
+    } else {
+        parse_quote! {}
+    }
+}
+
+/// Lints allowed for the constructors with the given maximal number of
+/// arguments. The constructors are not used by the parser.
+fn constructor_lints(args: usize) -> Vec<syn::Path> {
+    let mut lints = vec![parse_quote! { dead_code }];
+    if args > 7 {
+        lints.push(parse_quote! { clippy::too_many_arguments });
+    }
+    lints
 }
 
 impl<'t> ProductionActionsGenerator<'t> {
@@ -27,10 +64,22 @@ impl<'t> ProductionActionsGenerator<'t> {
     pub fn new(
         grammar: &Grammar,
         types: &'t SymbolTypes,
+        borrowed: &'t BTreeSet<String>,
+        settings: &Settings,
     ) -> Box<dyn ActionsGenerator + 't> {
+        let mut derives = vec![parse_quote! { Debug }, parse_quote! { Clone }];
+        if settings.derive_hash_eq {
+            derives.extend([
+                parse_quote! { PartialEq },
+                parse_quote! { Eq },
+                parse_quote! { Hash },
+            ]);
+        }
         Box::new(Self {
             types,
             term_len: grammar.terminals.len(),
+            borrowed,
+            derives,
         })
     }
 
@@ -46,12 +95,15 @@ impl<'t> ProductionActionsGenerator<'t> {
             ChoiceKind::Struct { fields, .. } => {
                 for field in fields {
                     let f_name = Ident::new(&field.name, Span::call_site());
-                    let f_type = Ident::new(&field.ref_type, Span::call_site());
+                    let f_type = type_ref(&field.ref_type, self.borrowed);
 
                     // If this type is Vec and ref type is recursion make it
                     // mutable to support *, +...
-                    if matches! { ty.kind, SymbolTypeKind::Vec{ .. } }
-                        && ty.name == field.ref_type
+                    if matches!(
+                        ty.kind,
+                        SymbolTypeKind::Vec { .. }
+                            | SymbolTypeKind::Columns { .. }
+                    ) && ty.name == field.ref_type
                     {
                         fn_args.push(parse_quote! { mut #f_name: #f_type });
                     } else {
@@ -60,7 +112,7 @@ impl<'t> ProductionActionsGenerator<'t> {
                 }
             }
             ChoiceKind::Ref { ref_type, .. } => {
-                let ty = Ident::new(ref_type, Span::call_site());
+                let ty = type_ref(ref_type, self.borrowed);
                 let name =
                     Ident::new(&to_snake_case(ref_type), Span::call_site());
                 fn_args.push(parse_quote! { #name: #ty });
@@ -70,6 +122,325 @@ impl<'t> ProductionActionsGenerator<'t> {
         fn_args
     }
 
+    /// Arguments of the action of a `@text` rule. The arguments are unused.
+    fn get_text_action_args(&self, choice: &Choice) -> Vec<syn::FnArg> {
+        let arg = |name: &str, ref_type: &str| -> syn::FnArg {
+            let name = format_ident!("_{}", name);
+            let ty = type_ref(ref_type, self.borrowed);
+            parse_quote! { #name: #ty }
+        };
+        match &choice.kind {
+            ChoiceKind::Struct { fields, .. } => fields
+                .iter()
+                .map(|field| arg(&field.name, &field.ref_type))
+                .collect(),
+            ChoiceKind::Ref { ref_type, .. } => {
+                vec![arg(&to_snake_case(ref_type), ref_type)]
+            }
+            ChoiceKind::Plain | ChoiceKind::Empty => vec![],
+        }
+    }
+
+    /// The fields of the struct repeated by a `Columns` type.
+    fn columns_fields(&self, ref_type: &str) -> &'t [Field] {
+        self.types
+            .get_type_by_name(ref_type)
+            .and_then(|ty| {
+                ty.choices.iter().find_map(|choice| match &choice.kind {
+                    ChoiceKind::Struct { fields, .. } => Some(&fields[..]),
+                    _ => None,
+                })
+            })
+            .unwrap_or_default()
+    }
+
+    /// If the given struct choice has a single field of a repetition type
+    /// (`*` or `+` operator) returns implementations of `IntoIterator` and
+    /// `Deref` to a slice which delegate to that field.
+    fn get_repetition_impls(
+        &self,
+        struct_type: &Ident,
+        choice: &Choice,
+    ) -> Vec<syn::Item> {
+        let field = match &choice.kind {
+            ChoiceKind::Struct { fields, .. } if fields.len() == 1 => {
+                &fields[0]
+            }
+            _ => return vec![],
+        };
+        let field_type = match self.types.get_type_by_name(&field.ref_type) {
+            Some(field_type) if !field.recursive.get() => field_type,
+            _ => return vec![],
+        };
+
+        // One or more is `Vec<T>` while zero or more is `Option<Vec<T>>`.
+        let (vec_type, optional) = match &field_type.kind {
+            SymbolTypeKind::Vec { .. } if !field_type.optional => {
+                (field_type, false)
+            }
+            SymbolTypeKind::Ref { ref_type, .. } if field_type.optional => {
+                match self.types.get_type_by_name(ref_type) {
+                    Some(vec_type) if !vec_type.optional => (vec_type, true),
+                    _ => return vec![],
+                }
+            }
+            _ => return vec![],
+        };
+        let elem_type: syn::Type = match &vec_type.kind {
+            SymbolTypeKind::Vec {
+                ref_type,
+                recursive,
+            } => {
+                let ref_type = type_ref(ref_type, self.borrowed);
+                if recursive.get() {
+                    parse_quote! { Box<#ref_type> }
+                } else {
+                    parse_quote! { #ref_type }
+                }
+            }
+            _ => return vec![],
+        };
+
+        let struct_name = struct_type.to_string();
+        let generics = type_generics(&struct_name, self.borrowed);
+        let ref_generics: syn::Generics =
+            if self.borrowed.contains(&struct_name) {
+                parse_quote! { <'a, 'i> }
+            } else {
+                parse_quote! { <'a> }
+            };
+        let struct_type = type_ref(&struct_name, self.borrowed);
+
+        let field_name = Ident::new(&field.name, Span::call_site());
+        let (into_iter, deref): (syn::Expr, syn::Expr) = if optional {
+            (
+                parse_quote! { self.#field_name.unwrap_or_default().into_iter() },
+                parse_quote! { self.#field_name.as_deref().unwrap_or_default() },
+            )
+        } else {
+            (
+                parse_quote! { self.#field_name.into_iter() },
+                parse_quote! { &self.#field_name },
+            )
+        };
+        vec![
+            parse_quote! {
+                impl #generics IntoIterator for #struct_type {
+                    type Item = #elem_type;
+                    type IntoIter = std::vec::IntoIter<#elem_type>;
+                    fn into_iter(self) -> Self::IntoIter {
+                        #into_iter
+                    }
+                }
+            },
+            parse_quote! {
+                impl #ref_generics IntoIterator for &'a #struct_type {
+                    type Item = &'a #elem_type;
+                    type IntoIter = std::slice::Iter<'a, #elem_type>;
+                    fn into_iter(self) -> Self::IntoIter {
+                        self.iter()
+                    }
+                }
+            },
+            parse_quote! {
+                impl #generics std::ops::Deref for #struct_type {
+                    type Target = [#elem_type];
+                    fn deref(&self) -> &Self::Target {
+                        #deref
+                    }
+                }
+            },
+        ]
+    }
+
+    /// Returns `From` implementations for the enum variants holding a
+    /// generated struct or enum type. Variants holding type aliases (e.g.
+    /// terminals) or the enum type itself are skipped, as well as variants
+    /// with a type used in more than one variant, as that would lead to
+    /// conflicting implementations.
+    fn get_variant_from_impls(
+        &self,
+        enum_type: &Ident,
+        choices: &[Choice],
+    ) -> Vec<syn::Item> {
+        let enum_name = enum_type.to_string();
+        let generics = type_generics(&enum_name, self.borrowed);
+        let enum_ty = type_ref(&enum_name, self.borrowed);
+        let variant_types: Vec<(&Choice, &str, bool)> = choices
+            .iter()
+            .filter_map(|choice| match &choice.kind {
+                ChoiceKind::Struct { type_name, .. } => {
+                    Some((choice, type_name.as_str(), false))
+                }
+                ChoiceKind::Ref {
+                    ref_type,
+                    recursive,
+                } => match self.types.get_type_by_name(ref_type) {
+                    Some(SymbolType {
+                        kind:
+                            SymbolTypeKind::Enum { type_name }
+                            | SymbolTypeKind::Struct { type_name },
+                        optional: false,
+                        ..
+                    }) if type_name == ref_type && *ref_type != enum_name => {
+                        Some((choice, ref_type.as_str(), recursive.get()))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        variant_types
+            .iter()
+            .filter(|(_, type_name, _)| {
+                variant_types
+                    .iter()
+                    .filter(|(_, t, _)| t == type_name)
+                    .count()
+                    == 1
+            })
+            .map(|(choice, type_name, recursive)| {
+                let variant = Ident::new(&choice.name, Span::call_site());
+                let variant_type = type_ref(type_name, self.borrowed);
+                let value: syn::Expr = if *recursive {
+                    parse_quote! { Box::new(value) }
+                } else {
+                    parse_quote! { value }
+                };
+                parse_quote! {
+                    impl #generics From<#variant_type> for #enum_ty {
+                        fn from(value: #variant_type) -> Self {
+                            #enum_type::#variant(#value)
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the constructors of the structs of the given choices. The
+    /// struct name is given for the choices of a struct type.
+    fn get_choice_constructors(
+        &self,
+        choices: &[Choice],
+        struct_name: Option<&str>,
+    ) -> Vec<syn::Item> {
+        choices
+            .iter()
+            .filter_map(|choice| match &choice.kind {
+                ChoiceKind::Struct { type_name, fields } => {
+                    Some(self.get_struct_constructor(
+                        struct_name.unwrap_or(type_name),
+                        fields,
+                    ))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the constructor of the struct with the given name and fields.
+    /// The arguments are the field values, boxed by the constructor where
+    /// needed.
+    fn get_struct_constructor(
+        &self,
+        struct_name: &str,
+        fields: &[Field],
+    ) -> syn::Item {
+        let generics = type_generics(struct_name, self.borrowed);
+        let struct_ty = type_ref(struct_name, self.borrowed);
+        let args = fields.iter().map(|f| -> syn::FnArg {
+            let name = Ident::new(&f.name, Span::call_site());
+            let ty = type_ref(&f.ref_type, self.borrowed);
+            parse_quote! { #name: #ty }
+        });
+        let values = fields.iter().map(|f| -> syn::FieldValue {
+            let name = Ident::new(&f.name, Span::call_site());
+            if f.recursive.get() {
+                parse_quote! { #name: Box::new(#name) }
+            } else {
+                parse_quote! { #name }
+            }
+        });
+        let allow = constructor_lints(fields.len());
+        parse_quote! {
+            #[allow(#(#allow),*)]
+            impl #generics #struct_ty {
+                pub fn new(#(#args),*) -> Self {
+                    Self { #(#values),* }
+                }
+            }
+        }
+    }
+
+    /// Returns the constructors of the given enum, one for each variant, named
+    /// `new_<variant>`. The arguments of a constructor of a struct variant are
+    /// the struct fields.
+    fn get_enum_constructors(
+        &self,
+        enum_name: &str,
+        choices: &[Choice],
+    ) -> syn::Item {
+        let generics = type_generics(enum_name, self.borrowed);
+        let enum_ty = type_ref(enum_name, self.borrowed);
+        let mut max_args = 0;
+        let constructors = choices.iter().filter_map(|choice| -> Option<syn::ImplItem> {
+            let variant = Ident::new(&choice.name, Span::call_site());
+            let name = format_ident!("new_{}", to_snake_case(&choice.name));
+            let (args, value): (Vec<syn::FnArg>, syn::Expr) = match &choice.kind {
+                ChoiceKind::Plain => (vec![], parse_quote! { Self::#variant }),
+                ChoiceKind::Struct { type_name, fields } => {
+                    let struct_type = Ident::new(type_name, Span::call_site());
+                    let names: Vec<Ident> = fields
+                        .iter()
+                        .map(|f| Ident::new(&f.name, Span::call_site()))
+                        .collect();
+                    let args = fields
+                        .iter()
+                        .zip(&names)
+                        .map(|(f, name)| {
+                            let ty = type_ref(&f.ref_type, self.borrowed);
+                            parse_quote! { #name: #ty }
+                        })
+                        .collect();
+                    (
+                        args,
+                        parse_quote! { Self::#variant(#struct_type::new(#(#names),*)) },
+                    )
+                }
+                ChoiceKind::Ref { ref_type, recursive } => {
+                    let name =
+                        Ident::new(&to_snake_case(ref_type), Span::call_site());
+                    let ty = type_ref(ref_type, self.borrowed);
+                    let value: syn::Expr = if recursive.get() {
+                        parse_quote! { Box::new(#name) }
+                    } else {
+                        parse_quote! { #name }
+                    };
+                    (
+                        vec![parse_quote! { #name: #ty }],
+                        parse_quote! { Self::#variant(#value) },
+                    )
+                }
+                ChoiceKind::Empty => return None,
+            };
+            max_args = max_args.max(args.len());
+            Some(parse_quote! {
+                pub fn #name(#(#args),*) -> Self {
+                    #value
+                }
+            })
+        }).collect::<Vec<_>>();
+        let allow = constructor_lints(max_args);
+        parse_quote! {
+            #[allow(#(#allow),*)]
+            impl #generics #enum_ty {
+                #(#constructors)*
+            }
+        }
+    }
+
     fn get_action_body(
         &self,
         ty: &SymbolType,
@@ -146,6 +517,10 @@ impl<'t> ProductionActionsGenerator<'t> {
 }
 
 impl ActionsGenerator for ProductionActionsGenerator<'_> {
+    fn borrows(&self, type_name: &str) -> bool {
+        self.borrowed.contains(type_name)
+    }
+
     fn nonterminal_types(&self, nonterminal: &NonTerminal) -> Vec<syn::Item> {
         let ty = self
             .types
@@ -155,25 +530,24 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
         fn get_choice_type(
             choice: &Choice,
             type_name: Option<&str>,
+            borrowed: &BTreeSet<String>,
+            derives: &[syn::Path],
         ) -> Option<syn::Item> {
             match &choice.kind {
                 ChoiceKind::Struct {
                     type_name: struct_type,
                     fields,
                 } => {
-                    let type_ident = if let Some(type_name) = type_name {
-                        Ident::new(type_name, Span::call_site())
-                    } else {
-                        Ident::new(struct_type, Span::call_site())
-                    };
+                    let type_name = type_name.unwrap_or(struct_type);
+                    let type_ident = Ident::new(type_name, Span::call_site());
+                    let generics = type_generics(type_name, borrowed);
 
                     let fields: Vec<syn::Field> = fields
                         .iter()
                         .map(|f| {
                             let field_name =
                                 Ident::new(&f.name, Span::call_site());
-                            let field_type =
-                                Ident::new(&f.ref_type, Span::call_site());
+                            let field_type = type_ref(&f.ref_type, borrowed);
                             syn::Field::parse_named
                                 .parse2(if f.recursive.get() {
                                     // Handle direct recursion
@@ -185,8 +559,8 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                         })
                         .collect();
                     Some(parse_quote! {
-                        #[derive(Debug, Clone)]
-                        pub struct #type_ident {
+                        #[derive(#(#derives),*)]
+                        pub struct #type_ident #generics {
                             #(#fields),*
                         }
                     })
@@ -198,14 +572,21 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
         fn get_choice_types(
             choices: &[Choice],
             type_name: Option<&str>,
+            borrowed: &BTreeSet<String>,
+            derives: &[syn::Path],
         ) -> Vec<syn::Item> {
             choices
                 .iter()
-                .filter_map(|choice| get_choice_type(choice, type_name))
+                .filter_map(|choice| {
+                    get_choice_type(choice, type_name, borrowed, derives)
+                })
                 .collect()
         }
 
-        fn get_variants(choices: &[Choice]) -> Vec<syn::Variant> {
+        fn get_variants(
+            choices: &[Choice],
+            borrowed: &BTreeSet<String>,
+        ) -> Vec<syn::Variant> {
             choices
                 .iter()
                 .filter_map(|v| {
@@ -215,18 +596,14 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                             Some(parse_quote! { #variant_ident })
                         }
                         ChoiceKind::Struct { type_name, .. } => {
-                            let type_ident =
-                                Ident::new(type_name, Span::call_site());
-                            Some(parse_quote! { #variant_ident(#type_ident) })
+                            let struct_type = type_ref(type_name, borrowed);
+                            Some(parse_quote! { #variant_ident(#struct_type) })
                         }
                         ChoiceKind::Ref {
                             ref_type,
                             recursive,
                         } => {
-                            let ref_type_ident =
-                                Ident::new(ref_type, Span::call_site());
-                            let mut ref_type: syn::Type =
-                                parse_quote! { #ref_type_ident };
+                            let mut ref_type = type_ref(ref_type, borrowed);
                             if recursive.get() {
                                 ref_type = parse_quote! { Box<#ref_type> };
                             }
@@ -242,32 +619,63 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
             SymbolTypeKind::Enum {
                 type_name: enum_type,
             } => {
-                let mut types = get_choice_types(&ty.choices, None);
-                let variants = get_variants(&ty.choices);
+                let mut types = get_choice_types(
+                    &ty.choices,
+                    None,
+                    self.borrowed,
+                    &self.derives,
+                );
+                let variants = get_variants(&ty.choices, self.borrowed);
+                let generics = type_generics(enum_type, self.borrowed);
+                let enum_ty = type_ref(enum_type, self.borrowed);
                 let enum_type = Ident::new(enum_type, Span::call_site());
 
                 if ty.optional {
                     types.push(
-                        parse_quote! {pub type #type_ident = Option<#enum_type>;},
+                        parse_quote! {pub type #type_ident #generics = Option<#enum_ty>;},
                     );
                 }
+                let derives = &self.derives;
                 types.push(parse_quote! {
-                    #[derive(Debug, Clone)]
-                    pub enum #enum_type {
+                    #[derive(#(#derives),*)]
+                    pub enum #enum_type #generics {
                         #(#variants),*
                     }
                 });
+                types.extend(
+                    self.get_variant_from_impls(&enum_type, &ty.choices),
+                );
+                types.extend(self.get_choice_constructors(&ty.choices, None));
+                types.push(self.get_enum_constructors(
+                    &enum_type.to_string(),
+                    &ty.choices,
+                ));
                 types
             }
             SymbolTypeKind::Struct {
                 type_name: struct_type,
             } => {
-                let mut types =
-                    get_choice_types(&ty.choices, Some(struct_type));
+                let mut types = get_choice_types(
+                    &ty.choices,
+                    Some(struct_type),
+                    self.borrowed,
+                    &self.derives,
+                );
+                let generics = type_generics(struct_type, self.borrowed);
+                let struct_ty = type_ref(struct_type, self.borrowed);
                 let struct_type = Ident::new(struct_type, Span::call_site());
+                for choice in &ty.choices {
+                    types.extend(
+                        self.get_repetition_impls(&struct_type, choice),
+                    );
+                }
+                types.extend(self.get_choice_constructors(
+                    &ty.choices,
+                    Some(&struct_type.to_string()),
+                ));
                 if ty.optional {
                     types.push(
-                        parse_quote! {pub type #type_ident = Option<#struct_type>;},
+                        parse_quote! {pub type #type_ident #generics = Option<#struct_ty>;},
                     );
                 }
                 types
@@ -276,32 +684,94 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                 ref_type,
                 recursive,
             } => {
-                let ref_type_ident = Ident::new(ref_type, Span::call_site());
-                let mut ref_type: syn::Type = parse_quote! { #ref_type_ident };
+                let generics = type_generics(&ty.name, self.borrowed);
+                let mut ref_type = type_ref(ref_type, self.borrowed);
                 if recursive.get() {
                     ref_type = parse_quote! { Box<#ref_type> }
                 }
                 if ty.optional {
                     vec![
-                        parse_quote! { pub type #type_ident = Option<#ref_type>; },
+                        parse_quote! { pub type #type_ident #generics = Option<#ref_type>; },
                     ]
                 } else {
-                    vec![parse_quote! { pub type #type_ident = #ref_type; }]
+                    vec![
+                        parse_quote! { pub type #type_ident #generics = #ref_type; },
+                    ]
                 }
             }
             SymbolTypeKind::Vec {
                 ref_type,
                 recursive,
             } => {
-                let ref_type = Ident::new(ref_type, Span::call_site());
+                let generics = type_generics(&ty.name, self.borrowed);
+                let ref_type = type_ref(ref_type, self.borrowed);
                 if recursive.get() {
                     vec![
-                        parse_quote! { pub type #type_ident = Vec<Box<#ref_type>>; },
+                        parse_quote! { pub type #type_ident #generics = Vec<Box<#ref_type>>; },
                     ]
                 } else {
                     vec![
-                        parse_quote! { pub type #type_ident = Vec<#ref_type>; },
+                        parse_quote! { pub type #type_ident #generics = Vec<#ref_type>; },
+                    ]
+                }
+            }
+            SymbolTypeKind::Columns { ref_type } => {
+                let generics = type_generics(&ty.name, self.borrowed);
+                let elem_type = type_ref(ref_type, self.borrowed);
+                let elem = format_ident!("{}", to_snake_case(ref_type));
+                let fields = self.columns_fields(ref_type);
+                let columns: Vec<syn::Field> = fields
+                    .iter()
+                    .map(|f| {
+                        let field_name = format_ident!("{}", f.name);
+                        let mut field_type =
+                            type_ref(&f.ref_type, self.borrowed);
+                        if f.recursive.get() {
+                            field_type = parse_quote! { Box<#field_type> };
+                        }
+                        syn::Field::parse_named
+                            .parse2(
+                                quote! { pub #field_name: Vec<#field_type> },
+                            )
+                            .unwrap()
+                    })
+                    .collect();
+                let names = fields
+                    .iter()
+                    .map(|f| format_ident!("{}", f.name))
+                    .collect::<Vec<_>>();
+                let first = &names[0];
+                let derives = &self.derives;
+                vec![
+                    parse_quote! {
+                        #[derive(#(#derives),*, Default)]
+                        pub struct #type_ident #generics {
+                            #(#columns),*
+                        }
+                    },
+                    parse_quote! {
+                        impl #generics #type_ident #generics {
+                            pub fn push(&mut self, #elem: #elem_type) {
+                                #(self.#names.push(#elem.#names);)*
+                            }
+                            pub fn len(&self) -> usize {
+                                self.#first.len()
+                            }
+                            pub fn is_empty(&self) -> bool {
+                                self.#first.is_empty()
+                            }
+                        }
+                    },
+                ]
+            }
+            SymbolTypeKind::Text => {
+                let generics = type_generics(&ty.name, self.borrowed);
+                if self.borrows(&ty.name) {
+                    vec![
+                        parse_quote! { pub type #type_ident #generics = &'i Input; },
                     ]
+                } else {
+                    vec![parse_quote! { pub type #type_ident = String; }]
                 }
             }
             SymbolTypeKind::Terminal => unreachable!(),
@@ -311,12 +781,28 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
     fn nonterminal_actions(
         &self,
         nonterminal: &NonTerminal,
-        _settings: &Settings,
+        settings: &Settings,
     ) -> Vec<(String, syn::Item)> {
+        let vis = item_vis(settings);
         let ty = self
             .types
             .get_type(nonterminal.idx.symbol_index(self.term_len));
-        let ret_type = Ident::new(&nonterminal.name, Span::call_site());
+        let ret_type = type_ref(&nonterminal.name, self.borrowed);
+        // Actions returning borrowed values take the context with the input
+        // lifetime.
+        let (generics, ctx_type): (syn::Generics, syn::Type) =
+            if self.borrows(&nonterminal.name) {
+                (parse_quote! { <'i> }, parse_quote! { Ctx<'i> })
+            } else {
+                (parse_quote! {}, parse_quote! { Ctx })
+            };
+        // Fallible actions return `Result` and may reject the input.
+        let fallible = settings.fallible_actions;
+        let ret_type: syn::Type = if fallible {
+            parse_quote! { Result<#ret_type> }
+        } else {
+            parse_quote! { #ret_type }
+        };
 
         match &ty.kind {
             SymbolTypeKind::Enum {
@@ -336,11 +822,16 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                     let action = Ident::new(&action_name, Span::call_site());
                     let args = self.get_action_args(ty, choice);
                     let body = self.get_action_body(ty, target_type, choice);
+                    let body: syn::Expr = if fallible {
+                        parse_quote! { Ok(#body) }
+                    } else {
+                        body
+                    };
 
                     (
                         action_name,
                         parse_quote! {
-                            pub fn #action(_ctx: &Ctx, #(#args),*) -> #ret_type {
+                            #vis fn #action #generics(_ctx: &#ctx_type, #(#args),*) -> #ret_type {
                                 #body
                             }
                         }
@@ -410,17 +901,115 @@ impl ActionsGenerator for ProductionActionsGenerator<'_> {
                         }
                         ChoiceKind::Plain => unreachable!(),
                     };
+                    if fallible {
+                        let last = body.pop().unwrap();
+                        body.push(parse_quote! { Ok(#last) });
+                    }
 
                     (
                         action_name,
                         parse_quote! {
-                            pub fn #action(_ctx: &Ctx, #(#args),*) -> #ret_type {
+                            #vis fn #action #generics(_ctx: &#ctx_type, #(#args),*) -> #ret_type {
                                 #(#body);*
                             }
                         }
                     )
                 })
                 .collect(),
+            // Columns are extended by pushing the fields of each element.
+            SymbolTypeKind::Columns { .. } => ty
+                .choices
+                .iter()
+                .map(|choice| {
+                    let action_name = action_name(nonterminal, choice);
+                    let action = Ident::new(&action_name, Span::call_site());
+                    let args = self.get_action_args(ty, choice);
+                    let columns_ident = Ident::new(&ty.name, Span::call_site());
+                    let columns =
+                        format_ident!("{}", to_snake_case(&nonterminal.name));
+
+                    let mut body: Vec<syn::Stmt> = match &choice.kind {
+                        ChoiceKind::Empty => {
+                            parse_quote! { #columns_ident::default() }
+                        }
+                        ChoiceKind::Struct { fields, .. } => match &fields[..] {
+                            [a, b] => {
+                                let mut a_i = format_ident!("{}", a.name);
+                                let mut b_i = format_ident!("{}", b.name);
+                                // Find which one is the columns
+                                if b.ref_type == nonterminal.name {
+                                    (a_i, b_i) = (b_i, a_i)
+                                }
+                                parse_quote! {
+                                    #a_i.push(#b_i);
+                                    #a_i
+                                }
+                            }
+                            [a] => {
+                                let a_i = format_ident!("{}", a.name);
+                                parse_quote! {
+                                    let mut #columns = #columns_ident::default();
+                                    #columns.push(#a_i);
+                                    #columns
+                                }
+                            }
+                            _ => unreachable!(),
+                        },
+                        ChoiceKind::Ref { ref_type, .. } => {
+                            let i = format_ident!("{}", to_snake_case(ref_type));
+                            parse_quote! {
+                                let mut #columns = #columns_ident::default();
+                                #columns.push(#i);
+                                #columns
+                            }
+                        }
+                        ChoiceKind::Plain => unreachable!(),
+                    };
+                    if fallible {
+                        if let Some(syn::Stmt::Expr(last)) = body.pop() {
+                            body.push(parse_quote! { Ok(#last) });
+                        }
+                    }
+
+                    (
+                        action_name,
+                        parse_quote! {
+                            #vis fn #action #generics(_ctx: &#ctx_type, #(#args),*) -> #ret_type {
+                                #(#body)*
+                            }
+                        }
+                    )
+                })
+                .collect(),
+            // The children are not used as the result is the input spanned
+            // by the production.
+            SymbolTypeKind::Text => ty
+                .choices
+                .iter()
+                .map(|choice| {
+                    let action_name = action_name(nonterminal, choice);
+                    let action = Ident::new(&action_name, Span::call_site());
+                    let args = self.get_text_action_args(choice);
+                    let mut body: syn::Expr =
+                        if self.borrows(&nonterminal.name) {
+                            parse_quote! { &ctx.input().unwrap()[ctx.range()] }
+                        } else {
+                            parse_quote! { ctx.input().unwrap()[ctx.range()].into() }
+                        };
+                    if fallible {
+                        body = parse_quote! { Ok(#body) };
+                    }
+
+                    (
+                        action_name,
+                        parse_quote! {
+                            #vis fn #action #generics(ctx: &#ctx_type, #(#args),*) -> #ret_type {
+                                #body
+                            }
+                        }
+                    )
+                })
+                .collect(),
             SymbolTypeKind::Terminal => unreachable! {},
         }
     }
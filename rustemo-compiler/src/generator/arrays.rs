@@ -46,9 +46,11 @@ impl<'g, 's> PartGenerator<'g, 's> for ArrayPartGenerator {
     ) -> Result<Vec<syn::Stmt>> {
         let parser_definition = &generator.parser_definition;
         let mut ast: Vec<syn::Stmt> = vec![];
+        let vis = generator.vis();
+        let crate_vis = generator.crate_vis();
 
         ast.push(parse_quote! {
-            pub struct #parser_definition {
+            #vis struct #parser_definition {
                 actions: [[[Action<State, ProdKind>; MAX_ACTIONS]; TERMINAL_COUNT]; STATE_COUNT],
                 gotos: [[Option<State>; NONTERMINAL_COUNT]; STATE_COUNT],
                 token_kinds: [[Option<(TokenKind, bool)>; MAX_RECOGNIZERS]; STATE_COUNT],
@@ -142,7 +144,7 @@ impl<'g, 's> PartGenerator<'g, 's> for ArrayPartGenerator {
             .collect();
 
         ast.push(parse_quote! {
-            pub(in crate) static PARSER_DEFINITION: #parser_definition = #parser_definition {
+            #crate_vis static PARSER_DEFINITION: #parser_definition = #parser_definition {
                 actions: [#(#actions),*],
                 gotos: [#(#gotos),*],
                 token_kinds: [#(#token_kinds),*],
@@ -157,6 +159,8 @@ impl<'g, 's> PartGenerator<'g, 's> for ArrayPartGenerator {
             "{}",
             generator.settings.lexical_disamb_grammar_order
         );
+        let ignored_fn = generator.ignored_fn();
+        let trivia_fn = generator.trivia_fn();
         ast.push(parse_quote! {
             impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for #parser_definition {
                 fn actions(&self, state: State, token: TokenKind) -> Vec<Action<State, ProdKind>> {
@@ -177,6 +181,8 @@ impl<'g, 's> PartGenerator<'g, 's> for ArrayPartGenerator {
                 fn grammar_order() -> bool {
                     #grammar_order
                 }
+                #(#ignored_fn)*
+                #(#trivia_fn)*
             }
         });
 
@@ -1,6 +1,8 @@
 use std::iter::repeat;
 
+use proc_macro2::Span;
 use quote::format_ident;
+use rustemo::{Location, Position};
 use syn::parse_quote;
 
 use crate::{
@@ -28,29 +30,46 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
     ) -> Result<Vec<syn::Stmt>> {
         let actions_file = &generator.actions_file;
         let input_type = &generator.input_type;
+        let vis = generator.vis();
+        let std_crate = generator.std_crate();
 
         let mut imports: Vec<syn::Stmt> = vec![];
 
         if let LexerType::Default = generator.settings.lexer_type {
-            let regex: syn::Stmt = if generator.settings.fancy_regex {
-                parse_quote! {
-                    use fancy_regex::Regex;
-                }
-            } else {
-                parse_quote! {
-                    use regex::Regex;
-                }
-            };
-            imports.extend::<Vec<syn::Stmt>>(parse_quote! {
-                #regex
-                use once_cell::sync::Lazy;
-                use rustemo::StringLexer;
+            // Regex crates are used only if there are regex terminals.
+            if generator.has_regex_terminals() {
+                let regex: syn::Stmt = if generator.settings.fancy_regex {
+                    parse_quote! {
+                        use fancy_regex::Regex;
+                    }
+                } else {
+                    parse_quote! {
+                        use regex::Regex;
+                    }
+                };
+                imports.extend::<Vec<syn::Stmt>>(parse_quote! {
+                    #regex
+                    use once_cell::sync::Lazy;
+                });
+            }
+            imports.push(parse_quote! {
+                use rustemo::{StringLexer, TokenRecognizer as TokenRecognizerT};
             });
+            if !regex_set_terminals(generator).is_empty() {
+                imports.push(parse_quote! {
+                    use regex::RegexSet;
+                });
+            }
         }
 
         imports.push(parse_quote! {
             use rustemo::LRBuilder;
         });
+        if generator.settings.fallible_actions {
+            imports.push(parse_quote! {
+                use rustemo::Context as ContextT;
+            });
+        }
         imports.extend::<Vec<syn::Stmt>>(
             match generator.settings.builder_type {
                 BuilderType::Default => parse_quote! {
@@ -60,10 +79,15 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                     use rustemo::{TreeNode, TreeBuilder};
                 },
                 BuilderType::Custom => parse_quote! {
-                    use std::cell::RefCell;
+                    use #std_crate::cell::RefCell;
                 },
             },
         );
+        if generator.settings.generic_tree {
+            imports.push(parse_quote! {
+                use rustemo::TreeBuilder;
+            });
+        }
 
         imports.extend::<Vec<syn::Stmt>>(
             match generator.settings.parser_algo {
@@ -76,23 +100,44 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             },
         );
 
+        // Items of the std prelude are imported from `alloc` in no std mode.
+        // Trace logging is not available in no std mode.
+        let (alloc_imports, colored_import): (Vec<syn::Stmt>, Vec<syn::Stmt>) =
+            if generator.settings.no_std {
+                (
+                    parse_quote! {
+                        extern crate alloc;
+                        #[allow(unused_imports)]
+                        use alloc::{boxed::Box, string::{String, ToString}, vec, vec::Vec};
+                    },
+                    vec![],
+                )
+            } else {
+                (
+                    vec![],
+                    parse_quote! {
+                        #[allow(unused_imports)]
+                        #[cfg(debug_assertions)]
+                        use colored::*;
+                    },
+                )
+            };
+
         let header: Vec<syn::Stmt> = parse_quote! {
             /// Generated by rustemo. Do not edit manually!
-            use std::fmt::Debug;
-            use std::hash::Hash;
+            #(#alloc_imports)*
+            use #std_crate::fmt::Debug;
+            use #std_crate::hash::Hash;
 
             use rustemo::{Result, Input as InputT, Lexer, Token,
-                          TokenRecognizer as TokenRecognizerT,
                           Parser, ParserDefinition, State as StateT, Builder};
             #(#imports)*
             use rustemo::Action::{self, Shift, Reduce, Accept};
             #[allow(unused_imports)]
             use rustemo::debug::{log, logn};
-            #[allow(unused_imports)]
-            #[cfg(debug_assertions)]
-            use colored::*;
+            #(#colored_import)*
 
-            pub type Input = #input_type;
+            #vis type Input = #input_type;
         };
 
         Ok(header)
@@ -106,13 +151,48 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         if !matches!(generator.settings.builder_type, BuilderType::Default) {
             return Ok(ast);
         }
-        let actions_file = &generator.actions_file;
+        let vis = generator.vis();
+
+        // `Copy` is derived for symbol enums whose variants carry only input
+        // references and values of known `Copy` types.
+        let term_copy = generator.grammar.terminals[1..]
+            .iter()
+            .filter(|t| t.reachable.get())
+            .all(|t| match t.output_type.as_deref() {
+                _ if !t.has_content => true,
+                Some(output_type) => is_copy_type(output_type),
+                // Borrowed terminals are input slices.
+                None => generator.borrowed_types.contains(&t.name),
+            });
+        let nonterm_copy = generator
+            .grammar
+            .nonterminals()
+            .iter()
+            .filter(|nt| nt.reachable.get())
+            .all(|nt| nt.no_content);
+        let copy_derive = |copy: bool| -> Vec<syn::Path> {
+            if copy {
+                vec![parse_quote!(Clone), parse_quote!(Copy)]
+            } else {
+                vec![]
+            }
+        };
+        let symbol_derive = copy_derive(term_copy && nonterm_copy);
+        let term_derive = copy_derive(term_copy);
+        let nonterm_derive = copy_derive(nonterm_copy);
+
+        let (nonterm_generics, nonterm_type): (syn::Generics, syn::Type) =
+            if generator.nonterminal_borrows() {
+                (parse_quote! { <'i> }, parse_quote! { NonTerminal<'i> })
+            } else {
+                (parse_quote! {}, parse_quote! { NonTerminal })
+            };
 
         ast.push(parse_quote! {
-            #[derive(Debug)]
-            pub enum Symbol {
-                Terminal(Terminal),
-                NonTerminal(NonTerminal)
+            #[derive(Debug #(, #symbol_derive)*)]
+            #vis enum Symbol<'i> {
+                Terminal(Terminal<'i>),
+                NonTerminal(#nonterm_type)
             }
         });
 
@@ -122,23 +202,64 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             .map(|t| {
                 let name = format_ident!("{}", t.name);
                 if t.has_content {
+                    let ty = generator.action_type(&t.name);
                     parse_quote! {
-                        #name(#actions_file::#name)
+                        #name(&'i Input, #ty)
                     }
                 } else {
                     parse_quote! {
-                        #name
+                        #name(&'i Input)
                     }
                 }
             })
             .collect();
 
-        ast.push(parse_quote! {
+        // Each terminal keeps the matched input so that the text can be
+        // retrieved regardless of the terminal kind.
+        let term_text_arms: Vec<syn::Arm> = generator.grammar.terminals[1..]
+            .iter()
+            .filter(|t| t.reachable.get())
+            .map(|t| {
+                let name = format_ident!("{}", t.name);
+                if t.has_content {
+                    parse_quote! { Terminal::#name(text, _) => text }
+                } else {
+                    parse_quote! { Terminal::#name(text) => text }
+                }
+            })
+            .collect();
+        let term_kind_arms: Vec<syn::Arm> = generator.grammar.terminals[1..]
+            .iter()
+            .filter(|t| t.reachable.get())
+            .map(|t| {
+                let name = format_ident!("{}", t.name);
+                parse_quote! { Terminal::#name(..) => TokenKind::#name }
+            })
+            .collect();
+
+        ast.extend::<Vec<syn::Stmt>>(parse_quote! {
             #[allow(clippy::upper_case_acronyms)]
-            #[derive(Debug)]
-            pub enum Terminal {
+            #[derive(Debug #(, #term_derive)*)]
+            #vis enum Terminal<'i> {
                 #(#term_variants),*
             }
+
+            #[allow(dead_code)]
+            impl<'i> Terminal<'i> {
+                /// The input matched by this terminal.
+                pub fn text(&self) -> &'i Input {
+                    match *self {
+                        #(#term_text_arms),*
+                    }
+                }
+
+                /// The kind of this terminal.
+                pub fn kind(&self) -> TokenKind {
+                    match *self {
+                        #(#term_kind_arms),*
+                    }
+                }
+            }
         });
 
         let nonterm_variants: Vec<syn::Variant> = generator
@@ -148,16 +269,109 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             .filter(|nt| nt.reachable.get())
             .map(|nt| {
                 let name = format_ident!("{}", nt.name);
+                if nt.no_content {
+                    parse_quote! {
+                        #name
+                    }
+                } else {
+                    let ty = generator.action_type(&nt.name);
+                    parse_quote! {
+                        #name(#ty)
+                    }
+                }
+            })
+            .collect();
+
+        let nonterm_kind_arms: Vec<syn::Arm> = generator
+            .grammar
+            .nonterminals()
+            .iter()
+            .filter(|nt| nt.reachable.get())
+            .map(|nt| {
+                let name = format_ident!("{}", nt.name);
+                if nt.no_content {
+                    parse_quote! { NonTerminal::#name => NonTermKind::#name }
+                } else {
+                    parse_quote! { NonTerminal::#name(_) => NonTermKind::#name }
+                }
+            })
+            .collect();
+
+        ast.extend::<Vec<syn::Stmt>>(parse_quote! {
+            #[derive(Debug #(, #nonterm_derive)*)]
+            #vis enum NonTerminal #nonterm_generics {
+                #(#nonterm_variants),*
+            }
+
+            #[allow(dead_code)]
+            impl #nonterm_generics #nonterm_type {
+                /// The kind of this non-terminal.
+                pub fn kind(&self) -> NonTermKind {
+                    match *self {
+                        #(#nonterm_kind_arms),*
+                    }
+                }
+            }
+        });
+
+        // Symbol kind names are the names of the grammar symbols.
+        let kind_name_arms: Vec<syn::Arm> = generator.grammar.terminals[1..]
+            .iter()
+            .filter(|t| t.reachable.get())
+            .map(|t| {
+                let name = format_ident!("{}", t.name);
+                let name_str = &t.name;
                 parse_quote! {
-                    #name(#actions_file::#name)
+                    Symbol::Terminal(Terminal::#name(..)) => #name_str
                 }
             })
+            .chain(
+                generator
+                    .grammar
+                    .nonterminals()
+                    .iter()
+                    .filter(|nt| nt.reachable.get())
+                    .map(|nt| {
+                        let name = format_ident!("{}", nt.name);
+                        let name_str = &nt.name;
+                        if nt.no_content {
+                            parse_quote! {
+                                Symbol::NonTerminal(NonTerminal::#name) => #name_str
+                            }
+                        } else {
+                            parse_quote! {
+                                Symbol::NonTerminal(NonTerminal::#name(_)) => #name_str
+                            }
+                        }
+                    }),
+            )
             .collect();
 
         ast.push(parse_quote! {
-            #[derive(Debug)]
-            pub enum NonTerminal {
-                #(#nonterm_variants),*
+            #[allow(dead_code)]
+            impl<'i> Symbol<'i> {
+                /// The name of the grammar symbol of this symbol.
+                pub fn kind_name(&self) -> &'static str {
+                    match *self {
+                        #(#kind_name_arms),*
+                    }
+                }
+
+                /// The kind of this symbol if it is a terminal.
+                pub fn token_kind(&self) -> Option<TokenKind> {
+                    match self {
+                        Symbol::Terminal(t) => Some(t.kind()),
+                        Symbol::NonTerminal(_) => None,
+                    }
+                }
+
+                /// The kind of this symbol if it is a non-terminal.
+                pub fn nonterm_kind(&self) -> Option<NonTermKind> {
+                    match self {
+                        Symbol::Terminal(_) => None,
+                        Symbol::NonTerminal(nt) => Some(nt.kind()),
+                    }
+                }
             }
         });
 
@@ -169,6 +383,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         generator: &ParserGenerator<'g, 's>,
     ) -> Result<Vec<syn::Stmt>> {
         let mut ast: Vec<syn::Stmt> = vec![];
+        let vis = generator.vis();
 
         let token_kind_variants: Vec<syn::Variant> = generator
             .grammar
@@ -183,7 +398,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         ast.extend::<Vec<_>>(parse_quote! {
             #[allow(clippy::upper_case_acronyms)]
             #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-            pub enum TokenKind {
+            #vis enum TokenKind {
                 #[default]
                 #(#token_kind_variants),*
             }
@@ -210,7 +425,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         ast.extend::<Vec<_>>(parse_quote! {
             #[allow(clippy::enum_variant_names)]
             #[derive(Clone, Copy, PartialEq)]
-            pub enum ProdKind {
+            #vis enum ProdKind {
                 #(#prodkind_variants),*
             }
             use ProdKind as PK;
@@ -226,9 +441,10 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                 parse_quote! { ProdKind::#prod_kind_ident => #prod_str }
             })
             .collect();
+        let std_crate = generator.std_crate();
         ast.push(parse_quote! {
-            impl std::fmt::Debug for ProdKind {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            impl #std_crate::fmt::Debug for ProdKind {
+                fn fmt(&self, f: &mut #std_crate::fmt::Formatter<'_>) -> #std_crate::fmt::Result {
                     let name = match self {
                         #(#display_arms),*
                     };
@@ -237,20 +453,39 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             }
         });
 
-        let nonterm_kind_variants: Vec<syn::Variant> = generator
-            .grammar
-            .nonterminals
-            .iter()
-            .map(|nt| {
-                let nt_kind = format_ident!("{}", nt.name);
-                parse_quote! {#nt_kind}
-            })
-            .collect();
+        // Synthetic non-terminals are never reduced so they may be left out.
+        // Explicit discriminants keep the indexes used in GOTO tables.
+        let nonterm_kind_variants: Vec<syn::Variant> =
+            if generator.settings.exclude_synthetic {
+                generator
+                    .grammar
+                    .nonterminals()
+                    .iter()
+                    .map(|nt| {
+                        let nt_kind = format_ident!("{}", nt.name);
+                        let idx = syn::LitInt::new(
+                            &nt.idx.0.to_string(),
+                            Span::call_site(),
+                        );
+                        parse_quote! {#nt_kind = #idx}
+                    })
+                    .collect()
+            } else {
+                generator
+                    .grammar
+                    .nonterminals
+                    .iter()
+                    .map(|nt| {
+                        let nt_kind = format_ident!("{}", nt.name);
+                        parse_quote! {#nt_kind}
+                    })
+                    .collect()
+            };
         ast.push(parse_quote! {
             #[allow(clippy::upper_case_acronyms)]
             #[allow(dead_code)]
             #[derive(Clone, Copy, Debug)]
-            pub enum NonTermKind {
+            #vis enum NonTermKind {
                 #(#nonterm_kind_variants),*
             }
         });
@@ -278,6 +513,60 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             }
         });
 
+        let rhs_len_arms: Vec<syn::Arm> = generator
+            .grammar
+            .productions()
+            .iter()
+            .map(|&prod| {
+                let prod_kind = generator.prod_kind_ident(prod);
+                let rhs_len = prod.rhs.len();
+                parse_quote! { ProdKind::#prod_kind => #rhs_len }
+            })
+            .collect();
+        // Productions not given in the grammar (e.g. the augmented production)
+        // are at (0, 0).
+        let source_location_arms: Vec<syn::Arm> = generator
+            .grammar
+            .productions()
+            .iter()
+            .map(|&prod| {
+                let prod_kind = generator.prod_kind_ident(prod);
+                let (line, column) = match prod.location {
+                    Some(Location {
+                        start: Position::LineBased(lc),
+                        ..
+                    }) => (lc.line as u32, lc.column as u32),
+                    _ => (0, 0),
+                };
+                parse_quote! { ProdKind::#prod_kind => (#line, #column) }
+            })
+            .collect();
+        ast.push(parse_quote! {
+            #[allow(dead_code)]
+            impl ProdKind {
+                /// The non-terminal this production reduces to.
+                pub fn nonterminal(&self) -> NonTermKind {
+                    (*self).into()
+                }
+
+                /// The number of symbols on the right-hand side of this
+                /// production.
+                pub fn rhs_len(&self) -> usize {
+                    match self {
+                        #(#rhs_len_arms),*
+                    }
+                }
+
+                /// The line and the column in the grammar file where this
+                /// production is defined.
+                pub fn source_location(&self) -> (u32, u32) {
+                    match self {
+                        #(#source_location_arms),*
+                    }
+                }
+            }
+        });
+
         let state_variants: Vec<syn::Variant> = generator
             .table
             .states
@@ -290,7 +579,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         ast.push(parse_quote! {
             #[allow(clippy::enum_variant_names)]
             #[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-            pub enum State {
+            #vis enum State {
                 #[default]
                 #(#state_variants),*
             }
@@ -304,10 +593,33 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             Some(state) => parse_quote! { Some(State::#state) },
             None => parse_quote! { None },
         };
-        ast.push(parse_quote! {
-            impl StateT for State {
-                fn default_layout() -> Option<Self> {
-                    #layout_state
+        let no_layout_states: Vec<syn::Pat> = generator
+            .table
+            .states
+            .iter()
+            .filter(|state| state.no_layout())
+            .map(|state| {
+                let state_kind = generator.state_kind_ident(state.idx);
+                parse_quote! { State::#state_kind }
+            })
+            .collect();
+        ast.push(if no_layout_states.is_empty() {
+            parse_quote! {
+                impl StateT for State {
+                    fn default_layout() -> Option<Self> {
+                        #layout_state
+                    }
+                }
+            }
+        } else {
+            parse_quote! {
+                impl StateT for State {
+                    fn default_layout() -> Option<Self> {
+                        #layout_state
+                    }
+                    fn no_layout(&self) -> bool {
+                        matches!(self, #(#no_layout_states)|*)
+                    }
                 }
             }
         });
@@ -335,9 +647,10 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             })
             .collect();
 
+        let std_crate = generator.std_crate();
         ast.push(parse_quote!{
-            impl std::fmt::Debug for State {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            impl #std_crate::fmt::Debug for State {
+                fn fmt(&self, f: &mut #std_crate::fmt::Formatter<'_>) -> #std_crate::fmt::Result {
                     let name = match self {
                         #(#state_display_arms),*,
                     };
@@ -355,14 +668,17 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
     ) -> Result<Vec<syn::Stmt>> {
         let parser = &generator.parser;
         let parser_definition = &generator.parser_definition;
+        let token_attr = &generator.token_attr_type;
+        let vis = generator.vis();
+        let crate_vis = generator.crate_vis();
         let mut ast: Vec<syn::Stmt> = vec![];
         // Context type
         ast.push(match generator.settings.parser_algo {
             ParserAlgo::LR => parse_quote!{
-                pub(crate) type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
+                #crate_vis type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
             },
             ParserAlgo::GLR => parse_quote!{
-                pub(crate) type Context<'i, I> = GssHead<'i, I, State, TokenKind>;
+                #crate_vis type Context<'i, I> = GssHead<'i, I, State, TokenKind>;
             },
         });
 
@@ -376,6 +692,13 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             generator.settings.skip_ws && !generator.grammar.has_layout();
 
         let lexer_instance: syn::Expr = match generator.settings.lexer_type {
+            LexerType::Default
+                if !regex_set_terminals(generator).is_empty() =>
+            {
+                parse_quote! {
+                    StringLexer::new(#skip_ws, &RECOGNIZERS).prefilter(regex_prefilter)
+                }
+            }
             LexerType::Default => parse_quote! {
                 StringLexer::new(#skip_ws, &RECOGNIZERS)
             },
@@ -389,6 +712,11 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             BuilderType::Default => parse_quote! {
                 DefaultBuilder::new()
             },
+            BuilderType::Generic if generator.settings.collapse_chains => {
+                parse_quote! {
+                    TreeBuilder::new().collapse_chains(true)
+                }
+            }
             BuilderType::Generic => parse_quote! {
                 TreeBuilder::new()
             },
@@ -398,10 +726,23 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         };
 
         let has_layout = generator.grammar.has_layout();
+        // Reductions guarded by semantic predicates are checked by the
+        // generated `predicate` function.
+        let predicate: Vec<syn::Ident> = (generator.grammar.has_predicates()
+            && matches!(generator.settings.parser_algo, ParserAlgo::LR))
+        .then(|| format_ident!("predicate"))
+        .into_iter()
+        .collect();
         let parser_instance: syn::Expr = match generator.settings.parser_algo {
+            ParserAlgo::LR if generator.settings.profile => parse_quote! {
+                LRParser::new(&PARSER_DEFINITION, State::default(), #partial_parse, #has_layout,
+                              #lexer_instance, #builder_instance).profile(true)
+                    #(.predicate(#predicate))*
+            },
             ParserAlgo::LR => parse_quote! {
                 LRParser::new(&PARSER_DEFINITION, State::default(), #partial_parse, #has_layout,
                               #lexer_instance, #builder_instance)
+                    #(.predicate(#predicate))*
             },
             ParserAlgo::GLR => parse_quote! {
                 GlrParser::new(&PARSER_DEFINITION, #partial_parse,
@@ -426,119 +767,542 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             LexerType::Custom => {
                 parser_impl_generics.params.push(parse_quote! { L });
                 parser_type_params.push(parse_quote! { L });
-                where_clause.push(parse_quote!{L: Lexer<'i, Context<'i, Input>, State, TokenKind, Input = Input> });
+                where_clause.push(parse_quote!{L: Lexer<'i, Context<'i, Input>, State, TokenKind #(, #token_attr)*, Input = Input> });
                 new_parameters.push(parse_quote! { lexer: L });
             }
         }
-        match generator.settings.builder_type {
-            BuilderType::Default => {
-                parser_type_params.push(parse_quote! { DefaultBuilder });
-            }
+        let builder_type: syn::Type = match generator.settings.builder_type {
+            BuilderType::Default => parse_quote! { DefaultBuilder<'i> },
             BuilderType::Generic => {
-                parser_type_params.push(
-                    parse_quote! { TreeBuilder<'i, Input, ProdKind, TokenKind> },
-                );
+                parse_quote! { TreeBuilder<'i, Input, ProdKind, TokenKind> }
+            }
+            BuilderType::Custom => parse_quote! { B },
+        };
+        parser_type_params.push(parse_quote! { #builder_type });
+        // Generated parsers in no std mode don't implement `Parser`.
+        let parse_output: syn::Type = if generator.settings.no_std {
+            parse_quote! { <#builder_type as Builder>::Output }
+        } else {
+            parse_quote! {
+                <Self as Parser<'i, Input, Context<'i, Input>, State, TokenKind>>::Output
             }
+        };
+        match generator.settings.builder_type {
+            BuilderType::Default | BuilderType::Generic => {}
             BuilderType::Custom => {
                 parser_impl_generics.params.push(parse_quote! { B });
-                parser_type_params.push(parse_quote! { B });
                 where_clause.push(
                     parse_quote! { B: LRBuilder<'i, Input, Context<'i, Input>,
-                    State, ProdKind, TokenKind> },
+                    State, ProdKind, TokenKind #(, #token_attr)*> },
                 );
                 new_parameters.push(parse_quote! { builder: B });
             }
         }
 
-        let parser_type: syn::Type =
-            if let ParserAlgo::LR = generator.settings.parser_algo {
-                parse_quote! {
-                    LRParser<'i, Context<'i, I>, State, ProdKind,
-                        TokenKind, NonTermKind, #parser_definition, L, B, I>
-                }
-            } else {
-                parse_quote! {
-                    GlrParser<'i, State, L, ProdKind, TokenKind, NonTermKind,
-                              #parser_definition, I, B>
-                }
-            };
-        ast.push(parse_quote! {
-            pub struct #parser <'i, I: InputT + ?Sized, L: Lexer<'i, Context<'i, I>,
-                                State, TokenKind, Input = I>, B>(#parser_type);
-        });
-
-        ast.push(if where_clause.is_empty() {
+        let parser_type: syn::Type = if let ParserAlgo::LR =
+            generator.settings.parser_algo
+        {
             parse_quote! {
-                #[allow(dead_code)]
-                impl #parser_impl_generics #parser <#(#parser_type_params),*>
-                {
-                    pub fn new(#(#new_parameters),*) -> Self {
-                        Self(#parser_instance)
-                    }
-                }
+                LRParser<'i, Context<'i, I>, State, ProdKind,
+                    TokenKind, NonTermKind, #parser_definition, L, B, I #(, #token_attr)*>
             }
         } else {
             parse_quote! {
-                #[allow(dead_code)]
-                impl #parser_impl_generics #parser <#(#parser_type_params),*>
-                where
-                    #(#where_clause),*
-                {
-                    pub fn new(#(#new_parameters),*) -> Self {
-                        Self(#parser_instance)
-                    }
-                }
+                GlrParser<'i, State, L, ProdKind, TokenKind, NonTermKind,
+                          #parser_definition, I, B>
             }
+        };
+        ast.push(parse_quote! {
+            #vis struct #parser <'i, I: InputT + ?Sized, L: Lexer<'i, Context<'i, I>,
+                                State, TokenKind #(, #token_attr)*, Input = I>, B>(#parser_type);
         });
 
-        let output_type: syn::Type =
-            if let ParserAlgo::GLR = generator.settings.parser_algo {
-                parse_quote! {
-                    Forest<'i, I, ProdKind, TokenKind>
+        // `parse_all` returns all outputs regardless of the parsing algorithm.
+        // For GLR, each tree from the forest is built using a new builder
+        // instance so it is not available for custom builders.
+        let mut parser_methods: Vec<syn::ImplItem> = vec![];
+        match generator.settings.parser_algo {
+            ParserAlgo::LR => parser_methods.push(parse_quote! {
+                pub fn parse_all(&self, input: &'i Input)
+                                 -> Result<Vec<<#builder_type as Builder>::Output>> {
+                    self.0.parse(input).map(|output| vec![output])
                 }
-            } else {
-                parse_quote! {
-                    B::Output
+            }),
+            ParserAlgo::GLR => {
+                if !matches!(
+                    generator.settings.builder_type,
+                    BuilderType::Custom
+                ) {
+                    parser_methods.push(parse_quote! {
+                        pub fn parse_all(&self, input: &'i Input)
+                                         -> Result<Vec<<#builder_type as Builder>::Output>> {
+                            let forest = self.0.parse(input)?;
+                            let mut builder = #builder_instance;
+                            Ok(forest
+                               .iter()
+                               .map(|tree| {
+                                   tree.build::<#builder_type, Context<'i, Input>, State>(
+                                       &mut builder)
+                               })
+                               .collect())
+                        }
+                    })
                 }
-            };
+            }
+        }
 
-        ast.push(parse_quote! {
-            #[allow(dead_code)]
-            impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind> for #parser <'i, I, L, B>
-            where
-                I: InputT + ?Sized + Debug,
-                L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
-                B: LRBuilder<'i, I, Context<'i, I>, State, ProdKind, TokenKind>
-            {
-                type Output = #output_type;
+        if let ParserAlgo::GLR = generator.settings.parser_algo {
+            parser_methods.push(parse_quote! {
+                pub fn max_solutions(self, max_solutions: usize) -> Self {
+                    Self(self.0.max_solutions(max_solutions))
+                }
+            });
+            parser_methods.push(parse_quote! {
+                pub fn max_heads(self, max_heads: usize) -> Self {
+                    Self(self.0.max_heads(max_heads))
+                }
+            });
+            parser_methods.push(parse_quote! {
+                pub fn ambiguity_is_error(self, ambiguity_is_error: bool) -> Self {
+                    Self(self.0.ambiguity_is_error(ambiguity_is_error))
+                }
+            })
+        }
 
-                fn parse(&self, input: &'i I) -> Result<Self::Output> {
-                    self.0.parse(input)
+        // `parse_str` is a convenience alias of `parse` for string inputs.
+        if generator.settings.input_type == "str" {
+            parser_methods.push(parse_quote! {
+                pub fn parse_str(&self, input: &'i str)
+                    -> Result<#parse_output> {
+                    self.parse(input)
                 }
+            })
+        }
 
-                fn parse_with_context(
-                    &self,
-                    context: &mut Context<'i, I>,
-                    input: &'i I,
-                ) -> Result<Self::Output> {
-                    self.0.parse_with_context(context, input)
+        // `parse_preprocessed` owns the preprocessed input, like `parse_file`.
+        if generator.settings.input_type == "str"
+            && (generator.settings.strip_bom
+                || generator.settings.normalize_newlines)
+        {
+            let strip_bom = generator.settings.strip_bom;
+            let normalize_newlines = generator.settings.normalize_newlines;
+            parser_methods.push(parse_quote! {
+                pub fn parse_preprocessed<'a>(&'a mut self, input: &str)
+                    -> Result<#parse_output>
+                where
+                    'a: 'i {
+                    self.0.parse_owned(
+                        rustemo::preprocess(input, #strip_bom, #normalize_newlines).into_owned())
                 }
+            })
+        }
 
-                fn parse_file<'a, F: AsRef<std::path::Path>>(
-                    &'a mut self,
-                    file: F,
-                ) -> Result<Self::Output>
+        // `parse_normalized` also returns the map of positions in the
+        // normalized input back to the given input.
+        if generator.settings.input_type == "str"
+            && generator.settings.normalize_nfc
+        {
+            let strip_bom = generator.settings.strip_bom;
+            let normalize_newlines = generator.settings.normalize_newlines;
+            parser_methods.push(parse_quote! {
+                #[allow(clippy::type_complexity)]
+                pub fn parse_normalized<'a>(&'a mut self, input: &str)
+                    -> Result<(#parse_output, rustemo::OffsetMap)>
                 where
                     'a: 'i {
-                    self.0.parse_file(file)
+                    let input = rustemo::preprocess(input, #strip_bom, #normalize_newlines);
+                    let (input, offsets) = rustemo::normalize_nfc(&input);
+                    let input = input.into_owned();
+                    Ok((self.0.parse_owned(input)?, offsets))
                 }
-            }
-        });
+            })
+        }
 
-        Ok(ast)
-    }
+        // `tokenize` drives the lexer by the LR automaton the same way as
+        // parsing does but only collects the shifted tokens.
+        if let ParserAlgo::LR = generator.settings.parser_algo {
+            let lexer_parameter: Vec<syn::FnArg> =
+                match generator.settings.lexer_type {
+                    LexerType::Default => vec![],
+                    LexerType::Custom => vec![parse_quote! { lexer: L }],
+                };
+            parser_methods.push(parse_quote! {
+                pub fn tokenize(#(#lexer_parameter,)* input: &'i Input)
+                                -> Result<Vec<Token<'i, Input, TokenKind #(, #token_attr)*>>> {
+                    LRParser::new(&PARSER_DEFINITION, State::default(), #partial_parse, #has_layout,
+                                  #lexer_instance, rustemo::TokensBuilder::new())
+                        #(.predicate(#predicate))*
+                        .parse(input)
+                }
+            })
+        }
 
-    fn lexer_definition(
+        // `parse_generic` builds the generic tree using the same tables and
+        // lexer as `parse`.
+        if generator.settings.generic_tree
+            && matches!(generator.settings.parser_algo, ParserAlgo::LR)
+        {
+            let lexer_parameter: Vec<syn::FnArg> =
+                match generator.settings.lexer_type {
+                    LexerType::Default => vec![],
+                    LexerType::Custom => vec![parse_quote! { lexer: L }],
+                };
+            parser_methods.push(parse_quote! {
+                pub fn parse_generic(#(#lexer_parameter,)* input: &'i Input)
+                                     -> Result<<TreeBuilder<'i, Input, ProdKind, TokenKind> as Builder>::Output> {
+                    LRParser::new(&PARSER_DEFINITION, State::default(), #partial_parse, #has_layout,
+                                  #lexer_instance, TreeBuilder::new())
+                        #(.predicate(#predicate))*
+                        .parse(input)
+                }
+            })
+        }
+
+        // `parse_iter` yields the items of the start rule repetition as soon
+        // as they are reduced.
+        if let Some((_, item)) = generator
+            .stream_list()
+            .filter(|_| generator.settings.streaming)
+        {
+            let item = generator.action_type(item);
+            let lexer_parameter: Vec<syn::FnArg> =
+                match generator.settings.lexer_type {
+                    LexerType::Default => vec![],
+                    LexerType::Custom => vec![parse_quote! { lexer: L }],
+                };
+            parser_methods.push(parse_quote! {
+                pub fn parse_iter(#(#lexer_parameter,)* input: &'i Input)
+                                  -> impl Iterator<Item = Result<#item>> + 'i {
+                    LRParser::new(&PARSER_DEFINITION, State::default(), #partial_parse, #has_layout,
+                                  #lexer_instance, DefaultStreamBuilder::new())
+                        #(.predicate(#predicate))*
+                        .parse_iter(input)
+                }
+            })
+        }
+
+        // `parse_<rule>` starts parsing from the start state of the augmented
+        // rule. Used in tests only.
+        if generator.settings.rule_parsers
+            && matches!(generator.settings.parser_algo, ParserAlgo::LR)
+        {
+            let lexer_parameter: Vec<syn::FnArg> =
+                match generator.settings.lexer_type {
+                    LexerType::Default => vec![],
+                    LexerType::Custom => vec![parse_quote! { lexer: L }],
+                };
+            for &(aug, rule) in &generator.grammar.augmented_rule_indexes {
+                let nonterminal = generator.grammar.symbol_to_nonterm(rule);
+                if !nonterminal.reachable.get() {
+                    continue;
+                }
+                let state = generator
+                    .table
+                    .states
+                    .iter()
+                    .find(|s| s.symbol == aug)
+                    .expect("Augmented rule start state must exist.");
+                let state = generator.state_kind_ident(state.idx);
+                let method =
+                    format_ident!("parse_{}", to_snake_case(&nonterminal.name));
+                let name = format_ident!("{}", nonterminal.name);
+                let (output, result): (syn::Type, syn::Arm) = if nonterminal
+                    .no_content
+                {
+                    (
+                        parse_quote! { () },
+                        parse_quote! { Some(NonTerminal::#name) => Ok(()) },
+                    )
+                } else {
+                    (
+                        generator.action_type(&nonterminal.name),
+                        parse_quote! { Some(NonTerminal::#name(r)) => Ok(r) },
+                    )
+                };
+                parser_methods.push(parse_quote! {
+                    #[cfg(test)]
+                    pub fn #method(#(#lexer_parameter,)* input: &'i Input) -> Result<#output> {
+                        let result = LRParser::new(&PARSER_DEFINITION, State::#state, #partial_parse,
+                                                   #has_layout, #lexer_instance, DefaultRuleBuilder::new())
+                            #(.predicate(#predicate))*
+                            .parse(input)?;
+                        match result {
+                            #result,
+                            _ => Err(rustemo::Error::Error {
+                                message: "Invalid result on the parse stack!".into(),
+                                file: None,
+                                location: None,
+                            }),
+                        }
+                    }
+                })
+            }
+        }
+
+        if matches!(generator.settings.parser_algo, ParserAlgo::LR) {
+            parser_methods.push(parse_quote! {
+                pub fn max_depth(self, max_depth: usize) -> Self {
+                    Self(self.0.max_depth(max_depth))
+                }
+            });
+            parser_methods.push(parse_quote! {
+                pub fn parse_opts(&self, input: &'i Input, opts: rustemo::ParseOpts)
+                                  -> Result<<#builder_type as Builder>::Output> {
+                    self.0.parse_opts(input, opts)
+                }
+            });
+            parser_methods.push(parse_quote! {
+                pub fn parse_with_driver(&self, input: &'i Input,
+                                         driver: &mut impl rustemo::ParseDriver<'i, Context<'i, Input>, ProdKind,
+                                                                                Input, TokenKind #(, #token_attr)*>)
+                                         -> Result<<#builder_type as Builder>::Output> {
+                    self.0.parse_with_driver(input, driver)
+                }
+            });
+            parser_methods.push(parse_quote! {
+                pub fn completions(&self, prefix: &'i Input) -> Vec<TokenKind> {
+                    self.0.completions(prefix).unwrap_or_default()
+                }
+            });
+            parser_methods.push(parse_quote! {
+                pub fn max_lex_bytes(self, max_lex_bytes: usize) -> Self {
+                    Self(self.0.max_lex_bytes(max_lex_bytes))
+                }
+            });
+            if !generator.settings.no_std {
+                parser_methods.push(parse_quote! {
+                    pub fn lex_timeout(self, lex_timeout: std::time::Duration) -> Self {
+                        Self(self.0.lex_timeout(lex_timeout))
+                    }
+                })
+            }
+            if generator.grammar.has_layout() {
+                parser_methods.push(parse_quote! {
+                    #[allow(clippy::type_complexity)]
+                    pub fn parse_with_layout(&self, input: &'i Input)
+                                             -> Result<(<#builder_type as Builder>::Output,
+                                                        Vec<rustemo::LayoutSegment<'i, Input>>)> {
+                        self.0.parse_with_layout(input)
+                    }
+                })
+            }
+        }
+
+        if generator.settings.profile
+            && matches!(generator.settings.parser_algo, ParserAlgo::LR)
+        {
+            parser_methods.push(parse_quote! {
+                pub fn parse_with_stats(&self, input: &'i Input)
+                                        -> Result<(<#builder_type as Builder>::Output, rustemo::ParseStats)> {
+                    let result = self.0.parse(input)?;
+                    Ok((result, self.0.stats().unwrap_or_default()))
+                }
+            })
+        }
+
+        // `parse_bytes_lossy` is used for parsing arbitrary bytes (e.g. in
+        // fuzzing). Invalid UTF-8 sequences are replaced before parsing.
+        // The output of borrowed terminals can't outlive the converted input.
+        if generator.settings.no_panic
+            && !generator.settings.borrowed_terminals
+            && matches!(generator.settings.parser_algo, ParserAlgo::LR)
+            && matches!(generator.settings.lexer_type, LexerType::Default)
+            && matches!(generator.settings.builder_type, BuilderType::Default)
+        {
+            parser_methods.push(parse_quote! {
+                pub fn parse_bytes_lossy(input: &[u8])
+                                         -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+                    let input = String::from_utf8_lossy(input);
+                    #parser::new().parse(&input)
+                }
+            })
+        }
+
+        // `parse_tokens` parses the tokens of an external tokenizer. The tokens
+        // are replayed by `TokensLexer` over the concatenation of their values
+        // which can't outlive the call.
+        if !generator.settings.borrowed_terminals
+            && generator.settings.input_type == "str"
+            && matches!(generator.settings.parser_algo, ParserAlgo::LR)
+            && matches!(generator.settings.lexer_type, LexerType::Default)
+            && matches!(generator.settings.builder_type, BuilderType::Default)
+        {
+            parser_methods.push(parse_quote! {
+                pub fn parse_tokens(&self, tokens: &[Token<'_, str, TokenKind #(, #token_attr)*>])
+                                    -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+                    let input = rustemo::TokensLexer::input(tokens);
+                    LRParser::new(&PARSER_DEFINITION, State::default(), #partial_parse, false,
+                                  rustemo::TokensLexer::new(tokens), DefaultBuilder::new())
+                        #(.predicate(#predicate))*
+                        .parse(&input)
+                }
+            })
+        }
+
+        // `parse_stream` awaits the bytes of an async stream and parses them
+        // when the stream ends. The output can't borrow from the input
+        // collected inside the call.
+        if generator.settings.async_stream
+            && matches!(generator.settings.parser_algo, ParserAlgo::LR)
+        {
+            parser_methods.push(parse_quote! {
+                pub async fn parse_stream(stream: impl rustemo::Stream<Item = u8>)
+                                          -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+                    let input = rustemo::read_stream(stream).await?;
+                    #parser::new().parse(&input)
+                }
+            })
+        }
+
+        ast.push(if where_clause.is_empty() {
+            parse_quote! {
+                #[allow(dead_code)]
+                impl #parser_impl_generics #parser <#(#parser_type_params),*>
+                {
+                    pub fn new(#(#new_parameters),*) -> Self {
+                        Self(#parser_instance)
+                    }
+
+                    #(#parser_methods)*
+                }
+            }
+        } else {
+            parse_quote! {
+                #[allow(dead_code)]
+                impl #parser_impl_generics #parser <#(#parser_type_params),*>
+                where
+                    #(#where_clause),*
+                {
+                    pub fn new(#(#new_parameters),*) -> Self {
+                        Self(#parser_instance)
+                    }
+
+                    #(#parser_methods)*
+                }
+            }
+        });
+
+        let output_type: syn::Type =
+            if let ParserAlgo::GLR = generator.settings.parser_algo {
+                parse_quote! {
+                    Forest<'i, I, ProdKind, TokenKind>
+                }
+            } else {
+                parse_quote! {
+                    B::Output
+                }
+            };
+
+        // `Parser` requires `parse_file` if `rustemo` is built with `std`. In
+        // no std mode the parse methods are inherent so that the generated
+        // code doesn't depend on the features of `rustemo`.
+        ast.push(if generator.settings.no_std {
+            parse_quote! {
+                #[allow(dead_code)]
+                impl<'i, I, L, B> #parser <'i, I, L, B>
+                where
+                    I: InputT + ?Sized + Debug,
+                    L: Lexer<'i, Context<'i, I>, State, TokenKind #(, #token_attr)*, Input = I>,
+                    B: LRBuilder<'i, I, Context<'i, I>, State, ProdKind, TokenKind #(, #token_attr)*>
+                {
+                    pub fn parse(&self, input: &'i I) -> Result<#output_type> {
+                        self.0.parse(input)
+                    }
+
+                    pub fn parse_with_context(
+                        &self,
+                        context: &mut Context<'i, I>,
+                        input: &'i I,
+                    ) -> Result<#output_type> {
+                        self.0.parse_with_context(context, input)
+                    }
+                }
+            }
+        } else {
+            parse_quote! {
+                #[allow(dead_code)]
+                impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind> for #parser <'i, I, L, B>
+                where
+                    I: InputT + ?Sized + Debug,
+                    L: Lexer<'i, Context<'i, I>, State, TokenKind #(, #token_attr)*, Input = I>,
+                    B: LRBuilder<'i, I, Context<'i, I>, State, ProdKind, TokenKind #(, #token_attr)*>
+                {
+                    type Output = #output_type;
+
+                    fn parse(&self, input: &'i I) -> Result<Self::Output> {
+                        self.0.parse(input)
+                    }
+
+                    fn parse_with_context(
+                        &self,
+                        context: &mut Context<'i, I>,
+                        input: &'i I,
+                    ) -> Result<Self::Output> {
+                        self.0.parse_with_context(context, input)
+                    }
+
+                    fn parse_file<'a, F: AsRef<std::path::Path>>(
+                        &'a mut self,
+                        file: F,
+                    ) -> Result<Self::Output>
+                    where
+                        'a: 'i {
+                        self.0.parse_file(file)
+                    }
+                }
+            }
+        });
+
+        // `FromStr` for the root type parses the whole string. The output
+        // can't borrow from the input.
+        if generator.settings.impl_fromstr {
+            let std_crate = generator.std_crate();
+            let root_type =
+                generator.action_type(&generator.root_symbol.to_string());
+            ast.push(parse_quote! {
+                impl #std_crate::str::FromStr for #root_type {
+                    type Err = rustemo::Error;
+
+                    fn from_str(input: &str) -> Result<Self> {
+                        #parser::new().parse(input)
+                    }
+                }
+            });
+        }
+
+        // Predicates are user functions from the predicates module. A
+        // reduction by a guarded production is done only if its predicate
+        // holds for the current context and the token ahead.
+        if !predicate.is_empty() {
+            let predicates_file = &generator.predicates_file;
+            let predicate_arms: Vec<syn::Arm> = generator
+                .grammar
+                .productions()
+                .iter()
+                .filter_map(|prod| {
+                    let predicate =
+                        format_ident!("{}", prod.predicate.as_ref()?);
+                    let prod_kind = generator.prod_kind_ident(prod);
+                    Some(parse_quote! {
+                        ProdKind::#prod_kind => super::#predicates_file::#predicate(context, input, token)
+                    })
+                })
+                .collect();
+            ast.push(parse_quote! {
+                fn predicate<'i>(context: &Context<'i, Input>, prod: ProdKind, input: &'i Input,
+                                 token: &Token<'i, Input, TokenKind #(, #token_attr)*>) -> bool {
+                    match prod {
+                        #(#predicate_arms,)*
+                        _ => true,
+                    }
+                }
+            });
+        }
+
+        Ok(ast)
+    }
+
+    fn lexer_definition(
         &self,
         generator: &ParserGenerator<'g, 's>,
     ) -> Result<Vec<syn::Stmt>> {
@@ -546,20 +1310,72 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         if !matches!(generator.settings.lexer_type, LexerType::Default) {
             return Ok(ast);
         }
+        let vis = generator.vis();
+        let crate_vis = generator.crate_vis();
+
+        // Terminals without recognizer are recognized by user provided
+        // functions if custom recognizers are used.
+        let custom_recognizers = generator.settings.custom_recognizers
+            && generator
+                .grammar
+                .terminals
+                .iter()
+                .any(|term| term.name != "STOP" && term.recognizer.is_none());
+
+        // Keywords are recognized only if the shadowed identifier terminal
+        // doesn't match a longer input.
+        let keywords = generator
+            .grammar
+            .terminals
+            .iter()
+            .any(|term| term.keyword_of.is_some());
 
+        let regex_terminals = generator.has_regex_terminals();
+        let str_alternatives = generator.grammar.terminals.iter().any(|term| {
+            matches!(term.recognizer, Some(Recognizer::StrAlternatives(_)))
+        });
+        let raw_terminals = generator.grammar.terminals.iter().any(|term| {
+            matches!(term.recognizer, Some(Recognizer::RawTerm(_)))
+        });
+        let mut recognizer_variants: Vec<syn::Variant> = vec![
+            parse_quote! { Stop },
+            parse_quote! { StrMatch(&'static str) },
+        ];
+        if regex_terminals {
+            recognizer_variants.push(parse_quote! { RegexMatch(Lazy<Regex>) });
+        }
+        if raw_terminals {
+            recognizer_variants.push(parse_quote! {
+                RawMatch(&'static str, &'static str, bool)
+            });
+        }
+        if str_alternatives {
+            recognizer_variants.push(parse_quote! {
+                StrAlternatives(&'static [&'static str])
+            });
+        }
+        if keywords {
+            recognizer_variants.push(parse_quote! {
+                KeywordMatch(&'static str, usize)
+            });
+        }
+        if custom_recognizers {
+            recognizer_variants.push(parse_quote! {
+                #[allow(clippy::type_complexity)]
+                Custom(for<'i> fn(&Context<'i, Input>, &'i str) -> Option<(usize, &'i str)>)
+            });
+        }
         ast.push(parse_quote! {
             #[allow(dead_code)]
             #[derive(Debug)]
-            pub enum Recognizer {
-                Stop,
-                StrMatch(&'static str),
-                RegexMatch(Lazy<Regex>)
+            #vis enum Recognizer {
+                #(#recognizer_variants),*
             }
         });
         ast.push(parse_quote! {
             #[allow(dead_code)]
             #[derive(Debug)]
-            pub struct TokenRecognizer(TokenKind, Recognizer);
+            #vis struct TokenRecognizer(TokenKind, Recognizer);
         });
 
         let regex: syn::Expr = if generator.settings.fancy_regex {
@@ -572,10 +1388,139 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             }
         };
 
+        let mut raw_arms: Vec<syn::Arm> = vec![];
+        if raw_terminals {
+            raw_arms.push(parse_quote! {
+                #[allow(unused_variables)]
+                TokenRecognizer(token_kind, Recognizer::RawMatch(start, end, nested)) => {
+                    logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                    // Scan up to the matching end delimiter counting
+                    // nesting levels if needed.
+                    let mut recognized = None;
+                    if input.starts_with(start) {
+                        let mut depth = 1;
+                        let mut pos = start.len();
+                        while pos < input.len() {
+                            let rest = &input[pos..];
+                            if rest.starts_with(end) {
+                                pos += end.len();
+                                depth -= 1;
+                                if depth == 0 {
+                                    recognized = Some(&input[..pos]);
+                                    break;
+                                }
+                            } else if *nested && rest.starts_with(start) {
+                                pos += start.len();
+                                depth += 1;
+                            } else {
+                                pos += rest.chars().next().unwrap().len_utf8();
+                            }
+                        }
+                    }
+                    match recognized {
+                        Some(x_str) => log!("{} '{}'", "recognized".bold().green(), x_str),
+                        None => log!("{}", "not recognized".red()),
+                    }
+                    recognized
+                }
+            });
+        }
+
+        let mut regex_arms: Vec<syn::Arm> = vec![];
+        if regex_terminals {
+            regex_arms.push(parse_quote! {
+                #[allow(unused_variables)]
+                TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
+                    logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                    let match_str = r.find(input);
+                    match match_str {
+                        #regex => {
+                            let x_str = x.as_str();
+                            log!("{} '{}'", "recognized".bold().green(), x_str);
+                            Some(x_str)
+                        },
+                        _ => {
+                            log!("{}", "not recognized".red());
+                            None
+                        }
+                    }
+                }
+            });
+        }
+
+        let mut custom_arms: Vec<syn::Arm> = vec![];
+        if str_alternatives {
+            // Alternatives are sorted longest first so the longest match wins.
+            custom_arms.push(parse_quote! {
+                #[allow(unused_variables)]
+                TokenRecognizer(token_kind, Recognizer::StrAlternatives(alternatives)) => {
+                    logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                    match alternatives.iter().find(|s| input.starts_with(*s)) {
+                        Some(s) => {
+                            log!("{} '{}'", "recognized".bold().green(), s);
+                            Some(s)
+                        }
+                        None => {
+                            log!("{}", "not recognized".red());
+                            None
+                        }
+                    }
+                }
+            });
+        }
+        if keywords {
+            custom_arms.push(parse_quote! {
+                #[allow(unused_variables)]
+                TokenRecognizer(token_kind, Recognizer::KeywordMatch(s, ident)) => {
+                    logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                    if input.starts_with(s)
+                        && RECOGNIZERS[*ident]
+                            .recognize_value(context, whole_input, position)
+                            .map_or(true, |(consumed, _)| consumed <= s.len()) {
+                        log!("{}", "recognized".bold().green());
+                        Some(s)
+                    } else {
+                        log!("{}", "not recognized".red());
+                        None
+                    }
+                }
+            });
+        }
+        let mut custom_methods: Vec<syn::ImplItem> = vec![];
+        if custom_recognizers {
+            // Custom recognizers get the whole input and may return the
+            // consumed length together with the token value.
+            custom_arms.push(parse_quote! {
+                #[allow(unused_variables)]
+                TokenRecognizer(token_kind, Recognizer::Custom(recognize)) => {
+                    logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                    let recognized = recognize(context, whole_input);
+                    match recognized {
+                        Some((_, x_str)) => log!("{} '{}'", "recognized".bold().green(), x_str),
+                        None => log!("{}", "not recognized".red()),
+                    }
+                    return recognized;
+                }
+            });
+            custom_methods.push(parse_quote! {
+                fn needs_context(&self) -> bool {
+                    matches!(self, TokenRecognizer(_, Recognizer::Custom(_)))
+                }
+            });
+        }
+
         ast.push(parse_quote!{
-            impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
-                fn recognize(&self, input: &'i str) -> Option<&'i str> {
-                    match &self {
+            impl<'i> TokenRecognizerT<'i, Context<'i, Input>> for TokenRecognizer {
+                #[allow(unused_variables, clippy::only_used_in_recursion)]
+                fn recognize_value(
+                    &self,
+                    context: &Context<'i, Input>,
+                    input: &'i str,
+                    position: usize,
+                ) -> Option<(usize, &'i str)> {
+                    let whole_input = input;
+                    let input = &input[position..];
+                    let recognized: Option<&'i str> = match &self {
                         #[allow(unused_variables)]
                         TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
                             logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
@@ -587,22 +1532,8 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                                 None
                             }
                         },
-                        #[allow(unused_variables)]
-                        TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
-                            logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
-                            let match_str = r.find(input);
-                            match match_str {
-                                #regex => {
-                                    let x_str = x.as_str();
-                                    log!("{} '{}'", "recognized".bold().green(), x_str);
-                                    Some(x_str)
-                                },
-                                _ => {
-                                    log!("{}", "not recognized".red());
-                                    None
-                                }
-                            }
-                        },
+                        #(#regex_arms)*
+                        #(#raw_arms)*
                         TokenRecognizer(_, Recognizer::Stop) => {
                             logn!("{} STOP -- ","    Recognizing".green());
                             if input.is_empty() {
@@ -613,10 +1544,69 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                                 None
                             }
                         },
-                    }
+                        #(#custom_arms)*
+                    };
+                    recognized.map(|x_str| (x_str.len(), x_str))
                 }
+
+                #(#custom_methods)*
             }
         });
+
+        if generator.settings.incremental_lexer {
+            // A keyword match may continue as the identifier and a prefix of
+            // a string alternative may continue as that alternative.
+            let mut partial_arms: Vec<syn::Arm> = if keywords {
+                vec![parse_quote! {
+                    (Recognizer::KeywordMatch(s, _), None) if s.starts_with(input) => Incomplete,
+                }]
+            } else {
+                vec![]
+            };
+            if str_alternatives {
+                partial_arms.push(parse_quote! {
+                    (Recognizer::StrAlternatives(alternatives), None)
+                        if alternatives.iter().any(|s| s.starts_with(input)) => Incomplete,
+                });
+            }
+            if raw_terminals {
+                partial_arms.push(parse_quote! {
+                    (Recognizer::RawMatch(start, _, _), None)
+                        if input.starts_with(start) || start.starts_with(input) => Incomplete,
+                });
+                partial_arms.push(parse_quote! {
+                    (Recognizer::RawMatch(..), Some(x_str)) => Match(x_str),
+                });
+            }
+            // Recognition of tokens split across the chunks of the input. A
+            // regex match which ends at the end of the input may continue
+            // while string and raw matches are complete.
+            ast.push(parse_quote! {
+                impl rustemo::PartialTokenRecognizer for TokenRecognizer {
+                    fn recognize_partial<'i>(
+                        &self,
+                        input: &'i str,
+                        complete: bool,
+                    ) -> rustemo::Recognized<&'i str> {
+                        use rustemo::Recognized::{Incomplete, Match, NoMatch};
+                        let recognized =
+                            TokenRecognizerT::<Context<Input>>::recognize(self, input);
+                        if complete {
+                            return recognized.map_or(NoMatch, Match);
+                        }
+                        match (&self.1, recognized) {
+                            #(#partial_arms)*
+                            (Recognizer::StrMatch(s), None) if s.starts_with(input) => Incomplete,
+                            (Recognizer::StrMatch(_), Some(x_str)) => Match(x_str),
+                            (_, Some(x_str)) if x_str.len() < input.len() => Match(x_str),
+                            (_, Some(_)) => Incomplete,
+                            (_, None) if input.is_empty() => Incomplete,
+                            (_, None) => NoMatch,
+                        }
+                    }
+                }
+            });
+        }
         let regex_recognizers: Vec<syn::Expr> = generator
             .grammar
             .terminals
@@ -630,8 +1620,17 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                         Some(r) => match r {
                             Recognizer::StrConst(s) => {
                                 let s = s.as_ref();
-                                parse_quote! {
-                                    TokenRecognizer(TokenKind::#token_kind, Recognizer::StrMatch(#s))
+                                match term.keyword_of {
+                                    Some(ident) => {
+                                        let ident = ident.0;
+                                        parse_quote! {
+                                            TokenRecognizer(TokenKind::#token_kind,
+                                                            Recognizer::KeywordMatch(#s, #ident))
+                                        }
+                                    }
+                                    None => parse_quote! {
+                                        TokenRecognizer(TokenKind::#token_kind, Recognizer::StrMatch(#s))
+                                    },
                                 }
                             },
                             Recognizer::RegexTerm(r) => {
@@ -642,6 +1641,38 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                                     })))
                                 }
                             },
+                            Recognizer::StrAlternatives(alternatives) => {
+                                let mut alternatives = alternatives
+                                    .iter()
+                                    .map(|s| s.as_ref().as_str())
+                                    .collect::<Vec<_>>();
+                                alternatives.sort_by_key(|s| std::cmp::Reverse(s.len()));
+                                parse_quote! {
+                                    TokenRecognizer(TokenKind::#token_kind,
+                                                    Recognizer::StrAlternatives(&[#(#alternatives),*]))
+                                }
+                            },
+                            Recognizer::RawTerm(r) => {
+                                let start = r.start.as_ref();
+                                let end = r.end.as_ref();
+                                let nested = r.nested;
+                                parse_quote! {
+                                    TokenRecognizer(TokenKind::#token_kind,
+                                                    Recognizer::RawMatch(#start, #end, #nested))
+                                }
+                            },
+                        },
+                        None if custom_recognizers => {
+                            let recognizers_file = &generator.recognizers_file;
+                            let recognize =
+                                format_ident!("{}", to_snake_case(&term.name));
+                            parse_quote! {
+                                TokenRecognizer(TokenKind::#token_kind,
+                                                Recognizer::Custom(|context, input| {
+                                                    super::#recognizers_file::#recognize(context, input)
+                                                        .map(rustemo::RecognizedValue::consumed_and_value)
+                                                }))
+                            }
                         },
                         // This should never happen as we check that all
                         // recognizers are defined when default lexer is used
@@ -652,11 +1683,35 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             .collect();
 
         ast.push(parse_quote!{
-            pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT]  = [
+            #crate_vis static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT]  = [
                 #(#regex_recognizers,)*
             ];
         });
 
+        // All regex terminals are matched by a single scan of the regex set
+        // and only the matched ones are recognized by their own regex to get
+        // the matched slice.
+        let regex_set_terminals = regex_set_terminals(generator);
+        if !regex_set_terminals.is_empty() {
+            let regexes = regex_set_terminals.iter().map(|(_, r)| r);
+            let terminals = regex_set_terminals.iter().map(|(idx, _)| idx);
+            let regex_count = regex_set_terminals.len();
+            ast.extend::<Vec<syn::Stmt>>(parse_quote! {
+                static REGEX_SET: Lazy<RegexSet> = Lazy::new(|| {
+                    RegexSet::new([#(concat!("^", #regexes)),*]).unwrap()
+                });
+                static REGEX_SET_TERMINALS: [usize; #regex_count] = [#(#terminals),*];
+                #crate_vis fn regex_prefilter(input: &str) -> [bool; TERMINAL_COUNT] {
+                    let mut candidates = [true; TERMINAL_COUNT];
+                    let matches = REGEX_SET.matches(input);
+                    for (idx, &term) in REGEX_SET_TERMINALS.iter().enumerate() {
+                        candidates[term] = matches.matched(idx);
+                    }
+                    candidates
+                }
+            });
+        }
+
         Ok(ast)
     }
 
@@ -676,30 +1731,122 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
 
         let actions_file = &generator.actions_file;
         let root_symbol = &generator.root_symbol;
+        let root_type = generator.action_type(&root_symbol.to_string());
+        let token_attr = &generator.token_attr_type;
         let context_var = format_ident!("context");
+        let vis = generator.vis();
+        // If the results borrow from the input the builder traits are
+        // implemented for the input lifetime.
+        let (builder_generics, builder_lifetime, nonterm_type): (
+            syn::Generics,
+            syn::Lifetime,
+            syn::Type,
+        ) = if generator.nonterminal_borrows() {
+            (
+                parse_quote! { <'i> },
+                parse_quote! { 'i },
+                parse_quote! { NonTerminal<'i> },
+            )
+        } else {
+            (
+                parse_quote! {},
+                parse_quote! { '_ },
+                parse_quote! { NonTerminal },
+            )
+        };
 
         ast.extend::<Vec<syn::Stmt>>(parse_quote! {
-            pub struct DefaultBuilder {
-                res_stack: Vec<Symbol>,
+            #vis struct DefaultBuilder<'i> {
+                res_stack: Vec<Symbol<'i>>,
             }
 
-            impl DefaultBuilder {
+            impl<'i> DefaultBuilder<'i> {
                 #[allow(dead_code)]
                 pub fn new() -> Self {
                     Self {
                         res_stack: vec![]
                     }
                 }
+
+                /// The symbols on the result stack. Used to inspect the
+                /// parsing progress, e.g. from a builder wrapping this one.
+                #[allow(dead_code)]
+                pub fn stack(&self) -> &[Symbol<'i>] {
+                    &self.res_stack
+                }
             }
 
-            impl Builder for DefaultBuilder
-            {
-                type Output = #actions_file::#root_symbol;
+            impl Default for DefaultBuilder<'_> {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
 
-                fn get_result(&mut self) -> Self::Output {
-                    match self.res_stack.pop().unwrap() {
-                        Symbol::NonTerminal(NonTerminal::#root_symbol(r)) => r,
-                        _ => panic!("Invalid result on the parse stack!"),
+        });
+
+        // In no panic mode the inconsistencies of the parse stack are reported
+        // as errors from the `try_*` methods which are used by the LR parser.
+        let no_panic = generator.settings.no_panic;
+        // Fallible actions are called from the `try_*` methods too. Errors
+        // without a location get the location of the reduction.
+        let fallible = generator.settings.fallible_actions;
+        let try_mode = no_panic || fallible;
+        let try_action: Vec<syn::Expr> = if fallible {
+            vec![parse_quote! {
+                map_err(|e| e.or_location(#context_var.location()))
+            }]
+        } else {
+            vec![]
+        };
+        let try_op: Vec<syn::Token![?]> =
+            fallible.then(Default::default).into_iter().collect();
+        let fail = |msg: &str| -> syn::Expr {
+            if no_panic {
+                parse_quote! {
+                    return Err(rustemo::Error::Error {
+                        message: #msg.into(),
+                        file: None,
+                        location: None,
+                    })
+                }
+            } else {
+                parse_quote! { panic!(#msg) }
+            }
+        };
+
+        ast.push(if no_panic {
+            parse_quote! {
+                impl #builder_generics Builder for DefaultBuilder<#builder_lifetime>
+                {
+                    type Output = #root_type;
+
+                    fn get_result(&mut self) -> Self::Output {
+                        self.try_get_result().unwrap()
+                    }
+
+                    fn try_get_result(&mut self) -> Result<Self::Output> {
+                        match self.res_stack.pop() {
+                            Some(Symbol::NonTerminal(NonTerminal::#root_symbol(r))) => Ok(r),
+                            _ => Err(rustemo::Error::Error {
+                                message: "Invalid result on the parse stack!".into(),
+                                file: None,
+                                location: None,
+                            }),
+                        }
+                    }
+                }
+            }
+        } else {
+            parse_quote! {
+                impl #builder_generics Builder for DefaultBuilder<#builder_lifetime>
+                {
+                    type Output = #root_type;
+
+                    fn get_result(&mut self) -> Self::Output {
+                        match self.res_stack.pop().unwrap() {
+                            Symbol::NonTerminal(NonTerminal::#root_symbol(r)) => r,
+                            _ => panic!("Invalid result on the parse stack!"),
+                        }
                     }
                 }
             }
@@ -712,11 +1859,19 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             let term = format_ident!("{}", terminal.name);
             if let Some(Recognizer::StrConst(_)) = terminal.recognizer {
                 parse_quote!{
-                    TokenKind::#term => Terminal::#term
+                    TokenKind::#term => Terminal::#term(token.value)
+                }
+            } else if fallible && terminal.output_type.is_some() {
+                // Actions of typed terminals reject values which can't be
+                // converted.
+                parse_quote!{
+                    TokenKind::#term => Terminal::#term(token.value,
+                        #actions_file::#action(&*context, token)
+                            .map_err(|e| e.or_location(context.location()))?)
                 }
             } else {
                 parse_quote!{
-                    TokenKind::#term => Terminal::#term(#actions_file::#action(&*context, token))
+                    TokenKind::#term => Terminal::#term(token.value, #actions_file::#action(&*context, token))
                 }
             }
         }).collect();
@@ -725,19 +1880,29 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
             .iter()
             .any(|t| !t.reachable.get())
         {
+            let fail = fail("Shift of unreachable terminal!");
             shift_match_arms.push(parse_quote! {
-                _ => panic!("Shift of unreachable terminal!")
+                _ => #fail
             })
         }
         let shift_match_arms = shift_match_arms;
 
-        let mut has_nonreachable_nonterminals = false;
-        let mut reduce_match_arms: Vec<syn::Arm> =
+        // With the dispatch table each reduction is a function taking the
+        // result stack instead of a match arm of `reduce_action`.
+        let dispatch_table = generator.settings.reduce_dispatch_table;
+        let res_stack: syn::Expr = if dispatch_table {
+            parse_quote! { res_stack }
+        } else {
+            parse_quote! { self.res_stack }
+        };
+
+        // Reductions in the order of `ProdKind`. `None` for productions of
+        // unreachable nonterminals.
+        let reductions: Vec<Option<(syn::Ident, syn::Expr)>> =
             generator.grammar.productions().iter()
-                                      .filter_map(|production| {
+                                      .map(|production| {
                 let nonterminal = &generator.grammar.nonterminals[production.nonterminal];
                 if !nonterminal.reachable.get() {
-                    has_nonreachable_nonterminals = true;
                     return None
                 }
                 let rhs_len = production.rhs.len();
@@ -747,22 +1912,35 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                 let action = format_ident!("{}", action_name(nonterminal, choice));
 
                 let prod_kind = generator.prod_kind_ident(production);
+                let no_content = nonterminal.no_content;
                 let nonterminal = format_ident!("{}", nonterminal.name);
 
-                if rhs_len == 0 {
+                let body: syn::Expr = if no_content && rhs_len == 0 {
+                    parse_quote!{
+                        NonTerminal::#nonterminal
+                    }
+                } else if no_content {
+                    // Optional of a terminal without content has no action
+                    parse_quote!{
+                        {
+                            #res_stack.truncate(#res_stack.len()-#rhs_len);
+                            NonTerminal::#nonterminal
+                        }
+                    }
+                } else if rhs_len == 0 {
                     // Handle EMPTY reduction
-                    Some(parse_quote!{
-                        ProdKind::#prod_kind => NonTerminal::#nonterminal(#actions_file::#action(#context_var))
-                    })
+                    parse_quote!{
+                        NonTerminal::#nonterminal(#actions_file::#action(#context_var)#(.#try_action)*#(#try_op)*)
+                    }
                 } else {
                     // Special handling of production with only str match terms in RHS
                     if production.rhs_with_content(generator.grammar).is_empty() {
-                        Some(parse_quote! {
-                            ProdKind::#prod_kind => {
-                                let _ = self.res_stack.split_off(self.res_stack.len()-#rhs_len).into_iter();
-                                NonTerminal::#nonterminal(#actions_file::#action(#context_var))
+                        parse_quote! {
+                            {
+                                #res_stack.truncate(#res_stack.len()-#rhs_len);
+                                NonTerminal::#nonterminal(#actions_file::#action(#context_var)#(.#try_action)*#(#try_op)*)
                             }
-                        })
+                        }
                     } else {
                         let mut next_rep: Vec<syn::Expr> = repeat(
                             parse_quote!{ i.next().unwrap() }
@@ -783,7 +1961,7 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                                 param_count += 1;
                                 if generator.grammar.is_term(symbol){
                                     let terminal = format_ident!("{}", generator.grammar.symbol_to_term(symbol).name);
-                                    parse_quote!{ Symbol::Terminal(Terminal::#terminal(#param)) }
+                                    parse_quote!{ Symbol::Terminal(Terminal::#terminal(_, #param)) }
                                 } else {
                                     let nonterminal = format_ident!("{}", generator.grammar.symbol_to_nonterm(symbol).name);
                                     parse_quote!{ Symbol::NonTerminal(NonTerminal::#nonterminal(#param)) }
@@ -802,57 +1980,353 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
                         let params: Vec<syn::Ident> = (0..production.rhs_with_content(generator.grammar).len())
                             .map( |idx| format_ident! { "p{}", idx }).collect();
 
-                        Some(parse_quote! {
-                            ProdKind::#prod_kind => {
-                                let mut i = self.res_stack.split_off(self.res_stack.len()-#rhs_len).into_iter();
+                        let fail = fail("Invalid symbol parse stack data.");
+                        // RHS results are drained from the stack in place to
+                        // avoid allocation on each reduction.
+                        parse_quote! {
+                            {
+                                let mut i = #res_stack.drain(#res_stack.len()-#rhs_len..);
                                 match #match_expr {
-                                    #match_lhs => NonTerminal::#nonterminal(#actions_file::#action(&*context, #(#params),*)),
-                                    _ => panic!("Invalid symbol parse stack data.")
+                                    #match_lhs => NonTerminal::#nonterminal(#actions_file::#action(&*context, #(#params),*)#(.#try_action)*#(#try_op)*),
+                                    _ => #fail
                                 }
 
                             }
-                        })
+                        }
                     }
-                }
+                };
+                Some((prod_kind, body))
         }).collect();
 
-        if has_nonreachable_nonterminals {
-            reduce_match_arms.push(parse_quote!(
-                 _ => panic!("Reduce of unreachable nonterminal!")
-            ))
+        let reduce_fail = fail("Reduce of unreachable nonterminal!");
+        let reduce_result: syn::Type = if try_mode {
+            parse_quote! { Result<#nonterm_type> }
+        } else {
+            parse_quote! { #nonterm_type }
+        };
+        let reduce_match: syn::Expr = if dispatch_table {
+            let reduce_fns: Vec<syn::Ident> = reductions
+                .iter()
+                .map(|reduction| match reduction {
+                    Some((prod_kind, _)) => format_ident!(
+                        "reduce_{}",
+                        to_snake_case(prod_kind.to_string())
+                    ),
+                    None => format_ident!("reduce_unreachable"),
+                })
+                .collect();
+            for (reduce_fn, (_, body)) in reduce_fns
+                .iter()
+                .zip(&reductions)
+                .filter_map(|(f, r)| r.as_ref().map(|r| (f, r)))
+            {
+                let body: syn::Expr = if try_mode {
+                    parse_quote! { Ok(#body) }
+                } else {
+                    body.clone()
+                };
+                ast.push(parse_quote! {
+                    #[inline]
+                    #[allow(unused_variables)]
+                    fn #reduce_fn<'i>(res_stack: &mut Vec<Symbol<'i>>,
+                                      #context_var: &mut Context<'i, Input>) -> #reduce_result {
+                        #body
+                    }
+                });
+            }
+            if reductions.iter().any(|r| r.is_none()) {
+                ast.push(parse_quote! {
+                    #[allow(unused_variables)]
+                    fn reduce_unreachable<'i>(res_stack: &mut Vec<Symbol<'i>>,
+                                              #context_var: &mut Context<'i, Input>) -> #reduce_result {
+                        #reduce_fail
+                    }
+                });
+            }
+            let prod_count = reductions.len();
+            ast.extend::<Vec<syn::Stmt>>(parse_quote! {
+                type ReduceFn = for<'i> fn(&mut Vec<Symbol<'i>>, &mut Context<'i, Input>) -> #reduce_result;
+                static REDUCE_ACTIONS: [ReduceFn; #prod_count] = [#(#reduce_fns),*];
+            });
+            let try_op: Vec<syn::Token![?]> =
+                try_mode.then(Default::default).into_iter().collect();
+            parse_quote! {
+                REDUCE_ACTIONS[prod as usize](&mut self.res_stack, #context_var)#(#try_op)*
+            }
+        } else {
+            let mut reduce_match_arms: Vec<syn::Arm> = reductions
+                .iter()
+                .flatten()
+                .map(|(prod_kind, body)| {
+                    parse_quote! { ProdKind::#prod_kind => #body }
+                })
+                .collect();
+            if reductions.iter().any(|r| r.is_none()) {
+                reduce_match_arms.push(parse_quote!(
+                     _ => #reduce_fail
+                ))
+            }
+            parse_quote! {
+                match prod {
+                    #(#reduce_match_arms),*
+                }
+            }
+        };
+
+        if try_mode {
+            let stop_fail = fail("Cannot shift STOP token!");
+            let stack_fail = fail("Not enough results on the parse stack!");
+            ast.push(parse_quote! {
+                impl<'i> LRBuilder<'i, Input,
+                     Context<'i, Input>, State, ProdKind, TokenKind #(, #token_attr)*> for DefaultBuilder<'i>
+                {
+                    #![allow(unused_variables)]
+                    fn shift_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        token: Token<'i, Input, TokenKind #(, #token_attr)*>) {
+                        self.try_shift_action(#context_var, token).unwrap()
+                    }
+
+                    fn reduce_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        prod: ProdKind,
+                        prod_len: usize) {
+                        self.try_reduce_action(#context_var, prod, prod_len).unwrap()
+                    }
+
+                    fn try_shift_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        token: Token<'i, Input, TokenKind #(, #token_attr)*>) -> Result<()> {
+                        let val = match token.kind {
+                            TokenKind::STOP => #stop_fail,
+                            #(#shift_match_arms),*
+                        };
+                        self.res_stack.push(Symbol::Terminal(val));
+                        Ok(())
+                    }
+
+                    fn try_reduce_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        prod: ProdKind,
+                        prod_len: usize) -> Result<()> {
+                        if self.res_stack.len() < prod_len {
+                            #stack_fail
+                        }
+                        let prod = #reduce_match;
+                        self.res_stack.push(Symbol::NonTerminal(prod));
+                        Ok(())
+                    }
+                }
+            });
+        } else {
+            ast.push(parse_quote! {
+                impl<'i> LRBuilder<'i, Input,
+                     Context<'i, Input>, State, ProdKind, TokenKind #(, #token_attr)*> for DefaultBuilder<'i>
+                {
+
+                    #![allow(unused_variables)]
+                    fn shift_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        token: Token<'i, Input, TokenKind #(, #token_attr)*>) {
+                        let val = match token.kind {
+                            TokenKind::STOP => panic!("Cannot shift STOP token!"),
+                            #(#shift_match_arms),*
+                        };
+                        self.res_stack.push(Symbol::Terminal(val));
+                    }
+
+                    fn reduce_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        prod: ProdKind,
+                        _prod_len: usize) {
+                        let prod = #reduce_match;
+                        self.res_stack.push(Symbol::NonTerminal(prod));
+                    }
+
+                }
+            });
         }
-        let reduce_match_arms = reduce_match_arms;
 
-        ast.push(parse_quote! {
-            impl<'i> LRBuilder<'i, Input,
-                 Context<'i, Input>, State, ProdKind, TokenKind> for DefaultBuilder
-            {
+        // The builder used by `parse_iter` takes each item out of the list as
+        // soon as it is reduced so the list is never built.
+        if let Some((list, item)) = generator
+            .stream_list()
+            .filter(|_| generator.settings.streaming)
+        {
+            let list_prods: Vec<syn::Ident> = list
+                .productions
+                .iter()
+                .map(|&prod| {
+                    generator.prod_kind_ident(&generator.grammar.productions[prod])
+                })
+                .collect();
+            let list = format_ident!("{}", list.name);
+            let item = generator.action_type(item);
+            ast.extend::<Vec<syn::Stmt>>(parse_quote! {
+                #vis struct DefaultStreamBuilder<'i> {
+                    builder: DefaultBuilder<'i>,
+                    item: Option<#item>,
+                }
 
-                #![allow(unused_variables)]
-                fn shift_action(
-                    &mut self,
-                    #context_var: &mut Context<'i, Input>,
-                    token: Token<'i, Input, TokenKind>) {
-                    let val = match token.kind {
-                        TokenKind::STOP => panic!("Cannot shift STOP token!"),
-                        #(#shift_match_arms),*
-                    };
-                    self.res_stack.push(Symbol::Terminal(val));
+                impl DefaultStreamBuilder<'_> {
+                    #[allow(dead_code)]
+                    pub fn new() -> Self {
+                        Self {
+                            builder: DefaultBuilder::new(),
+                            item: None,
+                        }
+                    }
+
+                    fn take_list_item(&mut self, prod: ProdKind) {
+                        if matches!(prod, #(ProdKind::#list_prods)|*) {
+                            if let Some(Symbol::NonTerminal(NonTerminal::#list(items))) =
+                                self.builder.res_stack.last_mut() {
+                                self.item = items.pop();
+                            }
+                        }
+                    }
                 }
 
-                fn reduce_action(
-                    &mut self,
-                    #context_var: &mut Context<'i, Input>,
-                    prod: ProdKind,
-                    _prod_len: usize) {
-                    let prod = match prod {
-                        #(#reduce_match_arms),*
-                    };
-                    self.res_stack.push(Symbol::NonTerminal(prod));
+                impl Default for DefaultStreamBuilder<'_> {
+                    fn default() -> Self {
+                        Self::new()
+                    }
                 }
 
-            }
-        });
+                impl Builder for DefaultStreamBuilder<'_> {
+                    type Output = ();
+
+                    fn get_result(&mut self) -> Self::Output {
+                        self.builder.res_stack.clear()
+                    }
+                }
+
+                impl #builder_generics rustemo::StreamBuilder for DefaultStreamBuilder<#builder_lifetime> {
+                    type Item = #item;
+
+                    fn take_item(&mut self) -> Option<Self::Item> {
+                        self.item.take()
+                    }
+                }
+
+                impl<'i> LRBuilder<'i, Input,
+                     Context<'i, Input>, State, ProdKind, TokenKind #(, #token_attr)*> for DefaultStreamBuilder<'i>
+                {
+                    fn shift_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        token: Token<'i, Input, TokenKind #(, #token_attr)*>) {
+                        self.builder.shift_action(#context_var, token)
+                    }
+
+                    fn reduce_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        prod: ProdKind,
+                        prod_len: usize) {
+                        self.builder.reduce_action(#context_var, prod, prod_len);
+                        self.take_list_item(prod)
+                    }
+
+                    fn try_shift_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        token: Token<'i, Input, TokenKind #(, #token_attr)*>) -> Result<()> {
+                        self.builder.try_shift_action(#context_var, token)
+                    }
+
+                    fn try_reduce_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        prod: ProdKind,
+                        prod_len: usize) -> Result<()> {
+                        self.builder.try_reduce_action(#context_var, prod, prod_len)?;
+                        self.take_list_item(prod);
+                        Ok(())
+                    }
+                }
+            });
+        }
+
+        // The builder used by `parse_<rule>` methods returns the value of any
+        // rule.
+        if generator.settings.rule_parsers {
+            ast.extend::<Vec<syn::Stmt>>(parse_quote! {
+                #[cfg(test)]
+                struct DefaultRuleBuilder<'i> {
+                    builder: DefaultBuilder<'i>,
+                }
+
+                #[cfg(test)]
+                impl DefaultRuleBuilder<'_> {
+                    fn new() -> Self {
+                        Self {
+                            builder: DefaultBuilder::new(),
+                        }
+                    }
+                }
+
+                #[cfg(test)]
+                impl Default for DefaultRuleBuilder<'_> {
+                    fn default() -> Self {
+                        Self::new()
+                    }
+                }
+
+                #[cfg(test)]
+                impl #builder_generics Builder for DefaultRuleBuilder<#builder_lifetime> {
+                    type Output = Option<#nonterm_type>;
+
+                    fn get_result(&mut self) -> Self::Output {
+                        match self.builder.res_stack.pop() {
+                            Some(Symbol::NonTerminal(r)) => Some(r),
+                            _ => None,
+                        }
+                    }
+                }
+
+                #[cfg(test)]
+                impl<'i> LRBuilder<'i, Input,
+                     Context<'i, Input>, State, ProdKind, TokenKind #(, #token_attr)*> for DefaultRuleBuilder<'i>
+                {
+                    fn shift_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        token: Token<'i, Input, TokenKind #(, #token_attr)*>) {
+                        self.builder.shift_action(#context_var, token)
+                    }
+
+                    fn reduce_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        prod: ProdKind,
+                        prod_len: usize) {
+                        self.builder.reduce_action(#context_var, prod, prod_len)
+                    }
+
+                    fn try_shift_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        token: Token<'i, Input, TokenKind #(, #token_attr)*>) -> Result<()> {
+                        self.builder.try_shift_action(#context_var, token)
+                    }
+
+                    fn try_reduce_action(
+                        &mut self,
+                        #context_var: &mut Context<'i, Input>,
+                        prod: ProdKind,
+                        prod_len: usize) -> Result<()> {
+                        self.builder.try_reduce_action(#context_var, prod, prod_len)
+                    }
+                }
+            });
+        }
 
         Ok(ast)
     }
@@ -861,3 +2335,46 @@ impl<'g, 's> PartGenerator<'g, 's> for BasePartGenerator {
         unimplemented!("Delegate not defined!")
     }
 }
+
+/// Indexes and regexes of the terminals recognized using the regex set. Empty
+/// if the regex set is not used.
+fn regex_set_terminals<'g>(
+    generator: &ParserGenerator<'g, '_>,
+) -> Vec<(usize, &'g str)> {
+    if !generator.settings.regex_set {
+        return vec![];
+    }
+    generator
+        .grammar
+        .terminals
+        .iter()
+        .filter_map(|term| match &term.recognizer {
+            Some(Recognizer::RegexTerm(r)) => {
+                Some((term.idx.0, r.as_ref().as_str()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Is the given inline terminal type a primitive type known to be `Copy`.
+fn is_copy_type(ty: &str) -> bool {
+    matches!(
+        ty.trim(),
+        "u8" | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "f32"
+            | "f64"
+            | "bool"
+            | "char"
+    )
+}
@@ -40,10 +40,12 @@ impl<'g, 's> PartGenerator<'g, 's> for FunctionPartGenerator {
     ) -> Result<Vec<syn::Stmt>> {
         let parser_definition = &generator.parser_definition;
         let mut ast: Vec<syn::Stmt> = vec![];
+        let vis = generator.vis();
+        let crate_vis = generator.crate_vis();
 
         ast.extend::<Vec<_>>(parse_quote! {
             type ActionFn = fn(token: TokenKind) -> Vec<Action<State, ProdKind>>;
-            pub struct #parser_definition {
+            #vis struct #parser_definition {
                 actions: [ActionFn; STATE_COUNT],
                 gotos: [fn(nonterm: NonTermKind) -> State; STATE_COUNT],
                 token_kinds: [[Option<(TokenKind, bool)>; MAX_RECOGNIZERS]; STATE_COUNT],
@@ -126,6 +128,7 @@ impl<'g, 's> PartGenerator<'g, 's> for FunctionPartGenerator {
             if match_arms.len() > 1 {
                 let goto_state_fn = goto_state_fn_name(state);
                 ast.push(parse_quote! {
+                    #[allow(unreachable_patterns)]
                     fn #goto_state_fn(nonterm_kind: NonTermKind) -> State {
                         match nonterm_kind {
                             #(#match_arms),*
@@ -194,7 +197,7 @@ impl<'g, 's> PartGenerator<'g, 's> for FunctionPartGenerator {
         });
 
         ast.push(parse_quote! {
-            pub(in crate) static PARSER_DEFINITION: #parser_definition = #parser_definition {
+            #crate_vis static PARSER_DEFINITION: #parser_definition = #parser_definition {
                 actions: [#(#actions_fn_names),*],
                 gotos: [#(#goto_fn_names),*],
                 token_kinds: [#(#token_kinds),*],
@@ -209,6 +212,8 @@ impl<'g, 's> PartGenerator<'g, 's> for FunctionPartGenerator {
             "{}",
             generator.settings.lexical_disamb_grammar_order
         );
+        let ignored_fn = generator.ignored_fn();
+        let trivia_fn = generator.trivia_fn();
         ast.push(parse_quote! {
             impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for #parser_definition {
                 fn actions(&self, state: State, token: TokenKind) -> Vec<Action<State, ProdKind>> {
@@ -226,6 +231,8 @@ impl<'g, 's> PartGenerator<'g, 's> for FunctionPartGenerator {
                 fn grammar_order() -> bool {
                     #grammar_order
                 }
+                #(#ignored_fn)*
+                #(#trivia_fn)*
             }
         });
 
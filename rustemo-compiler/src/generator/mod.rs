@@ -7,6 +7,7 @@ use colored::Colorize;
 use quote::format_ident;
 use rustemo::Parser;
 use std::{
+    collections::{BTreeMap, BTreeSet},
     fs,
     path::{Path, PathBuf},
 };
@@ -15,14 +16,16 @@ use syn::{parse_quote, Ident};
 use crate::{
     error::{Error, Result},
     index::{StateIndex, TermIndex},
-    lang::rustemo::RustemoParser,
-    settings::{BuilderType, GeneratorTableType, LexerType, Settings},
-    table::{Action, LRTable},
+    lang::{rustemo::RustemoParser, rustemo_actions::Recognizer},
+    settings::{BuilderType, GeneratorTableType, LexerType, Settings, Visibility},
+    table::{Action, LRTable, TableMetrics},
 };
 use crate::{grammar::builder::GrammarBuilder, ParserAlgo};
 use crate::{
     grammar::{
-        types::{to_pascal_case, to_snake_case, Choice, SymbolTypes},
+        types::{
+            to_pascal_case, to_snake_case, Choice, SymbolTypeKind, SymbolTypes,
+        },
         Grammar, NonTerminal, Production,
     },
     index::NonTermIndex,
@@ -104,15 +107,121 @@ pub fn generate_parser(
     let out_dir = out_dir.unwrap_or(&grammar_dir);
     let out_dir_actions = out_dir_actions.unwrap_or(&grammar_dir);
 
+    let name = grammar_path
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+            Error::Error(format!(
+                "Cannot deduce base file name from {:?}",
+                grammar_path
+            ))
+        })?;
+
     let mut parser = RustemoParser::new();
     let file = parser.parse_file(grammar_path)?;
-    let grammar: Grammar =
-        GrammarBuilder::new().try_from_file(file, Some(grammar_path))?;
+    // Files with terminals only are included in other grammars by the
+    // `%terminals` directive and don't produce a parser.
+    if file.grammar_rules.is_none() {
+        println!("Skipping terminals file {:?}", grammar_path);
+        return Ok(());
+    }
+    let grammar: Grammar = GrammarBuilder::new()
+        .rename(settings.rename.clone())
+        .rule_parsers(settings.rule_parsers)
+        .try_from_file(file, Some(grammar_path))?;
+
+    generate_from_grammar(
+        &grammar,
+        name,
+        &grammar_path.with_extension("dot"),
+        out_dir,
+        out_dir_actions,
+        settings,
+    )
+}
+
+/// Calculates the LR table for the given grammar file and returns its
+/// metrics. Nothing is generated.
+pub(crate) fn table_metrics(
+    grammar_path: &Path,
+    settings: &Settings,
+) -> Result<TableMetrics> {
+    settings.validate()?;
+    let file = RustemoParser::new().parse_file(grammar_path)?;
+    let grammar: Grammar = GrammarBuilder::new()
+        .rename(settings.rename.clone())
+        .rule_parsers(settings.rule_parsers)
+        .try_from_file(file, Some(grammar_path))?;
+    let table = LRTable::new(&grammar, settings)?;
+    Ok(table.metrics())
+}
+
+/// Suggests single production removals which resolve all LR conflicts of the
+/// given grammar file. Returns the descriptions of the fixes.
+pub(crate) fn suggest_fixes(
+    grammar_path: &Path,
+    settings: &Settings,
+) -> Result<Vec<String>> {
+    settings.validate()?;
+    let file = RustemoParser::new().parse_file(grammar_path)?;
+    let grammar: Grammar = GrammarBuilder::new()
+        .rename(settings.rename.clone())
+        .rule_parsers(settings.rule_parsers)
+        .try_from_file(file, Some(grammar_path))?;
+    Ok(LRTable::suggest_fixes(&grammar, settings)
+        .iter()
+        .map(|fix| fix.to_string(&grammar))
+        .collect())
+}
+
+/// Generates the parser from the grammar given as a string. Used when the
+/// grammar is embedded in the Rust source (e.g. with `include_str!`).
+///
+/// The outputs are named after the given `name` and written to `out_dir`
+/// together with the actions (if default builder is used).
+///
+/// # Errors
+///
+/// In case of an error a value of [rustemo::Error] is returned.
+pub fn generate_parser_from_source(
+    source: &str,
+    name: &str,
+    out_dir: &Path,
+    settings: &Settings,
+) -> Result<()> {
+    settings.validate()?;
+    println!("Generating parser {name:?} from source");
+
+    let file = RustemoParser::new().parse(source)?;
+    let grammar: Grammar = GrammarBuilder::new()
+        .rename(settings.rename.clone())
+        .rule_parsers(settings.rule_parsers)
+        .try_from_file(file, None)?;
+
+    generate_from_grammar(
+        &grammar,
+        name,
+        &out_dir.join(name).with_extension("dot"),
+        out_dir,
+        out_dir,
+        settings,
+    )
+}
 
+fn generate_from_grammar(
+    grammar: &Grammar,
+    name: &str,
+    dot_file: &Path,
+    out_dir: &Path,
+    out_dir_actions: &Path,
+    settings: &Settings,
+) -> Result<()> {
     // Check recognizers definition. If default string lexer is used all
-    // recognizers must be defined. If custom lexer is used no recognizer should
-    // be defined.
-    if let LexerType::Default = settings.lexer_type {
+    // recognizers must be defined, unless custom recognizers are used. If
+    // custom lexer is used no recognizer should be defined.
+    if matches!(settings.lexer_type, LexerType::Default)
+        && !settings.custom_recognizers
+    {
         for term in &grammar.terminals {
             if term.idx != TermIndex(0) && term.recognizer.is_none() {
                 return Err(Error::Error(format!(
@@ -123,35 +232,90 @@ pub fn generate_parser(
         }
     }
 
-    let table = LRTable::new(&grammar, settings)?;
+    if let BuilderType::Default = settings.builder_type {
+        check_reserved_names(grammar)?;
+    }
+
+    let mut table = if settings.table_cache {
+        let cache_file = out_dir.join(name).with_extension("table");
+        let table = LRTable::with_cache(grammar, settings, &cache_file)?;
+        if !table.computed {
+            println!("Using cached LR table {cache_file:?}.");
+        }
+        table
+    } else {
+        LRTable::new(grammar, settings)?
+    };
     if settings.dot {
-        let dot_file = grammar_path.with_extension("dot");
-        println!("Writting dot file: {:?}", &dot_file);
+        println!("Writting dot file: {:?}", dot_file);
         fs::write(dot_file, table.to_dot())?;
     }
+    if settings.dump_automaton {
+        let automaton_file = dot_file.with_extension("automaton");
+        println!("Writting automaton file: {:?}", automaton_file);
+        if let Some(dir) = automaton_file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(automaton_file, table.to_automaton())?;
+    }
 
+    // Settings used if the LR table is not deterministic and GLR fallback is
+    // configured.
+    let glr_settings;
+    let mut settings = settings;
     if let ParserAlgo::LR = settings.parser_algo {
         let conflicts = table.get_conflicts();
         if !conflicts.is_empty() {
             println!("{}", "\nCONFLICTS:".red());
             table.print_conflicts_report(&conflicts);
+            if !settings.glr_fallback {
+                return Err(Error::Error(
+                    "Grammar is not deterministic. There are conflicts."
+                        .to_string(),
+                ));
+            }
+            println!("{}", "Falling back to GLR parsing.".yellow());
+            // Lexical disambiguation should stay the same as for LR.
+            glr_settings = settings
+                .clone()
+                .parser_algo(ParserAlgo::GLR)
+                .lexical_disamb_grammar_order(
+                    settings.lexical_disamb_grammar_order,
+                );
+            settings = &glr_settings;
+            table = LRTable::new(grammar, settings)?;
+        }
+    }
+
+    if let ParserAlgo::GLR = settings.parser_algo {
+        // Trivia terminals are ignored too but report them by their directive.
+        if grammar.terminals.iter().any(|t| t.trivia) {
+            return Err(Error::Error(
+                "Trivia terminals can be used only with LR algorithm."
+                    .to_string(),
+            ));
+        }
+        if grammar.terminals.iter().any(|t| t.ignored) {
             return Err(Error::Error(
-                "Grammar is not deterministic. There are conflicts."
+                "Ignored terminals can be used only with LR algorithm."
                     .to_string(),
             ));
         }
     }
 
     let generator = ParserGenerator::new(
-        grammar_path,
+        name,
         out_dir.to_owned(),
         out_dir_actions.to_owned(),
-        &grammar,
+        grammar,
         table,
         settings,
     )?;
 
     generator.generate(out_dir)?;
+    if settings.standalone_crate {
+        generator.generate_crate(out_dir)?;
+    }
     Ok(())
 }
 
@@ -167,40 +331,34 @@ struct ParserGenerator<'g, 's> {
     actions_file: Ident,
     lexer_file: Ident,
     builder_file: Ident,
+    recognizers_file: Ident,
+    predicates_file: Ident,
     grammar: &'g Grammar,
     out_dir: PathBuf,
     out_dir_actions: PathBuf,
     table: LRTable<'g, 's>,
     settings: &'s Settings,
     input_type: syn::Type,
+    /// The type of token attributes. Empty if tokens have no attributes.
+    token_attr_type: Vec<syn::Type>,
+    /// Inner attributes at the top of the generated files.
+    file_header: Vec<syn::Attribute>,
     part_generator: Box<dyn PartGenerator<'g, 's>>,
     types: Option<SymbolTypes>,
+    /// The names of the action types with the input lifetime. Empty if
+    /// terminals are not borrowed.
+    borrowed_types: BTreeSet<String>,
 }
 
 impl<'g, 's> ParserGenerator<'g, 's> {
     fn new(
-        grammar_path: &Path,
+        file_name: &str,
         out_dir: PathBuf,
         out_dir_actions: PathBuf,
         grammar: &'g Grammar,
         table: LRTable<'g, 's>,
         settings: &'s Settings,
     ) -> Result<Self> {
-        let file_name = grammar_path
-            .file_stem()
-            .ok_or_else(|| {
-                Error::Error(format!(
-                    "Cannot deduce base file name from {:?}",
-                    grammar_path
-                ))
-            })?
-            .to_str()
-            .ok_or_else(|| {
-                Error::Error(format!(
-                    "Cannot deduce base file name from {:?}",
-                    grammar_path
-                ))
-            })?;
         let parser_name = to_pascal_case(file_name);
         let root_symbol =
             format_ident!("{}", grammar.symbol_name(grammar.start_index));
@@ -210,6 +368,8 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         let actions_file = format_ident!("{}_actions", file_name);
         let lexer_file = format_ident!("{}_lexer", file_name);
         let builder_file = format_ident!("{}_builder", file_name);
+        let recognizers_file = format_ident!("{}_recognizers", file_name);
+        let predicates_file = format_ident!("{}_predicates", file_name);
 
         // Choose parser implementation strategy.
         let part_generator: Box<dyn PartGenerator> =
@@ -223,6 +383,21 @@ impl<'g, 's> ParserGenerator<'g, 's> {
             };
 
         let input_type = syn::parse_str(&settings.input_type)?;
+        let token_attr_type = settings
+            .token_attr_type
+            .iter()
+            .map(|t| syn::parse_str(t))
+            .collect::<syn::Result<_>>()?;
+        let file_header =
+            syn::parse_file(settings.file_header.as_deref().unwrap_or(""))?;
+        if !file_header.items.is_empty() {
+            return Err(Error::Error(
+                "File header can contain only inner attributes and doc \
+                 comments."
+                    .to_string(),
+            ));
+        }
+        let file_header = file_header.attrs;
 
         let types = if let BuilderType::Default = settings.builder_type {
             // Deduce AST types
@@ -230,8 +405,14 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         } else {
             None
         };
+        let borrowed_types = match &types {
+            Some(types) if settings.borrowed_terminals => {
+                types.borrowed_types(grammar)
+            }
+            _ => BTreeSet::new(),
+        };
 
-        Ok(Self {
+        let generator = Self {
             file_name: file_name.to_string(),
             root_symbol,
             parser,
@@ -240,15 +421,73 @@ impl<'g, 's> ParserGenerator<'g, 's> {
             actions_file,
             lexer_file,
             builder_file,
+            recognizers_file,
+            predicates_file,
             grammar,
             out_dir,
             out_dir_actions,
             table,
             settings,
             input_type,
+            token_attr_type,
+            file_header,
             part_generator,
             types,
-        })
+            borrowed_types,
+        };
+        if settings.streaming && generator.stream_list().is_none() {
+            return Err(Error::Error(
+                "Streaming requires the start rule to be a repetition \
+                 (e.g. `File: Record*;`)."
+                    .to_string(),
+            ));
+        }
+        // The input is available to the actions only in LR parsing.
+        if matches!(settings.parser_algo, ParserAlgo::GLR)
+            && matches!(settings.builder_type, BuilderType::Default)
+        {
+            if let Some(nt) = grammar
+                .nonterminals()
+                .iter()
+                .find(|nt| matches!(nt.annotation, Some(ref a) if a == "text"))
+            {
+                return Err(Error::Error(format!(
+                    "Rule \"{}\" annotated with `@text` can't be used with \
+                     GLR algorithm.",
+                    nt.name
+                )));
+            }
+        }
+        // Production kinds are the names of the `ProdKind` variants.
+        let mut prod_kinds: BTreeMap<String, &Production> = BTreeMap::new();
+        for prod in grammar.productions() {
+            let kind = generator.prod_kind(prod);
+            if let Some(other) = prod_kinds.insert(kind.clone(), prod) {
+                return Err(Error::Error(format!(
+                    "Production kind \"{kind}\" of production \"{}\" is \
+                     already used by production \"{}\".",
+                    prod.to_string(grammar),
+                    other.to_string(grammar)
+                )));
+            }
+        }
+        if let Some(types) = &generator.types {
+            if let Some(nt) = grammar.nonterminals().iter().find(|nt| {
+                matches!(nt.annotation, Some(ref a) if a == "columns")
+                    && !matches!(
+                        types.get_type_by_name(&nt.name).map(|t| &t.kind),
+                        Some(SymbolTypeKind::Columns { .. })
+                    )
+            }) {
+                return Err(Error::Error(format!(
+                    "Rule \"{}\" annotated with `@columns` must be a \
+                     repetition of a rule with a struct type (e.g. \
+                     `A: A B | B;`).",
+                    nt.name
+                )));
+            }
+        }
+        Ok(generator)
     }
 
     fn generate(&self, out_dir: &Path) -> Result<()> {
@@ -269,6 +508,7 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         })?;
 
         let mut file: syn::File = parse_quote!();
+        file.attrs.extend(self.file_header.iter().cloned());
 
         file.items.extend(ast.into_iter().map(|s| match s {
             syn::Stmt::Item(i) => i,
@@ -288,7 +528,91 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         Ok(())
     }
 
+    /// Generates `Cargo.toml` and `lib.rs` in the output dir so that the
+    /// generated modules form a standalone crate. `Cargo.toml` is not
+    /// overwritten if exists unless forced.
+    fn generate_crate(&self, out_dir: &Path) -> Result<()> {
+        let mut modules = vec![self.file_name.clone()];
+        if let BuilderType::Default = self.settings.builder_type {
+            if self.out_dir_actions != self.out_dir {
+                return Err(Error::Error(
+                    "Standalone crate requires the actions to be generated in \
+                     the parser output directory."
+                        .to_string(),
+                ));
+            }
+            modules.push(self.actions_file.to_string());
+        }
+        if self.settings.custom_recognizers
+            && self
+                .grammar
+                .terminals
+                .iter()
+                .any(|term| term.name != "STOP" && term.recognizer.is_none())
+        {
+            modules.push(self.recognizers_file.to_string());
+        }
+        if self.grammar.has_predicates() {
+            modules.push(self.predicates_file.to_string());
+        }
+
+        let lib_file = out_dir.join("lib.rs");
+        println!("Writing crate root file {:?}", lib_file);
+        let mut lib =
+            String::from("//! Generated by rustemo. Do not edit manually!\n");
+        for module in &modules {
+            lib.push_str(&format!("pub mod {module};\n"));
+        }
+        fs::write(&lib_file, lib)?;
+
+        let cargo_file = out_dir.join("Cargo.toml");
+        if cargo_file.exists() && !self.settings.force {
+            println!(
+                "Crate manifest {:?} exists. Not overwriting.",
+                cargo_file
+            );
+            return Ok(());
+        }
+        let mut dependencies = vec![
+            format!("rustemo = \"{}\"", env!("CARGO_PKG_VERSION")),
+            "colored = \"2\"".to_string(),
+        ];
+        if matches!(self.settings.lexer_type, LexerType::Default)
+            && self.has_regex_terminals()
+        {
+            dependencies.push("once_cell = \"1\"".to_string());
+            dependencies.push(if self.settings.fancy_regex {
+                "fancy-regex = \"0.13\"".to_string()
+            } else {
+                "regex = \"1\"".to_string()
+            });
+        }
+        println!("Writing crate manifest {:?}", cargo_file);
+        fs::write(
+            &cargo_file,
+            format!(
+                "[package]\n\
+                 name = \"{}\"\n\
+                 version = \"0.1.0\"\n\
+                 edition = \"2021\"\n\
+                 \n\
+                 [lib]\n\
+                 path = \"lib.rs\"\n\
+                 \n\
+                 [dependencies]\n\
+                 {}\n",
+                self.file_name.replace('_', "-"),
+                dependencies.join("\n")
+            ),
+        )?;
+        Ok(())
+    }
+
     fn prod_kind(&self, prod: &Production) -> String {
+        if let (true, Some(kind)) = (self.settings.bare_prod_kinds, &prod.kind)
+        {
+            return kind.clone();
+        }
         format!(
             "{}{}",
             prod.nonterminal(self.grammar).name,
@@ -300,6 +624,28 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         )
     }
 
+    /// Visibility of the generated parser items.
+    fn vis(&self) -> syn::Visibility {
+        item_vis(self.settings)
+    }
+
+    /// Visibility of the generated items which are never public.
+    fn crate_vis(&self) -> syn::Visibility {
+        match self.settings.visibility {
+            Visibility::Private => parse_quote! { pub(super) },
+            _ => parse_quote! { pub(crate) },
+        }
+    }
+
+    /// The crate used for `fmt`, `hash` and `cell` in the generated code.
+    fn std_crate(&self) -> syn::Ident {
+        if self.settings.no_std {
+            format_ident!("core")
+        } else {
+            format_ident!("std")
+        }
+    }
+
     fn prod_kind_ident(&self, prod: &Production) -> syn::Ident {
         format_ident!("{}", self.prod_kind(prod))
     }
@@ -320,6 +666,100 @@ impl<'g, 's> ParserGenerator<'g, 's> {
         )
     }
 
+    /// The type of the given symbol in the actions module. Types containing
+    /// borrowed terminals get the input lifetime.
+    fn action_type(&self, name: &str) -> syn::Type {
+        let actions_file = &self.actions_file;
+        let name_ident = format_ident!("{}", name);
+        if self.borrowed_types.contains(name) {
+            parse_quote! { #actions_file::#name_ident<'i> }
+        } else {
+            parse_quote! { #actions_file::#name_ident }
+        }
+    }
+
+    /// Does the generated `NonTerminal` enum hold values with the input
+    /// lifetime.
+    fn nonterminal_borrows(&self) -> bool {
+        self.grammar
+            .nonterminals()
+            .iter()
+            .filter(|nt| nt.reachable.get() && !nt.no_content)
+            .any(|nt| self.borrowed_types.contains(&nt.name))
+    }
+
+    /// Does any terminal use a regex recognizer. If not, the generated lexer
+    /// doesn't depend on the regex crates.
+    fn has_regex_terminals(&self) -> bool {
+        self.grammar
+            .terminals
+            .iter()
+            .any(|t| matches!(t.recognizer, Some(Recognizer::RegexTerm(_))))
+    }
+
+    /// The list rule of the start rule repetition and the type of its items
+    /// (e.g. `Record1` and `Record` for `File: Record*;`). Used for streaming.
+    fn stream_list(&self) -> Option<(&'g NonTerminal, &str)> {
+        let types = self.types.as_ref()?;
+        let mut symbol_type = types.get_type(self.grammar.start_index);
+        // Follow the references, e.g. the optional list of `Record*`.
+        for _ in 0..self.grammar.nonterminals.len() {
+            match &symbol_type.kind {
+                SymbolTypeKind::Vec { ref_type, .. } => {
+                    return Some((
+                        self.grammar.nonterm_by_name(&symbol_type.name),
+                        ref_type,
+                    ))
+                }
+                SymbolTypeKind::Ref { ref_type, .. } => {
+                    symbol_type = types.get_type_by_name(ref_type)?
+                }
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// The `ignored` function of the parser definition. Empty if the grammar
+    /// has no ignored terminals.
+    fn ignored_fn(&self) -> Vec<syn::ImplItem> {
+        let ignored: Vec<syn::Ident> = self
+            .grammar
+            .terminals
+            .iter()
+            .filter(|t| t.ignored)
+            .map(|t| format_ident!("{}", t.name))
+            .collect();
+        if ignored.is_empty() {
+            return vec![];
+        }
+        vec![parse_quote! {
+            fn ignored(token: TokenKind) -> bool {
+                matches!(token, #(TokenKind::#ignored)|*)
+            }
+        }]
+    }
+
+    /// The `trivia` function of the parser definition. Empty if the grammar
+    /// has no trivia terminals.
+    fn trivia_fn(&self) -> Vec<syn::ImplItem> {
+        let trivia: Vec<syn::Ident> = self
+            .grammar
+            .terminals
+            .iter()
+            .filter(|t| t.trivia)
+            .map(|t| format_ident!("{}", t.name))
+            .collect();
+        if trivia.is_empty() {
+            return vec![];
+        }
+        vec![parse_quote! {
+            fn trivia(token: TokenKind) -> bool {
+                matches!(token, #(TokenKind::#trivia)|*)
+            }
+        }]
+    }
+
     fn action_to_syntax(&self, action: &Option<Action>) -> syn::Expr {
         match action {
             Some(action) => match action {
@@ -339,6 +779,49 @@ impl<'g, 's> ParserGenerator<'g, 's> {
     }
 }
 
+/// Visibility of the generated items which reference the parser types.
+fn item_vis(settings: &Settings) -> syn::Visibility {
+    match settings.visibility {
+        Visibility::Pub => parse_quote! { pub },
+        Visibility::PubCrate => parse_quote! { pub(crate) },
+        Visibility::Private => parse_quote! { pub(super) },
+    }
+}
+
+/// Names used in the generated actions which can't be used for grammar symbols
+/// as the generated types would collide with them.
+const RESERVED_NAMES: &[&str] = &[
+    "Box",
+    "Ctx",
+    "Context",
+    "Input",
+    "Option",
+    "Result",
+    "String",
+    "Token",
+    "TokenKind",
+    "Vec",
+];
+
+/// Checks that the grammar symbols don't collide with the names used in the
+/// generated actions.
+fn check_reserved_names(grammar: &Grammar) -> Result<()> {
+    let symbols = grammar
+        .terminals
+        .iter()
+        .map(|t| &t.name)
+        .chain(grammar.nonterminals.iter().map(|nt| &nt.name));
+    for name in symbols {
+        if RESERVED_NAMES.contains(&name.as_str()) {
+            return Err(Error::Error(format!(
+                "Grammar symbol '{name}' collides with the name used in the \
+                 generated code. Use `Settings::rename` to rename it."
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn action_name(nonterminal: &NonTerminal, choice: &Choice) -> String {
     to_snake_case(format!("{}_{}", nonterminal.name, &choice.name))
 }
@@ -4,7 +4,7 @@ use std::{
     path::Path,
 };
 
-use rustemo::{Error, Result, ValLoc};
+use rustemo::{Error, Parser, Result, ValLoc};
 
 use crate::{
     grammar::{Grammar, DEFAULT_PRIORITY},
@@ -12,14 +12,20 @@ use crate::{
         NonTermIndex, NonTermVec, ProdIndex, ProdVec, SymbolIndex, TermIndex,
         TermVec,
     },
-    lang::rustemo_actions::{
-        self, ConstVal, File, GrammarRule, GrammarSymbol, GrammarSymbolRef,
-        Name, Recognizer, RepetitionOperatorOp, TermMetaDatas,
+    lang::{
+        rustemo::RustemoParser,
+        rustemo_actions::{
+            self, ActionsCodes, CommentDirective, ConstVal, File, GrammarRule,
+            GrammarSymbol, GrammarSymbolRef, IgnoreDirectives,
+            KeywordsDirectives, Name, Recognizer, RepetitionOperatorOp,
+            StartDirectives, StrConst, TermMetaDatas, TerminalsDirectives,
+            TriviaDirectives,
+        },
     },
 };
 
 use super::{
-    Associativity, NonTerminal, Production, ResolvingAssignment,
+    res_symbol, Associativity, NonTerminal, Production, ResolvingAssignment,
     ResolvingSymbolIndex, Terminal,
 };
 
@@ -47,6 +53,12 @@ pub(crate) struct GrammarBuilder {
     next_nonterm_idx: NonTermIndex,
     next_prod_idx: ProdIndex,
     start_rule_name: String,
+    /// Renames of grammar symbols applied to the built grammar.
+    rename: BTreeMap<String, String>,
+    /// Should each rule be augmented so that it can be parsed on its own.
+    rule_parsers: bool,
+    /// Names of the augmented non-terminals and their rules.
+    augmented_rules: Vec<(String, String)>,
 }
 
 impl GrammarBuilder {
@@ -61,9 +73,26 @@ impl GrammarBuilder {
             next_nonterm_idx: NonTermIndex(0),
             next_prod_idx: ProdIndex(0),
             start_rule_name: "".into(),
+            rename: BTreeMap::new(),
+            rule_parsers: false,
+            augmented_rules: vec![],
         }
     }
 
+    /// Grammar symbols to rename. The names are checked to be valid Rust
+    /// identifiers after the rename.
+    pub fn rename(mut self, rename: BTreeMap<String, String>) -> Self {
+        self.rename = rename;
+        self
+    }
+
+    /// Augments each rule so that the LR table has a start state for it. Used
+    /// for generating parsers of individual rules.
+    pub fn rule_parsers(mut self, rule_parsers: bool) -> Self {
+        self.rule_parsers = rule_parsers;
+        self
+    }
+
     fn get_term_idx(&mut self) -> TermIndex {
         let ret = self.next_term_idx;
         self.next_term_idx.0 += 1;
@@ -84,12 +113,27 @@ impl GrammarBuilder {
 
     pub fn try_from_file(
         mut self,
-        file: File,
+        mut file: File,
         path: Option<&Path>,
     ) -> Result<Grammar> {
         if let Some(path) = path {
             self.file = path.to_string_lossy().into();
         }
+
+        // Comment directives are expanded to layout rules and terminals.
+        self.expand_comment_directives(&mut file)?;
+
+        // Rule macros are instantiated to regular rules.
+        self.expand_rule_macros(&mut file)?;
+
+        let keywords_directives = file.keywords_directives.take();
+        let start_directives = file.start_directives.take();
+        let ignore_directives = file.ignore_directives.take();
+        let trivia_directives = file.trivia_directives.take();
+        let terminals_directives = file.terminals_directives.take();
+        let actions_code =
+            self.check_actions_code(file.actions_codes.take())?;
+
         // Create implicit STOP terminal used to signify the end of the input.
         let term_idx = self.get_term_idx();
         self.terminals.insert(
@@ -104,6 +148,12 @@ impl GrammarBuilder {
             },
         );
 
+        // Included terminals come first so that grammars sharing them get the
+        // same terminal indexes.
+        if let Some(directives) = terminals_directives {
+            self.include_terminals(directives, path)?;
+        }
+
         // Collect grammar terminals
         if let Some(grammar_terminals) = file.terminal_rules {
             self.collect_terminals(grammar_terminals)?;
@@ -111,19 +161,40 @@ impl GrammarBuilder {
 
         if let Some(rules) = file.grammar_rules {
             // Extract productions and nonterminals from grammar rules.
-            self.start_rule_name = rules[0].name.as_ref().into();
+            self.start_rule_name =
+                self.resolve_start(start_directives, &rules)?;
             self.extract_productions_and_symbols(rules)?;
+            if self.rule_parsers {
+                self.augment_rules()?;
+            }
         }
 
         // Create implicit terminals from string constants.
         self.resolve_inline_terminals_from_productions()?;
 
+        // Mark keywords shadowing identifier terminals.
+        if let Some(directives) = keywords_directives {
+            self.resolve_keywords(directives)?;
+        }
+
+        // Mark terminals skipped by the parser.
+        if let Some(directives) = ignore_directives {
+            self.resolve_ignored(directives)?;
+        }
+        if let Some(directives) = trivia_directives {
+            self.resolve_trivia(directives)?;
+        }
+
         // Resolve references in productions.
         self.resolve_references()?;
 
+        // Right-recursive lists are parsed as left-recursive.
+        self.left_recursive_lists();
+
         let term_len = self.terminals.len();
-        let grammar = Grammar {
+        let mut grammar = Grammar {
             imports: file.imports.unwrap_or_default(),
+            actions_code,
             productions: self.productions,
             empty_index: term_len.into(), // Right after the last terminal
             augmented_index: (term_len
@@ -133,6 +204,16 @@ impl GrammarBuilder {
                 .nonterminals
                 .get("AUGL")
                 .map(|x| SymbolIndex(term_len + x.idx.0)),
+            augmented_rule_indexes: self
+                .augmented_rules
+                .iter()
+                .map(|(aug, rule)| {
+                    (
+                        self.nonterminals[aug].idx.symbol_index(term_len),
+                        self.nonterminals[rule].idx.symbol_index(term_len),
+                    )
+                })
+                .collect(),
             start_index: (term_len
                 + self.nonterminals.get(&self.start_rule_name).unwrap().idx.0)
                 .into(),
@@ -161,6 +242,7 @@ impl GrammarBuilder {
             },
         };
 
+        grammar.rename_symbols(&self.rename)?;
         mark_reachable_symbols(&grammar);
 
         // TODO: Dump only if tracing is used
@@ -168,24 +250,508 @@ impl GrammarBuilder {
         Ok(grammar)
     }
 
+    /// Returns the Rust code of the actions code blocks checking that each
+    /// block is a valid sequence of Rust items.
+    fn check_actions_code(
+        &self,
+        codes: Option<ActionsCodes>,
+    ) -> Result<Vec<String>> {
+        codes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|code| match syn::parse_file(code.as_ref()) {
+                Ok(_) => Ok(code.as_ref().to_string()),
+                Err(e) => err!(
+                    format!(
+                        "Invalid Rust code in the actions code block: {e}."
+                    ),
+                    Some(self.file.clone()),
+                    code.location
+                ),
+            })
+            .collect()
+    }
+
+    /// Returns the name of the start rule. The start rule is given by the
+    /// `%start` directive or, if not given, it is the first grammar rule.
+    fn resolve_start(
+        &self,
+        directives: Option<StartDirectives>,
+        rules: &[GrammarRule],
+    ) -> Result<String> {
+        let Some(mut directives) = directives else {
+            return Ok(rules[0].name.as_ref().into());
+        };
+        if directives.len() > 1 {
+            return err!(
+                "Only one %start directive is allowed.".into(),
+                Some(self.file.clone()),
+                directives[1].start.location
+            );
+        }
+        let start = directives.remove(0).start;
+        if !rules.iter().any(|r| r.name.as_ref() == start.as_ref()) {
+            return err!(
+                format!(
+                    "Start rule '{}' is not a grammar rule.",
+                    start.as_ref()
+                ),
+                Some(self.file.clone()),
+                start.location
+            );
+        }
+        Ok(start.as_ref().into())
+    }
+
+    /// Marks string terminals given in `%keywords` directives as keywords of the
+    /// identifier terminal. Keywords are given by their match string or the
+    /// terminal name.
+    fn resolve_keywords(
+        &mut self,
+        directives: KeywordsDirectives,
+    ) -> Result<()> {
+        for directive in directives {
+            let ident = match self.terminals.get(directive.ident.as_ref()) {
+                Some(Terminal {
+                    idx,
+                    recognizer: Some(Recognizer::RegexTerm(_)),
+                    ..
+                }) => *idx,
+                _ => {
+                    return err!(
+                        format!(
+                            "Keywords can shadow only a regex terminal but \
+                             '{}' is not.",
+                            directive.ident.as_ref()
+                        ),
+                        Some(self.file.clone()),
+                        directive.ident.location
+                    )
+                }
+            };
+            for keyword in &directive.keywords {
+                let name = match self.terminals_matches.get(keyword.as_ref()) {
+                    Some((name, _)) => name.clone(),
+                    None => keyword.as_ref().clone(),
+                };
+                match self.terminals.get_mut(&name) {
+                    Some(
+                        terminal @ Terminal {
+                            recognizer: Some(Recognizer::StrConst(_)),
+                            ..
+                        },
+                    ) => terminal.keyword_of = Some(ident),
+                    _ => err!(
+                        format!(
+                            "Keyword '{}' is not a string terminal.",
+                            keyword.as_ref()
+                        ),
+                        Some(self.file.clone()),
+                        keyword.location
+                    )?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks terminals given in `%ignore` directives as ignored. Terminals are
+    /// given by their name or match string.
+    fn resolve_ignored(&mut self, directives: IgnoreDirectives) -> Result<()> {
+        let terminals = directives.into_iter().flat_map(|d| d.terminals);
+        self.mark_terminals(terminals, "Ignored", |t| t.ignored = true)
+    }
+
+    /// Marks terminals given in `%trivia` directives as trivia. Trivia
+    /// terminals are skipped by the parser as ignored terminals.
+    fn resolve_trivia(&mut self, directives: TriviaDirectives) -> Result<()> {
+        let terminals = directives.into_iter().flat_map(|d| d.terminals);
+        self.mark_terminals(terminals, "Trivia", |t| {
+            t.ignored = true;
+            t.trivia = true;
+        })
+    }
+
+    /// Marks the given terminals by `mark`. Terminals are given by their name
+    /// or match string. `kind` is used in the error for undefined terminals.
+    fn mark_terminals(
+        &mut self,
+        terminals: impl IntoIterator<Item = Name>,
+        kind: &str,
+        mark: fn(&mut Terminal),
+    ) -> Result<()> {
+        for terminal in terminals {
+            let name = match self.terminals_matches.get(terminal.as_ref()) {
+                Some((name, _)) => name.clone(),
+                None => terminal.as_ref().clone(),
+            };
+            match self.terminals.get_mut(&name) {
+                Some(t) if name != "STOP" => mark(t),
+                _ => err!(
+                    format!(
+                        "{kind} terminal '{}' is not defined.",
+                        terminal.as_ref()
+                    ),
+                    Some(self.file.clone()),
+                    terminal.location
+                )?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Collects terminals from the files given in `%terminals` directives.
+    /// Paths are relative to the directory of the grammar file.
+    fn include_terminals(
+        &mut self,
+        directives: TerminalsDirectives,
+        path: Option<&Path>,
+    ) -> Result<()> {
+        let grammar_dir =
+            path.and_then(|p| p.parent()).unwrap_or(Path::new(""));
+        for directive in directives {
+            let include_path = grammar_dir.join(directive.path.as_ref());
+            let file = RustemoParser::new().parse_file(&include_path)?;
+            let (None, Some(terminal_rules)) =
+                (file.grammar_rules, file.terminal_rules)
+            else {
+                err!(
+                    format!(
+                        "File {:?} must contain only terminals.",
+                        include_path
+                    ),
+                    Some(self.file.clone()),
+                    directive.path.location
+                )?
+            };
+            // Errors in included terminals are reported for the included file.
+            let grammar_file = std::mem::replace(
+                &mut self.file,
+                include_path.to_string_lossy().into(),
+            );
+            let result = self.collect_terminals(terminal_rules);
+            self.file = grammar_file;
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Expands `%line_comment` and `%block_comment` directives to the `Layout`
+    /// rule which skips whitespaces and the configured comments.
+    fn expand_comment_directives(&self, file: &mut File) -> Result<()> {
+        let directives = match file.comment_directives.take() {
+            Some(directives) => directives,
+            None => return Ok(()),
+        };
+        let location = match &directives[0] {
+            CommentDirective::Line { start } => start.location,
+            CommentDirective::Block { start, .. } => start.location,
+        };
+
+        // Escape the given string to be used inside regex terminal.
+        let escape =
+            |s: &StrConst| regex::escape(s.as_ref()).replace('/', r"\/");
+        let mut line_comments = vec![];
+        let mut block_comments = vec![];
+        for directive in &directives {
+            match directive {
+                CommentDirective::Line { start } => {
+                    line_comments.push(format!("{}.*", escape(start)))
+                }
+                CommentDirective::Block { start, end } => block_comments
+                    .push(format!("{}.*?{}", escape(start), escape(end))),
+            }
+        }
+
+        let mut items = vec!["WS"];
+        let mut terminals = vec![r"WS: /\s+/;".to_string()];
+        if !line_comments.is_empty() {
+            items.push("LineComment");
+            terminals.push(format!(
+                "LineComment: /(?:{})/;",
+                line_comments.join("|")
+            ));
+        }
+        if !block_comments.is_empty() {
+            items.push("BlockComment");
+            terminals.push(format!(
+                "BlockComment: /(?s)(?:{})/;",
+                block_comments.join("|")
+            ));
+        }
+
+        // Generated names must not clash with user defined rules.
+        let grammar_rules = file.grammar_rules.get_or_insert_with(Vec::new);
+        let terminal_rules = file.terminal_rules.get_or_insert_with(Vec::new);
+        for name in grammar_rules
+            .iter()
+            .map(|r| &r.name)
+            .chain(terminal_rules.iter().map(|t| &t.name))
+        {
+            if name.as_ref().to_lowercase() == "layout"
+                || name.as_ref() == "LayoutItem"
+                || items.contains(&name.as_ref().as_str())
+            {
+                err!(
+                    format!(
+                        "Name '{name}' can't be used together with comment directives."
+                    ),
+                    Some(self.file.clone()),
+                    name.location
+                )?
+            }
+        }
+
+        let layout = format!(
+            "Layout: LayoutItem*;\nLayoutItem: {};\nterminals\n{}\n",
+            items.join(" | "),
+            terminals.join("\n")
+        );
+        let layout_file = RustemoParser::new().parse(&layout).or_else(|e| {
+            err!(
+                format!("Invalid comment directive: {e}"),
+                Some(self.file.clone()),
+                location
+            )
+        })?;
+        grammar_rules.extend(layout_file.grammar_rules.unwrap_or_default());
+        terminal_rules.extend(layout_file.terminal_rules.unwrap_or_default());
+        Ok(())
+    }
+
+    /// Expands rule macros (parametrized rules). Each macro call is replaced
+    /// by the reference to the rule instantiated for the given arguments.
+    /// Identical instantiations are created only once.
+    fn expand_rule_macros(&self, file: &mut File) -> Result<()> {
+        let (macros, mut rules): (Vec<_>, Vec<_>) = file
+            .grammar_rules
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|r| r.params.is_some());
+
+        let mut macros_by_name: BTreeMap<String, GrammarRule> = BTreeMap::new();
+        for rule_macro in macros {
+            if macros_by_name.contains_key(rule_macro.name.as_ref()) {
+                err!(
+                    format!(
+                        "Rule macro '{}' is defined twice.",
+                        rule_macro.name
+                    ),
+                    Some(self.file.clone()),
+                    rule_macro.name.location
+                )?
+            }
+            macros_by_name.insert(rule_macro.name.as_ref().clone(), rule_macro);
+        }
+
+        let defined_names: BTreeSet<String> = rules
+            .iter()
+            .map(|r| r.name.as_ref().clone())
+            .chain(
+                file.terminal_rules
+                    .iter()
+                    .flatten()
+                    .map(|t| t.name.as_ref().clone()),
+            )
+            .collect();
+        let mut instances = BTreeSet::new();
+
+        // Instantiated rules are appended and processed in turn as they may
+        // contain macro calls themselves.
+        let mut rule_idx = 0;
+        while rule_idx < rules.len() {
+            let mut calls = vec![];
+            for production in rules[rule_idx].rhs.iter_mut() {
+                for assignment in production.assignments.iter_mut() {
+                    use rustemo_actions::Assignment::*;
+                    let gsymref = match assignment {
+                        PlainAssignment(assign) | BoolAssignment(assign) => {
+                            &mut assign.gsymref
+                        }
+                        GrammarSymbolRef(reference) => reference,
+                    };
+                    if let Some(call) = gsymref.macro_call.take() {
+                        let name = Name::new(
+                            std::iter::once(&call.name)
+                                .chain(call.args.iter())
+                                .map(|part| {
+                                    let mut chars = part.as_ref().chars();
+                                    match chars.next() {
+                                        Some(first) => first
+                                            .to_uppercase()
+                                            .chain(chars)
+                                            .collect(),
+                                        None => String::new(),
+                                    }
+                                })
+                                .collect::<String>(),
+                            call.name.location,
+                        );
+                        gsymref.gsymbol =
+                            Some(GrammarSymbol::Name(name.clone()));
+                        calls.push((name, call));
+                    }
+                }
+            }
+
+            for (name, call) in calls {
+                if instances.contains(name.as_ref()) {
+                    continue;
+                }
+                let rule_macro = match macros_by_name.get(call.name.as_ref()) {
+                    Some(rule_macro) => rule_macro,
+                    None => err!(
+                        format!("Unknown rule macro '{}'.", call.name),
+                        Some(self.file.clone()),
+                        call.name.location
+                    )?,
+                };
+                let params = rule_macro.params.as_ref().unwrap();
+                if params.len() != call.args.len() {
+                    err!(
+                        format!(
+                            "Rule macro '{}' expects {} argument(s) but {} given.",
+                            call.name,
+                            params.len(),
+                            call.args.len()
+                        ),
+                        Some(self.file.clone()),
+                        call.name.location
+                    )?
+                }
+                if defined_names.contains(name.as_ref()) {
+                    err!(
+                        format!(
+                            "Name '{name}' of the rule macro instance is already used."
+                        ),
+                        Some(self.file.clone()),
+                        call.name.location
+                    )?
+                }
+
+                // Substitute macro parameters with the call arguments.
+                let substitute = |name: &mut Name| {
+                    if let Some(pos) =
+                        params.iter().position(|p| p.as_ref() == name.as_ref())
+                    {
+                        *name = Name::new(
+                            call.args[pos].as_ref().clone(),
+                            name.location,
+                        );
+                    }
+                };
+                let mut rule = rule_macro.clone();
+                rule.name = name.clone();
+                rule.params = None;
+                for production in rule.rhs.iter_mut() {
+                    for assignment in production.assignments.iter_mut() {
+                        use rustemo_actions::Assignment::*;
+                        let gsymref = match assignment {
+                            PlainAssignment(assign)
+                            | BoolAssignment(assign) => &mut assign.gsymref,
+                            GrammarSymbolRef(reference) => reference,
+                        };
+                        if let Some(GrammarSymbol::Name(ref mut name)) =
+                            gsymref.gsymbol
+                        {
+                            substitute(name);
+                        }
+                        if let Some(ref mut call) = gsymref.macro_call {
+                            call.args.iter_mut().for_each(substitute);
+                        }
+                    }
+                }
+                instances.insert(name.as_ref().clone());
+                rules.push(rule);
+            }
+            rule_idx += 1;
+        }
+
+        if !rules.is_empty() {
+            file.grammar_rules = Some(rules);
+        }
+        Ok(())
+    }
+
     fn collect_terminals(
         &mut self,
         grammar_terminals: Vec<rustemo_actions::TerminalRule>,
     ) -> Result<()> {
         for mut terminal in grammar_terminals {
-            let term_idx = self.get_term_idx();
             self.check_identifier(&terminal.name)?;
+            if self.terminals.contains_key(terminal.name.as_ref()) {
+                err!(
+                    format!("Terminal '{}' is defined twice.", terminal.name),
+                    Some(self.file.clone()),
+                    terminal.name.location
+                )?
+            }
+            let term_idx = self.get_term_idx();
+            if let Some(output_type) = &terminal.terminal_type {
+                if let Some(Recognizer::StrConst(_)) = &terminal.recognizer {
+                    err!(
+                        format!(
+                            "Terminal '{}' has no content and can't have a type.",
+                            terminal.name
+                        ),
+                        Some(self.file.clone()),
+                        output_type.location
+                    )?
+                }
+                if syn::parse_str::<syn::Type>(&output_type.to_string())
+                    .is_err()
+                {
+                    err!(
+                        format!(
+                            "Can't use '{}' as a valid Rust type.",
+                            output_type
+                        ),
+                        Some(self.file.clone()),
+                        output_type.location
+                    )?
+                }
+            }
+            if let Some(Recognizer::StrAlternatives(alternatives)) =
+                &terminal.recognizer
+            {
+                for alternative in alternatives {
+                    if alternative.as_ref().is_empty() {
+                        err!(
+                            "String alternatives must not be empty.".to_owned(),
+                            Some(self.file.clone()),
+                            alternative.location
+                        )?
+                    }
+                }
+            }
+            if let Some(Recognizer::RawTerm(raw)) = &terminal.recognizer {
+                for delimiter in [&raw.start, &raw.end] {
+                    if delimiter.as_ref().is_empty() {
+                        err!(
+                            "Raw terminal delimiters must not be empty."
+                                .to_owned(),
+                            Some(self.file.clone()),
+                            delimiter.location
+                        )?
+                    }
+                }
+            }
             self.terminals.insert(
                 terminal.name.as_ref().to_string(),
                 Terminal {
                     idx: term_idx,
                     name: terminal.name.into(),
                     annotation: terminal.annotation.map(|a| a.into()),
+                    doc: terminal.doc,
+                    output_type: terminal.terminal_type.map(|t| (*t).into()),
                     has_content: match &terminal.recognizer {
                         Some(recognizer) => match recognizer {
                             // Terminal has no content only if it is a string match
                             Recognizer::StrConst(_) => false,
                             Recognizer::RegexTerm(_) => true,
+                            Recognizer::RawTerm(_) => true,
+                            Recognizer::StrAlternatives(_) => true,
                         },
                         None => true,
                     },
@@ -216,6 +782,9 @@ impl GrammarBuilder {
                     },
                     meta: terminal.meta,
                     reachable: false.into(),
+                    keyword_of: None,
+                    ignored: false,
+                    trivia: false,
                 },
             );
         }
@@ -248,11 +817,14 @@ impl GrammarBuilder {
                 name: "EMPTY".to_string(),
                 productions: vec![],
                 annotation: None,
+                doc: vec![],
                 reachable: false.into(),
+                no_content: false,
             },
         );
 
-        self.create_aug_nt_and_production("AUG", rules[0].name.as_ref());
+        let start_rule_name = self.start_rule_name.clone();
+        self.create_aug_nt_and_production("AUG", &start_rule_name);
 
         let layout_rule = rules
             .iter()
@@ -342,9 +914,32 @@ impl GrammarBuilder {
                         })
                         .collect::<Result<Vec<_>>>()?,
                     meta: production.meta,
+                    location: production.location,
                     ..Production::default()
                 };
 
+                // Labels are used as the field names of the generated types
+                // thus they must be unique inside the production.
+                for (idx, assign) in new_production.rhs.iter().enumerate() {
+                    if let Some(name) = &assign.name {
+                        if new_production.rhs[..idx]
+                            .iter()
+                            .filter_map(|a| a.name.as_ref())
+                            .any(|n| n.as_ref() == name.as_ref())
+                        {
+                            err!(
+                                format!(
+                                    "Label '{}' is used more than once in a \
+                                     production of rule '{}'.",
+                                    name, rule.name
+                                ),
+                                Some(self.file.clone()),
+                                name.location
+                            )?
+                        }
+                    }
+                }
+
                 // Inherit meta-data from Rule.
                 for (key, data) in &rule.meta {
                     if !new_production.meta.contains_key(key) {
@@ -365,6 +960,12 @@ impl GrammarBuilder {
                     new_production.kind = Some(kind.into());
                 }
 
+                if let Some(ConstVal::String(predicate)) =
+                    new_production.meta.remove("predicate")
+                {
+                    new_production.predicate = Some(predicate.into());
+                }
+
                 if new_production.meta.remove("left").is_some() {
                     new_production.assoc = Associativity::Left;
                 }
@@ -377,7 +978,13 @@ impl GrammarBuilder {
                 if new_production.meta.remove("nopse").is_some() {
                     new_production.nopse = true;
                 }
+                if new_production.meta.remove("nolayout").is_some() {
+                    new_production.nolayout = true;
+                }
 
+                for desugar_production in &mut desugar_productions {
+                    desugar_production.location = new_production.location;
+                }
                 self.productions.push(new_production);
                 self.productions.extend(desugar_productions);
                 let nonterminal = self
@@ -390,6 +997,7 @@ impl GrammarBuilder {
                             .annotation
                             .as_ref()
                             .map(|a| a.as_ref().into()),
+                        doc: rule.doc.clone(),
                         ..Default::default()
                     });
                 nonterminal.productions.push(prod_idx);
@@ -435,6 +1043,32 @@ impl GrammarBuilder {
         });
     }
 
+    /// Creates an augmented non-terminal `AUG<Rule>` for each rule, including
+    /// the rules created by desugaring.
+    fn augment_rules(&mut self) -> Result<()> {
+        let mut rules = self
+            .nonterminals
+            .values()
+            .filter(|nt| !["EMPTY", "AUG", "AUGL"].contains(&nt.name.as_str()))
+            .map(|nt| (nt.idx, nt.name.clone()))
+            .collect::<Vec<_>>();
+        rules.sort();
+        for (_, rule) in rules {
+            let aug = format!("AUG{rule}");
+            if self.nonterminals.contains_key(&aug) {
+                err!(
+                    format!(
+                        "Rule '{aug}' clashes with the augmented rule for '{rule}'."
+                    ),
+                    Some(self.file.clone())
+                )?;
+            }
+            self.create_aug_nt_and_production(&aug, &rule);
+            self.augmented_rules.push((aug, rule));
+        }
+        Ok(())
+    }
+
     /// Support for regex-like syntax sugar. E.g: A+, A*, A? and greedy
     /// variants with ! suffix: A*!...
     fn desugar_regex(
@@ -644,6 +1278,67 @@ impl GrammarBuilder {
         Ok(())
     }
 
+    /// Rewrites right-recursive lists annotated with `@vec` or `@columns` (e.g.
+    /// `A: B A | B;` or `A: B Comma A | B;`) to the left-recursive form (`A: A B | B;`) used
+    /// by the repetition sugar. The LR parser reduces left-recursive lists
+    /// after each element so long lists don't build a deep parse stack, and the
+    /// elements are pushed to the `Vec` in the input order.
+    ///
+    /// Only annotated rules are rewritten as the actions of other rules get
+    /// the children in the order given in the grammar. Lists with a separator
+    /// and an `EMPTY` alternative are left as they are as reversing would
+    /// change the language (e.g. `A: B Comma A | B | EMPTY;` matches `B Comma`
+    /// but `A: A Comma B | B | EMPTY;` matches `Comma B`).
+    fn left_recursive_lists(&mut self) {
+        let term_len = self.terminals.len();
+        for nt in self.nonterminals.values() {
+            if !matches!(nt.annotation.as_deref(), Some("vec" | "columns")) {
+                continue;
+            }
+            let nt_symbol = nt.idx.symbol_index(term_len);
+            let rhs_symbols = |prod: ProdIndex| {
+                self.productions[prod]
+                    .rhs
+                    .iter()
+                    .map(res_symbol)
+                    .collect::<Vec<_>>()
+            };
+            // The element of the list given by the non-recursive production
+            // and the right-recursive production with the same element.
+            let (mut single, mut recursive) = (vec![], vec![]);
+            let (mut is_list, mut empty, mut separated) = (true, false, false);
+            for &prod in &nt.productions {
+                match rhs_symbols(prod)[..] {
+                    [] => empty = true,
+                    [element] => single.push(element),
+                    [element, last]
+                        if last == nt_symbol && element != nt_symbol =>
+                    {
+                        recursive.push((prod, element))
+                    }
+                    [element, _, last]
+                        if last == nt_symbol && element != nt_symbol =>
+                    {
+                        separated = true;
+                        recursive.push((prod, element))
+                    }
+                    _ => is_list = false,
+                }
+            }
+            if empty && separated {
+                continue;
+            }
+            if let ([element], [(prod, rec_element)]) =
+                (&single[..], &recursive[..])
+            {
+                if is_list && element == rec_element {
+                    let prod = *prod;
+                    self.productions[prod].rhs.reverse();
+                }
+            }
+        }
+    }
+
     fn create_optional(
         &mut self,
         name: Name,
@@ -677,6 +1372,10 @@ impl GrammarBuilder {
                     prod_idx
                 })
                 .collect(),
+            no_content: self
+                .terminals
+                .get(ref_name.as_ref())
+                .is_some_and(|t| !t.has_content),
             ..Default::default()
         };
         self.nonterminals.insert(name.into(), nt);
@@ -694,6 +1393,7 @@ impl GrammarBuilder {
             idx: nt_idx,
             name: name.as_ref().clone(),
             annotation: Some("vec".into()),
+            doc: vec![],
             productions: (0..2)
                 .map(|idx| {
                     let prod_idx = self.get_prod_idx();
@@ -732,6 +1432,7 @@ impl GrammarBuilder {
                 })
                 .collect(),
             reachable: false.into(),
+            no_content: false,
         };
         self.nonterminals.insert(name.into(), nt);
     }
@@ -747,6 +1448,7 @@ impl GrammarBuilder {
             idx: nt_idx,
             name: name.as_ref().clone(),
             annotation: Some("vec".into()),
+            doc: vec![],
             productions: (0..2)
                 .map(|idx| {
                     let prod_idx = self.get_prod_idx();
@@ -771,11 +1473,16 @@ impl GrammarBuilder {
                 })
                 .collect(),
             reachable: false.into(),
+            no_content: false,
         };
         self.nonterminals.insert(name.into(), nt);
     }
 
     fn check_identifier(&self, name: &ValLoc<String>) -> Result<()> {
+        // Renamed symbols are checked after the rename.
+        if self.rename.contains_key(name.as_ref()) {
+            return Ok(());
+        }
         let result = syn::parse_str::<syn::Ident>(name.as_ref());
         if result.is_err() {
             err!(
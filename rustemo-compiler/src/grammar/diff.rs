@@ -0,0 +1,137 @@
+//! Comparison of grammars, e.g. two versions of a language.
+use std::fmt::Display;
+
+use super::{Associativity, Grammar, Priority};
+
+/// The differences between two grammars found by [`Grammar::diff`].
+///
+/// Terminals are compared by name and productions by the name of their
+/// non-terminal and the names of their right-hand side symbols (e.g. `E: E Plus
+/// E`).
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GrammarDiff {
+    pub added_terminals: Vec<String>,
+    pub removed_terminals: Vec<String>,
+    pub added_productions: Vec<String>,
+    pub removed_productions: Vec<String>,
+    /// Terminals and productions present in both grammars with a different
+    /// priority or associativity.
+    pub changed_precedence: Vec<PrecedenceChange>,
+}
+
+/// A change of priority and/or associativity of a terminal or production.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PrecedenceChange {
+    /// The name of the terminal or the production.
+    pub name: String,
+    pub prio: (Priority, Priority),
+    pub assoc: (Associativity, Associativity),
+}
+
+impl GrammarDiff {
+    /// Returns `true` if the grammars are the same.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Removals and precedence changes may make valid inputs invalid or change
+    /// the way they are parsed. Additions only extend the language.
+    pub fn is_breaking(&self) -> bool {
+        !self.removed_terminals.is_empty()
+            || !self.removed_productions.is_empty()
+            || !self.changed_precedence.is_empty()
+    }
+}
+
+impl Display for GrammarDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (title, names) in [
+            ("Added terminal", &self.added_terminals),
+            ("Removed terminal", &self.removed_terminals),
+            ("Added production", &self.added_productions),
+            ("Removed production", &self.removed_productions),
+        ] {
+            for name in names {
+                writeln!(f, "{title}: {name}")?;
+            }
+        }
+        for change in &self.changed_precedence {
+            writeln!(
+                f,
+                "Changed precedence: {} (priority {} -> {}, associativity \
+                 {:?} -> {:?})",
+                change.name,
+                change.prio.0,
+                change.prio.1,
+                change.assoc.0,
+                change.assoc.1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Grammar {
+    /// Compares this grammar with the `other` (e.g. a newer version) and
+    /// returns the terminals and productions added or removed in the `other`
+    /// and the ones with a changed precedence.
+    pub fn diff(&self, other: &Grammar) -> GrammarDiff {
+        let terminals = |grammar: &Grammar| {
+            grammar
+                .terminals()
+                .into_iter()
+                .map(|t| (t.name.clone(), (t.prio, t.assoc)))
+                .collect::<Vec<_>>()
+        };
+        let productions = |grammar: &Grammar| {
+            grammar
+                .productions()
+                .into_iter()
+                .map(|p| (p.to_string(grammar), (p.prio, p.assoc)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut diff = GrammarDiff::default();
+        for (old, new, added, removed) in [
+            (
+                terminals(self),
+                terminals(other),
+                &mut diff.added_terminals,
+                &mut diff.removed_terminals,
+            ),
+            (
+                productions(self),
+                productions(other),
+                &mut diff.added_productions,
+                &mut diff.removed_productions,
+            ),
+        ] {
+            let find = |elements: &[(String, (Priority, Associativity))],
+                        name: &str| {
+                elements
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|&(_, precedence)| precedence)
+            };
+            for (name, precedence) in &old {
+                match find(&new, name) {
+                    None => removed.push(name.clone()),
+                    Some(new_precedence) if new_precedence != *precedence => {
+                        diff.changed_precedence.push(PrecedenceChange {
+                            name: name.clone(),
+                            prio: (precedence.0, new_precedence.0),
+                            assoc: (precedence.1, new_precedence.1),
+                        })
+                    }
+                    Some(_) => (),
+                }
+            }
+            added.extend(
+                new.iter()
+                    .filter(|(name, _)| find(&old, name).is_none())
+                    .map(|(name, _)| name.clone()),
+            );
+        }
+        diff
+    }
+}
@@ -0,0 +1,120 @@
+//! Checks of the grammar for suspicious constructs.
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::{
+    index::{NonTermIndex, SymbolIndex},
+    table::{first_sets, FirstSets},
+};
+
+use super::Grammar;
+
+/// A problem found in the grammar by [`Grammar::lint`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Lint {
+    /// The non-terminal derives only EMPTY and thus contributes nothing to the
+    /// parse. Usually a mistake in the grammar.
+    OnlyEmpty(String),
+
+    /// The first set of the symbol is empty, i.e. the symbol can't derive any
+    /// sentence due to infinite recursion. GOTO links on such symbol would
+    /// never be traversed.
+    InfiniteRecursion(String),
+}
+
+impl Lint {
+    /// Errors prevent parser generation. Other lints are just warnings.
+    pub fn is_error(&self) -> bool {
+        matches!(self, Lint::InfiniteRecursion(_))
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Lint::OnlyEmpty(name) => {
+                write!(f, "Non-terminal {name:?} derives only EMPTY.")
+            }
+            Lint::InfiniteRecursion(name) => write!(
+                f,
+                "First set empty for grammar symbol {name:?}.\n\
+                 An infinite recursion on the grammar symbol."
+            ),
+        }
+    }
+}
+
+impl Grammar {
+    /// Checks the grammar and returns all problems found.
+    pub fn lint(&self) -> Vec<Lint> {
+        lint(self, &first_sets(self))
+    }
+
+    /// Returns the names of the left-recursive non-terminals, i.e.
+    /// non-terminals which can derive a sentential form starting with
+    /// themselves either directly or through other non-terminals.
+    pub fn left_recursive(&self) -> Vec<String> {
+        left_recursive(self, &first_sets(self))
+    }
+}
+
+/// Checks the grammar using already calculated first sets.
+pub(crate) fn lint(grammar: &Grammar, first_sets: &FirstSets) -> Vec<Lint> {
+    first_sets
+        .iter()
+        .enumerate()
+        .map(|(idx, firsts)| (SymbolIndex(idx), firsts))
+        .filter(|&(symbol, _)| symbol != grammar.empty_index)
+        .filter_map(|(symbol, firsts)| {
+            let name = grammar.symbol_name(symbol);
+            if firsts.is_empty() {
+                Some(Lint::InfiniteRecursion(name))
+            } else if firsts.len() == 1 && firsts.contains(&grammar.empty_index)
+            {
+                Some(Lint::OnlyEmpty(name))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Finds left-recursive non-terminals using already calculated first sets.
+pub(crate) fn left_recursive(
+    grammar: &Grammar,
+    first_sets: &FirstSets,
+) -> Vec<String> {
+    // Non-terminals which can start each non-terminal. Symbols after a
+    // non-terminal deriving EMPTY can start the production too.
+    let mut corners = grammar.new_nontermvec(BTreeSet::<NonTermIndex>::new());
+    for production in &grammar.productions {
+        for symbol in production.rhs_symbols() {
+            if grammar.is_nonterm(symbol) {
+                corners[production.nonterminal]
+                    .insert(grammar.symbol_to_nonterm_index(symbol));
+            }
+            if !first_sets[symbol].contains(&grammar.empty_index) {
+                break;
+            }
+        }
+    }
+
+    grammar
+        .nonterminals
+        .iter()
+        .filter(|nonterm| {
+            let mut visited = BTreeSet::new();
+            let mut stack: Vec<_> =
+                corners[nonterm.idx].iter().copied().collect();
+            while let Some(corner) = stack.pop() {
+                if corner == nonterm.idx {
+                    return true;
+                }
+                if visited.insert(corner) {
+                    stack.extend(corners[corner].iter().copied());
+                }
+            }
+            false
+        })
+        .map(|nonterm| nonterm.name.clone())
+        .collect()
+}
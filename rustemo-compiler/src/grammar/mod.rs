@@ -6,7 +6,7 @@ use std::{
     str::FromStr,
 };
 
-use rustemo::{Error, Parser, Result};
+use rustemo::{err, Error, Location, Parser, Result};
 
 use crate::{
     index::{
@@ -23,13 +23,55 @@ use super::lang::rustemo_actions::{
 };
 
 pub(crate) mod builder;
+pub(crate) mod diff;
+pub(crate) mod lint;
 #[cfg(test)]
 mod tests;
 pub(crate) mod types;
 
+pub use self::diff::{GrammarDiff, PrecedenceChange};
+pub use self::lint::Lint;
+
+/// A grammar given in the Rustemo grammar language.
+///
+/// The grammar is constructed by parsing its textual description (see
+/// [`FromStr`] implementation). Terminals, non-terminals and productions,
+/// together with their priority, associativity and recognizers, can be
+/// inspected by tools analyzing `.rustemo` files.
+///
+/// # Example
+///
+/// ```rust
+/// use rustemo_compiler::grammar::{Grammar, RecognizerKind};
+///
+/// let grammar: Grammar = r#"
+///     E: E '+' E {left, 1} | Num;
+///     terminals
+///     Plus: '+';
+///     Num: /\d+/;
+/// "#
+/// .parse()
+/// .unwrap();
+///
+/// let terminals = grammar
+///     .terminals()
+///     .iter()
+///     .map(|t| (t.name.as_str(), t.recognizer_kind()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     terminals,
+///     [("Plus", RecognizerKind::String), ("Num", RecognizerKind::Regex)]
+/// );
+///
+/// let prods = grammar.productions();
+/// assert_eq!(prods[0].to_string(&grammar), "E: E Plus E");
+/// assert_eq!(prods[0].prio, 1);
+/// ```
 #[derive(Debug)]
 pub struct Grammar {
     pub imports: Imports,
+    /// Rust code of `%{ ... %}` blocks spliced into the actions file.
+    pub actions_code: Vec<String>,
     pub productions: ProdVec<Production>,
 
     pub terminals: TermVec<Terminal>,
@@ -44,6 +86,9 @@ pub struct Grammar {
     pub augmented_index: SymbolIndex,
     /// Index of augmented symbol for Layout rule if given
     pub augmented_layout_index: Option<SymbolIndex>,
+    /// Indexes of augmented symbols and their rules if rules are augmented to
+    /// be parsed on their own.
+    pub augmented_rule_indexes: Vec<(SymbolIndex, SymbolIndex)>,
     /// An index of the start symbol. First non-terminal or terminal of the grammar.
     pub start_index: SymbolIndex,
 }
@@ -81,8 +126,15 @@ pub struct Terminal {
     pub idx: TermIndex,
     pub name: String,
     pub annotation: Option<String>,
+
+    /// Lines of the `///` comments preceding the terminal in the grammar.
+    pub doc: Vec<String>,
     pub recognizer: Option<Recognizer>,
 
+    /// Rust type of the terminal content given inline in the grammar (e.g.
+    /// `Number: /\d+/: u32;`).
+    pub output_type: Option<String>,
+
     /// Terminal will carry content if it is a non-constant match (e.g. a regex
     /// or a custom recognizer).
     pub has_content: bool,
@@ -97,20 +149,73 @@ pub struct Terminal {
     /// Associativity used to decide shift/reduce conflict resolutions
     pub assoc: Associativity,
 
+    /// The identifier terminal shadowed by this keyword (see `%keywords`
+    /// directive). The keyword is recognized only if the identifier doesn't
+    /// match a longer input.
+    pub keyword_of: Option<TermIndex>,
+
+    /// Is this terminal given in the `%ignore` directive. Ignored terminals
+    /// are skipped by the parser where they are not expected.
+    pub ignored: bool,
+
+    /// Is this terminal given in the `%trivia` directive. Trivia terminals
+    /// are ignored and handed to the builder to be attached to the adjacent
+    /// tokens.
+    pub trivia: bool,
+
     pub meta: TermMetaDatas,
 }
 grammar_elem!(Terminal);
 
+impl Terminal {
+    /// The kind of the recognizer given for this terminal in the grammar.
+    pub fn recognizer_kind(&self) -> RecognizerKind {
+        match self.recognizer {
+            Some(Recognizer::StrConst(_)) => RecognizerKind::String,
+            Some(Recognizer::RegexTerm(_)) => RecognizerKind::Regex,
+            Some(Recognizer::RawTerm(_)) => RecognizerKind::Raw,
+            Some(Recognizer::StrAlternatives(_)) => {
+                RecognizerKind::Alternatives
+            }
+            None => RecognizerKind::Custom,
+        }
+    }
+}
+
+/// The kind of the terminal recognizer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecognizerKind {
+    /// String match (e.g. `'+'`).
+    String,
+    /// Regex match (e.g. `/\d+/`).
+    Regex,
+    /// Match up to the end delimiter (e.g. `raw("{", "}", nested)`).
+    Raw,
+    /// One of the alternative strings (e.g. `'true' | 'false'`).
+    Alternatives,
+    /// No recognizer is given. The terminal is recognized by a custom lexer
+    /// or a custom recognizer.
+    Custom,
+}
+
 #[derive(Debug, Default)]
 pub struct NonTerminal {
     pub idx: NonTermIndex,
     pub name: String,
     pub annotation: Option<String>,
+
+    /// Lines of the `///` comments preceding the rule in the grammar. For a
+    /// rule defined more than once only the first one is used.
+    pub doc: Vec<String>,
     pub productions: Vec<ProdIndex>,
 
     /// Is this non-terminal reachable from the start rule.
     /// Used to determine layout-only rules.
     pub reachable: Cell<bool>,
+
+    /// Optional sugar over a terminal without content (e.g. `';'?`). Such
+    /// non-terminal doesn't carry content and is dropped from the actions.
+    pub no_content: bool,
 }
 grammar_elem!(NonTerminal);
 
@@ -158,7 +263,7 @@ impl Display for Grammar {
     }
 }
 
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Associativity {
     #[default]
     None,
@@ -181,7 +286,15 @@ pub struct Production {
     pub dynamic: bool,
     pub nops: bool,
     pub nopse: bool,
+    pub nolayout: bool,
+    /// The name of the semantic predicate guarding reductions by this
+    /// production (`{? predicate}`).
+    pub predicate: Option<String>,
     pub meta: ProdMetaDatas,
+    /// The location of the production in the grammar. Productions created
+    /// from the regex-like sugar share the location of the production where
+    /// the sugar is used.
+    pub location: Option<Location>,
 }
 grammar_elem!(Production);
 
@@ -200,7 +313,10 @@ impl Default for Production {
             dynamic: Default::default(),
             nops: Default::default(),
             nopse: Default::default(),
+            nolayout: Default::default(),
+            predicate: None,
             meta: Default::default(),
+            location: None,
         }
     }
 }
@@ -318,6 +434,8 @@ pub(crate) fn res_symbol(assign: &ResolvingAssignment) -> SymbolIndex {
 //     }
 // }
 
+/// Parses the grammar description and constructs a [`Grammar`], e.g.
+/// `grammar_str.parse::<Grammar>()`.
 impl FromStr for Grammar {
     type Err = Error;
 
@@ -356,14 +474,84 @@ impl Grammar {
         NonTermVec(vec![default; self.nonterminals.len()])
     }
 
+    /// The index of the symbol with the given name. Panics if there is no such
+    /// symbol. See [Grammar::try_symbol_index].
     pub fn symbol_index(&self, name: &str) -> SymbolIndex {
-        *self.term_by_name.get(name).unwrap_or_else(|| {
-            self.nonterm_by_name.get(name).unwrap_or_else(|| {
-                panic!("No Symbol by name {:?}", name);
-            })
+        self.try_symbol_index(name).unwrap_or_else(|| {
+            panic!("No Symbol by name {:?}", name);
         })
     }
 
+    /// The index of the symbol with the given name or `None` if there is no
+    /// such symbol.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rustemo_compiler::grammar::Grammar;
+    ///
+    /// let grammar: Grammar = r#"
+    ///     E: E '+' Num | Num;
+    ///     terminals
+    ///     Plus: '+';
+    ///     Num: /\d+/;
+    /// "#
+    /// .parse()
+    /// .unwrap();
+    ///
+    /// let num = grammar.try_symbol_index("Num").unwrap();
+    /// assert_eq!(grammar.try_symbol_name(num), Some("Num"));
+    /// assert_eq!(grammar.try_symbol_index("Minus"), None);
+    /// assert_eq!(grammar.try_symbol_name(1000.into()), None);
+    /// ```
+    pub fn try_symbol_index(&self, name: &str) -> Option<SymbolIndex> {
+        self.term_by_name
+            .get(name)
+            .or_else(|| self.nonterm_by_name.get(name))
+            .copied()
+    }
+
+    /// Renames grammar symbols using the given map from the old to the new
+    /// name. Used to control the names of the generated types and functions,
+    /// e.g. for symbols whose names are Rust keywords.
+    pub fn rename_symbols(
+        &mut self,
+        rename: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        for (from, to) in rename {
+            if syn::parse_str::<syn::Ident>(to).is_err() {
+                return err!(format!(
+                    "Can't rename '{from}' to '{to}' as it is not a valid Rust \
+                     identifier."
+                ));
+            }
+            if self.term_by_name.contains_key(to)
+                || self.nonterm_by_name.contains_key(to)
+            {
+                return err!(format!(
+                    "Can't rename '{from}' to '{to}' as the symbol '{to}' \
+                     already exists."
+                ));
+            }
+            if let Some(symbol) = self.term_by_name.remove(from) {
+                let term = self.symbol_to_term_index(symbol);
+                self.terminals[term].name = to.clone();
+                self.term_by_name.insert(to.clone(), symbol);
+            } else if let Some(symbol) = self.nonterm_by_name.remove(from) {
+                let nonterm = self.symbol_to_nonterm_index(symbol);
+                self.nonterminals[nonterm].name = to.clone();
+                self.nonterm_by_name.insert(to.clone(), symbol);
+            } else {
+                return err!(format!(
+                    "Can't rename unexisting grammar symbol '{from}'."
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The name of the symbol with the given index. Panics if the index is out
+    /// of range. See [Grammar::try_symbol_name].
     pub fn symbol_name(&self, index: SymbolIndex) -> String {
         if index.0 < self.terminals.len() {
             self.symbol_to_term(index).name.clone()
@@ -372,13 +560,29 @@ impl Grammar {
         }
     }
 
+    /// The name of the symbol with the given index or `None` if there is no
+    /// such symbol.
+    pub fn try_symbol_name(&self, index: SymbolIndex) -> Option<&str> {
+        if index.0 < self.terminals.len() {
+            Some(&self.symbol_to_term(index).name)
+        } else if index.0 < self.terminals.len() + self.nonterminals.len() {
+            Some(&self.symbol_to_nonterm(index).name)
+        } else {
+            None
+        }
+    }
+
     /// If this symbol is either a non-terminal of a terminal with a content.
-    /// I.e. not a constant match terminal (keyword, punctuation...)
+    /// I.e. not a constant match terminal (keyword, punctuation...) or an
+    /// optional of such terminal.
     #[inline]
     pub fn symbol_has_content(&self, symbol: SymbolIndex) -> bool {
         !self.is_empty(symbol)
-            && (self.is_nonterm(symbol)
-                || self.symbol_to_term(symbol).has_content)
+            && if self.is_nonterm(symbol) {
+                !self.symbol_to_nonterm(symbol).no_content
+            } else {
+                self.symbol_to_term(symbol).has_content
+            }
     }
 
     pub fn symbol_indexes(&self, names: &[&str]) -> SymbolVec<SymbolIndex> {
@@ -483,16 +687,20 @@ impl Grammar {
         self.productions[prod].rhs.iter().map(res_symbol).collect()
     }
 
+    /// Returns all terminals except special STOP.
+    pub fn terminals(&self) -> Vec<&Terminal> {
+        self.terminals
+            .iter()
+            .filter(|&t| self.term_to_symbol_index(t.idx) != self.stop_index)
+            .collect()
+    }
+
     /// Returns all productions except special AUG and AUGL.
     pub fn productions(&self) -> Vec<&Production> {
         self.productions
             .iter()
             .filter(|&p| {
-                let nt_symbol = self.nonterm_to_symbol_index(p.nonterminal);
-                nt_symbol != self.augmented_index
-                    && self
-                        .augmented_layout_index
-                        .map_or(true, |li| li != nt_symbol)
+                !self.is_augmented(self.nonterm_to_symbol_index(p.nonterminal))
             })
             .collect()
     }
@@ -503,15 +711,23 @@ impl Grammar {
             .iter()
             .filter(|&n| {
                 let nt_symbol = self.nonterm_to_symbol_index(n.idx);
-                nt_symbol != self.empty_index
-                    && nt_symbol != self.augmented_index
-                    && self
-                        .augmented_layout_index
-                        .map_or(true, |li| li != nt_symbol)
+                nt_symbol != self.empty_index && !self.is_augmented(nt_symbol)
             })
             .collect()
     }
 
+    /// Is the symbol one of the augmented non-terminals (AUG, AUGL or the
+    /// augmented rules).
+    #[inline]
+    pub fn is_augmented(&self, symbol: SymbolIndex) -> bool {
+        symbol == self.augmented_index
+            || self.augmented_layout_index == Some(symbol)
+            || self
+                .augmented_rule_indexes
+                .iter()
+                .any(|&(aug, _)| aug == symbol)
+    }
+
     #[inline]
     pub fn is_enum(&self, nonterminal: &NonTerminal) -> bool {
         let prods = nonterminal.productions(self);
@@ -522,4 +738,10 @@ impl Grammar {
     pub fn has_layout(&self) -> bool {
         self.augmented_layout_index.is_some()
     }
+
+    /// Returns `true` if some productions are guarded by semantic predicates.
+    #[inline]
+    pub fn has_predicates(&self) -> bool {
+        self.productions.iter().any(|p| p.predicate.is_some())
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    grammar::{Associativity, Grammar},
+    grammar::{Associativity, Grammar, Lint, PrecedenceChange, RecognizerKind},
     index::ProdIndex,
     lang::rustemo_actions::Recognizer,
     output_cmp,
@@ -118,7 +118,9 @@ fn terminals_regex() {
             .as_ref()
             .unwrap()
         {
-            Recognizer::StrConst(_) => false,
+            Recognizer::StrConst(_)
+            | Recognizer::RawTerm(_)
+            | Recognizer::StrAlternatives(_) => false,
             Recognizer::RegexTerm(regex) => regex.as_ref() == term_regex,
         });
     }
@@ -175,8 +177,8 @@ fn nonterminal_productions() {
 fn productions_meta_data() {
     let grammar: Grammar = r#"
         S: A "some_term" B {5} | B {nops};
-        A: B {nopse, bla: 5};
-        B: some_term {right};
+        A: B {nopse, bla: 5, ? is_a};
+        B: some_term {right, nolayout};
         terminals
         some_term: "some_term";
         "#
@@ -194,11 +196,18 @@ fn productions_meta_data() {
     assert_eq!(grammar.productions[ProdIndex(3)].prio, 10);
     assert!(grammar.productions[ProdIndex(3)].nopse);
     assert_eq!(grammar.productions[ProdIndex(3)].meta.len(), 1);
+    assert_eq!(
+        grammar.productions[ProdIndex(3)].predicate.as_deref(),
+        Some("is_a")
+    );
+    assert_eq!(grammar.productions[ProdIndex(2)].predicate, None);
 
     assert_eq!(
         grammar.productions[ProdIndex(4)].assoc,
         Associativity::Right
     );
+    assert!(grammar.productions[ProdIndex(4)].nolayout);
+    assert!(!grammar.productions[ProdIndex(3)].nolayout);
 
     output_cmp!(
         "src/grammar/tests/productions_meta_data.expected",
@@ -296,6 +305,45 @@ fn regex_sugar_one_or_more() {
     );
 }
 
+#[test]
+fn vec_right_recursive() {
+    let grammar: Grammar = r#"
+        S: A B D C;
+        @vec
+        A: Num A | Num;
+        @vec
+        B: Num Comma B | Num | EMPTY;
+        @vec
+        D: Num Comma D | Num;
+        C: Num C | Num;
+        terminals
+        Num: /\d+/;
+        Comma: ',';
+        "#
+    .parse()
+    .unwrap();
+    let productions = grammar
+        .productions()
+        .into_iter()
+        .map(|p| p.to_string(&grammar))
+        .collect::<Vec<_>>();
+    // Reversed `B` would accept a leading separator instead of a trailing one
+    // so it is left as is.
+    for production in [
+        "A: A Num",
+        "B: Num Comma B",
+        "B: ",
+        "D: D Comma Num",
+        "C: Num C",
+        "C: Num",
+    ] {
+        assert!(
+            productions.iter().any(|p| p == production),
+            "{production} not in {productions:?}"
+        );
+    }
+}
+
 #[test]
 fn regex_sugar_optional() {
     let grammar: Grammar = r#"
@@ -386,3 +434,459 @@ fn invalid_names_3() {
         format!("{:#?}", grammar)
     );
 }
+
+/// Test that labels are unique inside a production.
+#[test]
+fn duplicate_labels() {
+    let grammar: rustemo::Result<Grammar> = r#"
+        S: left=A Comma left=A;
+        terminals
+        A: 'a';
+        Comma: ',';
+    "#
+    .parse();
+
+    output_cmp!(
+        "src/grammar/tests/duplicate_labels.err",
+        format!("{:#?}", grammar)
+    );
+}
+
+/// Test that comment directives can't be used with user defined layout.
+#[test]
+fn comment_directives_with_layout() {
+    let grammar: rustemo::Result<Grammar> = r#"
+        %line_comment '//'
+        S: A+;
+        Layout: WS*;
+        terminals
+        A: 'a';
+        WS: /\s+/;
+    "#
+    .parse();
+
+    output_cmp!(
+        "src/grammar/tests/comment_directives_with_layout.err",
+        format!("{:#?}", grammar)
+    );
+}
+
+/// Test that inline terminal types must be valid Rust types.
+#[test]
+fn terminal_type_invalid() {
+    let grammar: rustemo::Result<Grammar> = r#"
+        S: A;
+        terminals
+        A: /\d+/: fn;
+    "#
+    .parse();
+
+    output_cmp!(
+        "src/grammar/tests/terminal_type_invalid.err",
+        format!("{:#?}", grammar)
+    );
+}
+
+/// Test that terminals without content can't have inline types.
+#[test]
+fn terminal_type_no_content() {
+    let grammar: rustemo::Result<Grammar> = r#"
+        S: A;
+        terminals
+        A: 'a': u32;
+    "#
+    .parse();
+
+    output_cmp!(
+        "src/grammar/tests/terminal_type_no_content.err",
+        format!("{:#?}", grammar)
+    );
+}
+
+/// Test that rule macros must be called with the right number of arguments.
+#[test]
+fn rule_macro_arguments() {
+    let grammar: rustemo::Result<Grammar> = r#"
+        S: pair(A);
+        pair(X, Y): X Y;
+        terminals
+        A: 'a';
+    "#
+    .parse();
+
+    output_cmp!(
+        "src/grammar/tests/rule_macro_arguments.err",
+        format!("{:#?}", grammar)
+    );
+}
+
+/// Test that non-terminals deriving only EMPTY are reported.
+#[test]
+fn lint_only_empty() {
+    let grammar: Grammar = r#"
+        S: A B Tb;
+        A: B B | EMPTY;
+        B: EMPTY;
+        terminals
+        Tb: 'b';
+    "#
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        grammar.lint(),
+        vec![Lint::OnlyEmpty("A".into()), Lint::OnlyEmpty("B".into())]
+    );
+}
+
+/// Test that direct and indirect left recursion is found, also through
+/// non-terminals deriving EMPTY.
+#[test]
+fn left_recursive() {
+    let grammar: Grammar = r#"
+        S: S Ta | A;
+        A: Opt B | Tb;
+        B: A Ta | Tb;
+        C: Tb C | Ta;
+        Opt: Ta | EMPTY;
+        terminals
+        Ta: 'a';
+        Tb: 'b';
+    "#
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        grammar.left_recursive(),
+        vec!["S".to_string(), "A".into(), "B".into()]
+    );
+}
+
+/// Test that a production added in the newer version of the grammar is
+/// reported.
+#[test]
+fn grammar_diff() {
+    let grammar: Grammar = r#"
+        E: E '+' E {left, 1} | Num;
+        terminals
+        Plus: '+';
+        Num: /\d+/;
+    "#
+    .parse()
+    .unwrap();
+    let newer: Grammar = r#"
+        E: E '+' E {left, 1} | E '*' E {left, 2} | Num;
+        terminals
+        Plus: '+';
+        Mul: '*';
+        Num: /\d+/;
+    "#
+    .parse()
+    .unwrap();
+
+    let diff = grammar.diff(&newer);
+    assert_eq!(diff.added_productions, ["E: E Mul E"]);
+    assert_eq!(diff.added_terminals, ["Mul"]);
+    assert!(diff.removed_productions.is_empty());
+    assert!(diff.removed_terminals.is_empty());
+    assert!(diff.changed_precedence.is_empty());
+    assert!(!diff.is_breaking());
+
+    let diff = newer.diff(&grammar);
+    assert_eq!(diff.removed_productions, ["E: E Mul E"]);
+    assert_eq!(diff.removed_terminals, ["Mul"]);
+    assert!(diff.is_breaking());
+    assert!(grammar.diff(&grammar).is_empty());
+}
+
+/// Test that changed priority and associativity of productions are reported.
+#[test]
+fn grammar_diff_precedence() {
+    let grammar: Grammar = r#"
+        E: E '+' E {left, 1} | Num;
+        terminals
+        Plus: '+';
+        Num: /\d+/;
+    "#
+    .parse()
+    .unwrap();
+    let newer: Grammar = r#"
+        E: E '+' E {right, 2} | Num;
+        terminals
+        Plus: '+';
+        Num: /\d+/;
+    "#
+    .parse()
+    .unwrap();
+
+    let diff = grammar.diff(&newer);
+    assert_eq!(
+        diff.changed_precedence,
+        [PrecedenceChange {
+            name: "E: E Plus E".into(),
+            prio: (1, 2),
+            assoc: (Associativity::Left, Associativity::Right),
+        }]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "Changed precedence: E: E Plus E (priority 1 -> 2, associativity \
+         Left -> Right)\n"
+    );
+}
+
+#[test]
+fn rename_symbols() {
+    let mut grammar: Grammar = r#"
+        S: A B;
+        B: Ta;
+        terminals
+        A: 'a';
+        Ta: 'ta';
+    "#
+    .parse()
+    .unwrap();
+
+    grammar
+        .rename_symbols(&[("B".into(), "Bs".into())].into())
+        .unwrap();
+    assert_eq!(grammar.symbol_name(grammar.symbol_index("Bs")), "Bs");
+    assert!(!grammar.nonterm_by_name.contains_key("B"));
+
+    assert_eq!(
+        grammar
+            .rename_symbols(&[("Bs".into(), "A".into())].into())
+            .unwrap_err()
+            .to_string(),
+        "Error:\n\tCan't rename 'Bs' to 'A' as the symbol 'A' already exists."
+    );
+    assert_eq!(
+        grammar
+            .rename_symbols(&[("C".into(), "Cs".into())].into())
+            .unwrap_err()
+            .to_string(),
+        "Error:\n\tCan't rename unexisting grammar symbol 'C'."
+    );
+}
+
+#[test]
+fn keywords_directive() {
+    let grammar: Grammar = r#"
+        %keywords Ident: if, Else;
+        S: If Else Ident;
+        terminals
+        If: 'if';
+        Else: 'else';
+        Ident: /\w+/;
+    "#
+    .parse()
+    .unwrap();
+    let ident = grammar.term_by_name("Ident").idx;
+    assert_eq!(grammar.term_by_name("If").keyword_of, Some(ident));
+    assert_eq!(grammar.term_by_name("Else").keyword_of, Some(ident));
+    assert_eq!(grammar.term_by_name("Ident").keyword_of, None);
+
+    let grammar: rustemo::Result<Grammar> = r#"
+        %keywords Ident: while;
+        S: If Ident;
+        terminals
+        If: 'if';
+        Ident: /\w+/;
+    "#
+    .parse();
+    assert!(grammar
+        .unwrap_err()
+        .to_string()
+        .contains("Keyword 'while' is not a string terminal."));
+
+    let grammar: rustemo::Result<Grammar> = r#"
+        %keywords If: if;
+        S: If;
+        terminals
+        If: 'if';
+    "#
+    .parse();
+    assert!(grammar.unwrap_err().to_string().contains(
+        "Keywords can shadow only a regex terminal but 'If' is not."
+    ));
+}
+
+#[test]
+fn ignore_directive() {
+    let grammar: Grammar = r#"
+        %ignore Noise, pragma;
+        S: A Noise;
+        terminals
+        A: 'a';
+        Noise: /~\w+/;
+        Pragma: 'pragma';
+    "#
+    .parse()
+    .unwrap();
+    assert!(grammar.term_by_name("Noise").ignored);
+    assert!(grammar.term_by_name("Pragma").ignored);
+    assert!(!grammar.term_by_name("A").ignored);
+
+    let grammar: rustemo::Result<Grammar> = r#"
+        %ignore Noise;
+        S: A;
+        terminals
+        A: 'a';
+    "#
+    .parse();
+    assert!(grammar
+        .unwrap_err()
+        .to_string()
+        .contains("Ignored terminal 'Noise' is not defined."));
+}
+
+#[test]
+fn trivia_directive() {
+    let grammar: Grammar = r#"
+        %trivia WS, Comment;
+        S: A+;
+        terminals
+        A: 'a';
+        WS: /\s+/;
+        Comment: /#.*/;
+    "#
+    .parse()
+    .unwrap();
+    let ws = grammar.term_by_name("WS");
+    assert!(ws.trivia && ws.ignored);
+    assert!(grammar.term_by_name("Comment").trivia);
+    assert!(!grammar.term_by_name("A").trivia);
+
+    let grammar: rustemo::Result<Grammar> = r#"
+        %trivia WS;
+        S: A;
+        terminals
+        A: 'a';
+    "#
+    .parse();
+    assert!(grammar
+        .unwrap_err()
+        .to_string()
+        .contains("Trivia terminal 'WS' is not defined."));
+}
+
+#[test]
+fn terminal_defined_twice() {
+    let grammar: rustemo::Result<Grammar> = r#"
+        S: A;
+        terminals
+        A: 'a';
+        A: 'b';
+    "#
+    .parse();
+    assert!(grammar
+        .unwrap_err()
+        .to_string()
+        .contains("Terminal 'A' is defined twice."));
+}
+
+#[test]
+fn terminal_alternatives() {
+    let grammar: Grammar = r#"
+        S: Bool;
+        terminals
+        Bool: 'true' | 'false';
+    "#
+    .parse()
+    .unwrap();
+    let bool_term = grammar.symbol_to_term(grammar.symbol_index("Bool"));
+    assert_eq!(bool_term.recognizer_kind(), RecognizerKind::Alternatives);
+    assert!(bool_term.has_content);
+    assert!(matches!(&bool_term.recognizer,
+                     Some(Recognizer::StrAlternatives(alternatives))
+                     if alternatives.iter().map(|a| a.as_ref().as_str())
+                                    .eq(["true", "false"])));
+
+    let grammar: rustemo::Result<Grammar> = r#"
+        S: Bool;
+        terminals
+        Bool: 'true' | '';
+    "#
+    .parse();
+    assert!(grammar
+        .unwrap_err()
+        .to_string()
+        .contains("String alternatives must not be empty."));
+}
+
+#[test]
+fn terminal_types() {
+    let grammar: Grammar = r#"
+        S: Path Generic Array;
+        terminals
+        Path: /\d+/: std::num::NonZeroU32;
+        Generic: /\w+/: Vec<u8> {prefer};
+        Array: /\d+/: [u8; 4] ;
+    "#
+    .parse()
+    .unwrap();
+    let output_type = |name| {
+        grammar
+            .symbol_to_term(grammar.symbol_index(name))
+            .output_type
+            .clone()
+    };
+    assert_eq!(output_type("Path").as_deref(), Some("std::num::NonZeroU32"));
+    assert_eq!(output_type("Generic").as_deref(), Some("Vec<u8>"));
+    assert_eq!(output_type("Array").as_deref(), Some("[u8; 4]"));
+
+    let grammar: rustemo::Result<Grammar> = r#"
+        S: Num;
+        terminals
+        Num: /\d+/: Vec<u8;
+    "#
+    .parse();
+    assert!(grammar
+        .unwrap_err()
+        .to_string()
+        .contains("Can't use 'Vec<u8' as a valid Rust type."));
+}
+
+#[test]
+fn start_directive() {
+    let grammar: Grammar = r#"
+        %start S;
+        B: A;
+        S: B A;
+        terminals
+        A: 'a';
+    "#
+    .parse()
+    .unwrap();
+    assert_eq!(grammar.start_index, grammar.symbol_index("S"));
+    assert_eq!(
+        grammar.productions[ProdIndex(0)].rhs_symbols(),
+        [grammar.symbol_index("S")]
+    );
+
+    let grammar: rustemo::Result<Grammar> = r#"
+        %start C;
+        S: A;
+        terminals
+        A: 'a';
+    "#
+    .parse();
+    assert!(grammar
+        .unwrap_err()
+        .to_string()
+        .contains("Start rule 'C' is not a grammar rule."));
+
+    let grammar: rustemo::Result<Grammar> = r#"
+        %start S;
+        %start B;
+        S: B;
+        B: A;
+        terminals
+        A: 'a';
+    "#
+    .parse();
+    assert!(grammar
+        .unwrap_err()
+        .to_string()
+        .contains("Only one %start directive is allowed."));
+}
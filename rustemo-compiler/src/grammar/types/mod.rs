@@ -3,7 +3,7 @@
 
 use std::{
     cell::Cell,
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
 };
 
 use crate::index::{SymbolIndex, SymbolVec};
@@ -64,6 +64,70 @@ impl SymbolTypes {
         &self.symbol_types[idx]
     }
 
+    pub(crate) fn get_type_by_name(&self, name: &str) -> Option<&SymbolType> {
+        self.symbol_types.iter().find(|t| t.name == name)
+    }
+
+    /// Returns the names of the types which carry the input lifetime if
+    /// content terminals without an inline type borrow from the input. These
+    /// are the types of such terminals, of the `@text` rules and all the types
+    /// referring to them.
+    pub(crate) fn borrowed_types(&self, grammar: &Grammar) -> BTreeSet<String> {
+        let mut borrowed: BTreeSet<String> = grammar
+            .terminals
+            .iter()
+            .filter(|t| t.has_content && t.output_type.is_none())
+            .map(|t| t.name.clone())
+            .chain(
+                self.symbol_types
+                    .iter()
+                    .filter(|t| matches!(t.kind, SymbolTypeKind::Text))
+                    .map(|t| t.name.clone()),
+            )
+            .collect();
+
+        fn choice_borrows(
+            choice: &Choice,
+            borrowed: &BTreeSet<String>,
+        ) -> bool {
+            match &choice.kind {
+                ChoiceKind::Ref { ref_type, .. } => borrowed.contains(ref_type),
+                ChoiceKind::Struct { fields, .. } => {
+                    fields.iter().any(|f| borrowed.contains(&f.ref_type))
+                }
+                ChoiceKind::Empty | ChoiceKind::Plain => false,
+            }
+        }
+
+        // Propagate until there are no new types referring to borrowed types.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for ty in &self.symbol_types {
+                let mut borrows = false;
+                for choice in &ty.choices {
+                    if choice_borrows(choice, &borrowed) {
+                        borrows = true;
+                        if let ChoiceKind::Struct { type_name, .. } =
+                            &choice.kind
+                        {
+                            changed |= borrowed.insert(type_name.clone());
+                        }
+                    }
+                }
+                if borrows {
+                    changed |= borrowed.insert(ty.name.clone());
+                    if let SymbolTypeKind::Enum { type_name }
+                    | SymbolTypeKind::Struct { type_name } = &ty.kind
+                    {
+                        changed |= borrowed.insert(type_name.clone());
+                    }
+                }
+            }
+        }
+        borrowed
+    }
+
     /// Returns a vector of all types inferred from the provided grammar.
     pub(crate) fn symbol_types(
         grammar: &Grammar,
@@ -135,18 +199,25 @@ impl SymbolTypes {
                         let type_names = grammar.symbol_names(
                             rhs.iter().map(|a| a.symbol).collect::<Vec<_>>(),
                         );
+                        let labels = rhs
+                            .iter()
+                            .filter_map(|a| a.name.as_ref())
+                            .map(|name| name.as_ref())
+                            .collect::<Vec<_>>();
                         for assign in &rhs {
                             let ref_type = grammar.symbol_name(assign.symbol);
+                            let field_name = to_snake_case(&ref_type);
                             let name =
                                 assign.name.clone().unwrap_or(Name::new(
                                     format!(
                                         "{}{}",
-                                        to_snake_case(&ref_type),
+                                        field_name,
                                         if type_names
                                             .iter()
                                             .filter(|&ty| *ty == ref_type)
                                             .count()
                                             > 1
+                                            || labels.contains(&&field_name)
                                         {
                                             // Not a unique rule ref inside this choice
                                             format!("_{}", assign.idx + 1)
@@ -190,20 +261,26 @@ impl SymbolTypes {
                 optional,
             });
         }
+        Self::columns_types(grammar, &mut types);
         Self::find_recursions(&mut types, start_symbol);
         types
     }
 
     /// Recognize different rule patters:
-    /// ```
+    /// ```text
     /// A: B | EMPTY ---> A is Option<B>
     /// A: A B | B; or A: A B | B | EMPTY; ---> A is Vec<B>
     /// A: <Whatever> ... | EMPTY; ---> A optional Enum
+    /// @columns A: A B | B; ---> A is columns of B fields
+    /// @text A: <Whatever>; ---> A is the matched input text
     /// ```
     fn get_type_kind(
         nt: &NonTerminal,
         choices: &Vec<Choice>,
     ) -> SymbolTypeKind {
+        if matches! { nt.annotation, Some(ref a) if a == "text" } {
+            return SymbolTypeKind::Text;
+        }
         let type_name = &nt.name;
         struct Match {
             no_match: bool,
@@ -272,7 +349,10 @@ impl SymbolTypes {
                 no_match: false,
                 ..
             } if single == recurse
-                && matches! { nt.annotation, Some(ref action) if action == "vec" } =>
+                && matches!(
+                    nt.annotation.as_deref(),
+                    Some("vec" | "columns")
+                ) =>
             {
                 SymbolTypeKind::Vec {
                     ref_type: single,
@@ -313,6 +393,31 @@ impl SymbolTypes {
         }
     }
 
+    /// Turns the `Vec` types of the rules annotated with `@columns` to
+    /// `Columns` if the repeated rule has a struct type. Other `@columns` rules
+    /// are left as they are and reported by the generator.
+    fn columns_types(grammar: &Grammar, types: &mut SymbolVec<SymbolType>) {
+        for nonterminal in &grammar.nonterminals {
+            if !matches!(nonterminal.annotation, Some(ref a) if a == "columns")
+            {
+                continue;
+            }
+            let idx = nonterminal.idx.symbol_index(grammar.terminals.len());
+            let ref_type = match &types[idx].kind {
+                SymbolTypeKind::Vec { ref_type, .. } => ref_type.clone(),
+                _ => continue,
+            };
+            let is_struct = types.iter().any(|t| {
+                t.name == ref_type
+                    && !t.optional
+                    && matches!(t.kind, SymbolTypeKind::Struct { .. })
+            });
+            if is_struct {
+                types[idx].kind = SymbolTypeKind::Columns { ref_type };
+            }
+        }
+    }
+
     /// Flags recursive types by performing a DFS over the types reference graph.
     fn find_recursions(
         symbol_types: &mut SymbolVec<SymbolType>,
@@ -352,6 +457,15 @@ impl SymbolTypes {
                         }
                     }
                 }
+                // Columns are vectors thus recursion through them is not
+                // flagged.
+                SymbolTypeKind::Columns { ref_type } => {
+                    if !visiting.contains(ref_type) {
+                        visiting.insert(ref_type.clone());
+                        dfs(types.get(ref_type).unwrap(), visiting, types);
+                        visiting.remove(ref_type);
+                    }
+                }
                 SymbolTypeKind::Struct { .. } | SymbolTypeKind::Enum { .. } => {
                     for choice in &ty.choices {
                         match &choice.kind {
@@ -397,7 +511,7 @@ impl SymbolTypes {
                         }
                     }
                 }
-                SymbolTypeKind::Terminal => (),
+                SymbolTypeKind::Terminal | SymbolTypeKind::Text => (),
             }
         }
         log!("Start symbol: {start_symbol:#?}");
@@ -441,6 +555,13 @@ pub(crate) enum SymbolTypeKind {
         recursive: Cell<bool>,
     },
 
+    /// A repetition of a struct type stored as a struct of vectors, one for
+    /// each field of the repeated struct. Produced by rules annotated with
+    /// `@columns`, e.g. `@columns A: A B | B;` where `B` is a struct.
+    Columns {
+        ref_type: String,
+    },
+
     /// Just a single choice as in `B: A C;`
     /// choices must be a single element of a Struct kind and
     /// optionally element of an Empty kind.
@@ -450,7 +571,7 @@ pub(crate) enum SymbolTypeKind {
     },
 
     /// The default. All other non-empty rules. Can be optional if
-    /// ```
+    /// ```text
     /// <Whatever>... | EMPTY
     /// ```
     ///
@@ -458,6 +579,10 @@ pub(crate) enum SymbolTypeKind {
         type_name: String,
     },
 
+    /// The input text matched by the rule. Typed as a content terminal.
+    /// Produced by rules annotated with `@text`.
+    Text,
+
     Terminal,
 }
 
@@ -66,6 +66,12 @@ fn symbols_type_deduction() {
             Recursive: RecursiveA | Tb;
             RecursiveA: Recursive Ta;
 
+            @text
+            Text: A B | Tc;
+
+            @columns
+            Columns: Columns Struct | Struct;
+
             terminals
             Num: /\d+/;
             Ta: 'a';
@@ -2,12 +2,12 @@
 use std::fmt::Debug;
 use std::hash::Hash;
 use rustemo::{
-    Result, Input as InputT, Lexer, Token, TokenRecognizer as TokenRecognizerT, Parser,
-    ParserDefinition, State as StateT, Builder,
+    Result, Input as InputT, Lexer, Token, Parser, ParserDefinition, State as StateT,
+    Builder,
 };
 use regex::Regex;
 use once_cell::sync::Lazy;
-use rustemo::StringLexer;
+use rustemo::{StringLexer, TokenRecognizer as TokenRecognizerT};
 use rustemo::LRBuilder;
 use super::rustemo_actions;
 use rustemo::{LRParser, LRContext};
@@ -18,10 +18,10 @@ use rustemo::debug::{log, logn};
 #[cfg(debug_assertions)]
 use colored::*;
 pub type Input = str;
-const STATE_COUNT: usize = 145usize;
-const MAX_RECOGNIZERS: usize = 15usize;
+const STATE_COUNT: usize = 223usize;
+const MAX_RECOGNIZERS: usize = 16usize;
 #[allow(dead_code)]
-const TERMINAL_COUNT: usize = 44usize;
+const TERMINAL_COUNT: usize = 60usize;
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenKind {
@@ -30,6 +30,13 @@ pub enum TokenKind {
     Terminals,
     Import,
     As,
+    LineCommentDirective,
+    BlockCommentDirective,
+    Keywords,
+    Start,
+    Ignore,
+    Trivia,
+    TerminalsInclude,
     Colon,
     SemiColon,
     Comma,
@@ -55,18 +62,27 @@ pub enum TokenKind {
     Dynamic,
     NOPS,
     NOPSE,
+    NoLayout,
+    Prec,
+    Assoc,
     Prefer,
     Finish,
     NoFinish,
+    Raw,
+    Nested,
     OComment,
     CComment,
     Name,
+    MacroName,
+    RustType,
     RegexTerm,
     IntConst,
     FloatConst,
     BoolConst,
     StrConst,
     Annotation,
+    DocComment,
+    ActionsCode,
     WS,
     CommentLine,
     NotComment,
@@ -84,19 +100,41 @@ pub enum ProdKind {
     GrammarRule1P1,
     GrammarRule1P2,
     FileP2,
-    ImportStm1P1,
-    ImportStm1P2,
+    FileHeader1P1,
+    FileHeader1P2,
     FileP3,
     TerminalRule1P1,
     TerminalRule1P2,
     FileP4,
     FileP5,
+    FileHeaderP1,
+    FileHeaderP2,
+    FileHeaderP3,
+    FileHeaderP4,
+    FileHeaderP5,
+    FileHeaderP6,
+    FileHeaderP7,
+    FileHeaderP8,
     ImportStmP1,
     ImportStmP2,
+    CommentDirectiveP1,
+    CommentDirectiveP2,
+    KeywordsDirectiveP1,
+    Name1P1,
+    Name1P2,
+    StartDirectiveP1,
+    IgnoreDirectiveP1,
+    TriviaDirectiveP1,
+    TerminalsDirectiveP1,
     GrammarRuleP1,
+    DocComment1P1,
+    DocComment1P2,
+    DocComment0P1,
+    DocComment0P2,
     AnnotationOptP1,
     AnnotationOptP2,
     GrammarRuleP2,
+    GrammarRuleP3,
     GrammarRuleRHSP1,
     GrammarRuleRHSP2,
     ProductionP1,
@@ -104,9 +142,12 @@ pub enum ProdKind {
     Assignment1P2,
     ProductionP2,
     TerminalRuleP1,
+    TerminalTypeOptP1,
+    TerminalTypeOptP2,
     TerminalRuleP2,
     TerminalRuleP3,
     TerminalRuleP4,
+    TerminalTypeP1,
     ProdMetaDataLeft,
     ProdMetaDataReduce,
     ProdMetaDataRight,
@@ -114,9 +155,14 @@ pub enum ProdKind {
     ProdMetaDataDynamic,
     ProdMetaDataNOPS,
     ProdMetaDataNOPSE,
+    ProdMetaDataNoLayout,
+    ProdMetaDataPredicate,
     ProdMetaDataPriority,
-    ProdMetaDataP9,
-    ProdMetaDataP10,
+    ProdMetaDataPrec,
+    ProdMetaDataAssocLeft,
+    ProdMetaDataAssocRight,
+    ProdMetaDataP14,
+    ProdMetaDataP15,
     ProdMetaDatasP1,
     ProdMetaDatasP2,
     TermMetaDataPrefer,
@@ -147,6 +193,8 @@ pub enum ProdKind {
     RepetitionOperatorOptP1,
     RepetitionOperatorOptP2,
     GrammarSymbolRefP2,
+    GrammarSymbolRefP3,
+    MacroCallP1,
     RepetitionOperatorP1,
     RepetitionModifiersOptP1,
     RepetitionModifiersOptP2,
@@ -164,6 +212,12 @@ pub enum ProdKind {
     GrammarSymbolP2,
     RecognizerP1,
     RecognizerP2,
+    RecognizerP3,
+    RecognizerP4,
+    StrAlternativesP1,
+    StrAlternativesP2,
+    RawTermP1,
+    RawTermP2,
     LayoutP1,
     LayoutItem1P1,
     LayoutItem1P2,
@@ -189,23 +243,55 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::FileP1 => "File: GrammarRule1",
             ProdKind::GrammarRule1P1 => "GrammarRule1: GrammarRule1 GrammarRule",
             ProdKind::GrammarRule1P2 => "GrammarRule1: GrammarRule",
-            ProdKind::FileP2 => "File: ImportStm1 GrammarRule1",
-            ProdKind::ImportStm1P1 => "ImportStm1: ImportStm1 ImportStm",
-            ProdKind::ImportStm1P2 => "ImportStm1: ImportStm",
+            ProdKind::FileP2 => "File: FileHeader1 GrammarRule1",
+            ProdKind::FileHeader1P1 => "FileHeader1: FileHeader1 FileHeader",
+            ProdKind::FileHeader1P2 => "FileHeader1: FileHeader",
             ProdKind::FileP3 => "File: GrammarRule1 Terminals TerminalRule1",
             ProdKind::TerminalRule1P1 => "TerminalRule1: TerminalRule1 TerminalRule",
             ProdKind::TerminalRule1P2 => "TerminalRule1: TerminalRule",
-            ProdKind::FileP4 => "File: ImportStm1 GrammarRule1 Terminals TerminalRule1",
+            ProdKind::FileP4 => "File: FileHeader1 GrammarRule1 Terminals TerminalRule1",
             ProdKind::FileP5 => "File: Terminals TerminalRule1",
+            ProdKind::FileHeaderP1 => "FileHeader: ImportStm",
+            ProdKind::FileHeaderP2 => "FileHeader: CommentDirective",
+            ProdKind::FileHeaderP3 => "FileHeader: KeywordsDirective",
+            ProdKind::FileHeaderP4 => "FileHeader: StartDirective",
+            ProdKind::FileHeaderP5 => "FileHeader: IgnoreDirective",
+            ProdKind::FileHeaderP6 => "FileHeader: TriviaDirective",
+            ProdKind::FileHeaderP7 => "FileHeader: TerminalsDirective",
+            ProdKind::FileHeaderP8 => "FileHeader: ActionsCode",
             ProdKind::ImportStmP1 => "ImportStm: Import StrConst",
             ProdKind::ImportStmP2 => "ImportStm: Import StrConst As Name",
+            ProdKind::CommentDirectiveP1 => {
+                "CommentDirective: LineCommentDirective StrConst"
+            }
+            ProdKind::CommentDirectiveP2 => {
+                "CommentDirective: BlockCommentDirective StrConst StrConst"
+            }
+            ProdKind::KeywordsDirectiveP1 => {
+                "KeywordsDirective: Keywords Name Colon Name1 SemiColon"
+            }
+            ProdKind::Name1P1 => "Name1: Name1 Comma Name",
+            ProdKind::Name1P2 => "Name1: Name",
+            ProdKind::StartDirectiveP1 => "StartDirective: Start Name SemiColon",
+            ProdKind::IgnoreDirectiveP1 => "IgnoreDirective: Ignore Name1 SemiColon",
+            ProdKind::TriviaDirectiveP1 => "TriviaDirective: Trivia Name1 SemiColon",
+            ProdKind::TerminalsDirectiveP1 => {
+                "TerminalsDirective: TerminalsInclude StrConst SemiColon"
+            }
             ProdKind::GrammarRuleP1 => {
-                "GrammarRule: AnnotationOpt Name Colon GrammarRuleRHS SemiColon"
+                "GrammarRule: DocComment0 AnnotationOpt Name Colon GrammarRuleRHS SemiColon"
             }
+            ProdKind::DocComment1P1 => "DocComment1: DocComment1 DocComment",
+            ProdKind::DocComment1P2 => "DocComment1: DocComment",
+            ProdKind::DocComment0P1 => "DocComment0: DocComment1",
+            ProdKind::DocComment0P2 => "DocComment0: ",
             ProdKind::AnnotationOptP1 => "AnnotationOpt: Annotation",
             ProdKind::AnnotationOptP2 => "AnnotationOpt: ",
             ProdKind::GrammarRuleP2 => {
-                "GrammarRule: AnnotationOpt Name OBrace ProdMetaDatas CBrace Colon GrammarRuleRHS SemiColon"
+                "GrammarRule: DocComment0 AnnotationOpt Name OBrace ProdMetaDatas CBrace Colon GrammarRuleRHS SemiColon"
+            }
+            ProdKind::GrammarRuleP3 => {
+                "GrammarRule: DocComment0 AnnotationOpt MacroName Name1 CBracket Colon GrammarRuleRHS SemiColon"
             }
             ProdKind::GrammarRuleRHSP1 => {
                 "GrammarRuleRHS: GrammarRuleRHS Choice Production"
@@ -218,17 +304,20 @@ impl std::fmt::Debug for ProdKind {
                 "Production: Assignment1 OBrace ProdMetaDatas CBrace"
             }
             ProdKind::TerminalRuleP1 => {
-                "TerminalRule: AnnotationOpt Name Colon Recognizer SemiColon"
+                "TerminalRule: DocComment0 AnnotationOpt Name Colon Recognizer TerminalTypeOpt SemiColon"
             }
+            ProdKind::TerminalTypeOptP1 => "TerminalTypeOpt: TerminalType",
+            ProdKind::TerminalTypeOptP2 => "TerminalTypeOpt: ",
             ProdKind::TerminalRuleP2 => {
-                "TerminalRule: AnnotationOpt Name Colon SemiColon"
+                "TerminalRule: DocComment0 AnnotationOpt Name Colon SemiColon"
             }
             ProdKind::TerminalRuleP3 => {
-                "TerminalRule: AnnotationOpt Name Colon Recognizer OBrace TermMetaDatas CBrace SemiColon"
+                "TerminalRule: DocComment0 AnnotationOpt Name Colon Recognizer TerminalTypeOpt OBrace TermMetaDatas CBrace SemiColon"
             }
             ProdKind::TerminalRuleP4 => {
-                "TerminalRule: AnnotationOpt Name Colon OBrace TermMetaDatas CBrace SemiColon"
+                "TerminalRule: DocComment0 AnnotationOpt Name Colon OBrace TermMetaDatas CBrace SemiColon"
             }
+            ProdKind::TerminalTypeP1 => "TerminalType: Colon RustType",
             ProdKind::ProdMetaDataLeft => "ProdMetaData: Left",
             ProdKind::ProdMetaDataReduce => "ProdMetaData: Reduce",
             ProdKind::ProdMetaDataRight => "ProdMetaData: Right",
@@ -236,9 +325,14 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::ProdMetaDataDynamic => "ProdMetaData: Dynamic",
             ProdKind::ProdMetaDataNOPS => "ProdMetaData: NOPS",
             ProdKind::ProdMetaDataNOPSE => "ProdMetaData: NOPSE",
+            ProdKind::ProdMetaDataNoLayout => "ProdMetaData: NoLayout",
+            ProdKind::ProdMetaDataPredicate => "ProdMetaData: Optional Name",
             ProdKind::ProdMetaDataPriority => "ProdMetaData: IntConst",
-            ProdKind::ProdMetaDataP9 => "ProdMetaData: UserMetaData",
-            ProdKind::ProdMetaDataP10 => "ProdMetaData: ProdKind",
+            ProdKind::ProdMetaDataPrec => "ProdMetaData: Prec Colon IntConst",
+            ProdKind::ProdMetaDataAssocLeft => "ProdMetaData: Assoc Colon Left",
+            ProdKind::ProdMetaDataAssocRight => "ProdMetaData: Assoc Colon Right",
+            ProdKind::ProdMetaDataP14 => "ProdMetaData: UserMetaData",
+            ProdKind::ProdMetaDataP15 => "ProdMetaData: ProdKind",
             ProdKind::ProdMetaDatasP1 => {
                 "ProdMetaDatas: ProdMetaDatas Comma ProdMetaData"
             }
@@ -283,6 +377,10 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::GrammarSymbolRefP2 => {
                 "GrammarSymbolRef: ProductionGroup RepetitionOperatorOpt"
             }
+            ProdKind::GrammarSymbolRefP3 => {
+                "GrammarSymbolRef: MacroCall RepetitionOperatorOpt"
+            }
+            ProdKind::MacroCallP1 => "MacroCall: MacroName Name1 CBracket",
             ProdKind::RepetitionOperatorP1 => {
                 "RepetitionOperator: RepetitionOperatorOp RepetitionModifiersOpt"
             }
@@ -316,6 +414,18 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::GrammarSymbolP2 => "GrammarSymbol: StrConst",
             ProdKind::RecognizerP1 => "Recognizer: StrConst",
             ProdKind::RecognizerP2 => "Recognizer: RegexTerm",
+            ProdKind::RecognizerP3 => "Recognizer: RawTerm",
+            ProdKind::RecognizerP4 => "Recognizer: StrAlternatives",
+            ProdKind::StrAlternativesP1 => {
+                "StrAlternatives: StrAlternatives Choice StrConst"
+            }
+            ProdKind::StrAlternativesP2 => "StrAlternatives: StrConst Choice StrConst",
+            ProdKind::RawTermP1 => {
+                "RawTerm: Raw OBracket StrConst Comma StrConst CBracket"
+            }
+            ProdKind::RawTermP2 => {
+                "RawTerm: Raw OBracket StrConst Comma StrConst Comma Nested CBracket"
+            }
             ProdKind::LayoutP1 => "Layout: LayoutItem0",
             ProdKind::LayoutItem1P1 => "LayoutItem1: LayoutItem1 LayoutItem",
             ProdKind::LayoutItem1P2 => "LayoutItem1: LayoutItem",
@@ -346,15 +456,27 @@ pub enum NonTermKind {
     AUGL,
     File,
     GrammarRule1,
-    ImportStm1,
+    FileHeader1,
     TerminalRule1,
+    FileHeader,
     ImportStm,
+    CommentDirective,
+    KeywordsDirective,
+    Name1,
+    StartDirective,
+    IgnoreDirective,
+    TriviaDirective,
+    TerminalsDirective,
     GrammarRule,
+    DocComment1,
+    DocComment0,
     AnnotationOpt,
     GrammarRuleRHS,
     Production,
     Assignment1,
     TerminalRule,
+    TerminalTypeOpt,
+    TerminalType,
     ProdMetaData,
     ProdMetaDatas,
     TermMetaData,
@@ -368,6 +490,7 @@ pub enum NonTermKind {
     ProductionGroup,
     GrammarSymbolRef,
     RepetitionOperatorOpt,
+    MacroCall,
     RepetitionOperator,
     RepetitionModifiersOpt,
     RepetitionOperatorOp,
@@ -376,6 +499,8 @@ pub enum NonTermKind {
     RepetitionModifier,
     GrammarSymbol,
     Recognizer,
+    StrAlternatives,
+    RawTerm,
     Layout,
     LayoutItem1,
     LayoutItem0,
@@ -393,19 +518,41 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::GrammarRule1P1 => NonTermKind::GrammarRule1,
             ProdKind::GrammarRule1P2 => NonTermKind::GrammarRule1,
             ProdKind::FileP2 => NonTermKind::File,
-            ProdKind::ImportStm1P1 => NonTermKind::ImportStm1,
-            ProdKind::ImportStm1P2 => NonTermKind::ImportStm1,
+            ProdKind::FileHeader1P1 => NonTermKind::FileHeader1,
+            ProdKind::FileHeader1P2 => NonTermKind::FileHeader1,
             ProdKind::FileP3 => NonTermKind::File,
             ProdKind::TerminalRule1P1 => NonTermKind::TerminalRule1,
             ProdKind::TerminalRule1P2 => NonTermKind::TerminalRule1,
             ProdKind::FileP4 => NonTermKind::File,
             ProdKind::FileP5 => NonTermKind::File,
+            ProdKind::FileHeaderP1 => NonTermKind::FileHeader,
+            ProdKind::FileHeaderP2 => NonTermKind::FileHeader,
+            ProdKind::FileHeaderP3 => NonTermKind::FileHeader,
+            ProdKind::FileHeaderP4 => NonTermKind::FileHeader,
+            ProdKind::FileHeaderP5 => NonTermKind::FileHeader,
+            ProdKind::FileHeaderP6 => NonTermKind::FileHeader,
+            ProdKind::FileHeaderP7 => NonTermKind::FileHeader,
+            ProdKind::FileHeaderP8 => NonTermKind::FileHeader,
             ProdKind::ImportStmP1 => NonTermKind::ImportStm,
             ProdKind::ImportStmP2 => NonTermKind::ImportStm,
+            ProdKind::CommentDirectiveP1 => NonTermKind::CommentDirective,
+            ProdKind::CommentDirectiveP2 => NonTermKind::CommentDirective,
+            ProdKind::KeywordsDirectiveP1 => NonTermKind::KeywordsDirective,
+            ProdKind::Name1P1 => NonTermKind::Name1,
+            ProdKind::Name1P2 => NonTermKind::Name1,
+            ProdKind::StartDirectiveP1 => NonTermKind::StartDirective,
+            ProdKind::IgnoreDirectiveP1 => NonTermKind::IgnoreDirective,
+            ProdKind::TriviaDirectiveP1 => NonTermKind::TriviaDirective,
+            ProdKind::TerminalsDirectiveP1 => NonTermKind::TerminalsDirective,
             ProdKind::GrammarRuleP1 => NonTermKind::GrammarRule,
+            ProdKind::DocComment1P1 => NonTermKind::DocComment1,
+            ProdKind::DocComment1P2 => NonTermKind::DocComment1,
+            ProdKind::DocComment0P1 => NonTermKind::DocComment0,
+            ProdKind::DocComment0P2 => NonTermKind::DocComment0,
             ProdKind::AnnotationOptP1 => NonTermKind::AnnotationOpt,
             ProdKind::AnnotationOptP2 => NonTermKind::AnnotationOpt,
             ProdKind::GrammarRuleP2 => NonTermKind::GrammarRule,
+            ProdKind::GrammarRuleP3 => NonTermKind::GrammarRule,
             ProdKind::GrammarRuleRHSP1 => NonTermKind::GrammarRuleRHS,
             ProdKind::GrammarRuleRHSP2 => NonTermKind::GrammarRuleRHS,
             ProdKind::ProductionP1 => NonTermKind::Production,
@@ -413,9 +560,12 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::Assignment1P2 => NonTermKind::Assignment1,
             ProdKind::ProductionP2 => NonTermKind::Production,
             ProdKind::TerminalRuleP1 => NonTermKind::TerminalRule,
+            ProdKind::TerminalTypeOptP1 => NonTermKind::TerminalTypeOpt,
+            ProdKind::TerminalTypeOptP2 => NonTermKind::TerminalTypeOpt,
             ProdKind::TerminalRuleP2 => NonTermKind::TerminalRule,
             ProdKind::TerminalRuleP3 => NonTermKind::TerminalRule,
             ProdKind::TerminalRuleP4 => NonTermKind::TerminalRule,
+            ProdKind::TerminalTypeP1 => NonTermKind::TerminalType,
             ProdKind::ProdMetaDataLeft => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDataReduce => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDataRight => NonTermKind::ProdMetaData,
@@ -423,9 +573,14 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::ProdMetaDataDynamic => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDataNOPS => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDataNOPSE => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataNoLayout => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataPredicate => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDataPriority => NonTermKind::ProdMetaData,
-            ProdKind::ProdMetaDataP9 => NonTermKind::ProdMetaData,
-            ProdKind::ProdMetaDataP10 => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataPrec => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataAssocLeft => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataAssocRight => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataP14 => NonTermKind::ProdMetaData,
+            ProdKind::ProdMetaDataP15 => NonTermKind::ProdMetaData,
             ProdKind::ProdMetaDatasP1 => NonTermKind::ProdMetaDatas,
             ProdKind::ProdMetaDatasP2 => NonTermKind::ProdMetaDatas,
             ProdKind::TermMetaDataPrefer => NonTermKind::TermMetaData,
@@ -456,6 +611,8 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::RepetitionOperatorOptP1 => NonTermKind::RepetitionOperatorOpt,
             ProdKind::RepetitionOperatorOptP2 => NonTermKind::RepetitionOperatorOpt,
             ProdKind::GrammarSymbolRefP2 => NonTermKind::GrammarSymbolRef,
+            ProdKind::GrammarSymbolRefP3 => NonTermKind::GrammarSymbolRef,
+            ProdKind::MacroCallP1 => NonTermKind::MacroCall,
             ProdKind::RepetitionOperatorP1 => NonTermKind::RepetitionOperator,
             ProdKind::RepetitionModifiersOptP1 => NonTermKind::RepetitionModifiersOpt,
             ProdKind::RepetitionModifiersOptP2 => NonTermKind::RepetitionModifiersOpt,
@@ -479,6 +636,12 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::GrammarSymbolP2 => NonTermKind::GrammarSymbol,
             ProdKind::RecognizerP1 => NonTermKind::Recognizer,
             ProdKind::RecognizerP2 => NonTermKind::Recognizer,
+            ProdKind::RecognizerP3 => NonTermKind::Recognizer,
+            ProdKind::RecognizerP4 => NonTermKind::Recognizer,
+            ProdKind::StrAlternativesP1 => NonTermKind::StrAlternatives,
+            ProdKind::StrAlternativesP2 => NonTermKind::StrAlternatives,
+            ProdKind::RawTermP1 => NonTermKind::RawTerm,
+            ProdKind::RawTermP2 => NonTermKind::RawTerm,
             ProdKind::LayoutP1 => NonTermKind::Layout,
             ProdKind::LayoutItem1P1 => NonTermKind::LayoutItem1,
             ProdKind::LayoutItem1P2 => NonTermKind::LayoutItem1,
@@ -499,6 +662,303 @@ impl From<ProdKind> for NonTermKind {
         }
     }
 }
+#[allow(dead_code)]
+impl ProdKind {
+    /// The non-terminal this production reduces to.
+    pub fn nonterminal(&self) -> NonTermKind {
+        (*self).into()
+    }
+    /// The number of symbols on the right-hand side of this
+    /// production.
+    pub fn rhs_len(&self) -> usize {
+        match self {
+            ProdKind::FileP1 => 1usize,
+            ProdKind::GrammarRule1P1 => 2usize,
+            ProdKind::GrammarRule1P2 => 1usize,
+            ProdKind::FileP2 => 2usize,
+            ProdKind::FileHeader1P1 => 2usize,
+            ProdKind::FileHeader1P2 => 1usize,
+            ProdKind::FileP3 => 3usize,
+            ProdKind::TerminalRule1P1 => 2usize,
+            ProdKind::TerminalRule1P2 => 1usize,
+            ProdKind::FileP4 => 4usize,
+            ProdKind::FileP5 => 2usize,
+            ProdKind::FileHeaderP1 => 1usize,
+            ProdKind::FileHeaderP2 => 1usize,
+            ProdKind::FileHeaderP3 => 1usize,
+            ProdKind::FileHeaderP4 => 1usize,
+            ProdKind::FileHeaderP5 => 1usize,
+            ProdKind::FileHeaderP6 => 1usize,
+            ProdKind::FileHeaderP7 => 1usize,
+            ProdKind::FileHeaderP8 => 1usize,
+            ProdKind::ImportStmP1 => 2usize,
+            ProdKind::ImportStmP2 => 4usize,
+            ProdKind::CommentDirectiveP1 => 2usize,
+            ProdKind::CommentDirectiveP2 => 3usize,
+            ProdKind::KeywordsDirectiveP1 => 5usize,
+            ProdKind::Name1P1 => 3usize,
+            ProdKind::Name1P2 => 1usize,
+            ProdKind::StartDirectiveP1 => 3usize,
+            ProdKind::IgnoreDirectiveP1 => 3usize,
+            ProdKind::TriviaDirectiveP1 => 3usize,
+            ProdKind::TerminalsDirectiveP1 => 3usize,
+            ProdKind::GrammarRuleP1 => 6usize,
+            ProdKind::DocComment1P1 => 2usize,
+            ProdKind::DocComment1P2 => 1usize,
+            ProdKind::DocComment0P1 => 1usize,
+            ProdKind::DocComment0P2 => 0usize,
+            ProdKind::AnnotationOptP1 => 1usize,
+            ProdKind::AnnotationOptP2 => 0usize,
+            ProdKind::GrammarRuleP2 => 9usize,
+            ProdKind::GrammarRuleP3 => 8usize,
+            ProdKind::GrammarRuleRHSP1 => 3usize,
+            ProdKind::GrammarRuleRHSP2 => 1usize,
+            ProdKind::ProductionP1 => 1usize,
+            ProdKind::Assignment1P1 => 2usize,
+            ProdKind::Assignment1P2 => 1usize,
+            ProdKind::ProductionP2 => 4usize,
+            ProdKind::TerminalRuleP1 => 7usize,
+            ProdKind::TerminalTypeOptP1 => 1usize,
+            ProdKind::TerminalTypeOptP2 => 0usize,
+            ProdKind::TerminalRuleP2 => 5usize,
+            ProdKind::TerminalRuleP3 => 10usize,
+            ProdKind::TerminalRuleP4 => 8usize,
+            ProdKind::TerminalTypeP1 => 2usize,
+            ProdKind::ProdMetaDataLeft => 1usize,
+            ProdKind::ProdMetaDataReduce => 1usize,
+            ProdKind::ProdMetaDataRight => 1usize,
+            ProdKind::ProdMetaDataShift => 1usize,
+            ProdKind::ProdMetaDataDynamic => 1usize,
+            ProdKind::ProdMetaDataNOPS => 1usize,
+            ProdKind::ProdMetaDataNOPSE => 1usize,
+            ProdKind::ProdMetaDataNoLayout => 1usize,
+            ProdKind::ProdMetaDataPredicate => 2usize,
+            ProdKind::ProdMetaDataPriority => 1usize,
+            ProdKind::ProdMetaDataPrec => 3usize,
+            ProdKind::ProdMetaDataAssocLeft => 3usize,
+            ProdKind::ProdMetaDataAssocRight => 3usize,
+            ProdKind::ProdMetaDataP14 => 1usize,
+            ProdKind::ProdMetaDataP15 => 1usize,
+            ProdKind::ProdMetaDatasP1 => 3usize,
+            ProdKind::ProdMetaDatasP2 => 1usize,
+            ProdKind::TermMetaDataPrefer => 1usize,
+            ProdKind::TermMetaDataFinish => 1usize,
+            ProdKind::TermMetaDataNoFinish => 1usize,
+            ProdKind::TermMetaDataLeft => 1usize,
+            ProdKind::TermMetaDataReduce => 1usize,
+            ProdKind::TermMetaDataRight => 1usize,
+            ProdKind::TermMetaDataShift => 1usize,
+            ProdKind::TermMetaDataDynamic => 1usize,
+            ProdKind::TermMetaDataPriority => 1usize,
+            ProdKind::TermMetaDataP10 => 1usize,
+            ProdKind::TermMetaDatasP1 => 3usize,
+            ProdKind::TermMetaDatasP2 => 1usize,
+            ProdKind::UserMetaDataP1 => 3usize,
+            ProdKind::ProdKindP1 => 1usize,
+            ProdKind::ConstValP1 => 1usize,
+            ProdKind::ConstValP2 => 1usize,
+            ProdKind::ConstValP3 => 1usize,
+            ProdKind::ConstValP4 => 1usize,
+            ProdKind::AssignmentP1 => 1usize,
+            ProdKind::AssignmentP2 => 1usize,
+            ProdKind::AssignmentP3 => 1usize,
+            ProdKind::PlainAssignmentP1 => 3usize,
+            ProdKind::BoolAssignmentP1 => 3usize,
+            ProdKind::ProductionGroupP1 => 3usize,
+            ProdKind::GrammarSymbolRefP1 => 2usize,
+            ProdKind::RepetitionOperatorOptP1 => 1usize,
+            ProdKind::RepetitionOperatorOptP2 => 0usize,
+            ProdKind::GrammarSymbolRefP2 => 2usize,
+            ProdKind::GrammarSymbolRefP3 => 2usize,
+            ProdKind::MacroCallP1 => 3usize,
+            ProdKind::RepetitionOperatorP1 => 2usize,
+            ProdKind::RepetitionModifiersOptP1 => 1usize,
+            ProdKind::RepetitionModifiersOptP2 => 0usize,
+            ProdKind::RepetitionOperatorOpZeroOrMore => 1usize,
+            ProdKind::RepetitionOperatorOpZeroOrMoreGreedy => 1usize,
+            ProdKind::RepetitionOperatorOpOneOrMore => 1usize,
+            ProdKind::RepetitionOperatorOpOneOrMoreGreedy => 1usize,
+            ProdKind::RepetitionOperatorOpOptional => 1usize,
+            ProdKind::RepetitionOperatorOpOptionalGreedy => 1usize,
+            ProdKind::RepetitionModifiersP1 => 3usize,
+            ProdKind::RepetitionModifier1P1 => 3usize,
+            ProdKind::RepetitionModifier1P2 => 1usize,
+            ProdKind::RepetitionModifierP1 => 1usize,
+            ProdKind::GrammarSymbolP1 => 1usize,
+            ProdKind::GrammarSymbolP2 => 1usize,
+            ProdKind::RecognizerP1 => 1usize,
+            ProdKind::RecognizerP2 => 1usize,
+            ProdKind::RecognizerP3 => 1usize,
+            ProdKind::RecognizerP4 => 1usize,
+            ProdKind::StrAlternativesP1 => 3usize,
+            ProdKind::StrAlternativesP2 => 3usize,
+            ProdKind::RawTermP1 => 6usize,
+            ProdKind::RawTermP2 => 8usize,
+            ProdKind::LayoutP1 => 1usize,
+            ProdKind::LayoutItem1P1 => 2usize,
+            ProdKind::LayoutItem1P2 => 1usize,
+            ProdKind::LayoutItem0P1 => 1usize,
+            ProdKind::LayoutItem0P2 => 0usize,
+            ProdKind::LayoutItemP1 => 1usize,
+            ProdKind::LayoutItemP2 => 1usize,
+            ProdKind::CommentP1 => 3usize,
+            ProdKind::CommentP2 => 1usize,
+            ProdKind::CorncsP1 => 1usize,
+            ProdKind::Cornc1P1 => 2usize,
+            ProdKind::Cornc1P2 => 1usize,
+            ProdKind::Cornc0P1 => 1usize,
+            ProdKind::Cornc0P2 => 0usize,
+            ProdKind::CorncP1 => 1usize,
+            ProdKind::CorncP2 => 1usize,
+            ProdKind::CorncP3 => 1usize,
+        }
+    }
+    /// The line and the column in the grammar file where this
+    /// production is defined.
+    pub fn source_location(&self) -> (u32, u32) {
+        match self {
+            ProdKind::FileP1 => (1u32, 6u32),
+            ProdKind::GrammarRule1P1 => (1u32, 6u32),
+            ProdKind::GrammarRule1P2 => (1u32, 6u32),
+            ProdKind::FileP2 => (2u32, 6u32),
+            ProdKind::FileHeader1P1 => (2u32, 6u32),
+            ProdKind::FileHeader1P2 => (2u32, 6u32),
+            ProdKind::FileP3 => (3u32, 6u32),
+            ProdKind::TerminalRule1P1 => (3u32, 6u32),
+            ProdKind::TerminalRule1P2 => (3u32, 6u32),
+            ProdKind::FileP4 => (4u32, 6u32),
+            ProdKind::FileP5 => (5u32, 6u32),
+            ProdKind::FileHeaderP1 => (7u32, 12u32),
+            ProdKind::FileHeaderP2 => (7u32, 24u32),
+            ProdKind::FileHeaderP3 => (7u32, 43u32),
+            ProdKind::FileHeaderP4 => (7u32, 63u32),
+            ProdKind::FileHeaderP5 => (8u32, 12u32),
+            ProdKind::FileHeaderP6 => (8u32, 30u32),
+            ProdKind::FileHeaderP7 => (8u32, 48u32),
+            ProdKind::FileHeaderP8 => (8u32, 69u32),
+            ProdKind::ImportStmP1 => (10u32, 11u32),
+            ProdKind::ImportStmP2 => (11u32, 11u32),
+            ProdKind::CommentDirectiveP1 => (13u32, 18u32),
+            ProdKind::CommentDirectiveP2 => (14u32, 18u32),
+            ProdKind::KeywordsDirectiveP1 => (16u32, 19u32),
+            ProdKind::Name1P1 => (16u32, 19u32),
+            ProdKind::Name1P2 => (16u32, 19u32),
+            ProdKind::StartDirectiveP1 => (18u32, 16u32),
+            ProdKind::IgnoreDirectiveP1 => (20u32, 17u32),
+            ProdKind::TriviaDirectiveP1 => (22u32, 17u32),
+            ProdKind::TerminalsDirectiveP1 => (24u32, 20u32),
+            ProdKind::GrammarRuleP1 => (26u32, 13u32),
+            ProdKind::DocComment1P1 => (26u32, 13u32),
+            ProdKind::DocComment1P2 => (26u32, 13u32),
+            ProdKind::DocComment0P1 => (26u32, 13u32),
+            ProdKind::DocComment0P2 => (26u32, 13u32),
+            ProdKind::AnnotationOptP1 => (26u32, 13u32),
+            ProdKind::AnnotationOptP2 => (26u32, 13u32),
+            ProdKind::GrammarRuleP2 => (27u32, 13u32),
+            ProdKind::GrammarRuleP3 => (28u32, 13u32),
+            ProdKind::GrammarRuleRHSP1 => (29u32, 16u32),
+            ProdKind::GrammarRuleRHSP2 => (30u32, 16u32),
+            ProdKind::ProductionP1 => (31u32, 12u32),
+            ProdKind::Assignment1P1 => (31u32, 12u32),
+            ProdKind::Assignment1P2 => (31u32, 12u32),
+            ProdKind::ProductionP2 => (32u32, 12u32),
+            ProdKind::TerminalRuleP1 => (34u32, 14u32),
+            ProdKind::TerminalTypeOptP1 => (34u32, 14u32),
+            ProdKind::TerminalTypeOptP2 => (34u32, 14u32),
+            ProdKind::TerminalRuleP2 => (35u32, 14u32),
+            ProdKind::TerminalRuleP3 => (36u32, 14u32),
+            ProdKind::TerminalRuleP4 => (37u32, 14u32),
+            ProdKind::TerminalTypeP1 => (38u32, 14u32),
+            ProdKind::ProdMetaDataLeft => (40u32, 14u32),
+            ProdKind::ProdMetaDataReduce => (40u32, 30u32),
+            ProdKind::ProdMetaDataRight => (40u32, 50u32),
+            ProdKind::ProdMetaDataShift => (40u32, 68u32),
+            ProdKind::ProdMetaDataDynamic => (41u32, 14u32),
+            ProdKind::ProdMetaDataNOPS => (41u32, 36u32),
+            ProdKind::ProdMetaDataNOPSE => (41u32, 52u32),
+            ProdKind::ProdMetaDataNoLayout => (42u32, 14u32),
+            ProdKind::ProdMetaDataPredicate => (43u32, 14u32),
+            ProdKind::ProdMetaDataPriority => (44u32, 14u32),
+            ProdKind::ProdMetaDataPrec => (45u32, 14u32),
+            ProdKind::ProdMetaDataAssocLeft => (46u32, 14u32),
+            ProdKind::ProdMetaDataAssocRight => (46u32, 47u32),
+            ProdKind::ProdMetaDataP14 => (47u32, 14u32),
+            ProdKind::ProdMetaDataP15 => (48u32, 14u32),
+            ProdKind::ProdMetaDatasP1 => (49u32, 15u32),
+            ProdKind::ProdMetaDatasP2 => (49u32, 59u32),
+            ProdKind::TermMetaDataPrefer => (51u32, 14u32),
+            ProdKind::TermMetaDataFinish => (51u32, 34u32),
+            ProdKind::TermMetaDataNoFinish => (51u32, 54u32),
+            ProdKind::TermMetaDataLeft => (52u32, 14u32),
+            ProdKind::TermMetaDataReduce => (52u32, 30u32),
+            ProdKind::TermMetaDataRight => (52u32, 50u32),
+            ProdKind::TermMetaDataShift => (52u32, 68u32),
+            ProdKind::TermMetaDataDynamic => (53u32, 14u32),
+            ProdKind::TermMetaDataPriority => (54u32, 14u32),
+            ProdKind::TermMetaDataP10 => (55u32, 14u32),
+            ProdKind::TermMetaDatasP1 => (56u32, 15u32),
+            ProdKind::TermMetaDatasP2 => (56u32, 59u32),
+            ProdKind::UserMetaDataP1 => (58u32, 14u32),
+            ProdKind::ProdKindP1 => (59u32, 10u32),
+            ProdKind::ConstValP1 => (60u32, 10u32),
+            ProdKind::ConstValP2 => (60u32, 21u32),
+            ProdKind::ConstValP3 => (60u32, 34u32),
+            ProdKind::ConstValP4 => (60u32, 46u32),
+            ProdKind::AssignmentP1 => (62u32, 12u32),
+            ProdKind::AssignmentP2 => (63u32, 12u32),
+            ProdKind::AssignmentP3 => (64u32, 12u32),
+            ProdKind::PlainAssignmentP1 => (65u32, 17u32),
+            ProdKind::BoolAssignmentP1 => (66u32, 16u32),
+            ProdKind::ProductionGroupP1 => (68u32, 17u32),
+            ProdKind::GrammarSymbolRefP1 => (70u32, 18u32),
+            ProdKind::RepetitionOperatorOptP1 => (70u32, 18u32),
+            ProdKind::RepetitionOperatorOptP2 => (70u32, 18u32),
+            ProdKind::GrammarSymbolRefP2 => (71u32, 18u32),
+            ProdKind::GrammarSymbolRefP3 => (72u32, 18u32),
+            ProdKind::MacroCallP1 => (73u32, 11u32),
+            ProdKind::RepetitionOperatorP1 => (74u32, 20u32),
+            ProdKind::RepetitionModifiersOptP1 => (74u32, 20u32),
+            ProdKind::RepetitionModifiersOptP2 => (74u32, 20u32),
+            ProdKind::RepetitionOperatorOpZeroOrMore => (75u32, 22u32),
+            ProdKind::RepetitionOperatorOpZeroOrMoreGreedy => (76u32, 22u32),
+            ProdKind::RepetitionOperatorOpOneOrMore => (77u32, 22u32),
+            ProdKind::RepetitionOperatorOpOneOrMoreGreedy => (78u32, 22u32),
+            ProdKind::RepetitionOperatorOpOptional => (79u32, 22u32),
+            ProdKind::RepetitionOperatorOpOptionalGreedy => (80u32, 22u32),
+            ProdKind::RepetitionModifiersP1 => (81u32, 21u32),
+            ProdKind::RepetitionModifier1P1 => (81u32, 21u32),
+            ProdKind::RepetitionModifier1P2 => (81u32, 21u32),
+            ProdKind::RepetitionModifierP1 => (82u32, 20u32),
+            ProdKind::GrammarSymbolP1 => (84u32, 15u32),
+            ProdKind::GrammarSymbolP2 => (84u32, 22u32),
+            ProdKind::RecognizerP1 => (85u32, 12u32),
+            ProdKind::RecognizerP2 => (85u32, 23u32),
+            ProdKind::RecognizerP3 => (85u32, 35u32),
+            ProdKind::RecognizerP4 => (85u32, 45u32),
+            ProdKind::StrAlternativesP1 => (86u32, 17u32),
+            ProdKind::StrAlternativesP2 => (86u32, 48u32),
+            ProdKind::RawTermP1 => (87u32, 9u32),
+            ProdKind::RawTermP2 => (88u32, 9u32),
+            ProdKind::LayoutP1 => (91u32, 8u32),
+            ProdKind::LayoutItem1P1 => (91u32, 8u32),
+            ProdKind::LayoutItem1P2 => (91u32, 8u32),
+            ProdKind::LayoutItem0P1 => (91u32, 8u32),
+            ProdKind::LayoutItem0P2 => (91u32, 8u32),
+            ProdKind::LayoutItemP1 => (92u32, 12u32),
+            ProdKind::LayoutItemP2 => (92u32, 17u32),
+            ProdKind::CommentP1 => (93u32, 9u32),
+            ProdKind::CommentP2 => (93u32, 28u32),
+            ProdKind::CorncsP1 => (94u32, 8u32),
+            ProdKind::Cornc1P1 => (94u32, 8u32),
+            ProdKind::Cornc1P2 => (94u32, 8u32),
+            ProdKind::Cornc0P1 => (94u32, 8u32),
+            ProdKind::Cornc0P2 => (94u32, 8u32),
+            ProdKind::CorncP1 => (95u32, 7u32),
+            ProdKind::CorncP2 => (95u32, 17u32),
+            ProdKind::CorncP3 => (95u32, 30u32),
+        }
+    }
+}
 #[allow(clippy::enum_variant_names)]
 #[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum State {
@@ -506,152 +966,230 @@ pub enum State {
     AUGS0,
     TerminalsS1,
     ImportS2,
-    AnnotationS3,
-    FileS4,
-    GrammarRule1S5,
-    ImportStm1S6,
-    ImportStmS7,
-    GrammarRuleS8,
-    AnnotationOptS9,
-    TerminalRule1S10,
-    AnnotationOptS11,
-    TerminalRuleS12,
-    StrConstS13,
-    TerminalsS14,
-    GrammarRuleS15,
-    GrammarRule1S16,
-    ImportStmS17,
-    NameS18,
-    TerminalRuleS19,
-    NameS20,
-    AsS21,
-    TerminalRule1S22,
-    TerminalsS23,
-    ColonS24,
-    OBraceS25,
-    ColonS26,
-    NameS27,
-    TerminalRule1S28,
-    OBracketS29,
-    NameS30,
+    LineCommentDirectiveS3,
+    BlockCommentDirectiveS4,
+    KeywordsS5,
+    StartS6,
+    IgnoreS7,
+    TriviaS8,
+    TerminalsIncludeS9,
+    DocCommentS10,
+    ActionsCodeS11,
+    FileS12,
+    GrammarRule1S13,
+    FileHeader1S14,
+    FileHeaderS15,
+    ImportStmS16,
+    CommentDirectiveS17,
+    KeywordsDirectiveS18,
+    StartDirectiveS19,
+    IgnoreDirectiveS20,
+    TriviaDirectiveS21,
+    TerminalsDirectiveS22,
+    GrammarRuleS23,
+    DocComment1S24,
+    DocComment0S25,
+    TerminalRule1S26,
+    DocComment0S27,
+    TerminalRuleS28,
+    StrConstS29,
+    StrConstS30,
     StrConstS31,
-    GrammarRuleRHSS32,
-    ProductionS33,
-    Assignment1S34,
-    AssignmentS35,
-    PlainAssignmentS36,
-    BoolAssignmentS37,
-    ProductionGroupS38,
-    GrammarSymbolRefS39,
-    GrammarSymbolS40,
-    LeftS41,
-    RightS42,
-    ReduceS43,
-    ShiftS44,
-    DynamicS45,
-    NOPSS46,
-    NOPSES47,
-    NameS48,
-    IntConstS49,
-    ProdMetaDataS50,
-    ProdMetaDatasS51,
-    UserMetaDataS52,
-    ProdKindS53,
+    NameS32,
+    NameS33,
+    NameS34,
+    Name1S35,
+    Name1S36,
+    StrConstS37,
+    TerminalsS38,
+    GrammarRuleS39,
+    GrammarRule1S40,
+    FileHeaderS41,
+    DocCommentS42,
+    AnnotationS43,
+    AnnotationOptS44,
+    TerminalRuleS45,
+    AnnotationOptS46,
+    AsS47,
+    StrConstS48,
+    ColonS49,
+    SemiColonS50,
+    SemiColonS51,
+    CommaS52,
+    SemiColonS53,
     SemiColonS54,
-    OBraceS55,
-    RegexTermS56,
-    StrConstS57,
-    RecognizerS58,
-    GrammarRuleRHSS59,
-    EqualsS60,
-    QEqualsS61,
-    SemiColonS62,
-    ChoiceS63,
-    OBraceS64,
-    AssignmentS65,
-    ZeroOrMoreS66,
-    ZeroOrMoreGreedyS67,
-    OneOrMoreS68,
-    OneOrMoreGreedyS69,
-    OptionalS70,
-    OptionalGreedyS71,
-    RepetitionOperatorOptS72,
-    RepetitionOperatorS73,
-    RepetitionOperatorOpS74,
-    RepetitionOperatorOptS75,
-    ColonS76,
-    CommaS77,
-    CBraceS78,
-    LeftS79,
-    RightS80,
-    ReduceS81,
-    ShiftS82,
-    DynamicS83,
-    PreferS84,
-    FinishS85,
-    NoFinishS86,
-    NameS87,
-    IntConstS88,
-    TermMetaDataS89,
-    TermMetaDatasS90,
-    UserMetaDataS91,
-    SemiColonS92,
-    OBraceS93,
-    CBracketS94,
-    NameS95,
-    GrammarSymbolRefS96,
-    GrammarSymbolRefS97,
-    ProductionS98,
-    ProdMetaDatasS99,
-    OSBracketS100,
-    RepetitionModifiersOptS101,
-    RepetitionModifiersS102,
-    IntConstS103,
-    FloatConstS104,
-    BoolConstS105,
-    StrConstS106,
-    ConstValS107,
-    ProdMetaDataS108,
-    ColonS109,
-    CommaS110,
-    CBraceS111,
-    TermMetaDatasS112,
-    CBraceS113,
-    NameS114,
-    RepetitionModifier1S115,
-    RepetitionModifierS116,
-    GrammarRuleRHSS117,
-    TermMetaDataS118,
-    SemiColonS119,
-    CBraceS120,
-    CommaS121,
-    CSBracketS122,
-    SemiColonS123,
-    SemiColonS124,
-    RepetitionModifierS125,
-    AUGLS126,
-    OCommentS127,
-    WSS128,
-    CommentLineS129,
-    LayoutS130,
-    LayoutItem1S131,
-    LayoutItem0S132,
-    LayoutItemS133,
-    CommentS134,
-    WSS135,
-    NotCommentS136,
-    CommentS137,
-    CorncsS138,
-    Cornc1S139,
-    Cornc0S140,
-    CorncS141,
-    LayoutItemS142,
-    CCommentS143,
-    CorncS144,
+    TerminalRule1S55,
+    TerminalsS56,
+    NameS57,
+    MacroNameS58,
+    NameS59,
+    NameS60,
+    Name1S61,
+    NameS62,
+    TerminalRule1S63,
+    ColonS64,
+    OBraceS65,
+    Name1S66,
+    ColonS67,
+    SemiColonS68,
+    OBracketS69,
+    NameS70,
+    MacroNameS71,
+    StrConstS72,
+    GrammarRuleRHSS73,
+    ProductionS74,
+    Assignment1S75,
+    AssignmentS76,
+    PlainAssignmentS77,
+    BoolAssignmentS78,
+    ProductionGroupS79,
+    GrammarSymbolRefS80,
+    MacroCallS81,
+    GrammarSymbolS82,
+    OptionalS83,
+    LeftS84,
+    RightS85,
+    ReduceS86,
+    ShiftS87,
+    DynamicS88,
+    NOPSS89,
+    NOPSES90,
+    NoLayoutS91,
+    PrecS92,
+    AssocS93,
+    NameS94,
+    IntConstS95,
+    ProdMetaDataS96,
+    ProdMetaDatasS97,
+    UserMetaDataS98,
+    ProdKindS99,
+    CBracketS100,
+    SemiColonS101,
+    OBraceS102,
+    RawS103,
+    RegexTermS104,
+    StrConstS105,
+    RecognizerS106,
+    StrAlternativesS107,
+    RawTermS108,
+    GrammarRuleRHSS109,
+    EqualsS110,
+    QEqualsS111,
+    Name1S112,
+    SemiColonS113,
+    ChoiceS114,
+    OBraceS115,
+    AssignmentS116,
+    ZeroOrMoreS117,
+    ZeroOrMoreGreedyS118,
+    OneOrMoreS119,
+    OneOrMoreGreedyS120,
+    OptionalS121,
+    OptionalGreedyS122,
+    RepetitionOperatorOptS123,
+    RepetitionOperatorS124,
+    RepetitionOperatorOpS125,
+    RepetitionOperatorOptS126,
+    RepetitionOperatorOptS127,
+    NameS128,
+    ColonS129,
+    ColonS130,
+    ColonS131,
+    CommaS132,
+    CBraceS133,
+    ColonS134,
+    LeftS135,
+    RightS136,
+    ReduceS137,
+    ShiftS138,
+    DynamicS139,
+    PreferS140,
+    FinishS141,
+    NoFinishS142,
+    NameS143,
+    IntConstS144,
+    TermMetaDataS145,
+    TermMetaDatasS146,
+    UserMetaDataS147,
+    OBracketS148,
+    ChoiceS149,
+    ColonS150,
+    TerminalTypeOptS151,
+    TerminalTypeS152,
+    ChoiceS153,
+    CBracketS154,
+    NameS155,
+    GrammarSymbolRefS156,
+    GrammarSymbolRefS157,
+    CBracketS158,
+    ProductionS159,
+    ProdMetaDatasS160,
+    OSBracketS161,
+    RepetitionModifiersOptS162,
+    RepetitionModifiersS163,
+    IntConstS164,
+    LeftS165,
+    RightS166,
+    IntConstS167,
+    FloatConstS168,
+    BoolConstS169,
+    StrConstS170,
+    ConstValS171,
+    ProdMetaDataS172,
+    ColonS173,
+    GrammarRuleRHSS174,
+    CommaS175,
+    CBraceS176,
+    StrConstS177,
+    StrConstS178,
+    RustTypeS179,
+    SemiColonS180,
+    OBraceS181,
+    StrConstS182,
+    CBraceS183,
+    NameS184,
+    RepetitionModifier1S185,
+    RepetitionModifierS186,
+    GrammarRuleRHSS187,
+    SemiColonS188,
+    TermMetaDataS189,
+    SemiColonS190,
+    CommaS191,
+    TermMetaDatasS192,
+    CommaS193,
+    CSBracketS194,
+    SemiColonS195,
+    StrConstS196,
+    CBraceS197,
+    RepetitionModifierS198,
+    CommaS199,
+    CBracketS200,
+    SemiColonS201,
+    NestedS202,
+    CBracketS203,
+    AUGLS204,
+    OCommentS205,
+    WSS206,
+    CommentLineS207,
+    LayoutS208,
+    LayoutItem1S209,
+    LayoutItem0S210,
+    LayoutItemS211,
+    CommentS212,
+    WSS213,
+    NotCommentS214,
+    CommentS215,
+    CorncsS216,
+    Cornc1S217,
+    Cornc0S218,
+    CorncS219,
+    LayoutItemS220,
+    CCommentS221,
+    CorncS222,
 }
 impl StateT for State {
     fn default_layout() -> Option<Self> {
-        Some(State::AUGLS126)
+        Some(State::AUGLS204)
     }
 }
 impl From<State> for usize {
@@ -665,212 +1203,439 @@ impl std::fmt::Debug for State {
             State::AUGS0 => "0:AUG",
             State::TerminalsS1 => "1:Terminals",
             State::ImportS2 => "2:Import",
-            State::AnnotationS3 => "3:Annotation",
-            State::FileS4 => "4:File",
-            State::GrammarRule1S5 => "5:GrammarRule1",
-            State::ImportStm1S6 => "6:ImportStm1",
-            State::ImportStmS7 => "7:ImportStm",
-            State::GrammarRuleS8 => "8:GrammarRule",
-            State::AnnotationOptS9 => "9:AnnotationOpt",
-            State::TerminalRule1S10 => "10:TerminalRule1",
-            State::AnnotationOptS11 => "11:AnnotationOpt",
-            State::TerminalRuleS12 => "12:TerminalRule",
-            State::StrConstS13 => "13:StrConst",
-            State::TerminalsS14 => "14:Terminals",
-            State::GrammarRuleS15 => "15:GrammarRule",
-            State::GrammarRule1S16 => "16:GrammarRule1",
-            State::ImportStmS17 => "17:ImportStm",
-            State::NameS18 => "18:Name",
-            State::TerminalRuleS19 => "19:TerminalRule",
-            State::NameS20 => "20:Name",
-            State::AsS21 => "21:As",
-            State::TerminalRule1S22 => "22:TerminalRule1",
-            State::TerminalsS23 => "23:Terminals",
-            State::ColonS24 => "24:Colon",
-            State::OBraceS25 => "25:OBrace",
-            State::ColonS26 => "26:Colon",
-            State::NameS27 => "27:Name",
-            State::TerminalRule1S28 => "28:TerminalRule1",
-            State::OBracketS29 => "29:OBracket",
-            State::NameS30 => "30:Name",
+            State::LineCommentDirectiveS3 => "3:LineCommentDirective",
+            State::BlockCommentDirectiveS4 => "4:BlockCommentDirective",
+            State::KeywordsS5 => "5:Keywords",
+            State::StartS6 => "6:Start",
+            State::IgnoreS7 => "7:Ignore",
+            State::TriviaS8 => "8:Trivia",
+            State::TerminalsIncludeS9 => "9:TerminalsInclude",
+            State::DocCommentS10 => "10:DocComment",
+            State::ActionsCodeS11 => "11:ActionsCode",
+            State::FileS12 => "12:File",
+            State::GrammarRule1S13 => "13:GrammarRule1",
+            State::FileHeader1S14 => "14:FileHeader1",
+            State::FileHeaderS15 => "15:FileHeader",
+            State::ImportStmS16 => "16:ImportStm",
+            State::CommentDirectiveS17 => "17:CommentDirective",
+            State::KeywordsDirectiveS18 => "18:KeywordsDirective",
+            State::StartDirectiveS19 => "19:StartDirective",
+            State::IgnoreDirectiveS20 => "20:IgnoreDirective",
+            State::TriviaDirectiveS21 => "21:TriviaDirective",
+            State::TerminalsDirectiveS22 => "22:TerminalsDirective",
+            State::GrammarRuleS23 => "23:GrammarRule",
+            State::DocComment1S24 => "24:DocComment1",
+            State::DocComment0S25 => "25:DocComment0",
+            State::TerminalRule1S26 => "26:TerminalRule1",
+            State::DocComment0S27 => "27:DocComment0",
+            State::TerminalRuleS28 => "28:TerminalRule",
+            State::StrConstS29 => "29:StrConst",
+            State::StrConstS30 => "30:StrConst",
             State::StrConstS31 => "31:StrConst",
-            State::GrammarRuleRHSS32 => "32:GrammarRuleRHS",
-            State::ProductionS33 => "33:Production",
-            State::Assignment1S34 => "34:Assignment1",
-            State::AssignmentS35 => "35:Assignment",
-            State::PlainAssignmentS36 => "36:PlainAssignment",
-            State::BoolAssignmentS37 => "37:BoolAssignment",
-            State::ProductionGroupS38 => "38:ProductionGroup",
-            State::GrammarSymbolRefS39 => "39:GrammarSymbolRef",
-            State::GrammarSymbolS40 => "40:GrammarSymbol",
-            State::LeftS41 => "41:Left",
-            State::RightS42 => "42:Right",
-            State::ReduceS43 => "43:Reduce",
-            State::ShiftS44 => "44:Shift",
-            State::DynamicS45 => "45:Dynamic",
-            State::NOPSS46 => "46:NOPS",
-            State::NOPSES47 => "47:NOPSE",
-            State::NameS48 => "48:Name",
-            State::IntConstS49 => "49:IntConst",
-            State::ProdMetaDataS50 => "50:ProdMetaData",
-            State::ProdMetaDatasS51 => "51:ProdMetaDatas",
-            State::UserMetaDataS52 => "52:UserMetaData",
-            State::ProdKindS53 => "53:ProdKind",
+            State::NameS32 => "32:Name",
+            State::NameS33 => "33:Name",
+            State::NameS34 => "34:Name",
+            State::Name1S35 => "35:Name1",
+            State::Name1S36 => "36:Name1",
+            State::StrConstS37 => "37:StrConst",
+            State::TerminalsS38 => "38:Terminals",
+            State::GrammarRuleS39 => "39:GrammarRule",
+            State::GrammarRule1S40 => "40:GrammarRule1",
+            State::FileHeaderS41 => "41:FileHeader",
+            State::DocCommentS42 => "42:DocComment",
+            State::AnnotationS43 => "43:Annotation",
+            State::AnnotationOptS44 => "44:AnnotationOpt",
+            State::TerminalRuleS45 => "45:TerminalRule",
+            State::AnnotationOptS46 => "46:AnnotationOpt",
+            State::AsS47 => "47:As",
+            State::StrConstS48 => "48:StrConst",
+            State::ColonS49 => "49:Colon",
+            State::SemiColonS50 => "50:SemiColon",
+            State::SemiColonS51 => "51:SemiColon",
+            State::CommaS52 => "52:Comma",
+            State::SemiColonS53 => "53:SemiColon",
             State::SemiColonS54 => "54:SemiColon",
-            State::OBraceS55 => "55:OBrace",
-            State::RegexTermS56 => "56:RegexTerm",
-            State::StrConstS57 => "57:StrConst",
-            State::RecognizerS58 => "58:Recognizer",
-            State::GrammarRuleRHSS59 => "59:GrammarRuleRHS",
-            State::EqualsS60 => "60:Equals",
-            State::QEqualsS61 => "61:QEquals",
-            State::SemiColonS62 => "62:SemiColon",
-            State::ChoiceS63 => "63:Choice",
-            State::OBraceS64 => "64:OBrace",
-            State::AssignmentS65 => "65:Assignment",
-            State::ZeroOrMoreS66 => "66:ZeroOrMore",
-            State::ZeroOrMoreGreedyS67 => "67:ZeroOrMoreGreedy",
-            State::OneOrMoreS68 => "68:OneOrMore",
-            State::OneOrMoreGreedyS69 => "69:OneOrMoreGreedy",
-            State::OptionalS70 => "70:Optional",
-            State::OptionalGreedyS71 => "71:OptionalGreedy",
-            State::RepetitionOperatorOptS72 => "72:RepetitionOperatorOpt",
-            State::RepetitionOperatorS73 => "73:RepetitionOperator",
-            State::RepetitionOperatorOpS74 => "74:RepetitionOperatorOp",
-            State::RepetitionOperatorOptS75 => "75:RepetitionOperatorOpt",
-            State::ColonS76 => "76:Colon",
-            State::CommaS77 => "77:Comma",
-            State::CBraceS78 => "78:CBrace",
-            State::LeftS79 => "79:Left",
-            State::RightS80 => "80:Right",
-            State::ReduceS81 => "81:Reduce",
-            State::ShiftS82 => "82:Shift",
-            State::DynamicS83 => "83:Dynamic",
-            State::PreferS84 => "84:Prefer",
-            State::FinishS85 => "85:Finish",
-            State::NoFinishS86 => "86:NoFinish",
-            State::NameS87 => "87:Name",
-            State::IntConstS88 => "88:IntConst",
-            State::TermMetaDataS89 => "89:TermMetaData",
-            State::TermMetaDatasS90 => "90:TermMetaDatas",
-            State::UserMetaDataS91 => "91:UserMetaData",
-            State::SemiColonS92 => "92:SemiColon",
-            State::OBraceS93 => "93:OBrace",
-            State::CBracketS94 => "94:CBracket",
-            State::NameS95 => "95:Name",
-            State::GrammarSymbolRefS96 => "96:GrammarSymbolRef",
-            State::GrammarSymbolRefS97 => "97:GrammarSymbolRef",
-            State::ProductionS98 => "98:Production",
-            State::ProdMetaDatasS99 => "99:ProdMetaDatas",
-            State::OSBracketS100 => "100:OSBracket",
-            State::RepetitionModifiersOptS101 => "101:RepetitionModifiersOpt",
-            State::RepetitionModifiersS102 => "102:RepetitionModifiers",
-            State::IntConstS103 => "103:IntConst",
-            State::FloatConstS104 => "104:FloatConst",
-            State::BoolConstS105 => "105:BoolConst",
-            State::StrConstS106 => "106:StrConst",
-            State::ConstValS107 => "107:ConstVal",
-            State::ProdMetaDataS108 => "108:ProdMetaData",
-            State::ColonS109 => "109:Colon",
-            State::CommaS110 => "110:Comma",
-            State::CBraceS111 => "111:CBrace",
-            State::TermMetaDatasS112 => "112:TermMetaDatas",
-            State::CBraceS113 => "113:CBrace",
-            State::NameS114 => "114:Name",
-            State::RepetitionModifier1S115 => "115:RepetitionModifier1",
-            State::RepetitionModifierS116 => "116:RepetitionModifier",
-            State::GrammarRuleRHSS117 => "117:GrammarRuleRHS",
-            State::TermMetaDataS118 => "118:TermMetaData",
-            State::SemiColonS119 => "119:SemiColon",
-            State::CBraceS120 => "120:CBrace",
-            State::CommaS121 => "121:Comma",
-            State::CSBracketS122 => "122:CSBracket",
-            State::SemiColonS123 => "123:SemiColon",
-            State::SemiColonS124 => "124:SemiColon",
-            State::RepetitionModifierS125 => "125:RepetitionModifier",
-            State::AUGLS126 => "126:AUGL",
-            State::OCommentS127 => "127:OComment",
-            State::WSS128 => "128:WS",
-            State::CommentLineS129 => "129:CommentLine",
-            State::LayoutS130 => "130:Layout",
-            State::LayoutItem1S131 => "131:LayoutItem1",
-            State::LayoutItem0S132 => "132:LayoutItem0",
-            State::LayoutItemS133 => "133:LayoutItem",
-            State::CommentS134 => "134:Comment",
-            State::WSS135 => "135:WS",
-            State::NotCommentS136 => "136:NotComment",
-            State::CommentS137 => "137:Comment",
-            State::CorncsS138 => "138:Corncs",
-            State::Cornc1S139 => "139:Cornc1",
-            State::Cornc0S140 => "140:Cornc0",
-            State::CorncS141 => "141:Cornc",
-            State::LayoutItemS142 => "142:LayoutItem",
-            State::CCommentS143 => "143:CComment",
-            State::CorncS144 => "144:Cornc",
+            State::TerminalRule1S55 => "55:TerminalRule1",
+            State::TerminalsS56 => "56:Terminals",
+            State::NameS57 => "57:Name",
+            State::MacroNameS58 => "58:MacroName",
+            State::NameS59 => "59:Name",
+            State::NameS60 => "60:Name",
+            State::Name1S61 => "61:Name1",
+            State::NameS62 => "62:Name",
+            State::TerminalRule1S63 => "63:TerminalRule1",
+            State::ColonS64 => "64:Colon",
+            State::OBraceS65 => "65:OBrace",
+            State::Name1S66 => "66:Name1",
+            State::ColonS67 => "67:Colon",
+            State::SemiColonS68 => "68:SemiColon",
+            State::OBracketS69 => "69:OBracket",
+            State::NameS70 => "70:Name",
+            State::MacroNameS71 => "71:MacroName",
+            State::StrConstS72 => "72:StrConst",
+            State::GrammarRuleRHSS73 => "73:GrammarRuleRHS",
+            State::ProductionS74 => "74:Production",
+            State::Assignment1S75 => "75:Assignment1",
+            State::AssignmentS76 => "76:Assignment",
+            State::PlainAssignmentS77 => "77:PlainAssignment",
+            State::BoolAssignmentS78 => "78:BoolAssignment",
+            State::ProductionGroupS79 => "79:ProductionGroup",
+            State::GrammarSymbolRefS80 => "80:GrammarSymbolRef",
+            State::MacroCallS81 => "81:MacroCall",
+            State::GrammarSymbolS82 => "82:GrammarSymbol",
+            State::OptionalS83 => "83:Optional",
+            State::LeftS84 => "84:Left",
+            State::RightS85 => "85:Right",
+            State::ReduceS86 => "86:Reduce",
+            State::ShiftS87 => "87:Shift",
+            State::DynamicS88 => "88:Dynamic",
+            State::NOPSS89 => "89:NOPS",
+            State::NOPSES90 => "90:NOPSE",
+            State::NoLayoutS91 => "91:NoLayout",
+            State::PrecS92 => "92:Prec",
+            State::AssocS93 => "93:Assoc",
+            State::NameS94 => "94:Name",
+            State::IntConstS95 => "95:IntConst",
+            State::ProdMetaDataS96 => "96:ProdMetaData",
+            State::ProdMetaDatasS97 => "97:ProdMetaDatas",
+            State::UserMetaDataS98 => "98:UserMetaData",
+            State::ProdKindS99 => "99:ProdKind",
+            State::CBracketS100 => "100:CBracket",
+            State::SemiColonS101 => "101:SemiColon",
+            State::OBraceS102 => "102:OBrace",
+            State::RawS103 => "103:Raw",
+            State::RegexTermS104 => "104:RegexTerm",
+            State::StrConstS105 => "105:StrConst",
+            State::RecognizerS106 => "106:Recognizer",
+            State::StrAlternativesS107 => "107:StrAlternatives",
+            State::RawTermS108 => "108:RawTerm",
+            State::GrammarRuleRHSS109 => "109:GrammarRuleRHS",
+            State::EqualsS110 => "110:Equals",
+            State::QEqualsS111 => "111:QEquals",
+            State::Name1S112 => "112:Name1",
+            State::SemiColonS113 => "113:SemiColon",
+            State::ChoiceS114 => "114:Choice",
+            State::OBraceS115 => "115:OBrace",
+            State::AssignmentS116 => "116:Assignment",
+            State::ZeroOrMoreS117 => "117:ZeroOrMore",
+            State::ZeroOrMoreGreedyS118 => "118:ZeroOrMoreGreedy",
+            State::OneOrMoreS119 => "119:OneOrMore",
+            State::OneOrMoreGreedyS120 => "120:OneOrMoreGreedy",
+            State::OptionalS121 => "121:Optional",
+            State::OptionalGreedyS122 => "122:OptionalGreedy",
+            State::RepetitionOperatorOptS123 => "123:RepetitionOperatorOpt",
+            State::RepetitionOperatorS124 => "124:RepetitionOperator",
+            State::RepetitionOperatorOpS125 => "125:RepetitionOperatorOp",
+            State::RepetitionOperatorOptS126 => "126:RepetitionOperatorOpt",
+            State::RepetitionOperatorOptS127 => "127:RepetitionOperatorOpt",
+            State::NameS128 => "128:Name",
+            State::ColonS129 => "129:Colon",
+            State::ColonS130 => "130:Colon",
+            State::ColonS131 => "131:Colon",
+            State::CommaS132 => "132:Comma",
+            State::CBraceS133 => "133:CBrace",
+            State::ColonS134 => "134:Colon",
+            State::LeftS135 => "135:Left",
+            State::RightS136 => "136:Right",
+            State::ReduceS137 => "137:Reduce",
+            State::ShiftS138 => "138:Shift",
+            State::DynamicS139 => "139:Dynamic",
+            State::PreferS140 => "140:Prefer",
+            State::FinishS141 => "141:Finish",
+            State::NoFinishS142 => "142:NoFinish",
+            State::NameS143 => "143:Name",
+            State::IntConstS144 => "144:IntConst",
+            State::TermMetaDataS145 => "145:TermMetaData",
+            State::TermMetaDatasS146 => "146:TermMetaDatas",
+            State::UserMetaDataS147 => "147:UserMetaData",
+            State::OBracketS148 => "148:OBracket",
+            State::ChoiceS149 => "149:Choice",
+            State::ColonS150 => "150:Colon",
+            State::TerminalTypeOptS151 => "151:TerminalTypeOpt",
+            State::TerminalTypeS152 => "152:TerminalType",
+            State::ChoiceS153 => "153:Choice",
+            State::CBracketS154 => "154:CBracket",
+            State::NameS155 => "155:Name",
+            State::GrammarSymbolRefS156 => "156:GrammarSymbolRef",
+            State::GrammarSymbolRefS157 => "157:GrammarSymbolRef",
+            State::CBracketS158 => "158:CBracket",
+            State::ProductionS159 => "159:Production",
+            State::ProdMetaDatasS160 => "160:ProdMetaDatas",
+            State::OSBracketS161 => "161:OSBracket",
+            State::RepetitionModifiersOptS162 => "162:RepetitionModifiersOpt",
+            State::RepetitionModifiersS163 => "163:RepetitionModifiers",
+            State::IntConstS164 => "164:IntConst",
+            State::LeftS165 => "165:Left",
+            State::RightS166 => "166:Right",
+            State::IntConstS167 => "167:IntConst",
+            State::FloatConstS168 => "168:FloatConst",
+            State::BoolConstS169 => "169:BoolConst",
+            State::StrConstS170 => "170:StrConst",
+            State::ConstValS171 => "171:ConstVal",
+            State::ProdMetaDataS172 => "172:ProdMetaData",
+            State::ColonS173 => "173:Colon",
+            State::GrammarRuleRHSS174 => "174:GrammarRuleRHS",
+            State::CommaS175 => "175:Comma",
+            State::CBraceS176 => "176:CBrace",
+            State::StrConstS177 => "177:StrConst",
+            State::StrConstS178 => "178:StrConst",
+            State::RustTypeS179 => "179:RustType",
+            State::SemiColonS180 => "180:SemiColon",
+            State::OBraceS181 => "181:OBrace",
+            State::StrConstS182 => "182:StrConst",
+            State::CBraceS183 => "183:CBrace",
+            State::NameS184 => "184:Name",
+            State::RepetitionModifier1S185 => "185:RepetitionModifier1",
+            State::RepetitionModifierS186 => "186:RepetitionModifier",
+            State::GrammarRuleRHSS187 => "187:GrammarRuleRHS",
+            State::SemiColonS188 => "188:SemiColon",
+            State::TermMetaDataS189 => "189:TermMetaData",
+            State::SemiColonS190 => "190:SemiColon",
+            State::CommaS191 => "191:Comma",
+            State::TermMetaDatasS192 => "192:TermMetaDatas",
+            State::CommaS193 => "193:Comma",
+            State::CSBracketS194 => "194:CSBracket",
+            State::SemiColonS195 => "195:SemiColon",
+            State::StrConstS196 => "196:StrConst",
+            State::CBraceS197 => "197:CBrace",
+            State::RepetitionModifierS198 => "198:RepetitionModifier",
+            State::CommaS199 => "199:Comma",
+            State::CBracketS200 => "200:CBracket",
+            State::SemiColonS201 => "201:SemiColon",
+            State::NestedS202 => "202:Nested",
+            State::CBracketS203 => "203:CBracket",
+            State::AUGLS204 => "204:AUGL",
+            State::OCommentS205 => "205:OComment",
+            State::WSS206 => "206:WS",
+            State::CommentLineS207 => "207:CommentLine",
+            State::LayoutS208 => "208:Layout",
+            State::LayoutItem1S209 => "209:LayoutItem1",
+            State::LayoutItem0S210 => "210:LayoutItem0",
+            State::LayoutItemS211 => "211:LayoutItem",
+            State::CommentS212 => "212:Comment",
+            State::WSS213 => "213:WS",
+            State::NotCommentS214 => "214:NotComment",
+            State::CommentS215 => "215:Comment",
+            State::CorncsS216 => "216:Corncs",
+            State::Cornc1S217 => "217:Cornc1",
+            State::Cornc0S218 => "218:Cornc0",
+            State::CorncS219 => "219:Cornc",
+            State::LayoutItemS220 => "220:LayoutItem",
+            State::CCommentS221 => "221:CComment",
+            State::CorncS222 => "222:Cornc",
         };
         write!(f, "{name}")
     }
 }
 #[derive(Debug)]
-pub enum Symbol {
-    Terminal(Terminal),
+pub enum Symbol<'i> {
+    Terminal(Terminal<'i>),
     NonTerminal(NonTerminal),
 }
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
-pub enum Terminal {
-    Terminals,
-    Import,
-    As,
-    Colon,
-    SemiColon,
-    Comma,
-    OBrace,
-    CBrace,
-    OBracket,
-    CBracket,
-    OSBracket,
-    CSBracket,
-    Choice,
-    ZeroOrMore,
-    ZeroOrMoreGreedy,
-    OneOrMore,
-    OneOrMoreGreedy,
-    Optional,
-    OptionalGreedy,
-    Equals,
-    QEquals,
-    Left,
-    Right,
-    Reduce,
-    Shift,
-    Dynamic,
-    NOPS,
-    NOPSE,
-    Prefer,
-    Finish,
-    NoFinish,
-    Name(rustemo_actions::Name),
-    RegexTerm(rustemo_actions::RegexTerm),
-    IntConst(rustemo_actions::IntConst),
-    FloatConst(rustemo_actions::FloatConst),
-    BoolConst(rustemo_actions::BoolConst),
-    StrConst(rustemo_actions::StrConst),
-    Annotation(rustemo_actions::Annotation),
+pub enum Terminal<'i> {
+    Terminals(&'i Input),
+    Import(&'i Input),
+    As(&'i Input),
+    LineCommentDirective(&'i Input),
+    BlockCommentDirective(&'i Input),
+    Keywords(&'i Input),
+    Start(&'i Input),
+    Ignore(&'i Input),
+    Trivia(&'i Input),
+    TerminalsInclude(&'i Input),
+    Colon(&'i Input),
+    SemiColon(&'i Input),
+    Comma(&'i Input),
+    OBrace(&'i Input),
+    CBrace(&'i Input),
+    OBracket(&'i Input),
+    CBracket(&'i Input),
+    OSBracket(&'i Input),
+    CSBracket(&'i Input),
+    Choice(&'i Input),
+    ZeroOrMore(&'i Input),
+    ZeroOrMoreGreedy(&'i Input),
+    OneOrMore(&'i Input),
+    OneOrMoreGreedy(&'i Input),
+    Optional(&'i Input),
+    OptionalGreedy(&'i Input),
+    Equals(&'i Input),
+    QEquals(&'i Input),
+    Left(&'i Input),
+    Right(&'i Input),
+    Reduce(&'i Input),
+    Shift(&'i Input),
+    Dynamic(&'i Input),
+    NOPS(&'i Input),
+    NOPSE(&'i Input),
+    NoLayout(&'i Input),
+    Prec(&'i Input),
+    Assoc(&'i Input),
+    Prefer(&'i Input),
+    Finish(&'i Input),
+    NoFinish(&'i Input),
+    Raw(&'i Input),
+    Nested(&'i Input),
+    Name(&'i Input, rustemo_actions::Name),
+    MacroName(&'i Input, rustemo_actions::MacroName),
+    RustType(&'i Input, rustemo_actions::RustType),
+    RegexTerm(&'i Input, rustemo_actions::RegexTerm),
+    IntConst(&'i Input, rustemo_actions::IntConst),
+    FloatConst(&'i Input, rustemo_actions::FloatConst),
+    BoolConst(&'i Input, rustemo_actions::BoolConst),
+    StrConst(&'i Input, rustemo_actions::StrConst),
+    Annotation(&'i Input, rustemo_actions::Annotation),
+    DocComment(&'i Input, rustemo_actions::DocComment),
+    ActionsCode(&'i Input, rustemo_actions::ActionsCode),
+}
+#[allow(dead_code)]
+impl<'i> Terminal<'i> {
+    /// The input matched by this terminal.
+    pub fn text(&self) -> &'i Input {
+        match *self {
+            Terminal::Terminals(text) => text,
+            Terminal::Import(text) => text,
+            Terminal::As(text) => text,
+            Terminal::LineCommentDirective(text) => text,
+            Terminal::BlockCommentDirective(text) => text,
+            Terminal::Keywords(text) => text,
+            Terminal::Start(text) => text,
+            Terminal::Ignore(text) => text,
+            Terminal::Trivia(text) => text,
+            Terminal::TerminalsInclude(text) => text,
+            Terminal::Colon(text) => text,
+            Terminal::SemiColon(text) => text,
+            Terminal::Comma(text) => text,
+            Terminal::OBrace(text) => text,
+            Terminal::CBrace(text) => text,
+            Terminal::OBracket(text) => text,
+            Terminal::CBracket(text) => text,
+            Terminal::OSBracket(text) => text,
+            Terminal::CSBracket(text) => text,
+            Terminal::Choice(text) => text,
+            Terminal::ZeroOrMore(text) => text,
+            Terminal::ZeroOrMoreGreedy(text) => text,
+            Terminal::OneOrMore(text) => text,
+            Terminal::OneOrMoreGreedy(text) => text,
+            Terminal::Optional(text) => text,
+            Terminal::OptionalGreedy(text) => text,
+            Terminal::Equals(text) => text,
+            Terminal::QEquals(text) => text,
+            Terminal::Left(text) => text,
+            Terminal::Right(text) => text,
+            Terminal::Reduce(text) => text,
+            Terminal::Shift(text) => text,
+            Terminal::Dynamic(text) => text,
+            Terminal::NOPS(text) => text,
+            Terminal::NOPSE(text) => text,
+            Terminal::NoLayout(text) => text,
+            Terminal::Prec(text) => text,
+            Terminal::Assoc(text) => text,
+            Terminal::Prefer(text) => text,
+            Terminal::Finish(text) => text,
+            Terminal::NoFinish(text) => text,
+            Terminal::Raw(text) => text,
+            Terminal::Nested(text) => text,
+            Terminal::Name(text, _) => text,
+            Terminal::MacroName(text, _) => text,
+            Terminal::RustType(text, _) => text,
+            Terminal::RegexTerm(text, _) => text,
+            Terminal::IntConst(text, _) => text,
+            Terminal::FloatConst(text, _) => text,
+            Terminal::BoolConst(text, _) => text,
+            Terminal::StrConst(text, _) => text,
+            Terminal::Annotation(text, _) => text,
+            Terminal::DocComment(text, _) => text,
+            Terminal::ActionsCode(text, _) => text,
+        }
+    }
+    /// The kind of this terminal.
+    pub fn kind(&self) -> TokenKind {
+        match *self {
+            Terminal::Terminals(..) => TokenKind::Terminals,
+            Terminal::Import(..) => TokenKind::Import,
+            Terminal::As(..) => TokenKind::As,
+            Terminal::LineCommentDirective(..) => TokenKind::LineCommentDirective,
+            Terminal::BlockCommentDirective(..) => TokenKind::BlockCommentDirective,
+            Terminal::Keywords(..) => TokenKind::Keywords,
+            Terminal::Start(..) => TokenKind::Start,
+            Terminal::Ignore(..) => TokenKind::Ignore,
+            Terminal::Trivia(..) => TokenKind::Trivia,
+            Terminal::TerminalsInclude(..) => TokenKind::TerminalsInclude,
+            Terminal::Colon(..) => TokenKind::Colon,
+            Terminal::SemiColon(..) => TokenKind::SemiColon,
+            Terminal::Comma(..) => TokenKind::Comma,
+            Terminal::OBrace(..) => TokenKind::OBrace,
+            Terminal::CBrace(..) => TokenKind::CBrace,
+            Terminal::OBracket(..) => TokenKind::OBracket,
+            Terminal::CBracket(..) => TokenKind::CBracket,
+            Terminal::OSBracket(..) => TokenKind::OSBracket,
+            Terminal::CSBracket(..) => TokenKind::CSBracket,
+            Terminal::Choice(..) => TokenKind::Choice,
+            Terminal::ZeroOrMore(..) => TokenKind::ZeroOrMore,
+            Terminal::ZeroOrMoreGreedy(..) => TokenKind::ZeroOrMoreGreedy,
+            Terminal::OneOrMore(..) => TokenKind::OneOrMore,
+            Terminal::OneOrMoreGreedy(..) => TokenKind::OneOrMoreGreedy,
+            Terminal::Optional(..) => TokenKind::Optional,
+            Terminal::OptionalGreedy(..) => TokenKind::OptionalGreedy,
+            Terminal::Equals(..) => TokenKind::Equals,
+            Terminal::QEquals(..) => TokenKind::QEquals,
+            Terminal::Left(..) => TokenKind::Left,
+            Terminal::Right(..) => TokenKind::Right,
+            Terminal::Reduce(..) => TokenKind::Reduce,
+            Terminal::Shift(..) => TokenKind::Shift,
+            Terminal::Dynamic(..) => TokenKind::Dynamic,
+            Terminal::NOPS(..) => TokenKind::NOPS,
+            Terminal::NOPSE(..) => TokenKind::NOPSE,
+            Terminal::NoLayout(..) => TokenKind::NoLayout,
+            Terminal::Prec(..) => TokenKind::Prec,
+            Terminal::Assoc(..) => TokenKind::Assoc,
+            Terminal::Prefer(..) => TokenKind::Prefer,
+            Terminal::Finish(..) => TokenKind::Finish,
+            Terminal::NoFinish(..) => TokenKind::NoFinish,
+            Terminal::Raw(..) => TokenKind::Raw,
+            Terminal::Nested(..) => TokenKind::Nested,
+            Terminal::Name(..) => TokenKind::Name,
+            Terminal::MacroName(..) => TokenKind::MacroName,
+            Terminal::RustType(..) => TokenKind::RustType,
+            Terminal::RegexTerm(..) => TokenKind::RegexTerm,
+            Terminal::IntConst(..) => TokenKind::IntConst,
+            Terminal::FloatConst(..) => TokenKind::FloatConst,
+            Terminal::BoolConst(..) => TokenKind::BoolConst,
+            Terminal::StrConst(..) => TokenKind::StrConst,
+            Terminal::Annotation(..) => TokenKind::Annotation,
+            Terminal::DocComment(..) => TokenKind::DocComment,
+            Terminal::ActionsCode(..) => TokenKind::ActionsCode,
+        }
+    }
 }
 #[derive(Debug)]
 pub enum NonTerminal {
     File(rustemo_actions::File),
     GrammarRule1(rustemo_actions::GrammarRule1),
-    ImportStm1(rustemo_actions::ImportStm1),
+    FileHeader1(rustemo_actions::FileHeader1),
     TerminalRule1(rustemo_actions::TerminalRule1),
+    FileHeader(rustemo_actions::FileHeader),
     ImportStm(rustemo_actions::ImportStm),
+    CommentDirective(rustemo_actions::CommentDirective),
+    KeywordsDirective(rustemo_actions::KeywordsDirective),
+    Name1(rustemo_actions::Name1),
+    StartDirective(rustemo_actions::StartDirective),
+    IgnoreDirective(rustemo_actions::IgnoreDirective),
+    TriviaDirective(rustemo_actions::TriviaDirective),
+    TerminalsDirective(rustemo_actions::TerminalsDirective),
     GrammarRule(rustemo_actions::GrammarRule),
+    DocComment1(rustemo_actions::DocComment1),
+    DocComment0(rustemo_actions::DocComment0),
     AnnotationOpt(rustemo_actions::AnnotationOpt),
     GrammarRuleRHS(rustemo_actions::GrammarRuleRHS),
     Production(rustemo_actions::Production),
     Assignment1(rustemo_actions::Assignment1),
     TerminalRule(rustemo_actions::TerminalRule),
+    TerminalTypeOpt(rustemo_actions::TerminalTypeOpt),
+    TerminalType(rustemo_actions::TerminalType),
     ProdMetaData(rustemo_actions::ProdMetaData),
     ProdMetaDatas(rustemo_actions::ProdMetaDatas),
     TermMetaData(rustemo_actions::TermMetaData),
@@ -884,6 +1649,7 @@ pub enum NonTerminal {
     ProductionGroup(rustemo_actions::ProductionGroup),
     GrammarSymbolRef(rustemo_actions::GrammarSymbolRef),
     RepetitionOperatorOpt(rustemo_actions::RepetitionOperatorOpt),
+    MacroCall(rustemo_actions::MacroCall),
     RepetitionOperator(rustemo_actions::RepetitionOperator),
     RepetitionModifiersOpt(rustemo_actions::RepetitionModifiersOpt),
     RepetitionOperatorOp(rustemo_actions::RepetitionOperatorOp),
@@ -892,6 +1658,206 @@ pub enum NonTerminal {
     RepetitionModifier(rustemo_actions::RepetitionModifier),
     GrammarSymbol(rustemo_actions::GrammarSymbol),
     Recognizer(rustemo_actions::Recognizer),
+    StrAlternatives(rustemo_actions::StrAlternatives),
+    RawTerm(rustemo_actions::RawTerm),
+}
+#[allow(dead_code)]
+impl NonTerminal {
+    /// The kind of this non-terminal.
+    pub fn kind(&self) -> NonTermKind {
+        match *self {
+            NonTerminal::File(_) => NonTermKind::File,
+            NonTerminal::GrammarRule1(_) => NonTermKind::GrammarRule1,
+            NonTerminal::FileHeader1(_) => NonTermKind::FileHeader1,
+            NonTerminal::TerminalRule1(_) => NonTermKind::TerminalRule1,
+            NonTerminal::FileHeader(_) => NonTermKind::FileHeader,
+            NonTerminal::ImportStm(_) => NonTermKind::ImportStm,
+            NonTerminal::CommentDirective(_) => NonTermKind::CommentDirective,
+            NonTerminal::KeywordsDirective(_) => NonTermKind::KeywordsDirective,
+            NonTerminal::Name1(_) => NonTermKind::Name1,
+            NonTerminal::StartDirective(_) => NonTermKind::StartDirective,
+            NonTerminal::IgnoreDirective(_) => NonTermKind::IgnoreDirective,
+            NonTerminal::TriviaDirective(_) => NonTermKind::TriviaDirective,
+            NonTerminal::TerminalsDirective(_) => NonTermKind::TerminalsDirective,
+            NonTerminal::GrammarRule(_) => NonTermKind::GrammarRule,
+            NonTerminal::DocComment1(_) => NonTermKind::DocComment1,
+            NonTerminal::DocComment0(_) => NonTermKind::DocComment0,
+            NonTerminal::AnnotationOpt(_) => NonTermKind::AnnotationOpt,
+            NonTerminal::GrammarRuleRHS(_) => NonTermKind::GrammarRuleRHS,
+            NonTerminal::Production(_) => NonTermKind::Production,
+            NonTerminal::Assignment1(_) => NonTermKind::Assignment1,
+            NonTerminal::TerminalRule(_) => NonTermKind::TerminalRule,
+            NonTerminal::TerminalTypeOpt(_) => NonTermKind::TerminalTypeOpt,
+            NonTerminal::TerminalType(_) => NonTermKind::TerminalType,
+            NonTerminal::ProdMetaData(_) => NonTermKind::ProdMetaData,
+            NonTerminal::ProdMetaDatas(_) => NonTermKind::ProdMetaDatas,
+            NonTerminal::TermMetaData(_) => NonTermKind::TermMetaData,
+            NonTerminal::TermMetaDatas(_) => NonTermKind::TermMetaDatas,
+            NonTerminal::UserMetaData(_) => NonTermKind::UserMetaData,
+            NonTerminal::ProdKind(_) => NonTermKind::ProdKind,
+            NonTerminal::ConstVal(_) => NonTermKind::ConstVal,
+            NonTerminal::Assignment(_) => NonTermKind::Assignment,
+            NonTerminal::PlainAssignment(_) => NonTermKind::PlainAssignment,
+            NonTerminal::BoolAssignment(_) => NonTermKind::BoolAssignment,
+            NonTerminal::ProductionGroup(_) => NonTermKind::ProductionGroup,
+            NonTerminal::GrammarSymbolRef(_) => NonTermKind::GrammarSymbolRef,
+            NonTerminal::RepetitionOperatorOpt(_) => NonTermKind::RepetitionOperatorOpt,
+            NonTerminal::MacroCall(_) => NonTermKind::MacroCall,
+            NonTerminal::RepetitionOperator(_) => NonTermKind::RepetitionOperator,
+            NonTerminal::RepetitionModifiersOpt(_) => NonTermKind::RepetitionModifiersOpt,
+            NonTerminal::RepetitionOperatorOp(_) => NonTermKind::RepetitionOperatorOp,
+            NonTerminal::RepetitionModifiers(_) => NonTermKind::RepetitionModifiers,
+            NonTerminal::RepetitionModifier1(_) => NonTermKind::RepetitionModifier1,
+            NonTerminal::RepetitionModifier(_) => NonTermKind::RepetitionModifier,
+            NonTerminal::GrammarSymbol(_) => NonTermKind::GrammarSymbol,
+            NonTerminal::Recognizer(_) => NonTermKind::Recognizer,
+            NonTerminal::StrAlternatives(_) => NonTermKind::StrAlternatives,
+            NonTerminal::RawTerm(_) => NonTermKind::RawTerm,
+        }
+    }
+}
+#[allow(dead_code)]
+impl<'i> Symbol<'i> {
+    /// The name of the grammar symbol of this symbol.
+    pub fn kind_name(&self) -> &'static str {
+        match *self {
+            Symbol::Terminal(Terminal::Terminals(..)) => "Terminals",
+            Symbol::Terminal(Terminal::Import(..)) => "Import",
+            Symbol::Terminal(Terminal::As(..)) => "As",
+            Symbol::Terminal(Terminal::LineCommentDirective(..)) => {
+                "LineCommentDirective"
+            }
+            Symbol::Terminal(Terminal::BlockCommentDirective(..)) => {
+                "BlockCommentDirective"
+            }
+            Symbol::Terminal(Terminal::Keywords(..)) => "Keywords",
+            Symbol::Terminal(Terminal::Start(..)) => "Start",
+            Symbol::Terminal(Terminal::Ignore(..)) => "Ignore",
+            Symbol::Terminal(Terminal::Trivia(..)) => "Trivia",
+            Symbol::Terminal(Terminal::TerminalsInclude(..)) => "TerminalsInclude",
+            Symbol::Terminal(Terminal::Colon(..)) => "Colon",
+            Symbol::Terminal(Terminal::SemiColon(..)) => "SemiColon",
+            Symbol::Terminal(Terminal::Comma(..)) => "Comma",
+            Symbol::Terminal(Terminal::OBrace(..)) => "OBrace",
+            Symbol::Terminal(Terminal::CBrace(..)) => "CBrace",
+            Symbol::Terminal(Terminal::OBracket(..)) => "OBracket",
+            Symbol::Terminal(Terminal::CBracket(..)) => "CBracket",
+            Symbol::Terminal(Terminal::OSBracket(..)) => "OSBracket",
+            Symbol::Terminal(Terminal::CSBracket(..)) => "CSBracket",
+            Symbol::Terminal(Terminal::Choice(..)) => "Choice",
+            Symbol::Terminal(Terminal::ZeroOrMore(..)) => "ZeroOrMore",
+            Symbol::Terminal(Terminal::ZeroOrMoreGreedy(..)) => "ZeroOrMoreGreedy",
+            Symbol::Terminal(Terminal::OneOrMore(..)) => "OneOrMore",
+            Symbol::Terminal(Terminal::OneOrMoreGreedy(..)) => "OneOrMoreGreedy",
+            Symbol::Terminal(Terminal::Optional(..)) => "Optional",
+            Symbol::Terminal(Terminal::OptionalGreedy(..)) => "OptionalGreedy",
+            Symbol::Terminal(Terminal::Equals(..)) => "Equals",
+            Symbol::Terminal(Terminal::QEquals(..)) => "QEquals",
+            Symbol::Terminal(Terminal::Left(..)) => "Left",
+            Symbol::Terminal(Terminal::Right(..)) => "Right",
+            Symbol::Terminal(Terminal::Reduce(..)) => "Reduce",
+            Symbol::Terminal(Terminal::Shift(..)) => "Shift",
+            Symbol::Terminal(Terminal::Dynamic(..)) => "Dynamic",
+            Symbol::Terminal(Terminal::NOPS(..)) => "NOPS",
+            Symbol::Terminal(Terminal::NOPSE(..)) => "NOPSE",
+            Symbol::Terminal(Terminal::NoLayout(..)) => "NoLayout",
+            Symbol::Terminal(Terminal::Prec(..)) => "Prec",
+            Symbol::Terminal(Terminal::Assoc(..)) => "Assoc",
+            Symbol::Terminal(Terminal::Prefer(..)) => "Prefer",
+            Symbol::Terminal(Terminal::Finish(..)) => "Finish",
+            Symbol::Terminal(Terminal::NoFinish(..)) => "NoFinish",
+            Symbol::Terminal(Terminal::Raw(..)) => "Raw",
+            Symbol::Terminal(Terminal::Nested(..)) => "Nested",
+            Symbol::Terminal(Terminal::Name(..)) => "Name",
+            Symbol::Terminal(Terminal::MacroName(..)) => "MacroName",
+            Symbol::Terminal(Terminal::RustType(..)) => "RustType",
+            Symbol::Terminal(Terminal::RegexTerm(..)) => "RegexTerm",
+            Symbol::Terminal(Terminal::IntConst(..)) => "IntConst",
+            Symbol::Terminal(Terminal::FloatConst(..)) => "FloatConst",
+            Symbol::Terminal(Terminal::BoolConst(..)) => "BoolConst",
+            Symbol::Terminal(Terminal::StrConst(..)) => "StrConst",
+            Symbol::Terminal(Terminal::Annotation(..)) => "Annotation",
+            Symbol::Terminal(Terminal::DocComment(..)) => "DocComment",
+            Symbol::Terminal(Terminal::ActionsCode(..)) => "ActionsCode",
+            Symbol::NonTerminal(NonTerminal::File(_)) => "File",
+            Symbol::NonTerminal(NonTerminal::GrammarRule1(_)) => "GrammarRule1",
+            Symbol::NonTerminal(NonTerminal::FileHeader1(_)) => "FileHeader1",
+            Symbol::NonTerminal(NonTerminal::TerminalRule1(_)) => "TerminalRule1",
+            Symbol::NonTerminal(NonTerminal::FileHeader(_)) => "FileHeader",
+            Symbol::NonTerminal(NonTerminal::ImportStm(_)) => "ImportStm",
+            Symbol::NonTerminal(NonTerminal::CommentDirective(_)) => "CommentDirective",
+            Symbol::NonTerminal(NonTerminal::KeywordsDirective(_)) => "KeywordsDirective",
+            Symbol::NonTerminal(NonTerminal::Name1(_)) => "Name1",
+            Symbol::NonTerminal(NonTerminal::StartDirective(_)) => "StartDirective",
+            Symbol::NonTerminal(NonTerminal::IgnoreDirective(_)) => "IgnoreDirective",
+            Symbol::NonTerminal(NonTerminal::TriviaDirective(_)) => "TriviaDirective",
+            Symbol::NonTerminal(NonTerminal::TerminalsDirective(_)) => {
+                "TerminalsDirective"
+            }
+            Symbol::NonTerminal(NonTerminal::GrammarRule(_)) => "GrammarRule",
+            Symbol::NonTerminal(NonTerminal::DocComment1(_)) => "DocComment1",
+            Symbol::NonTerminal(NonTerminal::DocComment0(_)) => "DocComment0",
+            Symbol::NonTerminal(NonTerminal::AnnotationOpt(_)) => "AnnotationOpt",
+            Symbol::NonTerminal(NonTerminal::GrammarRuleRHS(_)) => "GrammarRuleRHS",
+            Symbol::NonTerminal(NonTerminal::Production(_)) => "Production",
+            Symbol::NonTerminal(NonTerminal::Assignment1(_)) => "Assignment1",
+            Symbol::NonTerminal(NonTerminal::TerminalRule(_)) => "TerminalRule",
+            Symbol::NonTerminal(NonTerminal::TerminalTypeOpt(_)) => "TerminalTypeOpt",
+            Symbol::NonTerminal(NonTerminal::TerminalType(_)) => "TerminalType",
+            Symbol::NonTerminal(NonTerminal::ProdMetaData(_)) => "ProdMetaData",
+            Symbol::NonTerminal(NonTerminal::ProdMetaDatas(_)) => "ProdMetaDatas",
+            Symbol::NonTerminal(NonTerminal::TermMetaData(_)) => "TermMetaData",
+            Symbol::NonTerminal(NonTerminal::TermMetaDatas(_)) => "TermMetaDatas",
+            Symbol::NonTerminal(NonTerminal::UserMetaData(_)) => "UserMetaData",
+            Symbol::NonTerminal(NonTerminal::ProdKind(_)) => "ProdKind",
+            Symbol::NonTerminal(NonTerminal::ConstVal(_)) => "ConstVal",
+            Symbol::NonTerminal(NonTerminal::Assignment(_)) => "Assignment",
+            Symbol::NonTerminal(NonTerminal::PlainAssignment(_)) => "PlainAssignment",
+            Symbol::NonTerminal(NonTerminal::BoolAssignment(_)) => "BoolAssignment",
+            Symbol::NonTerminal(NonTerminal::ProductionGroup(_)) => "ProductionGroup",
+            Symbol::NonTerminal(NonTerminal::GrammarSymbolRef(_)) => "GrammarSymbolRef",
+            Symbol::NonTerminal(NonTerminal::RepetitionOperatorOpt(_)) => {
+                "RepetitionOperatorOpt"
+            }
+            Symbol::NonTerminal(NonTerminal::MacroCall(_)) => "MacroCall",
+            Symbol::NonTerminal(NonTerminal::RepetitionOperator(_)) => {
+                "RepetitionOperator"
+            }
+            Symbol::NonTerminal(NonTerminal::RepetitionModifiersOpt(_)) => {
+                "RepetitionModifiersOpt"
+            }
+            Symbol::NonTerminal(NonTerminal::RepetitionOperatorOp(_)) => {
+                "RepetitionOperatorOp"
+            }
+            Symbol::NonTerminal(NonTerminal::RepetitionModifiers(_)) => {
+                "RepetitionModifiers"
+            }
+            Symbol::NonTerminal(NonTerminal::RepetitionModifier1(_)) => {
+                "RepetitionModifier1"
+            }
+            Symbol::NonTerminal(NonTerminal::RepetitionModifier(_)) => {
+                "RepetitionModifier"
+            }
+            Symbol::NonTerminal(NonTerminal::GrammarSymbol(_)) => "GrammarSymbol",
+            Symbol::NonTerminal(NonTerminal::Recognizer(_)) => "Recognizer",
+            Symbol::NonTerminal(NonTerminal::StrAlternatives(_)) => "StrAlternatives",
+            Symbol::NonTerminal(NonTerminal::RawTerm(_)) => "RawTerm",
+        }
+    }
+    /// The kind of this symbol if it is a terminal.
+    pub fn token_kind(&self) -> Option<TokenKind> {
+        match self {
+            Symbol::Terminal(t) => Some(t.kind()),
+            Symbol::NonTerminal(_) => None,
+        }
+    }
+    /// The kind of this symbol if it is a non-terminal.
+    pub fn nonterm_kind(&self) -> Option<NonTermKind> {
+        match self {
+            Symbol::Terminal(_) => None,
+            Symbol::NonTerminal(nt) => Some(nt.kind()),
+        }
+    }
 }
 type ActionFn = fn(token: TokenKind) -> Vec<Action<State, ProdKind>>;
 pub struct RustemoParserDefinition {
@@ -903,238 +1869,773 @@ fn action_aug_s0(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Terminals => Vec::from(&[Shift(State::TerminalsS1)]),
         TK::Import => Vec::from(&[Shift(State::ImportS2)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::LineCommentDirective => Vec::from(&[Shift(State::LineCommentDirectiveS3)]),
+        TK::BlockCommentDirective => Vec::from(&[Shift(State::BlockCommentDirectiveS4)]),
+        TK::Keywords => Vec::from(&[Shift(State::KeywordsS5)]),
+        TK::Start => Vec::from(&[Shift(State::StartS6)]),
+        TK::Ignore => Vec::from(&[Shift(State::IgnoreS7)]),
+        TK::Trivia => Vec::from(&[Shift(State::TriviaS8)]),
+        TK::TerminalsInclude => Vec::from(&[Shift(State::TerminalsIncludeS9)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
+        TK::ActionsCode => Vec::from(&[Shift(State::ActionsCodeS11)]),
         _ => vec![],
     }
 }
 fn action_terminals_s1(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
         _ => vec![],
     }
 }
 fn action_import_s2(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS13)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS29)]),
         _ => vec![],
     }
 }
-fn action_annotation_s3(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_linecommentdirective_s3(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP1, 1usize)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS30)]),
+        _ => vec![],
+    }
+}
+fn action_blockcommentdirective_s4(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
         _ => vec![],
     }
 }
-fn action_file_s4(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_keywords_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Accept]),
+        TK::Name => Vec::from(&[Shift(State::NameS32)]),
         _ => vec![],
     }
 }
-fn action_grammarrule1_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_start_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FileP1, 1usize)]),
-        TK::Terminals => Vec::from(&[Shift(State::TerminalsS14)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Name => Vec::from(&[Shift(State::NameS33)]),
         _ => vec![],
     }
 }
-fn action_importstm1_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ignore_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Import => Vec::from(&[Shift(State::ImportS2)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Name => Vec::from(&[Shift(State::NameS34)]),
+        _ => vec![],
+    }
+}
+fn action_trivia_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS34)]),
+        _ => vec![],
+    }
+}
+fn action_terminalsinclude_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS37)]),
+        _ => vec![],
+    }
+}
+fn action_doccomment_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Reduce(PK::DocComment1P2, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::DocComment1P2, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment1P2, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::DocComment1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_actionscode_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeaderP8, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_file_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Accept]),
         _ => vec![],
     }
 }
-fn action_importstm_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrule1_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Import => Vec::from(&[Reduce(PK::ImportStm1P2, 1usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::ImportStm1P2, 1usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::ImportStm1P2, 1usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FileP1, 1usize)]),
+        TK::Terminals => Vec::from(&[Shift(State::TerminalsS38)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
         _ => vec![],
     }
 }
-fn action_grammarrule_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_fileheader1_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Shift(State::ImportS2)]),
+        TK::LineCommentDirective => Vec::from(&[Shift(State::LineCommentDirectiveS3)]),
+        TK::BlockCommentDirective => Vec::from(&[Shift(State::BlockCommentDirectiveS4)]),
+        TK::Keywords => Vec::from(&[Shift(State::KeywordsS5)]),
+        TK::Start => Vec::from(&[Shift(State::StartS6)]),
+        TK::Ignore => Vec::from(&[Shift(State::IgnoreS7)]),
+        TK::Trivia => Vec::from(&[Shift(State::TriviaS8)]),
+        TK::TerminalsInclude => Vec::from(&[Shift(State::TerminalsIncludeS9)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
+        TK::ActionsCode => Vec::from(&[Shift(State::ActionsCodeS11)]),
+        _ => vec![],
+    }
+}
+fn action_fileheader_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeader1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_importstm_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeaderP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_commentdirective_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeaderP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_keywordsdirective_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeaderP3, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_startdirective_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeaderP4, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_ignoredirective_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeaderP5, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_triviadirective_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeaderP6, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_terminalsdirective_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeaderP7, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_grammarrule_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
         TK::Terminals => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::GrammarRule1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_annotationopt_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_doccomment1_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS18)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P1, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::DocComment0P1, 1usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P1, 1usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS42)]),
         _ => vec![],
     }
 }
-fn action_terminalrule1_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_doccomment0_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FileP5, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS43)]),
+        _ => vec![],
+    }
+}
+fn action_terminalrule1_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::FileP5, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
         _ => vec![],
     }
 }
-fn action_annotationopt_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_doccomment0_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS20)]),
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
+        TK::Annotation => Vec::from(&[Shift(State::AnnotationS43)]),
         _ => vec![],
     }
 }
-fn action_terminalrule_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminalrule_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::TerminalRule1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_strconst_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_strconst_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Import => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
-        TK::As => Vec::from(&[Shift(State::AsS21)]),
+        TK::As => Vec::from(&[Shift(State::AsS47)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::ImportStmP1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_terminals_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_strconst_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Import => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::CommentDirectiveP1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS48)]),
         _ => vec![],
     }
 }
-fn action_grammarrule_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS49)]),
+        _ => vec![],
+    }
+}
+fn action_name_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS50)]),
+        _ => vec![],
+    }
+}
+fn action_name_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::Name1P2, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::Name1P2, 1usize)]),
+        TK::CBracket => Vec::from(&[Reduce(PK::Name1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_name1_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS51)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS52)]),
+        _ => vec![],
+    }
+}
+fn action_name1_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS53)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS52)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS54)]),
+        _ => vec![],
+    }
+}
+fn action_terminals_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
+        _ => vec![],
+    }
+}
+fn action_grammarrule_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
         TK::Terminals => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::GrammarRule1P1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_grammarrule1_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrule1_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::FileP2, 2usize)]),
-        TK::Terminals => Vec::from(&[Shift(State::TerminalsS23)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Terminals => Vec::from(&[Shift(State::TerminalsS56)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
+        _ => vec![],
+    }
+}
+fn action_fileheader_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::FileHeader1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_doccomment_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Reduce(PK::DocComment1P1, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::DocComment1P1, 2usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment1P1, 2usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::DocComment1P1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_importstm_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_annotation_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Import => Vec::from(&[Reduce(PK::ImportStm1P1, 2usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::ImportStm1P1, 2usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::ImportStm1P1, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP1, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::AnnotationOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_name_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_annotationopt_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS24)]),
-        TK::OBrace => Vec::from(&[Shift(State::OBraceS25)]),
+        TK::Name => Vec::from(&[Shift(State::NameS57)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS58)]),
         _ => vec![],
     }
 }
-fn action_terminalrule_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminalrule_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::TerminalRule1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_annotationopt_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS59)]),
+        _ => vec![],
+    }
+}
+fn action_as_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS60)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::CommentDirectiveP2, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS34)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::StartDirectiveP1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::IgnoreDirectiveP1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS62)]),
         _ => vec![],
     }
 }
-fn action_name_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS26)]),
+        TK::Import => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::TriviaDirectiveP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_as_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS27)]),
+        TK::Import => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::LineCommentDirective => {
+            Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)])
+        }
+        TK::BlockCommentDirective => {
+            Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)])
+        }
+        TK::Keywords => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::TerminalsDirectiveP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_terminalrule1_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminalrule1_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::FileP3, 3usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
         _ => vec![],
     }
 }
-fn action_terminals_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminals_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
         _ => vec![],
     }
 }
-fn action_colon_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS64)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS65)]),
         _ => vec![],
     }
 }
-fn action_obrace_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_macroname_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS41)]),
-        TK::Right => Vec::from(&[Shift(State::RightS42)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS43)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS44)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS45)]),
-        TK::NOPS => Vec::from(&[Shift(State::NOPSS46)]),
-        TK::NOPSE => Vec::from(&[Shift(State::NOPSES47)]),
-        TK::Name => Vec::from(&[Shift(State::NameS48)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS49)]),
+        TK::Name => Vec::from(&[Shift(State::NameS34)]),
         _ => vec![],
     }
 }
-fn action_colon_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS54)]),
-        TK::OBrace => Vec::from(&[Shift(State::OBraceS55)]),
-        TK::RegexTerm => Vec::from(&[Shift(State::RegexTermS56)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS57)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS67)]),
         _ => vec![],
     }
 }
-fn action_name_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Import => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::BlockCommentDirective => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::Keywords => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
         TK::Name => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
         TK::Annotation => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::ImportStmP2, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_name1_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS68)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS52)]),
+        _ => vec![],
+    }
+}
+fn action_name_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::Name1P1, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::Name1P1, 3usize)]),
+        TK::CBracket => Vec::from(&[Reduce(PK::Name1P1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_terminalrule1_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminalrule1_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::FileP4, 4usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::AnnotationOptP2, 0usize)]),
-        TK::Annotation => Vec::from(&[Shift(State::AnnotationS3)]),
+        TK::Name => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::DocComment0P2, 0usize)]),
+        TK::DocComment => Vec::from(&[Shift(State::DocCommentS10)]),
         _ => vec![],
     }
 }
-fn action_obracket_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS69)]),
+        TK::Name => Vec::from(&[Shift(State::NameS70)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS71)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS72)]),
+        _ => vec![],
+    }
+}
+fn action_obrace_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Optional => Vec::from(&[Shift(State::OptionalS83)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS84)]),
+        TK::Right => Vec::from(&[Shift(State::RightS85)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS86)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS87)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS88)]),
+        TK::NOPS => Vec::from(&[Shift(State::NOPSS89)]),
+        TK::NOPSE => Vec::from(&[Shift(State::NOPSES90)]),
+        TK::NoLayout => Vec::from(&[Shift(State::NoLayoutS91)]),
+        TK::Prec => Vec::from(&[Shift(State::PrecS92)]),
+        TK::Assoc => Vec::from(&[Shift(State::AssocS93)]),
+        TK::Name => Vec::from(&[Shift(State::NameS94)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS95)]),
+        _ => vec![],
+    }
+}
+fn action_name1_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Shift(State::CommaS52)]),
+        TK::CBracket => Vec::from(&[Shift(State::CBracketS100)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS101)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS102)]),
+        TK::Raw => Vec::from(&[Shift(State::RawS103)]),
+        TK::RegexTerm => Vec::from(&[Shift(State::RegexTermS104)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS105)]),
         _ => vec![],
     }
 }
-fn action_name_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Import => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::LineCommentDirective => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::BlockCommentDirective => {
+            Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)])
+        }
+        TK::Keywords => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::Start => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::Ignore => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::Trivia => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::TerminalsInclude => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        TK::ActionsCode => Vec::from(&[Reduce(PK::KeywordsDirectiveP1, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_obracket_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS69)]),
+        TK::Name => Vec::from(&[Shift(State::NameS70)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS71)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS72)]),
+        _ => vec![],
+    }
+}
+fn action_name_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
@@ -1147,14 +2648,21 @@ fn action_name_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::OneOrMoreGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::Optional => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::OptionalGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
-        TK::Equals => Vec::from(&[Shift(State::EqualsS60)]),
-        TK::QEquals => Vec::from(&[Shift(State::QEqualsS61)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS110)]),
+        TK::QEquals => Vec::from(&[Shift(State::QEqualsS111)]),
         TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_strconst_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_macroname_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS34)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
@@ -1168,18 +2676,19 @@ fn action_strconst_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::Optional => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
         TK::OptionalGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_grammarrulerhs_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrulerhs_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS62)]),
-        TK::Choice => Vec::from(&[Shift(State::ChoiceS63)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS113)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS114)]),
         _ => vec![],
     }
 }
-fn action_production_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_production_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarRuleRHSP2, 1usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::GrammarRuleRHSP2, 1usize)]),
@@ -1187,19 +2696,20 @@ fn action_production_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_assignment1_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_assignment1_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::ProductionP1, 1usize)]),
-        TK::OBrace => Vec::from(&[Shift(State::OBraceS64)]),
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS115)]),
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS69)]),
         TK::CBracket => Vec::from(&[Reduce(PK::ProductionP1, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::ProductionP1, 1usize)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Name => Vec::from(&[Shift(State::NameS70)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS71)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS72)]),
         _ => vec![],
     }
 }
-fn action_assignment_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_assignment_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
@@ -1207,11 +2717,12 @@ fn action_assignment_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         TK::CBracket => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::Assignment1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_plainassignment_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_plainassignment_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
@@ -1219,11 +2730,12 @@ fn action_plainassignment_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKi
         TK::CBracket => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::AssignmentP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_boolassignment_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_boolassignment_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
@@ -1231,29 +2743,31 @@ fn action_boolassignment_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKin
         TK::CBracket => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::AssignmentP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_productiongroup_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_productiongroup_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::OBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
-        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS66)]),
-        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS67)]),
-        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS68)]),
-        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS69)]),
-        TK::Optional => Vec::from(&[Shift(State::OptionalS70)]),
-        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS71)]),
+        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS117)]),
+        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS118)]),
+        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS119)]),
+        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS120)]),
+        TK::Optional => Vec::from(&[Shift(State::OptionalS121)]),
+        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS122)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_grammarsymbolref_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarsymbolref_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
@@ -1261,219 +2775,315 @@ fn action_grammarsymbolref_s39(token_kind: TokenKind) -> Vec<Action<State, ProdK
         TK::CBracket => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::AssignmentP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_grammarsymbol_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_macrocall_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::OBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::CBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS117)]),
+        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS118)]),
+        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS119)]),
+        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS120)]),
+        TK::Optional => Vec::from(&[Shift(State::OptionalS121)]),
+        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS122)]),
+        TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_grammarsymbol_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::OBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
-        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS66)]),
-        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS67)]),
-        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS68)]),
-        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS69)]),
-        TK::Optional => Vec::from(&[Shift(State::OptionalS70)]),
-        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS71)]),
+        TK::ZeroOrMore => Vec::from(&[Shift(State::ZeroOrMoreS117)]),
+        TK::ZeroOrMoreGreedy => Vec::from(&[Shift(State::ZeroOrMoreGreedyS118)]),
+        TK::OneOrMore => Vec::from(&[Shift(State::OneOrMoreS119)]),
+        TK::OneOrMoreGreedy => Vec::from(&[Shift(State::OneOrMoreGreedyS120)]),
+        TK::Optional => Vec::from(&[Shift(State::OptionalS121)]),
+        TK::OptionalGreedy => Vec::from(&[Shift(State::OptionalGreedyS122)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_left_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_optional_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Name => Vec::from(&[Shift(State::NameS128)]),
+        _ => vec![],
+    }
+}
+fn action_left_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataLeft, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataLeft, 1usize)]),
         _ => vec![],
     }
 }
-fn action_right_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_right_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataRight, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataRight, 1usize)]),
         _ => vec![],
     }
 }
-fn action_reduce_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_reduce_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataReduce, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataReduce, 1usize)]),
         _ => vec![],
     }
 }
-fn action_shift_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_shift_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataShift, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataShift, 1usize)]),
         _ => vec![],
     }
 }
-fn action_dynamic_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_dynamic_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataDynamic, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataDynamic, 1usize)]),
         _ => vec![],
     }
 }
-fn action_nops_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nops_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataNOPS, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataNOPS, 1usize)]),
         _ => vec![],
     }
 }
-fn action_nopse_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nopse_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataNOPSE, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataNOPSE, 1usize)]),
         _ => vec![],
     }
 }
-fn action_name_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nolayout_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS76)]),
-        TK::Comma => Vec::from(&[Reduce(PK::ProdKindP1, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::ProdKindP1, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataNoLayout, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataNoLayout, 1usize)]),
         _ => vec![],
     }
 }
-fn action_intconst_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prec_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS129)]),
+        _ => vec![],
+    }
+}
+fn action_assoc_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS130)]),
+        _ => vec![],
+    }
+}
+fn action_name_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS131)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ProdKindP1, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdKindP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_intconst_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataPriority, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataPriority, 1usize)]),
         _ => vec![],
     }
 }
-fn action_prodmetadata_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodmetadata_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDatasP2, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDatasP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_prodmetadatas_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodmetadatas_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS77)]),
-        TK::CBrace => Vec::from(&[Shift(State::CBraceS78)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS132)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS133)]),
         _ => vec![],
     }
 }
-fn action_usermetadata_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_usermetadata_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataP9, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataP9, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataP14, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataP14, 1usize)]),
         _ => vec![],
     }
 }
-fn action_prodkind_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodkind_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataP10, 1usize)]),
-        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataP10, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataP15, 1usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataP15, 1usize)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbracket_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS134)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s101(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP2, 5usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP2, 5usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP2, 5usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::TerminalRuleP2, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_obrace_s102(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP2, 4usize)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS135)]),
+        TK::Right => Vec::from(&[Shift(State::RightS136)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS137)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS138)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS139)]),
+        TK::Prefer => Vec::from(&[Shift(State::PreferS140)]),
+        TK::Finish => Vec::from(&[Shift(State::FinishS141)]),
+        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS142)]),
+        TK::Name => Vec::from(&[Shift(State::NameS143)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS144)]),
         _ => vec![],
     }
 }
-fn action_obrace_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_raw_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS79)]),
-        TK::Right => Vec::from(&[Shift(State::RightS80)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS81)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS82)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS83)]),
-        TK::Prefer => Vec::from(&[Shift(State::PreferS84)]),
-        TK::Finish => Vec::from(&[Shift(State::FinishS85)]),
-        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS86)]),
-        TK::Name => Vec::from(&[Shift(State::NameS87)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS88)]),
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS148)]),
         _ => vec![],
     }
 }
-fn action_regexterm_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_regexterm_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::Colon => Vec::from(&[Reduce(PK::RecognizerP2, 1usize)]),
         TK::SemiColon => Vec::from(&[Reduce(PK::RecognizerP2, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RecognizerP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_strconst_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_strconst_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::Colon => Vec::from(&[Reduce(PK::RecognizerP1, 1usize)]),
         TK::SemiColon => Vec::from(&[Reduce(PK::RecognizerP1, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RecognizerP1, 1usize)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS149)]),
         _ => vec![],
     }
 }
-fn action_recognizer_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_recognizer_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS92)]),
-        TK::OBrace => Vec::from(&[Shift(State::OBraceS93)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS150)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::TerminalTypeOptP2, 0usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::TerminalTypeOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_grammarrulerhs_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_stralternatives_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CBracket => Vec::from(&[Shift(State::CBracketS94)]),
-        TK::Choice => Vec::from(&[Shift(State::ChoiceS63)]),
+        TK::Colon => Vec::from(&[Reduce(PK::RecognizerP4, 1usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::RecognizerP4, 1usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::RecognizerP4, 1usize)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS153)]),
         _ => vec![],
     }
 }
-fn action_equals_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_rawterm_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS95)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::Colon => Vec::from(&[Reduce(PK::RecognizerP3, 1usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::RecognizerP3, 1usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::RecognizerP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_qequals_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrulerhs_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS95)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::CBracket => Vec::from(&[Shift(State::CBracketS154)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS114)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_equals_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
-        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRuleP1, 5usize)]),
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS69)]),
+        TK::Name => Vec::from(&[Shift(State::NameS155)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS71)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS72)]),
         _ => vec![],
     }
 }
-fn action_choice_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_qequals_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS69)]),
+        TK::Name => Vec::from(&[Shift(State::NameS155)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS71)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS72)]),
+        _ => vec![],
+    }
+}
+fn action_name1_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Shift(State::CommaS52)]),
+        TK::CBracket => Vec::from(&[Shift(State::CBracketS158)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::GrammarRuleP1, 6usize)]),
+        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRuleP1, 6usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarRuleP1, 6usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarRuleP1, 6usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRuleP1, 6usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::GrammarRuleP1, 6usize)]),
+        _ => vec![],
+    }
+}
+fn action_choice_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS69)]),
+        TK::Name => Vec::from(&[Shift(State::NameS70)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS71)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS72)]),
         _ => vec![],
     }
 }
-fn action_obrace_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_obrace_s115(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS41)]),
-        TK::Right => Vec::from(&[Shift(State::RightS42)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS43)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS44)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS45)]),
-        TK::NOPS => Vec::from(&[Shift(State::NOPSS46)]),
-        TK::NOPSE => Vec::from(&[Shift(State::NOPSES47)]),
-        TK::Name => Vec::from(&[Shift(State::NameS48)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS49)]),
+        TK::Optional => Vec::from(&[Shift(State::OptionalS83)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS84)]),
+        TK::Right => Vec::from(&[Shift(State::RightS85)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS86)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS87)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS88)]),
+        TK::NOPS => Vec::from(&[Shift(State::NOPSS89)]),
+        TK::NOPSE => Vec::from(&[Shift(State::NOPSES90)]),
+        TK::NoLayout => Vec::from(&[Shift(State::NoLayoutS91)]),
+        TK::Prec => Vec::from(&[Shift(State::PrecS92)]),
+        TK::Assoc => Vec::from(&[Shift(State::AssocS93)]),
+        TK::Name => Vec::from(&[Shift(State::NameS94)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS95)]),
         _ => vec![],
     }
 }
-fn action_assignment_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_assignment_s116(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
@@ -1481,11 +3091,12 @@ fn action_assignment_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         TK::CBracket => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::Assignment1P1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_zeroormore_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_zeroormore_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
@@ -1494,11 +3105,12 @@ fn action_zeroormore_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         TK::OSBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMore, 1usize)]),
         _ => vec![],
     }
 }
-fn action_zeroormoregreedy_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_zeroormoregreedy_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMoreGreedy, 1usize)])
@@ -1521,13 +3133,16 @@ fn action_zeroormoregreedy_s67(token_kind: TokenKind) -> Vec<Action<State, ProdK
         TK::Name => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMoreGreedy, 1usize)])
         }
+        TK::MacroName => {
+            Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMoreGreedy, 1usize)])
+        }
         TK::StrConst => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpZeroOrMoreGreedy, 1usize)])
         }
         _ => vec![],
     }
 }
-fn action_oneormore_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_oneormore_s119(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
@@ -1536,11 +3151,12 @@ fn action_oneormore_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::OSBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMore, 1usize)]),
         _ => vec![],
     }
 }
-fn action_oneormoregreedy_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_oneormoregreedy_s120(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMoreGreedy, 1usize)])
@@ -1561,13 +3177,16 @@ fn action_oneormoregreedy_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKi
             Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMoreGreedy, 1usize)])
         }
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMoreGreedy, 1usize)]),
+        TK::MacroName => {
+            Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMoreGreedy, 1usize)])
+        }
         TK::StrConst => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpOneOrMoreGreedy, 1usize)])
         }
         _ => vec![],
     }
 }
-fn action_optional_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_optional_s121(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
@@ -1576,11 +3195,12 @@ fn action_optional_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::OSBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptional, 1usize)]),
         _ => vec![],
     }
 }
-fn action_optionalgreedy_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_optionalgreedy_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpOptionalGreedy, 1usize)])
@@ -1601,13 +3221,16 @@ fn action_optionalgreedy_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKin
             Vec::from(&[Reduce(PK::RepetitionOperatorOpOptionalGreedy, 1usize)])
         }
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOpOptionalGreedy, 1usize)]),
+        TK::MacroName => {
+            Vec::from(&[Reduce(PK::RepetitionOperatorOpOptionalGreedy, 1usize)])
+        }
         TK::StrConst => {
             Vec::from(&[Reduce(PK::RepetitionOperatorOpOptionalGreedy, 1usize)])
         }
         _ => vec![],
     }
 }
-fn action_repetitionoperatoropt_s72(
+fn action_repetitionoperatoropt_s123(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1617,11 +3240,14 @@ fn action_repetitionoperatoropt_s72(
         TK::CBracket => Vec::from(&[Reduce(PK::GrammarSymbolRefP2, 2usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::GrammarSymbolRefP2, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolRefP2, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarSymbolRefP2, 2usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolRefP2, 2usize)]),
         _ => vec![],
     }
 }
-fn action_repetitionoperator_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_repetitionoperator_s124(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
@@ -1629,11 +3255,12 @@ fn action_repetitionoperator_s73(token_kind: TokenKind) -> Vec<Action<State, Pro
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_repetitionoperatorop_s74(
+fn action_repetitionoperatorop_s125(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1641,14 +3268,30 @@ fn action_repetitionoperatorop_s74(
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         TK::OBracket => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
-        TK::OSBracket => Vec::from(&[Shift(State::OSBracketS100)]),
+        TK::OSBracket => Vec::from(&[Shift(State::OSBracketS161)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionModifiersOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_repetitionoperatoropt_s75(
+fn action_repetitionoperatoropt_s126(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbolRefP3, 2usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::GrammarSymbolRefP3, 2usize)]),
+        TK::OBracket => Vec::from(&[Reduce(PK::GrammarSymbolRefP3, 2usize)]),
+        TK::CBracket => Vec::from(&[Reduce(PK::GrammarSymbolRefP3, 2usize)]),
+        TK::Choice => Vec::from(&[Reduce(PK::GrammarSymbolRefP3, 2usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolRefP3, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarSymbolRefP3, 2usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolRefP3, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_repetitionoperatoropt_s127(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1658,153 +3301,202 @@ fn action_repetitionoperatoropt_s75(
         TK::CBracket => Vec::from(&[Reduce(PK::GrammarSymbolRefP1, 2usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::GrammarSymbolRefP1, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolRefP1, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarSymbolRefP1, 2usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolRefP1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_colon_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataPredicate, 2usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataPredicate, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS164)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Left => Vec::from(&[Shift(State::LeftS165)]),
+        TK::Right => Vec::from(&[Shift(State::RightS166)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS103)]),
-        TK::FloatConst => Vec::from(&[Shift(State::FloatConstS104)]),
-        TK::BoolConst => Vec::from(&[Shift(State::BoolConstS105)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS106)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS167)]),
+        TK::FloatConst => Vec::from(&[Shift(State::FloatConstS168)]),
+        TK::BoolConst => Vec::from(&[Shift(State::BoolConstS169)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS170)]),
         _ => vec![],
     }
 }
-fn action_comma_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS41)]),
-        TK::Right => Vec::from(&[Shift(State::RightS42)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS43)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS44)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS45)]),
-        TK::NOPS => Vec::from(&[Shift(State::NOPSS46)]),
-        TK::NOPSE => Vec::from(&[Shift(State::NOPSES47)]),
-        TK::Name => Vec::from(&[Shift(State::NameS48)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS49)]),
+        TK::Optional => Vec::from(&[Shift(State::OptionalS83)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS84)]),
+        TK::Right => Vec::from(&[Shift(State::RightS85)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS86)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS87)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS88)]),
+        TK::NOPS => Vec::from(&[Shift(State::NOPSS89)]),
+        TK::NOPSE => Vec::from(&[Shift(State::NOPSES90)]),
+        TK::NoLayout => Vec::from(&[Shift(State::NoLayoutS91)]),
+        TK::Prec => Vec::from(&[Shift(State::PrecS92)]),
+        TK::Assoc => Vec::from(&[Shift(State::AssocS93)]),
+        TK::Name => Vec::from(&[Shift(State::NameS94)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS95)]),
         _ => vec![],
     }
 }
-fn action_cbrace_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS109)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS173)]),
         _ => vec![],
     }
 }
-fn action_left_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS69)]),
+        TK::Name => Vec::from(&[Shift(State::NameS70)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS71)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS72)]),
+        _ => vec![],
+    }
+}
+fn action_left_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataLeft, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataLeft, 1usize)]),
         _ => vec![],
     }
 }
-fn action_right_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_right_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataRight, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataRight, 1usize)]),
         _ => vec![],
     }
 }
-fn action_reduce_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_reduce_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataReduce, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataReduce, 1usize)]),
         _ => vec![],
     }
 }
-fn action_shift_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_shift_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataShift, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataShift, 1usize)]),
         _ => vec![],
     }
 }
-fn action_dynamic_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_dynamic_s139(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataDynamic, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataDynamic, 1usize)]),
         _ => vec![],
     }
 }
-fn action_prefer_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prefer_s140(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataPrefer, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataPrefer, 1usize)]),
         _ => vec![],
     }
 }
-fn action_finish_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_finish_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataFinish, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataFinish, 1usize)]),
         _ => vec![],
     }
 }
-fn action_nofinish_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nofinish_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataNoFinish, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataNoFinish, 1usize)]),
         _ => vec![],
     }
 }
-fn action_name_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS76)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS131)]),
         _ => vec![],
     }
 }
-fn action_intconst_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_intconst_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataPriority, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataPriority, 1usize)]),
         _ => vec![],
     }
 }
-fn action_termmetadata_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_termmetadata_s145(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDatasP2, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDatasP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_termmetadatas_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_termmetadatas_s146(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS110)]),
-        TK::CBrace => Vec::from(&[Shift(State::CBraceS111)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS175)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS176)]),
         _ => vec![],
     }
 }
-fn action_usermetadata_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_usermetadata_s147(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDataP10, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDataP10, 1usize)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_obracket_s148(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS177)]),
+        _ => vec![],
+    }
+}
+fn action_choice_s149(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS178)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s150(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::RustType => Vec::from(&[Shift(State::RustTypeS179)]),
+        _ => vec![],
+    }
+}
+fn action_terminaltypeopt_s151(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP1, 5usize)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS180)]),
+        TK::OBrace => Vec::from(&[Shift(State::OBraceS181)]),
         _ => vec![],
     }
 }
-fn action_obrace_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_terminaltype_s152(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS79)]),
-        TK::Right => Vec::from(&[Shift(State::RightS80)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS81)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS82)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS83)]),
-        TK::Prefer => Vec::from(&[Shift(State::PreferS84)]),
-        TK::Finish => Vec::from(&[Shift(State::FinishS85)]),
-        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS86)]),
-        TK::Name => Vec::from(&[Shift(State::NameS87)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS88)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::TerminalTypeOptP1, 1usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::TerminalTypeOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cbracket_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_choice_s153(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS182)]),
+        _ => vec![],
+    }
+}
+fn action_cbracket_s154(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
@@ -1818,11 +3510,12 @@ fn action_cbracket_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::Optional => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
         TK::OptionalGreedy => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
         TK::Name => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::ProductionGroupP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_name_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s155(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
@@ -1836,11 +3529,12 @@ fn action_name_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::Optional => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::OptionalGreedy => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::GrammarSymbolP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_grammarsymbolref_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarsymbolref_s156(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
@@ -1848,11 +3542,12 @@ fn action_grammarsymbolref_s96(token_kind: TokenKind) -> Vec<Action<State, ProdK
         TK::CBracket => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
         TK::Name => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::PlainAssignmentP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_grammarsymbolref_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarsymbolref_s157(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
@@ -1860,11 +3555,31 @@ fn action_grammarsymbolref_s97(token_kind: TokenKind) -> Vec<Action<State, ProdK
         TK::CBracket => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
         TK::Name => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::BoolAssignmentP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_production_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbracket_s158(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::OBracket => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::CBracket => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::Choice => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::ZeroOrMore => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::ZeroOrMoreGreedy => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::OneOrMore => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::OneOrMoreGreedy => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::Optional => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::OptionalGreedy => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        TK::StrConst => Vec::from(&[Reduce(PK::MacroCallP1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_production_s159(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::GrammarRuleRHSP1, 3usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::GrammarRuleRHSP1, 3usize)]),
@@ -1872,20 +3587,20 @@ fn action_production_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_prodmetadatas_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodmetadatas_s160(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS77)]),
-        TK::CBrace => Vec::from(&[Shift(State::CBraceS113)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS132)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS183)]),
         _ => vec![],
     }
 }
-fn action_osbracket_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_osbracket_s161(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS114)]),
+        TK::Name => Vec::from(&[Shift(State::NameS184)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifiersopt_s101(
+fn action_repetitionmodifiersopt_s162(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1895,11 +3610,12 @@ fn action_repetitionmodifiersopt_s101(
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionOperatorP1, 2usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionOperatorP1, 2usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionOperatorP1, 2usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionOperatorP1, 2usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionOperatorP1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifiers_s102(
+fn action_repetitionmodifiers_s163(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -1909,89 +3625,167 @@ fn action_repetitionmodifiers_s102(
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionModifiersOptP1, 1usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionModifiersOptP1, 1usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionModifiersOptP1, 1usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionModifiersOptP1, 1usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionModifiersOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_intconst_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_intconst_s164(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataPrec, 3usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataPrec, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_left_s165(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataAssocLeft, 3usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataAssocLeft, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_right_s166(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDataAssocRight, 3usize)]),
+        TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDataAssocRight, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_intconst_s167(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ConstValP1, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ConstValP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_floatconst_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_floatconst_s168(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ConstValP2, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ConstValP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_boolconst_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_boolconst_s169(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ConstValP3, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ConstValP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_strconst_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_strconst_s170(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ConstValP4, 1usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ConstValP4, 1usize)]),
         _ => vec![],
     }
 }
-fn action_constval_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_constval_s171(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::UserMetaDataP1, 3usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::UserMetaDataP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_prodmetadata_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_prodmetadata_s172(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::ProdMetaDatasP1, 3usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::ProdMetaDatasP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_colon_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s173(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OBracket => Vec::from(&[Shift(State::OBracketS29)]),
-        TK::Name => Vec::from(&[Shift(State::NameS30)]),
-        TK::StrConst => Vec::from(&[Shift(State::StrConstS31)]),
+        TK::OBracket => Vec::from(&[Shift(State::OBracketS69)]),
+        TK::Name => Vec::from(&[Shift(State::NameS70)]),
+        TK::MacroName => Vec::from(&[Shift(State::MacroNameS71)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS72)]),
+        _ => vec![],
+    }
+}
+fn action_grammarrulerhs_s174(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS188)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS114)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s175(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Left => Vec::from(&[Shift(State::LeftS135)]),
+        TK::Right => Vec::from(&[Shift(State::RightS136)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS137)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS138)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS139)]),
+        TK::Prefer => Vec::from(&[Shift(State::PreferS140)]),
+        TK::Finish => Vec::from(&[Shift(State::FinishS141)]),
+        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS142)]),
+        TK::Name => Vec::from(&[Shift(State::NameS143)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS144)]),
+        _ => vec![],
+    }
+}
+fn action_cbrace_s176(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS190)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s177(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Comma => Vec::from(&[Shift(State::CommaS191)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s178(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Reduce(PK::StrAlternativesP2, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::StrAlternativesP2, 3usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::StrAlternativesP2, 3usize)]),
+        TK::Choice => Vec::from(&[Reduce(PK::StrAlternativesP2, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_rusttype_s179(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Reduce(PK::TerminalTypeP1, 2usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::TerminalTypeP1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_comma_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s180(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Left => Vec::from(&[Shift(State::LeftS79)]),
-        TK::Right => Vec::from(&[Shift(State::RightS80)]),
-        TK::Reduce => Vec::from(&[Shift(State::ReduceS81)]),
-        TK::Shift => Vec::from(&[Shift(State::ShiftS82)]),
-        TK::Dynamic => Vec::from(&[Shift(State::DynamicS83)]),
-        TK::Prefer => Vec::from(&[Shift(State::PreferS84)]),
-        TK::Finish => Vec::from(&[Shift(State::FinishS85)]),
-        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS86)]),
-        TK::Name => Vec::from(&[Shift(State::NameS87)]),
-        TK::IntConst => Vec::from(&[Shift(State::IntConstS88)]),
+        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP1, 7usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP1, 7usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP1, 7usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::TerminalRuleP1, 7usize)]),
         _ => vec![],
     }
 }
-fn action_cbrace_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_obrace_s181(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS119)]),
+        TK::Left => Vec::from(&[Shift(State::LeftS135)]),
+        TK::Right => Vec::from(&[Shift(State::RightS136)]),
+        TK::Reduce => Vec::from(&[Shift(State::ReduceS137)]),
+        TK::Shift => Vec::from(&[Shift(State::ShiftS138)]),
+        TK::Dynamic => Vec::from(&[Shift(State::DynamicS139)]),
+        TK::Prefer => Vec::from(&[Shift(State::PreferS140)]),
+        TK::Finish => Vec::from(&[Shift(State::FinishS141)]),
+        TK::NoFinish => Vec::from(&[Shift(State::NoFinishS142)]),
+        TK::Name => Vec::from(&[Shift(State::NameS143)]),
+        TK::IntConst => Vec::from(&[Shift(State::IntConstS144)]),
         _ => vec![],
     }
 }
-fn action_termmetadatas_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_strconst_s182(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS110)]),
-        TK::CBrace => Vec::from(&[Shift(State::CBraceS120)]),
+        TK::Colon => Vec::from(&[Reduce(PK::StrAlternativesP1, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::StrAlternativesP1, 3usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::StrAlternativesP1, 3usize)]),
+        TK::Choice => Vec::from(&[Reduce(PK::StrAlternativesP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_cbrace_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s183(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::ProductionP2, 4usize)]),
         TK::CBracket => Vec::from(&[Reduce(PK::ProductionP2, 4usize)]),
@@ -1999,23 +3793,23 @@ fn action_cbrace_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_name_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_name_s184(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::RepetitionModifierP1, 1usize)]),
         TK::CSBracket => Vec::from(&[Reduce(PK::RepetitionModifierP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifier1_s115(
+fn action_repetitionmodifier1_s185(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS121)]),
-        TK::CSBracket => Vec::from(&[Shift(State::CSBracketS122)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS193)]),
+        TK::CSBracket => Vec::from(&[Shift(State::CSBracketS194)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifier_s116(
+fn action_repetitionmodifier_s186(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -2024,41 +3818,60 @@ fn action_repetitionmodifier_s116(
         _ => vec![],
     }
 }
-fn action_grammarrulerhs_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_grammarrulerhs_s187(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS195)]),
+        TK::Choice => Vec::from(&[Shift(State::ChoiceS114)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s188(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS123)]),
-        TK::Choice => Vec::from(&[Shift(State::ChoiceS63)]),
+        TK::STOP => Vec::from(&[Reduce(PK::GrammarRuleP3, 8usize)]),
+        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRuleP3, 8usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarRuleP3, 8usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarRuleP3, 8usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRuleP3, 8usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::GrammarRuleP3, 8usize)]),
         _ => vec![],
     }
 }
-fn action_termmetadata_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_termmetadata_s189(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Comma => Vec::from(&[Reduce(PK::TermMetaDatasP1, 3usize)]),
         TK::CBrace => Vec::from(&[Reduce(PK::TermMetaDatasP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s119(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s190(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP4, 8usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP4, 8usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP4, 8usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::TerminalRuleP4, 8usize)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s191(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP4, 7usize)]),
+        TK::StrConst => Vec::from(&[Shift(State::StrConstS196)]),
         _ => vec![],
     }
 }
-fn action_cbrace_s120(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_termmetadatas_s192(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS124)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS175)]),
+        TK::CBrace => Vec::from(&[Shift(State::CBraceS197)]),
         _ => vec![],
     }
 }
-fn action_comma_s121(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s193(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Name => Vec::from(&[Shift(State::NameS114)]),
+        TK::Name => Vec::from(&[Shift(State::NameS184)]),
         _ => vec![],
     }
 }
-fn action_csbracket_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_csbracket_s194(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::SemiColon => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
         TK::OBrace => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
@@ -2066,28 +3879,36 @@ fn action_csbracket_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         TK::CBracket => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
         TK::Choice => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
         TK::Name => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
         TK::StrConst => Vec::from(&[Reduce(PK::RepetitionModifiersP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s123(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s195(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::GrammarRuleP2, 9usize)]),
+        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRuleP2, 9usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::GrammarRuleP2, 9usize)]),
+        TK::MacroName => Vec::from(&[Reduce(PK::GrammarRuleP2, 9usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRuleP2, 9usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::GrammarRuleP2, 9usize)]),
+        _ => vec![],
+    }
+}
+fn action_strconst_s196(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::GrammarRuleP2, 8usize)]),
-        TK::Terminals => Vec::from(&[Reduce(PK::GrammarRuleP2, 8usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::GrammarRuleP2, 8usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::GrammarRuleP2, 8usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS199)]),
+        TK::CBracket => Vec::from(&[Shift(State::CBracketS200)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s124(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cbrace_s197(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
-        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
-        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP3, 8usize)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS201)]),
         _ => vec![],
     }
 }
-fn action_repetitionmodifier_s125(
+fn action_repetitionmodifier_s198(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
@@ -2096,26 +3917,63 @@ fn action_repetitionmodifier_s125(
         _ => vec![],
     }
 }
-fn action_augl_s126(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comma_s199(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Nested => Vec::from(&[Shift(State::NestedS202)]),
+        _ => vec![],
+    }
+}
+fn action_cbracket_s200(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Reduce(PK::RawTermP1, 6usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::RawTermP1, 6usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::RawTermP1, 6usize)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s201(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::TerminalRuleP3, 10usize)]),
+        TK::Name => Vec::from(&[Reduce(PK::TerminalRuleP3, 10usize)]),
+        TK::Annotation => Vec::from(&[Reduce(PK::TerminalRuleP3, 10usize)]),
+        TK::DocComment => Vec::from(&[Reduce(PK::TerminalRuleP3, 10usize)]),
+        _ => vec![],
+    }
+}
+fn action_nested_s202(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CBracket => Vec::from(&[Shift(State::CBracketS203)]),
+        _ => vec![],
+    }
+}
+fn action_cbracket_s203(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Reduce(PK::RawTermP2, 8usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::RawTermP2, 8usize)]),
+        TK::OBrace => Vec::from(&[Reduce(PK::RawTermP2, 8usize)]),
+        _ => vec![],
+    }
+}
+fn action_augl_s204(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P2, 0usize)]),
-        TK::OComment => Vec::from(&[Shift(State::OCommentS127)]),
-        TK::WS => Vec::from(&[Shift(State::WSS128)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS129)]),
+        TK::OComment => Vec::from(&[Shift(State::OCommentS205)]),
+        TK::WS => Vec::from(&[Shift(State::WSS206)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS207)]),
         _ => vec![],
     }
 }
-fn action_ocomment_s127(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ocomment_s205(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OComment => Vec::from(&[Shift(State::OCommentS127)]),
+        TK::OComment => Vec::from(&[Shift(State::OCommentS205)]),
         TK::CComment => Vec::from(&[Reduce(PK::Cornc0P2, 0usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS135)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS129)]),
-        TK::NotComment => Vec::from(&[Shift(State::NotCommentS136)]),
+        TK::WS => Vec::from(&[Shift(State::WSS213)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS207)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS214)]),
         _ => vec![],
     }
 }
-fn action_ws_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ws_s206(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
@@ -2124,7 +3982,7 @@ fn action_ws_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_commentline_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_commentline_s207(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
@@ -2135,28 +3993,28 @@ fn action_commentline_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>
         _ => vec![],
     }
 }
-fn action_layout_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layout_s208(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Accept]),
         _ => vec![],
     }
 }
-fn action_layoutitem1_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem1_s209(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P1, 1usize)]),
-        TK::OComment => Vec::from(&[Shift(State::OCommentS127)]),
-        TK::WS => Vec::from(&[Shift(State::WSS128)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS129)]),
+        TK::OComment => Vec::from(&[Shift(State::OCommentS205)]),
+        TK::WS => Vec::from(&[Shift(State::WSS206)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS207)]),
         _ => vec![],
     }
 }
-fn action_layoutitem0_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem0_s210(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_layoutitem_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem_s211(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
@@ -2165,7 +4023,7 @@ fn action_layoutitem_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_comment_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comment_s212(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
@@ -2174,7 +4032,7 @@ fn action_comment_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_ws_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ws_s213(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::CorncP3, 1usize)]),
@@ -2184,7 +4042,7 @@ fn action_ws_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_notcomment_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_notcomment_s214(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::CorncP2, 1usize)]),
@@ -2194,7 +4052,7 @@ fn action_notcomment_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_comment_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comment_s215(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::CorncP1, 1usize)]),
@@ -2204,29 +4062,29 @@ fn action_comment_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_corncs_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_corncs_s216(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::CComment => Vec::from(&[Shift(State::CCommentS143)]),
+        TK::CComment => Vec::from(&[Shift(State::CCommentS221)]),
         _ => vec![],
     }
 }
-fn action_cornc1_s139(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc1_s217(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OComment => Vec::from(&[Shift(State::OCommentS127)]),
+        TK::OComment => Vec::from(&[Shift(State::OCommentS205)]),
         TK::CComment => Vec::from(&[Reduce(PK::Cornc0P1, 1usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS135)]),
-        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS129)]),
-        TK::NotComment => Vec::from(&[Shift(State::NotCommentS136)]),
+        TK::WS => Vec::from(&[Shift(State::WSS213)]),
+        TK::CommentLine => Vec::from(&[Shift(State::CommentLineS207)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS214)]),
         _ => vec![],
     }
 }
-fn action_cornc0_s140(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc0_s218(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::CComment => Vec::from(&[Reduce(PK::CorncsP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_cornc_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc_s219(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::Cornc1P2, 1usize)]),
@@ -2236,7 +4094,7 @@ fn action_cornc_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_layoutitem_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_layoutitem_s220(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
@@ -2245,7 +4103,7 @@ fn action_layoutitem_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_ccomment_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ccomment_s221(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
         TK::OComment => Vec::from(&[Reduce(PK::CommentP1, 3usize)]),
@@ -2256,7 +4114,7 @@ fn action_ccomment_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
-fn action_cornc_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_cornc_s222(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::OComment => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
         TK::CComment => Vec::from(&[Reduce(PK::Cornc1P1, 2usize)]),
@@ -2266,14 +4124,23 @@ fn action_cornc_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
+#[allow(unreachable_patterns)]
 fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::File => State::FileS4,
-        NonTermKind::GrammarRule1 => State::GrammarRule1S5,
-        NonTermKind::ImportStm1 => State::ImportStm1S6,
-        NonTermKind::ImportStm => State::ImportStmS7,
-        NonTermKind::GrammarRule => State::GrammarRuleS8,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS9,
+        NonTermKind::File => State::FileS12,
+        NonTermKind::GrammarRule1 => State::GrammarRule1S13,
+        NonTermKind::FileHeader1 => State::FileHeader1S14,
+        NonTermKind::FileHeader => State::FileHeaderS15,
+        NonTermKind::ImportStm => State::ImportStmS16,
+        NonTermKind::CommentDirective => State::CommentDirectiveS17,
+        NonTermKind::KeywordsDirective => State::KeywordsDirectiveS18,
+        NonTermKind::StartDirective => State::StartDirectiveS19,
+        NonTermKind::IgnoreDirective => State::IgnoreDirectiveS20,
+        NonTermKind::TriviaDirective => State::TriviaDirectiveS21,
+        NonTermKind::TerminalsDirective => State::TerminalsDirectiveS22,
+        NonTermKind::GrammarRule => State::GrammarRuleS23,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S25,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
@@ -2282,11 +4149,13 @@ fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
         }
     }
 }
+#[allow(unreachable_patterns)]
 fn goto_terminals_s1(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TerminalRule1 => State::TerminalRule1S10,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS12,
+        NonTermKind::TerminalRule1 => State::TerminalRule1S26,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S27,
+        NonTermKind::TerminalRule => State::TerminalRuleS28,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
@@ -2295,435 +4164,623 @@ fn goto_terminals_s1(nonterm_kind: NonTermKind) -> State {
         }
     }
 }
-fn goto_grammarrule1_s5(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_ignore_s7(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Name1 => State::Name1S35,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IgnoreS7
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_trivia_s8(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Name1 => State::Name1S36,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TriviaS8
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_grammarrule1_s13(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::GrammarRule => State::GrammarRuleS39,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S25,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GrammarRule1S13
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_fileheader1_s14(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::GrammarRule1 => State::GrammarRule1S40,
+        NonTermKind::FileHeader => State::FileHeaderS41,
+        NonTermKind::ImportStm => State::ImportStmS16,
+        NonTermKind::CommentDirective => State::CommentDirectiveS17,
+        NonTermKind::KeywordsDirective => State::KeywordsDirectiveS18,
+        NonTermKind::StartDirective => State::StartDirectiveS19,
+        NonTermKind::IgnoreDirective => State::IgnoreDirectiveS20,
+        NonTermKind::TriviaDirective => State::TriviaDirectiveS21,
+        NonTermKind::TerminalsDirective => State::TerminalsDirectiveS22,
+        NonTermKind::GrammarRule => State::GrammarRuleS23,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S25,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::FileHeader1S14
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_doccomment0_s25(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnnotationOpt => State::AnnotationOptS44,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::DocComment0S25
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_terminalrule1_s26(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S27,
+        NonTermKind::TerminalRule => State::TerminalRuleS45,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TerminalRule1S26
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_doccomment0_s27(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnnotationOpt => State::AnnotationOptS46,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::DocComment0S27
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_terminals_s38(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TerminalRule1 => State::TerminalRule1S55,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S27,
+        NonTermKind::TerminalRule => State::TerminalRuleS28,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TerminalsS38
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_grammarrule1_s40(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::GrammarRule => State::GrammarRuleS39,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S25,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GrammarRule1S40
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_colon_s49(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Name1 => State::Name1S61,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColonS49
+            )
+        }
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_terminalrule1_s55(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRule => State::GrammarRuleS15,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS9,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S27,
+        NonTermKind::TerminalRule => State::TerminalRuleS45,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GrammarRule1S5
+                State::TerminalRule1S55
             )
         }
     }
 }
-fn goto_importstm1_s6(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_terminals_s56(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRule1 => State::GrammarRule1S16,
-        NonTermKind::ImportStm => State::ImportStmS17,
-        NonTermKind::GrammarRule => State::GrammarRuleS8,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS9,
+        NonTermKind::TerminalRule1 => State::TerminalRule1S63,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S27,
+        NonTermKind::TerminalRule => State::TerminalRuleS28,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ImportStm1S6
+                State::TerminalsS56
             )
         }
     }
 }
-fn goto_terminalrule1_s10(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_macroname_s58(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS19,
+        NonTermKind::Name1 => State::Name1S66,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalRule1S10
+                State::MacroNameS58
             )
         }
     }
 }
-fn goto_terminals_s14(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_terminalrule1_s63(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TerminalRule1 => State::TerminalRule1S22,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS12,
+        NonTermKind::DocComment1 => State::DocComment1S24,
+        NonTermKind::DocComment0 => State::DocComment0S27,
+        NonTermKind::TerminalRule => State::TerminalRuleS45,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalsS14
+                State::TerminalRule1S63
             )
         }
     }
 }
-fn goto_grammarrule1_s16(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_colon_s64(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRule => State::GrammarRuleS15,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS9,
+        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS73,
+        NonTermKind::Production => State::ProductionS74,
+        NonTermKind::Assignment1 => State::Assignment1S75,
+        NonTermKind::Assignment => State::AssignmentS76,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS77,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS78,
+        NonTermKind::ProductionGroup => State::ProductionGroupS79,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS80,
+        NonTermKind::MacroCall => State::MacroCallS81,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GrammarRule1S16
+                State::ColonS64
             )
         }
     }
 }
-fn goto_terminalrule1_s22(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_obrace_s65(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS19,
+        NonTermKind::ProdMetaData => State::ProdMetaDataS96,
+        NonTermKind::ProdMetaDatas => State::ProdMetaDatasS97,
+        NonTermKind::UserMetaData => State::UserMetaDataS98,
+        NonTermKind::ProdKind => State::ProdKindS99,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalRule1S22
+                State::OBraceS65
             )
         }
     }
 }
-fn goto_terminals_s23(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_colon_s67(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TerminalRule1 => State::TerminalRule1S28,
-        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS12,
+        NonTermKind::Recognizer => State::RecognizerS106,
+        NonTermKind::StrAlternatives => State::StrAlternativesS107,
+        NonTermKind::RawTerm => State::RawTermS108,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalsS23
+                State::ColonS67
             )
         }
     }
 }
-fn goto_colon_s24(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_obracket_s69(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS32,
-        NonTermKind::Production => State::ProductionS33,
-        NonTermKind::Assignment1 => State::Assignment1S34,
-        NonTermKind::Assignment => State::AssignmentS35,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS109,
+        NonTermKind::Production => State::ProductionS74,
+        NonTermKind::Assignment1 => State::Assignment1S75,
+        NonTermKind::Assignment => State::AssignmentS76,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS77,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS78,
+        NonTermKind::ProductionGroup => State::ProductionGroupS79,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS80,
+        NonTermKind::MacroCall => State::MacroCallS81,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS24
+                State::OBracketS69
             )
         }
     }
 }
-fn goto_obrace_s25(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_macroname_s71(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProdMetaData => State::ProdMetaDataS50,
-        NonTermKind::ProdMetaDatas => State::ProdMetaDatasS51,
-        NonTermKind::UserMetaData => State::UserMetaDataS52,
-        NonTermKind::ProdKind => State::ProdKindS53,
+        NonTermKind::Name1 => State::Name1S112,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBraceS25
+                State::MacroNameS71
             )
         }
     }
 }
-fn goto_colon_s26(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_assignment1_s75(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Recognizer => State::RecognizerS58,
+        NonTermKind::Assignment => State::AssignmentS116,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS77,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS78,
+        NonTermKind::ProductionGroup => State::ProductionGroupS79,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS80,
+        NonTermKind::MacroCall => State::MacroCallS81,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS26
+                State::Assignment1S75
             )
         }
     }
 }
-fn goto_terminalrule1_s28(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_productiongroup_s79(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::AnnotationOpt => State::AnnotationOptS11,
-        NonTermKind::TerminalRule => State::TerminalRuleS19,
+        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS123,
+        NonTermKind::RepetitionOperator => State::RepetitionOperatorS124,
+        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS125,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TerminalRule1S28
+                State::ProductionGroupS79
             )
         }
     }
 }
-fn goto_obracket_s29(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_macrocall_s81(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS59,
-        NonTermKind::Production => State::ProductionS33,
-        NonTermKind::Assignment1 => State::Assignment1S34,
-        NonTermKind::Assignment => State::AssignmentS35,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS126,
+        NonTermKind::RepetitionOperator => State::RepetitionOperatorS124,
+        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS125,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBracketS29
+                State::MacroCallS81
             )
         }
     }
 }
-fn goto_assignment1_s34(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_grammarsymbol_s82(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Assignment => State::AssignmentS65,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS127,
+        NonTermKind::RepetitionOperator => State::RepetitionOperatorS124,
+        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS125,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::Assignment1S34
+                State::GrammarSymbolS82
             )
         }
     }
 }
-fn goto_productiongroup_s38(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_obrace_s102(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS72,
-        NonTermKind::RepetitionOperator => State::RepetitionOperatorS73,
-        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS74,
+        NonTermKind::TermMetaData => State::TermMetaDataS145,
+        NonTermKind::TermMetaDatas => State::TermMetaDatasS146,
+        NonTermKind::UserMetaData => State::UserMetaDataS147,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ProductionGroupS38
+                State::OBraceS102
             )
         }
     }
 }
-fn goto_grammarsymbol_s40(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_recognizer_s106(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionOperatorOpt => State::RepetitionOperatorOptS75,
-        NonTermKind::RepetitionOperator => State::RepetitionOperatorS73,
-        NonTermKind::RepetitionOperatorOp => State::RepetitionOperatorOpS74,
+        NonTermKind::TerminalTypeOpt => State::TerminalTypeOptS151,
+        NonTermKind::TerminalType => State::TerminalTypeS152,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GrammarSymbolS40
+                State::RecognizerS106
             )
         }
     }
 }
-fn goto_obrace_s55(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_equals_s110(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TermMetaData => State::TermMetaDataS89,
-        NonTermKind::TermMetaDatas => State::TermMetaDatasS90,
-        NonTermKind::UserMetaData => State::UserMetaDataS91,
+        NonTermKind::ProductionGroup => State::ProductionGroupS79,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS156,
+        NonTermKind::MacroCall => State::MacroCallS81,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBraceS55
+                State::EqualsS110
             )
         }
     }
 }
-fn goto_equals_s60(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_qequals_s111(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS96,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::ProductionGroup => State::ProductionGroupS79,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS157,
+        NonTermKind::MacroCall => State::MacroCallS81,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::EqualsS60
+                State::QEqualsS111
             )
         }
     }
 }
-fn goto_qequals_s61(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_choice_s114(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS97,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::Production => State::ProductionS159,
+        NonTermKind::Assignment1 => State::Assignment1S75,
+        NonTermKind::Assignment => State::AssignmentS76,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS77,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS78,
+        NonTermKind::ProductionGroup => State::ProductionGroupS79,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS80,
+        NonTermKind::MacroCall => State::MacroCallS81,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::QEqualsS61
+                State::ChoiceS114
             )
         }
     }
 }
-fn goto_choice_s63(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_obrace_s115(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Production => State::ProductionS98,
-        NonTermKind::Assignment1 => State::Assignment1S34,
-        NonTermKind::Assignment => State::AssignmentS35,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::ProdMetaData => State::ProdMetaDataS96,
+        NonTermKind::ProdMetaDatas => State::ProdMetaDatasS160,
+        NonTermKind::UserMetaData => State::UserMetaDataS98,
+        NonTermKind::ProdKind => State::ProdKindS99,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ChoiceS63
+                State::OBraceS115
             )
         }
     }
 }
-fn goto_obrace_s64(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_repetitionoperatorop_s125(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProdMetaData => State::ProdMetaDataS50,
-        NonTermKind::ProdMetaDatas => State::ProdMetaDatasS99,
-        NonTermKind::UserMetaData => State::UserMetaDataS52,
-        NonTermKind::ProdKind => State::ProdKindS53,
+        NonTermKind::RepetitionModifiersOpt => State::RepetitionModifiersOptS162,
+        NonTermKind::RepetitionModifiers => State::RepetitionModifiersS163,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBraceS64
+                State::RepetitionOperatorOpS125
             )
         }
     }
 }
-fn goto_repetitionoperatorop_s74(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_colon_s131(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionModifiersOpt => State::RepetitionModifiersOptS101,
-        NonTermKind::RepetitionModifiers => State::RepetitionModifiersS102,
+        NonTermKind::ConstVal => State::ConstValS171,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::RepetitionOperatorOpS74
+                State::ColonS131
             )
         }
     }
 }
-fn goto_colon_s76(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_comma_s132(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ConstVal => State::ConstValS107,
+        NonTermKind::ProdMetaData => State::ProdMetaDataS172,
+        NonTermKind::UserMetaData => State::UserMetaDataS98,
+        NonTermKind::ProdKind => State::ProdKindS99,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS76
+                State::CommaS132
             )
         }
     }
 }
-fn goto_comma_s77(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_colon_s134(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::ProdMetaData => State::ProdMetaDataS108,
-        NonTermKind::UserMetaData => State::UserMetaDataS52,
-        NonTermKind::ProdKind => State::ProdKindS53,
+        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS174,
+        NonTermKind::Production => State::ProductionS74,
+        NonTermKind::Assignment1 => State::Assignment1S75,
+        NonTermKind::Assignment => State::AssignmentS76,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS77,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS78,
+        NonTermKind::ProductionGroup => State::ProductionGroupS79,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS80,
+        NonTermKind::MacroCall => State::MacroCallS81,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CommaS77
+                State::ColonS134
             )
         }
     }
 }
-fn goto_obrace_s93(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_osbracket_s161(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TermMetaData => State::TermMetaDataS89,
-        NonTermKind::TermMetaDatas => State::TermMetaDatasS112,
-        NonTermKind::UserMetaData => State::UserMetaDataS91,
+        NonTermKind::RepetitionModifier1 => State::RepetitionModifier1S185,
+        NonTermKind::RepetitionModifier => State::RepetitionModifierS186,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OBraceS93
+                State::OSBracketS161
             )
         }
     }
 }
-fn goto_osbracket_s100(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_colon_s173(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionModifier1 => State::RepetitionModifier1S115,
-        NonTermKind::RepetitionModifier => State::RepetitionModifierS116,
+        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS187,
+        NonTermKind::Production => State::ProductionS74,
+        NonTermKind::Assignment1 => State::Assignment1S75,
+        NonTermKind::Assignment => State::AssignmentS76,
+        NonTermKind::PlainAssignment => State::PlainAssignmentS77,
+        NonTermKind::BoolAssignment => State::BoolAssignmentS78,
+        NonTermKind::ProductionGroup => State::ProductionGroupS79,
+        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS80,
+        NonTermKind::MacroCall => State::MacroCallS81,
+        NonTermKind::GrammarSymbol => State::GrammarSymbolS82,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OSBracketS100
+                State::ColonS173
             )
         }
     }
 }
-fn goto_colon_s109(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_comma_s175(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::GrammarRuleRHS => State::GrammarRuleRHSS117,
-        NonTermKind::Production => State::ProductionS33,
-        NonTermKind::Assignment1 => State::Assignment1S34,
-        NonTermKind::Assignment => State::AssignmentS35,
-        NonTermKind::PlainAssignment => State::PlainAssignmentS36,
-        NonTermKind::BoolAssignment => State::BoolAssignmentS37,
-        NonTermKind::ProductionGroup => State::ProductionGroupS38,
-        NonTermKind::GrammarSymbolRef => State::GrammarSymbolRefS39,
-        NonTermKind::GrammarSymbol => State::GrammarSymbolS40,
+        NonTermKind::TermMetaData => State::TermMetaDataS189,
+        NonTermKind::UserMetaData => State::UserMetaDataS147,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS109
+                State::CommaS175
             )
         }
     }
 }
-fn goto_comma_s110(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_obrace_s181(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::TermMetaData => State::TermMetaDataS118,
-        NonTermKind::UserMetaData => State::UserMetaDataS91,
+        NonTermKind::TermMetaData => State::TermMetaDataS145,
+        NonTermKind::TermMetaDatas => State::TermMetaDatasS192,
+        NonTermKind::UserMetaData => State::UserMetaDataS147,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CommaS110
+                State::OBraceS181
             )
         }
     }
 }
-fn goto_comma_s121(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_comma_s193(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::RepetitionModifier => State::RepetitionModifierS125,
+        NonTermKind::RepetitionModifier => State::RepetitionModifierS198,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CommaS121
+                State::CommaS193
             )
         }
     }
 }
-fn goto_augl_s126(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_augl_s204(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Layout => State::LayoutS130,
-        NonTermKind::LayoutItem1 => State::LayoutItem1S131,
-        NonTermKind::LayoutItem0 => State::LayoutItem0S132,
-        NonTermKind::LayoutItem => State::LayoutItemS133,
-        NonTermKind::Comment => State::CommentS134,
+        NonTermKind::Layout => State::LayoutS208,
+        NonTermKind::LayoutItem1 => State::LayoutItem1S209,
+        NonTermKind::LayoutItem0 => State::LayoutItem0S210,
+        NonTermKind::LayoutItem => State::LayoutItemS211,
+        NonTermKind::Comment => State::CommentS212,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGLS126
+                State::AUGLS204
             )
         }
     }
 }
-fn goto_ocomment_s127(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_ocomment_s205(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Comment => State::CommentS137,
-        NonTermKind::Corncs => State::CorncsS138,
-        NonTermKind::Cornc1 => State::Cornc1S139,
-        NonTermKind::Cornc0 => State::Cornc0S140,
-        NonTermKind::Cornc => State::CorncS141,
+        NonTermKind::Comment => State::CommentS215,
+        NonTermKind::Corncs => State::CorncsS216,
+        NonTermKind::Cornc1 => State::Cornc1S217,
+        NonTermKind::Cornc0 => State::Cornc0S218,
+        NonTermKind::Cornc => State::CorncS219,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OCommentS127
+                State::OCommentS205
             )
         }
     }
 }
-fn goto_layoutitem1_s131(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_layoutitem1_s209(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::LayoutItem => State::LayoutItemS142,
-        NonTermKind::Comment => State::CommentS134,
+        NonTermKind::LayoutItem => State::LayoutItemS220,
+        NonTermKind::Comment => State::CommentS212,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LayoutItem1S131
+                State::LayoutItem1S209
             )
         }
     }
 }
-fn goto_cornc1_s139(nonterm_kind: NonTermKind) -> State {
+#[allow(unreachable_patterns)]
+fn goto_cornc1_s217(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Comment => State::CommentS137,
-        NonTermKind::Cornc => State::CorncS144,
+        NonTermKind::Comment => State::CommentS215,
+        NonTermKind::Cornc => State::CorncS222,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::Cornc1S139
+                State::Cornc1S217
             )
         }
     }
@@ -2736,148 +4793,226 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         action_aug_s0,
         action_terminals_s1,
         action_import_s2,
-        action_annotation_s3,
-        action_file_s4,
-        action_grammarrule1_s5,
-        action_importstm1_s6,
-        action_importstm_s7,
-        action_grammarrule_s8,
-        action_annotationopt_s9,
-        action_terminalrule1_s10,
-        action_annotationopt_s11,
-        action_terminalrule_s12,
-        action_strconst_s13,
-        action_terminals_s14,
-        action_grammarrule_s15,
-        action_grammarrule1_s16,
-        action_importstm_s17,
-        action_name_s18,
-        action_terminalrule_s19,
-        action_name_s20,
-        action_as_s21,
-        action_terminalrule1_s22,
-        action_terminals_s23,
-        action_colon_s24,
-        action_obrace_s25,
-        action_colon_s26,
-        action_name_s27,
-        action_terminalrule1_s28,
-        action_obracket_s29,
-        action_name_s30,
+        action_linecommentdirective_s3,
+        action_blockcommentdirective_s4,
+        action_keywords_s5,
+        action_start_s6,
+        action_ignore_s7,
+        action_trivia_s8,
+        action_terminalsinclude_s9,
+        action_doccomment_s10,
+        action_actionscode_s11,
+        action_file_s12,
+        action_grammarrule1_s13,
+        action_fileheader1_s14,
+        action_fileheader_s15,
+        action_importstm_s16,
+        action_commentdirective_s17,
+        action_keywordsdirective_s18,
+        action_startdirective_s19,
+        action_ignoredirective_s20,
+        action_triviadirective_s21,
+        action_terminalsdirective_s22,
+        action_grammarrule_s23,
+        action_doccomment1_s24,
+        action_doccomment0_s25,
+        action_terminalrule1_s26,
+        action_doccomment0_s27,
+        action_terminalrule_s28,
+        action_strconst_s29,
+        action_strconst_s30,
         action_strconst_s31,
-        action_grammarrulerhs_s32,
-        action_production_s33,
-        action_assignment1_s34,
-        action_assignment_s35,
-        action_plainassignment_s36,
-        action_boolassignment_s37,
-        action_productiongroup_s38,
-        action_grammarsymbolref_s39,
-        action_grammarsymbol_s40,
-        action_left_s41,
-        action_right_s42,
-        action_reduce_s43,
-        action_shift_s44,
-        action_dynamic_s45,
-        action_nops_s46,
-        action_nopse_s47,
-        action_name_s48,
-        action_intconst_s49,
-        action_prodmetadata_s50,
-        action_prodmetadatas_s51,
-        action_usermetadata_s52,
-        action_prodkind_s53,
+        action_name_s32,
+        action_name_s33,
+        action_name_s34,
+        action_name1_s35,
+        action_name1_s36,
+        action_strconst_s37,
+        action_terminals_s38,
+        action_grammarrule_s39,
+        action_grammarrule1_s40,
+        action_fileheader_s41,
+        action_doccomment_s42,
+        action_annotation_s43,
+        action_annotationopt_s44,
+        action_terminalrule_s45,
+        action_annotationopt_s46,
+        action_as_s47,
+        action_strconst_s48,
+        action_colon_s49,
+        action_semicolon_s50,
+        action_semicolon_s51,
+        action_comma_s52,
+        action_semicolon_s53,
         action_semicolon_s54,
-        action_obrace_s55,
-        action_regexterm_s56,
-        action_strconst_s57,
-        action_recognizer_s58,
-        action_grammarrulerhs_s59,
-        action_equals_s60,
-        action_qequals_s61,
-        action_semicolon_s62,
-        action_choice_s63,
-        action_obrace_s64,
-        action_assignment_s65,
-        action_zeroormore_s66,
-        action_zeroormoregreedy_s67,
-        action_oneormore_s68,
-        action_oneormoregreedy_s69,
-        action_optional_s70,
-        action_optionalgreedy_s71,
-        action_repetitionoperatoropt_s72,
-        action_repetitionoperator_s73,
-        action_repetitionoperatorop_s74,
-        action_repetitionoperatoropt_s75,
-        action_colon_s76,
-        action_comma_s77,
-        action_cbrace_s78,
-        action_left_s79,
-        action_right_s80,
-        action_reduce_s81,
-        action_shift_s82,
-        action_dynamic_s83,
-        action_prefer_s84,
-        action_finish_s85,
-        action_nofinish_s86,
-        action_name_s87,
-        action_intconst_s88,
-        action_termmetadata_s89,
-        action_termmetadatas_s90,
-        action_usermetadata_s91,
-        action_semicolon_s92,
-        action_obrace_s93,
-        action_cbracket_s94,
-        action_name_s95,
-        action_grammarsymbolref_s96,
-        action_grammarsymbolref_s97,
-        action_production_s98,
-        action_prodmetadatas_s99,
-        action_osbracket_s100,
-        action_repetitionmodifiersopt_s101,
-        action_repetitionmodifiers_s102,
-        action_intconst_s103,
-        action_floatconst_s104,
-        action_boolconst_s105,
-        action_strconst_s106,
-        action_constval_s107,
-        action_prodmetadata_s108,
-        action_colon_s109,
-        action_comma_s110,
-        action_cbrace_s111,
-        action_termmetadatas_s112,
-        action_cbrace_s113,
-        action_name_s114,
-        action_repetitionmodifier1_s115,
-        action_repetitionmodifier_s116,
-        action_grammarrulerhs_s117,
-        action_termmetadata_s118,
-        action_semicolon_s119,
-        action_cbrace_s120,
-        action_comma_s121,
-        action_csbracket_s122,
-        action_semicolon_s123,
-        action_semicolon_s124,
-        action_repetitionmodifier_s125,
-        action_augl_s126,
-        action_ocomment_s127,
-        action_ws_s128,
-        action_commentline_s129,
-        action_layout_s130,
-        action_layoutitem1_s131,
-        action_layoutitem0_s132,
-        action_layoutitem_s133,
-        action_comment_s134,
-        action_ws_s135,
-        action_notcomment_s136,
-        action_comment_s137,
-        action_corncs_s138,
-        action_cornc1_s139,
-        action_cornc0_s140,
-        action_cornc_s141,
-        action_layoutitem_s142,
-        action_ccomment_s143,
-        action_cornc_s144,
+        action_terminalrule1_s55,
+        action_terminals_s56,
+        action_name_s57,
+        action_macroname_s58,
+        action_name_s59,
+        action_name_s60,
+        action_name1_s61,
+        action_name_s62,
+        action_terminalrule1_s63,
+        action_colon_s64,
+        action_obrace_s65,
+        action_name1_s66,
+        action_colon_s67,
+        action_semicolon_s68,
+        action_obracket_s69,
+        action_name_s70,
+        action_macroname_s71,
+        action_strconst_s72,
+        action_grammarrulerhs_s73,
+        action_production_s74,
+        action_assignment1_s75,
+        action_assignment_s76,
+        action_plainassignment_s77,
+        action_boolassignment_s78,
+        action_productiongroup_s79,
+        action_grammarsymbolref_s80,
+        action_macrocall_s81,
+        action_grammarsymbol_s82,
+        action_optional_s83,
+        action_left_s84,
+        action_right_s85,
+        action_reduce_s86,
+        action_shift_s87,
+        action_dynamic_s88,
+        action_nops_s89,
+        action_nopse_s90,
+        action_nolayout_s91,
+        action_prec_s92,
+        action_assoc_s93,
+        action_name_s94,
+        action_intconst_s95,
+        action_prodmetadata_s96,
+        action_prodmetadatas_s97,
+        action_usermetadata_s98,
+        action_prodkind_s99,
+        action_cbracket_s100,
+        action_semicolon_s101,
+        action_obrace_s102,
+        action_raw_s103,
+        action_regexterm_s104,
+        action_strconst_s105,
+        action_recognizer_s106,
+        action_stralternatives_s107,
+        action_rawterm_s108,
+        action_grammarrulerhs_s109,
+        action_equals_s110,
+        action_qequals_s111,
+        action_name1_s112,
+        action_semicolon_s113,
+        action_choice_s114,
+        action_obrace_s115,
+        action_assignment_s116,
+        action_zeroormore_s117,
+        action_zeroormoregreedy_s118,
+        action_oneormore_s119,
+        action_oneormoregreedy_s120,
+        action_optional_s121,
+        action_optionalgreedy_s122,
+        action_repetitionoperatoropt_s123,
+        action_repetitionoperator_s124,
+        action_repetitionoperatorop_s125,
+        action_repetitionoperatoropt_s126,
+        action_repetitionoperatoropt_s127,
+        action_name_s128,
+        action_colon_s129,
+        action_colon_s130,
+        action_colon_s131,
+        action_comma_s132,
+        action_cbrace_s133,
+        action_colon_s134,
+        action_left_s135,
+        action_right_s136,
+        action_reduce_s137,
+        action_shift_s138,
+        action_dynamic_s139,
+        action_prefer_s140,
+        action_finish_s141,
+        action_nofinish_s142,
+        action_name_s143,
+        action_intconst_s144,
+        action_termmetadata_s145,
+        action_termmetadatas_s146,
+        action_usermetadata_s147,
+        action_obracket_s148,
+        action_choice_s149,
+        action_colon_s150,
+        action_terminaltypeopt_s151,
+        action_terminaltype_s152,
+        action_choice_s153,
+        action_cbracket_s154,
+        action_name_s155,
+        action_grammarsymbolref_s156,
+        action_grammarsymbolref_s157,
+        action_cbracket_s158,
+        action_production_s159,
+        action_prodmetadatas_s160,
+        action_osbracket_s161,
+        action_repetitionmodifiersopt_s162,
+        action_repetitionmodifiers_s163,
+        action_intconst_s164,
+        action_left_s165,
+        action_right_s166,
+        action_intconst_s167,
+        action_floatconst_s168,
+        action_boolconst_s169,
+        action_strconst_s170,
+        action_constval_s171,
+        action_prodmetadata_s172,
+        action_colon_s173,
+        action_grammarrulerhs_s174,
+        action_comma_s175,
+        action_cbrace_s176,
+        action_strconst_s177,
+        action_strconst_s178,
+        action_rusttype_s179,
+        action_semicolon_s180,
+        action_obrace_s181,
+        action_strconst_s182,
+        action_cbrace_s183,
+        action_name_s184,
+        action_repetitionmodifier1_s185,
+        action_repetitionmodifier_s186,
+        action_grammarrulerhs_s187,
+        action_semicolon_s188,
+        action_termmetadata_s189,
+        action_semicolon_s190,
+        action_comma_s191,
+        action_termmetadatas_s192,
+        action_comma_s193,
+        action_csbracket_s194,
+        action_semicolon_s195,
+        action_strconst_s196,
+        action_cbrace_s197,
+        action_repetitionmodifier_s198,
+        action_comma_s199,
+        action_cbracket_s200,
+        action_semicolon_s201,
+        action_nested_s202,
+        action_cbracket_s203,
+        action_augl_s204,
+        action_ocomment_s205,
+        action_ws_s206,
+        action_commentline_s207,
+        action_layout_s208,
+        action_layoutitem1_s209,
+        action_layoutitem0_s210,
+        action_layoutitem_s211,
+        action_comment_s212,
+        action_ws_s213,
+        action_notcomment_s214,
+        action_comment_s215,
+        action_corncs_s216,
+        action_cornc1_s217,
+        action_cornc0_s218,
+        action_cornc_s219,
+        action_layoutitem_s220,
+        action_ccomment_s221,
+        action_cornc_s222,
     ],
     gotos: [
         goto_aug_s0,
@@ -2885,48 +5020,42 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_grammarrule1_s5,
-        goto_importstm1_s6,
         goto_invalid,
         goto_invalid,
+        goto_ignore_s7,
+        goto_trivia_s8,
         goto_invalid,
-        goto_terminalrule1_s10,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_terminals_s14,
+        goto_grammarrule1_s13,
+        goto_fileheader1_s14,
         goto_invalid,
-        goto_grammarrule1_s16,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_terminalrule1_s22,
-        goto_terminals_s23,
-        goto_colon_s24,
-        goto_obrace_s25,
-        goto_colon_s26,
         goto_invalid,
-        goto_terminalrule1_s28,
-        goto_obracket_s29,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_doccomment0_s25,
+        goto_terminalrule1_s26,
+        goto_doccomment0_s27,
         goto_invalid,
-        goto_assignment1_s34,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_productiongroup_s38,
         goto_invalid,
-        goto_grammarsymbol_s40,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_terminals_s38,
         goto_invalid,
+        goto_grammarrule1_s40,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -2935,34 +5064,40 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_obrace_s55,
+        goto_colon_s49,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_equals_s60,
-        goto_qequals_s61,
         goto_invalid,
-        goto_choice_s63,
-        goto_obrace_s64,
+        goto_terminalrule1_s55,
+        goto_terminals_s56,
         goto_invalid,
+        goto_macroname_s58,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_terminalrule1_s63,
+        goto_colon_s64,
+        goto_obrace_s65,
         goto_invalid,
+        goto_colon_s67,
         goto_invalid,
+        goto_obracket_s69,
         goto_invalid,
+        goto_macroname_s71,
         goto_invalid,
-        goto_repetitionoperatorop_s74,
         goto_invalid,
-        goto_colon_s76,
-        goto_comma_s77,
         goto_invalid,
+        goto_assignment1_s75,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_productiongroup_s79,
         goto_invalid,
+        goto_macrocall_s81,
+        goto_grammarsymbol_s82,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -2973,27 +5108,29 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_obrace_s93,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_osbracket_s100,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_obrace_s102,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_recognizer_s106,
         goto_invalid,
         goto_invalid,
-        goto_colon_s109,
-        goto_comma_s110,
         goto_invalid,
+        goto_equals_s110,
+        goto_qequals_s111,
         goto_invalid,
         goto_invalid,
+        goto_choice_s114,
+        goto_obrace_s115,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -3001,52 +5138,130 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_comma_s121,
         goto_invalid,
         goto_invalid,
+        goto_repetitionoperatorop_s125,
         goto_invalid,
         goto_invalid,
-        goto_augl_s126,
-        goto_ocomment_s127,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_layoutitem1_s131,
+        goto_colon_s131,
+        goto_comma_s132,
         goto_invalid,
+        goto_colon_s134,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_cornc1_s139,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-    ],
-    token_kinds: [
-        [
-            Some((TK::Terminals, true)),
-            Some((TK::Import, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_osbracket_s161,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_colon_s173,
+        goto_invalid,
+        goto_comma_s175,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_obrace_s181,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_comma_s193,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_augl_s204,
+        goto_ocomment_s205,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_layoutitem1_s209,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_cornc1_s217,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+    ],
+    token_kinds: [
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Terminals, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+        ],
         [
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
             None,
             None,
             None,
@@ -3077,9 +5292,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
@@ -3096,7 +5313,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, false)),
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
@@ -3113,10 +5331,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Terminals, true)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3130,9 +5349,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Import, true)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3147,9 +5367,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Import, true)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3164,10 +5385,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Terminals, true)),
             Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3181,7 +5403,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Name, false)),
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
@@ -3198,9 +5421,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
             None,
             None,
             None,
@@ -3215,7 +5439,26 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
             None,
             None,
             None,
@@ -3233,8 +5476,12 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::STOP, true)),
+            Some((TK::Terminals, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
             None,
             None,
             None,
@@ -3244,40 +5491,165 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
             None,
         ],
         [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
             Some((TK::Import, true)),
-            Some((TK::As, true)),
+            Some((TK::Start, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
             None,
             None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
             None,
         ],
         [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
             None,
-            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
             None,
@@ -3286,8 +5658,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             Some((TK::STOP, true)),
             Some((TK::Terminals, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::Annotation, false)),
-            None,
+            Some((TK::DocComment, false)),
             None,
             None,
             None,
@@ -3300,10 +5673,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Terminals, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
             None,
             None,
             None,
@@ -3317,8 +5691,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Import, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::Annotation, false)),
             None,
             None,
@@ -3332,11 +5706,13 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::Colon, true)),
-            Some((TK::OBrace, true)),
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
             None,
             None,
             None,
@@ -3351,7 +5727,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
             None,
@@ -3366,9 +5741,14 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
+            None,
         ],
         [
-            Some((TK::Colon, true)),
+            Some((TK::STOP, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
             None,
             None,
             None,
@@ -3381,11 +5761,46 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::As, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
             None,
             None,
         ],
         [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
@@ -3402,9 +5817,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3419,8 +5835,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::SemiColon, true)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3436,9 +5853,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::OBracket, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
+            Some((TK::SemiColon, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBracket, true)),
+            None,
             None,
             None,
             None,
@@ -3453,15 +5871,16 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::NOPSE, true)),
-            Some((TK::Left, true)),
-            Some((TK::NOPS, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
+            Some((TK::SemiColon, true)),
+            Some((TK::Comma, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3471,9 +5890,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::RegexTerm, false)),
-            Some((TK::StrConst, false)),
+            Some((TK::Comma, true)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3487,9 +5907,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Import, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::SemiColon, true)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3504,9 +5925,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
             None,
             None,
             None,
@@ -3521,9 +5943,1252 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::OBracket, true)),
+            Some((TK::STOP, true)),
+            Some((TK::Terminals, true)),
             Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Terminals, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            Some((TK::OBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::Comma, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBracket, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NoLayout, true)),
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::NOPSE, true)),
+            Some((TK::Assoc, true)),
+            Some((TK::Left, true)),
+            Some((TK::NOPS, true)),
+            Some((TK::Prec, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Raw, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::RegexTerm, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BlockCommentDirective, true)),
+            Some((TK::LineCommentDirective, true)),
+            Some((TK::TerminalsInclude, true)),
+            Some((TK::Keywords, true)),
+            Some((TK::Ignore, true)),
+            Some((TK::Trivia, true)),
+            Some((TK::Import, true)),
+            Some((TK::Start, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            Some((TK::ActionsCode, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBracket, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::QEquals, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Equals, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+        ],
+        [
+            Some((TK::Name, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::Choice, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Name, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NoFinish, true)),
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Prefer, true)),
+            Some((TK::Finish, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::Choice, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::Choice, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            None,
             None,
             None,
             None,
@@ -3538,48 +7203,177 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
-            Some((TK::QEquals, true)),
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
-            Some((TK::Equals, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBracket, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBracket, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Comma, true)),
+            Some((TK::CBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Terminals, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OBracket, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::NoLayout, true)),
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::NOPSE, true)),
+            Some((TK::Assoc, true)),
+            Some((TK::Left, true)),
+            Some((TK::NOPS, true)),
+            Some((TK::Prec, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
+            None,
+            None,
+            None,
         ],
         [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
             Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::OSBracket, true)),
             Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::OSBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -3590,14 +7384,33 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
+            Some((TK::OSBracket, true)),
             Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::OSBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
@@ -3610,8 +7423,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
+            Some((TK::OSBracket, true)),
             Some((TK::Choice, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -3620,15 +7435,16 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
+            Some((TK::OSBracket, true)),
             Some((TK::Choice, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -3637,7 +7453,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-            None,
         ],
         [
             Some((TK::SemiColon, true)),
@@ -3646,6 +7461,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -3663,6 +7479,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -3674,21 +7491,22 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
+            Some((TK::OSBracket, true)),
             Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
             Some((TK::SemiColon, true)),
@@ -3697,6 +7515,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -3708,21 +7527,22 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -3740,10 +7560,12 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::IntConst, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3759,8 +7581,31 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::Right, true)),
+            Some((TK::Left, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IntConst, false)),
+            Some((TK::FloatConst, false)),
+            Some((TK::BoolConst, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3770,14 +7615,29 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::NoLayout, true)),
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::NOPSE, true)),
+            Some((TK::Assoc, true)),
+            Some((TK::Left, true)),
+            Some((TK::NOPS, true)),
+            Some((TK::Prec, true)),
+            Some((TK::Optional, true)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
             None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -3793,9 +7653,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
+            Some((TK::OBracket, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -3825,6 +7686,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -3842,9 +7704,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::Colon, true)),
             Some((TK::Comma, true)),
             Some((TK::CBrace, true)),
             None,
@@ -3859,6 +7721,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -3876,6 +7740,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -3893,6 +7758,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -3910,6 +7776,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -3927,6 +7794,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -3944,13 +7812,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
-            None,
-            None,
+            Some((TK::Colon, true)),
             None,
             None,
             None,
@@ -3961,18 +7826,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::NoFinish, true)),
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Prefer, true)),
-            Some((TK::Finish, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::Left, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
             None,
             None,
             None,
@@ -3980,8 +7833,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
             None,
             None,
             None,
@@ -3997,8 +7851,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
             None,
             None,
             None,
@@ -4014,8 +7869,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
             None,
             None,
             None,
@@ -4031,8 +7887,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
+            None,
             None,
             None,
             None,
@@ -4048,8 +7905,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::OBracket, true)),
-            Some((TK::Name, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -4063,29 +7918,16 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::OBracket, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
-            None,
-            None,
-            None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::Terminals, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
             None,
             None,
             None,
@@ -4099,11 +7941,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::OBracket, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
+            Some((TK::RustType, false)),
             None,
             None,
             None,
@@ -4114,18 +7952,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::NOPSE, true)),
-            Some((TK::Left, true)),
-            Some((TK::NOPS, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
-            None,
             None,
             None,
             None,
@@ -4135,12 +7961,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         [
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
             None,
             None,
             None,
@@ -4148,16 +7968,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::OSBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4169,12 +7979,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         [
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::OSBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4182,16 +7986,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::OSBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4201,13 +7995,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::OSBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -4216,16 +8003,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::OSBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
+            None,
             None,
             None,
             None,
@@ -4235,38 +8013,40 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
-            Some((TK::OSBracket, true)),
             Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
-            None,
-            None,
-            None,
-            None,
-            None,
         ],
         [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
         ],
         [
             Some((TK::SemiColon, true)),
@@ -4275,6 +8055,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -4290,9 +8071,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
-            Some((TK::OSBracket, true)),
             Some((TK::Choice, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
@@ -4301,33 +8082,30 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
+            Some((TK::ZeroOrMoreGreedy, true)),
+            Some((TK::OneOrMoreGreedy, true)),
+            Some((TK::OptionalGreedy, true)),
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
             Some((TK::OBracket, true)),
             Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
+            Some((TK::ZeroOrMore, true)),
+            Some((TK::OneOrMore, true)),
+            Some((TK::Optional, true)),
             Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
             Some((TK::StrConst, false)),
             None,
             None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
         ],
         [
-            Some((TK::IntConst, false)),
-            Some((TK::FloatConst, false)),
-            Some((TK::BoolConst, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::SemiColon, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
             None,
             None,
             None,
@@ -4335,17 +8113,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::NOPSE, true)),
-            Some((TK::Left, true)),
-            Some((TK::NOPS, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
             None,
             None,
             None,
@@ -4354,7 +8121,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Colon, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -4371,11 +8139,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
+            Some((TK::Name, false)),
             None,
             None,
             None,
@@ -4386,15 +8150,21 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4405,13 +8175,14 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4437,6 +8208,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -4454,6 +8226,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -4471,6 +8244,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -4488,6 +8262,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -4505,9 +8280,11 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::Colon, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CBrace, true)),
             None,
             None,
             None,
@@ -4539,6 +8316,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -4556,6 +8334,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::Comma, true)),
@@ -4573,11 +8352,13 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
+            Some((TK::OBracket, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4592,9 +8373,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::SemiColon, true)),
+            Some((TK::Choice, true)),
+            None,
+            None,
             None,
             None,
             None,
@@ -4624,49 +8406,12 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
             None,
         ],
         [
-            Some((TK::ZeroOrMoreGreedy, true)),
-            Some((TK::OneOrMoreGreedy, true)),
-            Some((TK::OptionalGreedy, true)),
             Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::ZeroOrMore, true)),
-            Some((TK::OneOrMore, true)),
-            Some((TK::Optional, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4675,28 +8420,17 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::Comma, true)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
             None,
             None,
             None,
@@ -4711,9 +8445,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
+            Some((TK::Colon, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::Choice, true)),
             None,
             None,
             None,
@@ -4728,7 +8463,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Name, false)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
             None,
             None,
             None,
@@ -4745,30 +8481,14 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
+            Some((TK::STOP, true)),
             Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::SemiColon, true)),
-            Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
             None,
             None,
             None,
@@ -4779,15 +8499,16 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
+            Some((TK::NoFinish, true)),
+            Some((TK::Dynamic, true)),
+            Some((TK::Reduce, true)),
+            Some((TK::Prefer, true)),
+            Some((TK::Finish, true)),
+            Some((TK::Right, true)),
+            Some((TK::Shift, true)),
+            Some((TK::Left, true)),
+            Some((TK::Name, false)),
+            Some((TK::IntConst, false)),
             None,
             None,
             None,
@@ -4796,9 +8517,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
-            None,
+            Some((TK::Colon, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::Choice, true)),
             None,
             None,
             None,
@@ -4813,8 +8535,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::CBracket, true)),
+            Some((TK::Choice, true)),
             None,
             None,
             None,
@@ -4831,7 +8554,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::CSBracket, true)),
+            None,
             None,
             None,
             None,
@@ -4848,7 +8572,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::CSBracket, true)),
+            None,
             None,
             None,
             None,
@@ -4865,7 +8590,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::CSBracket, true)),
+            None,
             None,
             None,
             None,
@@ -4881,12 +8607,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::OBracket, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
-            None,
-            None,
-            None,
+            Some((TK::SemiColon, true)),
+            Some((TK::Choice, true)),
             None,
             None,
             None,
@@ -4896,18 +8618,6 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::NoFinish, true)),
-            Some((TK::Dynamic, true)),
-            Some((TK::Reduce, true)),
-            Some((TK::Prefer, true)),
-            Some((TK::Finish, true)),
-            Some((TK::Right, true)),
-            Some((TK::Shift, true)),
-            Some((TK::Left, true)),
-            Some((TK::Name, false)),
-            Some((TK::IntConst, false)),
             None,
             None,
             None,
@@ -4915,11 +8625,12 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::Terminals, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
             None,
             None,
             None,
@@ -4947,11 +8658,13 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
-            Some((TK::SemiColon, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
+            Some((TK::STOP, true)),
+            Some((TK::Name, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
             None,
             None,
             None,
@@ -4966,8 +8679,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CSBracket, true)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -4984,7 +8698,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::Comma, true)),
-            Some((TK::CSBracket, true)),
+            Some((TK::CBrace, true)),
+            None,
             None,
             None,
             None,
@@ -5000,8 +8715,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CSBracket, true)),
+            Some((TK::Name, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -5018,12 +8734,31 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
+            Some((TK::OBracket, true)),
+            Some((TK::CBracket, true)),
             Some((TK::Choice, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Terminals, true)),
+            Some((TK::Name, false)),
+            Some((TK::MacroName, false)),
+            Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -5035,7 +8770,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::Comma, true)),
-            Some((TK::CBrace, true)),
+            Some((TK::CBracket, true)),
+            None,
             None,
             None,
             None,
@@ -5051,9 +8787,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::SemiColon, true)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5068,7 +8805,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::SemiColon, true)),
+            Some((TK::Comma, true)),
+            Some((TK::CSBracket, true)),
             None,
             None,
             None,
@@ -5085,7 +8823,8 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Name, false)),
+            Some((TK::Nested, true)),
+            None,
             None,
             None,
             None,
@@ -5102,13 +8841,14 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
+            Some((TK::Colon, true)),
             Some((TK::SemiColon, true)),
             Some((TK::OBrace, true)),
-            Some((TK::OBracket, true)),
-            Some((TK::CBracket, true)),
-            Some((TK::Choice, true)),
-            Some((TK::Name, false)),
-            Some((TK::StrConst, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5120,9 +8860,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
         ],
         [
             Some((TK::STOP, true)),
-            Some((TK::Terminals, true)),
             Some((TK::Name, false)),
             Some((TK::Annotation, false)),
+            Some((TK::DocComment, false)),
+            None,
             None,
             None,
             None,
@@ -5136,9 +8877,10 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::Name, false)),
-            Some((TK::Annotation, false)),
+            Some((TK::CBracket, true)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5153,8 +8895,9 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::CSBracket, true)),
+            Some((TK::Colon, true)),
+            Some((TK::SemiColon, true)),
+            Some((TK::OBrace, true)),
             None,
             None,
             None,
@@ -5185,6 +8928,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::OComment, true)),
@@ -5202,6 +8946,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5219,6 +8964,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5236,6 +8982,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, false)),
@@ -5253,6 +9000,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5270,6 +9018,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, false)),
@@ -5287,6 +9036,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5304,6 +9054,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5321,6 +9072,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::OComment, true)),
@@ -5338,6 +9090,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::OComment, true)),
@@ -5355,6 +9108,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::OComment, true)),
@@ -5372,6 +9126,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CComment, true)),
@@ -5389,6 +9144,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::OComment, true)),
@@ -5406,6 +9162,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::CComment, true)),
@@ -5423,6 +9180,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::OComment, true)),
@@ -5440,6 +9198,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5457,6 +9216,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5474,6 +9234,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
         [
             Some((TK::OComment, true)),
@@ -5491,6 +9252,7 @@ pub(crate) static PARSER_DEFINITION: RustemoParserDefinition = RustemoParserDefi
             None,
             None,
             None,
+            None,
         ],
     ],
 };
@@ -5539,7 +9301,7 @@ impl<
     'i,
     Input,
     StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
-    DefaultBuilder,
+    DefaultBuilder<'i>,
 > {
     pub fn new() -> Self {
         Self(
@@ -5553,6 +9315,87 @@ impl<
             ),
         )
     }
+    pub fn parse_all(
+        &self,
+        input: &'i Input,
+    ) -> Result<Vec<<DefaultBuilder<'i> as Builder>::Output>> {
+        self.0.parse(input).map(|output| vec![output])
+    }
+    pub fn parse_str(
+        &self,
+        input: &'i str,
+    ) -> Result<
+        <Self as Parser<'i, Input, Context<'i, Input>, State, TokenKind>>::Output,
+    > {
+        self.parse(input)
+    }
+    pub fn tokenize(input: &'i Input) -> Result<Vec<Token<'i, Input, TokenKind>>> {
+        LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                true,
+                StringLexer::new(false, &RECOGNIZERS),
+                rustemo::TokensBuilder::new(),
+            )
+            .parse(input)
+    }
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self(self.0.max_depth(max_depth))
+    }
+    pub fn parse_opts(
+        &self,
+        input: &'i Input,
+        opts: rustemo::ParseOpts,
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        self.0.parse_opts(input, opts)
+    }
+    pub fn parse_with_driver(
+        &self,
+        input: &'i Input,
+        driver: &mut impl rustemo::ParseDriver<
+            'i,
+            Context<'i, Input>,
+            ProdKind,
+            Input,
+            TokenKind,
+        >,
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        self.0.parse_with_driver(input, driver)
+    }
+    pub fn completions(&self, prefix: &'i Input) -> Vec<TokenKind> {
+        self.0.completions(prefix).unwrap_or_default()
+    }
+    pub fn max_lex_bytes(self, max_lex_bytes: usize) -> Self {
+        Self(self.0.max_lex_bytes(max_lex_bytes))
+    }
+    pub fn lex_timeout(self, lex_timeout: std::time::Duration) -> Self {
+        Self(self.0.lex_timeout(lex_timeout))
+    }
+    #[allow(clippy::type_complexity)]
+    pub fn parse_with_layout(
+        &self,
+        input: &'i Input,
+    ) -> Result<
+        (<DefaultBuilder<'i> as Builder>::Output, Vec<rustemo::LayoutSegment<'i, Input>>),
+    > {
+        self.0.parse_with_layout(input)
+    }
+    pub fn parse_tokens(
+        &self,
+        tokens: &[Token<'_, str, TokenKind>],
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        let input = rustemo::TokensLexer::input(tokens);
+        LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                false,
+                rustemo::TokensLexer::new(tokens),
+                DefaultBuilder::new(),
+            )
+            .parse(&input)
+    }
 }
 #[allow(dead_code)]
 impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind>
@@ -5593,9 +9436,17 @@ pub enum Recognizer {
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct TokenRecognizer(TokenKind, Recognizer);
-impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
-    fn recognize(&self, input: &'i str) -> Option<&'i str> {
-        match &self {
+impl<'i> TokenRecognizerT<'i, Context<'i, Input>> for TokenRecognizer {
+    #[allow(unused_variables, clippy::only_used_in_recursion)]
+    fn recognize_value(
+        &self,
+        context: &Context<'i, Input>,
+        input: &'i str,
+        position: usize,
+    ) -> Option<(usize, &'i str)> {
+        let whole_input = input;
+        let input = &input[position..];
+        let recognized: Option<&'i str> = match &self {
             #[allow(unused_variables)]
             TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
                 logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
@@ -5617,7 +9468,7 @@ impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
                         log!("{} '{}'", "recognized".bold().green(), x_str);
                         Some(x_str)
                     }
-                    None => {
+                    _ => {
                         log!("{}", "not recognized".red());
                         None
                     }
@@ -5633,7 +9484,8 @@ impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
                     None
                 }
             }
-        }
+        };
+        recognized.map(|x_str| (x_str.len(), x_str))
     }
 }
 pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
@@ -5641,6 +9493,19 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
     TokenRecognizer(TokenKind::Terminals, Recognizer::StrMatch("terminals")),
     TokenRecognizer(TokenKind::Import, Recognizer::StrMatch("import")),
     TokenRecognizer(TokenKind::As, Recognizer::StrMatch("as")),
+    TokenRecognizer(
+        TokenKind::LineCommentDirective,
+        Recognizer::StrMatch("%line_comment"),
+    ),
+    TokenRecognizer(
+        TokenKind::BlockCommentDirective,
+        Recognizer::StrMatch("%block_comment"),
+    ),
+    TokenRecognizer(TokenKind::Keywords, Recognizer::StrMatch("%keywords")),
+    TokenRecognizer(TokenKind::Start, Recognizer::StrMatch("%start")),
+    TokenRecognizer(TokenKind::Ignore, Recognizer::StrMatch("%ignore")),
+    TokenRecognizer(TokenKind::Trivia, Recognizer::StrMatch("%trivia")),
+    TokenRecognizer(TokenKind::TerminalsInclude, Recognizer::StrMatch("%terminals")),
     TokenRecognizer(TokenKind::Colon, Recognizer::StrMatch(":")),
     TokenRecognizer(TokenKind::SemiColon, Recognizer::StrMatch(";")),
     TokenRecognizer(TokenKind::Comma, Recognizer::StrMatch(",")),
@@ -5666,38 +9531,63 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
     TokenRecognizer(TokenKind::Dynamic, Recognizer::StrMatch("dynamic")),
     TokenRecognizer(TokenKind::NOPS, Recognizer::StrMatch("nops")),
     TokenRecognizer(TokenKind::NOPSE, Recognizer::StrMatch("nopse")),
+    TokenRecognizer(TokenKind::NoLayout, Recognizer::StrMatch("nolayout")),
+    TokenRecognizer(TokenKind::Prec, Recognizer::StrMatch("prec")),
+    TokenRecognizer(TokenKind::Assoc, Recognizer::StrMatch("assoc")),
     TokenRecognizer(TokenKind::Prefer, Recognizer::StrMatch("prefer")),
     TokenRecognizer(TokenKind::Finish, Recognizer::StrMatch("finish")),
     TokenRecognizer(TokenKind::NoFinish, Recognizer::StrMatch("nofinish")),
+    TokenRecognizer(TokenKind::Raw, Recognizer::StrMatch("raw")),
+    TokenRecognizer(TokenKind::Nested, Recognizer::StrMatch("nested")),
     TokenRecognizer(TokenKind::OComment, Recognizer::StrMatch("/*")),
     TokenRecognizer(TokenKind::CComment, Recognizer::StrMatch("*/")),
     TokenRecognizer(
         TokenKind::Name,
         Recognizer::RegexMatch(
             Lazy::new(|| {
-                Regex::new(concat!("^(", "[a-zA-Z_][a-zA-Z0-9_\\.]*", ")")).unwrap()
+                Regex::new(concat!("^", "[a-zA-Z_][a-zA-Z0-9_\\.]*")).unwrap()
             }),
         ),
     ),
     TokenRecognizer(
-        TokenKind::RegexTerm,
+        TokenKind::MacroName,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "[a-zA-Z_][a-zA-Z0-9_]*\\(")).unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::RustType,
         Recognizer::RegexMatch(
             Lazy::new(|| {
-                Regex::new(concat!("^(", "/(\\\\.|[^/\\\\])*/", ")")).unwrap()
+                Regex::new(
+                        concat!(
+                            "^",
+                            "(?:[^;{}\\[\\]/\\s]|\\[[^\\]]*\\])(?:[^;{}\\[\\]/]|\\[[^\\]]*\\])*"
+                        ),
+                    )
+                    .unwrap()
             }),
         ),
     ),
+    TokenRecognizer(
+        TokenKind::RegexTerm,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "/(\\\\.|[^/\\\\])*/")).unwrap() }),
+        ),
+    ),
     TokenRecognizer(
         TokenKind::IntConst,
         Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^(", "\\d+", ")")).unwrap() }),
+            Lazy::new(|| { Regex::new(concat!("^", "\\d+")).unwrap() }),
         ),
     ),
     TokenRecognizer(
         TokenKind::FloatConst,
         Recognizer::RegexMatch(
             Lazy::new(|| {
-                Regex::new(concat!("^(", "[+-]?[0-9]+[.][0-9]*([e][+-]?[0-9]+)?", ")"))
+                Regex::new(concat!("^", "[+-]?[0-9]+[.][0-9]*([e][+-]?[0-9]+)?"))
                     .unwrap()
             }),
         ),
@@ -5705,7 +9595,7 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
     TokenRecognizer(
         TokenKind::BoolConst,
         Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^(", "true|false", ")")).unwrap() }),
+            Lazy::new(|| { Regex::new(concat!("^", "true|false")).unwrap() }),
         ),
     ),
     TokenRecognizer(
@@ -5714,9 +9604,8 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
             Lazy::new(|| {
                 Regex::new(
                         concat!(
-                            "^(",
-                            "(?s)(^'[^'\\\\]*(?:\\\\.[^'\\\\]*)*')|(^\"[^\"\\\\]*(?:\\\\.[^\"\\\\]*)*\")",
-                            ")"
+                            "^",
+                            "(?s)(^'[^'\\\\]*(?:\\\\.[^'\\\\]*)*')|(^\"[^\"\\\\]*(?:\\\\.[^\"\\\\]*)*\")"
                         ),
                     )
                     .unwrap()
@@ -5726,40 +9615,65 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
     TokenRecognizer(
         TokenKind::Annotation,
         Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^(", "@[a-zA-Z0-9_]+", ")")).unwrap() }),
+            Lazy::new(|| { Regex::new(concat!("^", "@[a-zA-Z0-9_]+")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::DocComment,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "///.*")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ActionsCode,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "%\\{(?s:.*?)%\\}")).unwrap() }),
         ),
     ),
     TokenRecognizer(
         TokenKind::WS,
         Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^(", "\\s+", ")")).unwrap() }),
+            Lazy::new(|| { Regex::new(concat!("^", "\\s+")).unwrap() }),
         ),
     ),
     TokenRecognizer(
         TokenKind::CommentLine,
         Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^(", "//.*", ")")).unwrap() }),
+            Lazy::new(|| {
+                Regex::new(concat!("^", "(?m)//([^/\\n].*|//.*)?$")).unwrap()
+            }),
         ),
     ),
     TokenRecognizer(
         TokenKind::NotComment,
         Recognizer::RegexMatch(
             Lazy::new(|| {
-                Regex::new(concat!("^(", "((\\*[^/])|[^\\s*/]|/[^\\*])+", ")")).unwrap()
+                Regex::new(concat!("^", "((\\*[^/])|[^\\s*/]|/[^\\*])+")).unwrap()
             }),
         ),
     ),
 ];
-pub struct DefaultBuilder {
-    res_stack: Vec<Symbol>,
+pub struct DefaultBuilder<'i> {
+    res_stack: Vec<Symbol<'i>>,
 }
-impl DefaultBuilder {
+impl<'i> DefaultBuilder<'i> {
     #[allow(dead_code)]
     pub fn new() -> Self {
         Self { res_stack: vec![] }
     }
+    /// The symbols on the result stack. Used to inspect the
+    /// parsing progress, e.g. from a builder wrapping this one.
+    #[allow(dead_code)]
+    pub fn stack(&self) -> &[Symbol<'i>] {
+        &self.res_stack
+    }
+}
+impl Default for DefaultBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
-impl Builder for DefaultBuilder {
+impl Builder for DefaultBuilder<'_> {
     type Output = rustemo_actions::File;
     fn get_result(&mut self) -> Self::Output {
         match self.res_stack.pop().unwrap() {
@@ -5769,7 +9683,7 @@ impl Builder for DefaultBuilder {
     }
 }
 impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
-for DefaultBuilder {
+for DefaultBuilder<'i> {
     #![allow(unused_variables)]
     fn shift_action(
         &mut self,
@@ -5778,55 +9692,115 @@ for DefaultBuilder {
     ) {
         let val = match token.kind {
             TokenKind::STOP => panic!("Cannot shift STOP token!"),
-            TokenKind::Terminals => Terminal::Terminals,
-            TokenKind::Import => Terminal::Import,
-            TokenKind::As => Terminal::As,
-            TokenKind::Colon => Terminal::Colon,
-            TokenKind::SemiColon => Terminal::SemiColon,
-            TokenKind::Comma => Terminal::Comma,
-            TokenKind::OBrace => Terminal::OBrace,
-            TokenKind::CBrace => Terminal::CBrace,
-            TokenKind::OBracket => Terminal::OBracket,
-            TokenKind::CBracket => Terminal::CBracket,
-            TokenKind::OSBracket => Terminal::OSBracket,
-            TokenKind::CSBracket => Terminal::CSBracket,
-            TokenKind::Choice => Terminal::Choice,
-            TokenKind::ZeroOrMore => Terminal::ZeroOrMore,
-            TokenKind::ZeroOrMoreGreedy => Terminal::ZeroOrMoreGreedy,
-            TokenKind::OneOrMore => Terminal::OneOrMore,
-            TokenKind::OneOrMoreGreedy => Terminal::OneOrMoreGreedy,
-            TokenKind::Optional => Terminal::Optional,
-            TokenKind::OptionalGreedy => Terminal::OptionalGreedy,
-            TokenKind::Equals => Terminal::Equals,
-            TokenKind::QEquals => Terminal::QEquals,
-            TokenKind::Left => Terminal::Left,
-            TokenKind::Right => Terminal::Right,
-            TokenKind::Reduce => Terminal::Reduce,
-            TokenKind::Shift => Terminal::Shift,
-            TokenKind::Dynamic => Terminal::Dynamic,
-            TokenKind::NOPS => Terminal::NOPS,
-            TokenKind::NOPSE => Terminal::NOPSE,
-            TokenKind::Prefer => Terminal::Prefer,
-            TokenKind::Finish => Terminal::Finish,
-            TokenKind::NoFinish => Terminal::NoFinish,
-            TokenKind::Name => Terminal::Name(rustemo_actions::name(&*context, token)),
+            TokenKind::Terminals => Terminal::Terminals(token.value),
+            TokenKind::Import => Terminal::Import(token.value),
+            TokenKind::As => Terminal::As(token.value),
+            TokenKind::LineCommentDirective => {
+                Terminal::LineCommentDirective(token.value)
+            }
+            TokenKind::BlockCommentDirective => {
+                Terminal::BlockCommentDirective(token.value)
+            }
+            TokenKind::Keywords => Terminal::Keywords(token.value),
+            TokenKind::Start => Terminal::Start(token.value),
+            TokenKind::Ignore => Terminal::Ignore(token.value),
+            TokenKind::Trivia => Terminal::Trivia(token.value),
+            TokenKind::TerminalsInclude => Terminal::TerminalsInclude(token.value),
+            TokenKind::Colon => Terminal::Colon(token.value),
+            TokenKind::SemiColon => Terminal::SemiColon(token.value),
+            TokenKind::Comma => Terminal::Comma(token.value),
+            TokenKind::OBrace => Terminal::OBrace(token.value),
+            TokenKind::CBrace => Terminal::CBrace(token.value),
+            TokenKind::OBracket => Terminal::OBracket(token.value),
+            TokenKind::CBracket => Terminal::CBracket(token.value),
+            TokenKind::OSBracket => Terminal::OSBracket(token.value),
+            TokenKind::CSBracket => Terminal::CSBracket(token.value),
+            TokenKind::Choice => Terminal::Choice(token.value),
+            TokenKind::ZeroOrMore => Terminal::ZeroOrMore(token.value),
+            TokenKind::ZeroOrMoreGreedy => Terminal::ZeroOrMoreGreedy(token.value),
+            TokenKind::OneOrMore => Terminal::OneOrMore(token.value),
+            TokenKind::OneOrMoreGreedy => Terminal::OneOrMoreGreedy(token.value),
+            TokenKind::Optional => Terminal::Optional(token.value),
+            TokenKind::OptionalGreedy => Terminal::OptionalGreedy(token.value),
+            TokenKind::Equals => Terminal::Equals(token.value),
+            TokenKind::QEquals => Terminal::QEquals(token.value),
+            TokenKind::Left => Terminal::Left(token.value),
+            TokenKind::Right => Terminal::Right(token.value),
+            TokenKind::Reduce => Terminal::Reduce(token.value),
+            TokenKind::Shift => Terminal::Shift(token.value),
+            TokenKind::Dynamic => Terminal::Dynamic(token.value),
+            TokenKind::NOPS => Terminal::NOPS(token.value),
+            TokenKind::NOPSE => Terminal::NOPSE(token.value),
+            TokenKind::NoLayout => Terminal::NoLayout(token.value),
+            TokenKind::Prec => Terminal::Prec(token.value),
+            TokenKind::Assoc => Terminal::Assoc(token.value),
+            TokenKind::Prefer => Terminal::Prefer(token.value),
+            TokenKind::Finish => Terminal::Finish(token.value),
+            TokenKind::NoFinish => Terminal::NoFinish(token.value),
+            TokenKind::Raw => Terminal::Raw(token.value),
+            TokenKind::Nested => Terminal::Nested(token.value),
+            TokenKind::Name => {
+                Terminal::Name(token.value, rustemo_actions::name(&*context, token))
+            }
+            TokenKind::MacroName => {
+                Terminal::MacroName(
+                    token.value,
+                    rustemo_actions::macro_name(&*context, token),
+                )
+            }
+            TokenKind::RustType => {
+                Terminal::RustType(
+                    token.value,
+                    rustemo_actions::rust_type(&*context, token),
+                )
+            }
             TokenKind::RegexTerm => {
-                Terminal::RegexTerm(rustemo_actions::regex_term(&*context, token))
+                Terminal::RegexTerm(
+                    token.value,
+                    rustemo_actions::regex_term(&*context, token),
+                )
             }
             TokenKind::IntConst => {
-                Terminal::IntConst(rustemo_actions::int_const(&*context, token))
+                Terminal::IntConst(
+                    token.value,
+                    rustemo_actions::int_const(&*context, token),
+                )
             }
             TokenKind::FloatConst => {
-                Terminal::FloatConst(rustemo_actions::float_const(&*context, token))
+                Terminal::FloatConst(
+                    token.value,
+                    rustemo_actions::float_const(&*context, token),
+                )
             }
             TokenKind::BoolConst => {
-                Terminal::BoolConst(rustemo_actions::bool_const(&*context, token))
+                Terminal::BoolConst(
+                    token.value,
+                    rustemo_actions::bool_const(&*context, token),
+                )
             }
             TokenKind::StrConst => {
-                Terminal::StrConst(rustemo_actions::str_const(&*context, token))
+                Terminal::StrConst(
+                    token.value,
+                    rustemo_actions::str_const(&*context, token),
+                )
             }
             TokenKind::Annotation => {
-                Terminal::Annotation(rustemo_actions::annotation(&*context, token))
+                Terminal::Annotation(
+                    token.value,
+                    rustemo_actions::annotation(&*context, token),
+                )
+            }
+            TokenKind::DocComment => {
+                Terminal::DocComment(
+                    token.value,
+                    rustemo_actions::doc_comment(&*context, token),
+                )
+            }
+            TokenKind::ActionsCode => {
+                Terminal::ActionsCode(
+                    token.value,
+                    rustemo_actions::actions_code(&*context, token),
+                )
             }
             _ => panic!("Shift of unreachable terminal!"),
         };
@@ -5840,10 +9814,7 @@ for DefaultBuilder {
     ) {
         let prod = match prod {
             ProdKind::FileP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::GrammarRule1(p0)) => {
                         NonTerminal::File(
@@ -5854,10 +9825,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::GrammarRule1P1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::GrammarRule1(p0)),
@@ -5871,10 +9839,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::GrammarRule1P2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::GrammarRule(p0)) => {
                         NonTerminal::GrammarRule1(
@@ -5885,203 +9850,415 @@ for DefaultBuilder {
                 }
             }
             ProdKind::FileP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
+                        Symbol::NonTerminal(NonTerminal::FileHeader1(p0)),
                         Symbol::NonTerminal(NonTerminal::GrammarRule1(p1)),
                     ) => NonTerminal::File(rustemo_actions::file_c2(&*context, p0, p1)),
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ImportStm1P1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+            ProdKind::FileHeader1P1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::FileHeader1(p0)),
+                        Symbol::NonTerminal(NonTerminal::FileHeader(p1)),
+                    ) => {
+                        NonTerminal::FileHeader1(
+                            rustemo_actions::file_header1_c1(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileHeader1P2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FileHeader(p0)) => {
+                        NonTerminal::FileHeader1(
+                            rustemo_actions::file_header1_file_header(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileP3 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p1)),
+                    ) => NonTerminal::File(rustemo_actions::file_c3(&*context, p0, p1)),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TerminalRule1P1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
-                        Symbol::NonTerminal(NonTerminal::ImportStm(p1)),
+                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p0)),
+                        Symbol::NonTerminal(NonTerminal::TerminalRule(p1)),
+                    ) => {
+                        NonTerminal::TerminalRule1(
+                            rustemo_actions::terminal_rule1_c1(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TerminalRule1P2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::TerminalRule(p0)) => {
+                        NonTerminal::TerminalRule1(
+                            rustemo_actions::terminal_rule1_terminal_rule(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileP4 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 4usize..);
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::FileHeader1(p0)),
+                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p1)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p2)),
                     ) => {
-                        NonTerminal::ImportStm1(
-                            rustemo_actions::import_stm1_c1(&*context, p0, p1),
+                        NonTerminal::File(
+                            rustemo_actions::file_c4(&*context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileP5 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::TerminalRule1(p0))) => {
+                        NonTerminal::File(
+                            rustemo_actions::file_terminal_rule1(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ImportStm1P2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+            ProdKind::FileHeaderP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::ImportStm(p0)) => {
-                        NonTerminal::ImportStm1(
-                            rustemo_actions::import_stm1_import_stm(&*context, p0),
+                        NonTerminal::FileHeader(
+                            rustemo_actions::file_header_import_stm(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileHeaderP2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::CommentDirective(p0)) => {
+                        NonTerminal::FileHeader(
+                            rustemo_actions::file_header_comment_directive(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileHeaderP3 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::KeywordsDirective(p0)) => {
+                        NonTerminal::FileHeader(
+                            rustemo_actions::file_header_keywords_directive(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileHeaderP4 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::StartDirective(p0)) => {
+                        NonTerminal::FileHeader(
+                            rustemo_actions::file_header_start_directive(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileHeaderP5 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::IgnoreDirective(p0)) => {
+                        NonTerminal::FileHeader(
+                            rustemo_actions::file_header_ignore_directive(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileHeaderP6 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::TriviaDirective(p0)) => {
+                        NonTerminal::FileHeader(
+                            rustemo_actions::file_header_trivia_directive(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileHeaderP7 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::TerminalsDirective(p0)) => {
+                        NonTerminal::FileHeader(
+                            rustemo_actions::file_header_terminals_directive(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FileHeaderP8 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::ActionsCode(_, p0)) => {
+                        NonTerminal::FileHeader(
+                            rustemo_actions::file_header_actions_code(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ImportStmP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::StrConst(_, p0))) => {
+                        NonTerminal::ImportStm(
+                            rustemo_actions::import_stm_c1(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ImportStmP2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 4usize..);
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(_, p0)),
+                        _,
+                        Symbol::Terminal(Terminal::Name(_, p1)),
+                    ) => {
+                        NonTerminal::ImportStm(
+                            rustemo_actions::import_stm_c2(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CommentDirectiveP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::StrConst(_, p0))) => {
+                        NonTerminal::CommentDirective(
+                            rustemo_actions::comment_directive_c1(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CommentDirectiveP2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(_, p0)),
+                        Symbol::Terminal(Terminal::StrConst(_, p1)),
+                    ) => {
+                        NonTerminal::CommentDirective(
+                            rustemo_actions::comment_directive_c2(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::KeywordsDirectiveP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 5usize..);
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        Symbol::Terminal(Terminal::Name(_, p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Name1(p1)),
+                        _,
+                    ) => {
+                        NonTerminal::KeywordsDirective(
+                            rustemo_actions::keywords_directive_c1(&*context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FileP3 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+            ProdKind::Name1P1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p0)),
+                        Symbol::NonTerminal(NonTerminal::Name1(p0)),
                         _,
-                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p1)),
-                    ) => NonTerminal::File(rustemo_actions::file_c3(&*context, p0, p1)),
+                        Symbol::Terminal(Terminal::Name(_, p1)),
+                    ) => NonTerminal::Name1(rustemo_actions::name1_c1(&*context, p0, p1)),
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::TerminalRule1P1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p0)),
-                        Symbol::NonTerminal(NonTerminal::TerminalRule(p1)),
-                    ) => {
-                        NonTerminal::TerminalRule1(
-                            rustemo_actions::terminal_rule1_c1(&*context, p0, p1),
-                        )
+            ProdKind::Name1P2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::Name(_, p0)) => {
+                        NonTerminal::Name1(rustemo_actions::name1_name(&*context, p0))
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::TerminalRule1P2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::TerminalRule(p0)) => {
-                        NonTerminal::TerminalRule1(
-                            rustemo_actions::terminal_rule1_terminal_rule(&*context, p0),
+            ProdKind::StartDirectiveP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::Name(_, p0)), _) => {
+                        NonTerminal::StartDirective(
+                            rustemo_actions::start_directive_c1(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FileP4 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
-                    .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::ImportStm1(p0)),
-                        Symbol::NonTerminal(NonTerminal::GrammarRule1(p1)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::TerminalRule1(p2)),
-                    ) => {
-                        NonTerminal::File(
-                            rustemo_actions::file_c4(&*context, p0, p1, p2),
+            ProdKind::IgnoreDirectiveP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::Name1(p0)), _) => {
+                        NonTerminal::IgnoreDirective(
+                            rustemo_actions::ignore_directive_c1(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FileP5 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::NonTerminal(NonTerminal::TerminalRule1(p0))) => {
-                        NonTerminal::File(
-                            rustemo_actions::file_terminal_rule1(&*context, p0),
+            ProdKind::TriviaDirectiveP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::Name1(p0)), _) => {
+                        NonTerminal::TriviaDirective(
+                            rustemo_actions::trivia_directive_c1(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ImportStmP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::Terminal(Terminal::StrConst(p0))) => {
-                        NonTerminal::ImportStm(
-                            rustemo_actions::import_stm_c1(&*context, p0),
+            ProdKind::TerminalsDirectiveP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::StrConst(_, p0)), _) => {
+                        NonTerminal::TerminalsDirective(
+                            rustemo_actions::terminals_directive_c1(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ImportStmP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
-                    .into_iter();
+            ProdKind::GrammarRuleP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 6usize..);
                 match (
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
                 ) {
                     (
+                        Symbol::NonTerminal(NonTerminal::DocComment0(p0)),
+                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p1)),
+                        Symbol::Terminal(Terminal::Name(_, p2)),
                         _,
-                        Symbol::Terminal(Terminal::StrConst(p0)),
+                        Symbol::NonTerminal(NonTerminal::GrammarRuleRHS(p3)),
                         _,
-                        Symbol::Terminal(Terminal::Name(p1)),
                     ) => {
-                        NonTerminal::ImportStm(
-                            rustemo_actions::import_stm_c2(&*context, p0, p1),
+                        NonTerminal::GrammarRule(
+                            rustemo_actions::grammar_rule_c1(&*context, p0, p1, p2, p3),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::GrammarRuleP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 5usize)
-                    .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
+            ProdKind::DocComment1P1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
+                match (i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p0)),
-                        Symbol::Terminal(Terminal::Name(p1)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::GrammarRuleRHS(p2)),
-                        _,
+                        Symbol::NonTerminal(NonTerminal::DocComment1(p0)),
+                        Symbol::Terminal(Terminal::DocComment(_, p1)),
                     ) => {
-                        NonTerminal::GrammarRule(
-                            rustemo_actions::grammar_rule_c1(&*context, p0, p1, p2),
+                        NonTerminal::DocComment1(
+                            rustemo_actions::doc_comment1_c1(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::DocComment1P2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::DocComment(_, p0)) => {
+                        NonTerminal::DocComment1(
+                            rustemo_actions::doc_comment1_doc_comment(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::DocComment0P1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::DocComment1(p0)) => {
+                        NonTerminal::DocComment0(
+                            rustemo_actions::doc_comment0_doc_comment1(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::DocComment0P2 => {
+                NonTerminal::DocComment0(rustemo_actions::doc_comment0_empty(context))
+            }
             ProdKind::AnnotationOptP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::Annotation(p0)) => {
+                    Symbol::Terminal(Terminal::Annotation(_, p0)) => {
                         NonTerminal::AnnotationOpt(
                             rustemo_actions::annotation_opt_annotation(&*context, p0),
                         )
@@ -6095,10 +10272,7 @@ for DefaultBuilder {
                 )
             }
             ProdKind::GrammarRuleP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 8usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 9usize..);
                 match (
                     i.next().unwrap(),
                     i.next().unwrap(),
@@ -6108,29 +10282,71 @@ for DefaultBuilder {
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
+                    i.next().unwrap(),
                 ) {
                     (
-                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p0)),
-                        Symbol::Terminal(Terminal::Name(p1)),
+                        Symbol::NonTerminal(NonTerminal::DocComment0(p0)),
+                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p1)),
+                        Symbol::Terminal(Terminal::Name(_, p2)),
                         _,
-                        Symbol::NonTerminal(NonTerminal::ProdMetaDatas(p2)),
+                        Symbol::NonTerminal(NonTerminal::ProdMetaDatas(p3)),
                         _,
                         _,
-                        Symbol::NonTerminal(NonTerminal::GrammarRuleRHS(p3)),
+                        Symbol::NonTerminal(NonTerminal::GrammarRuleRHS(p4)),
+                        _,
+                    ) => {
+                        NonTerminal::GrammarRule(
+                            rustemo_actions::grammar_rule_c2(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::GrammarRuleP3 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 8usize..);
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::DocComment0(p0)),
+                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p1)),
+                        Symbol::Terminal(Terminal::MacroName(_, p2)),
+                        Symbol::NonTerminal(NonTerminal::Name1(p3)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::GrammarRuleRHS(p4)),
                         _,
                     ) => {
                         NonTerminal::GrammarRule(
-                            rustemo_actions::grammar_rule_c2(&*context, p0, p1, p2, p3),
+                            rustemo_actions::grammar_rule_c3(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
             ProdKind::GrammarRuleRHSP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::GrammarRuleRHS(p0)),
@@ -6145,10 +10361,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::GrammarRuleRHSP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Production(p0)) => {
                         NonTerminal::GrammarRuleRHS(
@@ -6159,10 +10372,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ProductionP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Assignment1(p0)) => {
                         NonTerminal::Production(
@@ -6173,10 +10383,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::Assignment1P1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::Assignment1(p0)),
@@ -6190,10 +10397,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::Assignment1P2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::Assignment(p0)) => {
                         NonTerminal::Assignment1(
@@ -6204,10 +10408,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ProductionP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 4usize..);
                 match (
                     i.next().unwrap(),
                     i.next().unwrap(),
@@ -6228,60 +10429,83 @@ for DefaultBuilder {
                 }
             }
             ProdKind::TerminalRuleP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 5usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 7usize..);
                 match (
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
                 ) {
                     (
-                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p0)),
-                        Symbol::Terminal(Terminal::Name(p1)),
+                        Symbol::NonTerminal(NonTerminal::DocComment0(p0)),
+                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p1)),
+                        Symbol::Terminal(Terminal::Name(_, p2)),
                         _,
-                        Symbol::NonTerminal(NonTerminal::Recognizer(p2)),
+                        Symbol::NonTerminal(NonTerminal::Recognizer(p3)),
+                        Symbol::NonTerminal(NonTerminal::TerminalTypeOpt(p4)),
                         _,
                     ) => {
                         NonTerminal::TerminalRule(
-                            rustemo_actions::terminal_rule_c1(&*context, p0, p1, p2),
+                            rustemo_actions::terminal_rule_c1(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TerminalTypeOptP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::TerminalType(p0)) => {
+                        NonTerminal::TerminalTypeOpt(
+                            rustemo_actions::terminal_type_opt_terminal_type(
+                                &*context,
+                                p0,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::TerminalTypeOptP2 => {
+                NonTerminal::TerminalTypeOpt(
+                    rustemo_actions::terminal_type_opt_empty(context),
+                )
+            }
             ProdKind::TerminalRuleP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 5usize..);
                 match (
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
+                    i.next().unwrap(),
                 ) {
                     (
-                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p0)),
-                        Symbol::Terminal(Terminal::Name(p1)),
+                        Symbol::NonTerminal(NonTerminal::DocComment0(p0)),
+                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p1)),
+                        Symbol::Terminal(Terminal::Name(_, p2)),
                         _,
                         _,
                     ) => {
                         NonTerminal::TerminalRule(
-                            rustemo_actions::terminal_rule_c2(&*context, p0, p1),
+                            rustemo_actions::terminal_rule_c2(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
             ProdKind::TerminalRuleP3 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 8usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 10usize..);
                 match (
                     i.next().unwrap(),
                     i.next().unwrap(),
@@ -6291,29 +10515,38 @@ for DefaultBuilder {
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
                 ) {
                     (
-                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p0)),
-                        Symbol::Terminal(Terminal::Name(p1)),
+                        Symbol::NonTerminal(NonTerminal::DocComment0(p0)),
+                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p1)),
+                        Symbol::Terminal(Terminal::Name(_, p2)),
                         _,
-                        Symbol::NonTerminal(NonTerminal::Recognizer(p2)),
+                        Symbol::NonTerminal(NonTerminal::Recognizer(p3)),
+                        Symbol::NonTerminal(NonTerminal::TerminalTypeOpt(p4)),
                         _,
-                        Symbol::NonTerminal(NonTerminal::TermMetaDatas(p3)),
+                        Symbol::NonTerminal(NonTerminal::TermMetaDatas(p5)),
                         _,
                         _,
                     ) => {
                         NonTerminal::TerminalRule(
-                            rustemo_actions::terminal_rule_c3(&*context, p0, p1, p2, p3),
+                            rustemo_actions::terminal_rule_c3(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                                p5,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
             ProdKind::TerminalRuleP4 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 7usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 8usize..);
                 match (
                     i.next().unwrap(),
                     i.next().unwrap(),
@@ -6322,83 +10555,89 @@ for DefaultBuilder {
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
+                    i.next().unwrap(),
                 ) {
                     (
-                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p0)),
-                        Symbol::Terminal(Terminal::Name(p1)),
+                        Symbol::NonTerminal(NonTerminal::DocComment0(p0)),
+                        Symbol::NonTerminal(NonTerminal::AnnotationOpt(p1)),
+                        Symbol::Terminal(Terminal::Name(_, p2)),
                         _,
                         _,
-                        Symbol::NonTerminal(NonTerminal::TermMetaDatas(p2)),
+                        Symbol::NonTerminal(NonTerminal::TermMetaDatas(p3)),
                         _,
                         _,
                     ) => {
                         NonTerminal::TerminalRule(
-                            rustemo_actions::terminal_rule_c4(&*context, p0, p1, p2),
+                            rustemo_actions::terminal_rule_c4(&*context, p0, p1, p2, p3),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TerminalTypeP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::RustType(_, p0))) => {
+                        NonTerminal::TerminalType(
+                            rustemo_actions::terminal_type_rust_type(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
             ProdKind::ProdMetaDataLeft => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::ProdMetaData(rustemo_actions::prod_meta_data_left(context))
             }
             ProdKind::ProdMetaDataReduce => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::ProdMetaData(
                     rustemo_actions::prod_meta_data_reduce(context),
                 )
             }
             ProdKind::ProdMetaDataRight => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::ProdMetaData(rustemo_actions::prod_meta_data_right(context))
             }
             ProdKind::ProdMetaDataShift => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::ProdMetaData(rustemo_actions::prod_meta_data_shift(context))
             }
             ProdKind::ProdMetaDataDynamic => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::ProdMetaData(
                     rustemo_actions::prod_meta_data_dynamic(context),
                 )
             }
             ProdKind::ProdMetaDataNOPS => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::ProdMetaData(rustemo_actions::prod_meta_data_nops(context))
             }
             ProdKind::ProdMetaDataNOPSE => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::ProdMetaData(rustemo_actions::prod_meta_data_nopse(context))
             }
+            ProdKind::ProdMetaDataNoLayout => {
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
+                NonTerminal::ProdMetaData(
+                    rustemo_actions::prod_meta_data_no_layout(context),
+                )
+            }
+            ProdKind::ProdMetaDataPredicate => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::Name(_, p0))) => {
+                        NonTerminal::ProdMetaData(
+                            rustemo_actions::prod_meta_data_predicate(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::ProdMetaDataPriority => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::IntConst(p0)) => {
+                    Symbol::Terminal(Terminal::IntConst(_, p0)) => {
                         NonTerminal::ProdMetaData(
                             rustemo_actions::prod_meta_data_priority(&*context, p0),
                         )
@@ -6406,11 +10645,31 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ProdMetaDataP9 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+            ProdKind::ProdMetaDataPrec => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, _, Symbol::Terminal(Terminal::IntConst(_, p0))) => {
+                        NonTerminal::ProdMetaData(
+                            rustemo_actions::prod_meta_data_prec(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ProdMetaDataAssocLeft => {
+                self.res_stack.truncate(self.res_stack.len() - 3usize);
+                NonTerminal::ProdMetaData(
+                    rustemo_actions::prod_meta_data_assoc_left(context),
+                )
+            }
+            ProdKind::ProdMetaDataAssocRight => {
+                self.res_stack.truncate(self.res_stack.len() - 3usize);
+                NonTerminal::ProdMetaData(
+                    rustemo_actions::prod_meta_data_assoc_right(context),
+                )
+            }
+            ProdKind::ProdMetaDataP14 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::UserMetaData(p0)) => {
                         NonTerminal::ProdMetaData(
@@ -6420,11 +10679,8 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ProdMetaDataP10 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+            ProdKind::ProdMetaDataP15 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::ProdKind(p0)) => {
                         NonTerminal::ProdMetaData(
@@ -6435,10 +10691,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ProdMetaDatasP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::ProdMetaDatas(p0)),
@@ -6453,10 +10706,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ProdMetaDatasP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::ProdMetaData(p0)) => {
                         NonTerminal::ProdMetaDatas(
@@ -6467,78 +10717,51 @@ for DefaultBuilder {
                 }
             }
             ProdKind::TermMetaDataPrefer => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::TermMetaData(
                     rustemo_actions::term_meta_data_prefer(context),
                 )
             }
             ProdKind::TermMetaDataFinish => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::TermMetaData(
                     rustemo_actions::term_meta_data_finish(context),
                 )
             }
             ProdKind::TermMetaDataNoFinish => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::TermMetaData(
                     rustemo_actions::term_meta_data_no_finish(context),
                 )
             }
             ProdKind::TermMetaDataLeft => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::TermMetaData(rustemo_actions::term_meta_data_left(context))
             }
             ProdKind::TermMetaDataReduce => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::TermMetaData(
                     rustemo_actions::term_meta_data_reduce(context),
                 )
             }
             ProdKind::TermMetaDataRight => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::TermMetaData(rustemo_actions::term_meta_data_right(context))
             }
             ProdKind::TermMetaDataShift => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::TermMetaData(rustemo_actions::term_meta_data_shift(context))
             }
             ProdKind::TermMetaDataDynamic => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::TermMetaData(
                     rustemo_actions::term_meta_data_dynamic(context),
                 )
             }
             ProdKind::TermMetaDataPriority => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::IntConst(p0)) => {
+                    Symbol::Terminal(Terminal::IntConst(_, p0)) => {
                         NonTerminal::TermMetaData(
                             rustemo_actions::term_meta_data_priority(&*context, p0),
                         )
@@ -6547,10 +10770,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::TermMetaDataP10 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::UserMetaData(p0)) => {
                         NonTerminal::TermMetaData(
@@ -6561,10 +10781,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::TermMetaDatasP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::TermMetaDatas(p0)),
@@ -6579,10 +10796,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::TermMetaDatasP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::TermMetaData(p0)) => {
                         NonTerminal::TermMetaDatas(
@@ -6593,13 +10807,10 @@ for DefaultBuilder {
                 }
             }
             ProdKind::UserMetaDataP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::Terminal(Terminal::Name(p0)),
+                        Symbol::Terminal(Terminal::Name(_, p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::ConstVal(p1)),
                     ) => {
@@ -6611,12 +10822,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ProdKindP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::Name(p0)) => {
+                    Symbol::Terminal(Terminal::Name(_, p0)) => {
                         NonTerminal::ProdKind(
                             rustemo_actions::prod_kind_name(&*context, p0),
                         )
@@ -6625,12 +10833,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ConstValP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::IntConst(p0)) => {
+                    Symbol::Terminal(Terminal::IntConst(_, p0)) => {
                         NonTerminal::ConstVal(
                             rustemo_actions::const_val_int_const(&*context, p0),
                         )
@@ -6639,12 +10844,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ConstValP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::FloatConst(p0)) => {
+                    Symbol::Terminal(Terminal::FloatConst(_, p0)) => {
                         NonTerminal::ConstVal(
                             rustemo_actions::const_val_float_const(&*context, p0),
                         )
@@ -6653,12 +10855,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ConstValP3 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::BoolConst(p0)) => {
+                    Symbol::Terminal(Terminal::BoolConst(_, p0)) => {
                         NonTerminal::ConstVal(
                             rustemo_actions::const_val_bool_const(&*context, p0),
                         )
@@ -6667,12 +10866,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ConstValP4 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::StrConst(p0)) => {
+                    Symbol::Terminal(Terminal::StrConst(_, p0)) => {
                         NonTerminal::ConstVal(
                             rustemo_actions::const_val_str_const(&*context, p0),
                         )
@@ -6681,10 +10877,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::AssignmentP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::PlainAssignment(p0)) => {
                         NonTerminal::Assignment(
@@ -6695,10 +10888,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::AssignmentP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::BoolAssignment(p0)) => {
                         NonTerminal::Assignment(
@@ -6709,10 +10899,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::AssignmentP3 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::GrammarSymbolRef(p0)) => {
                         NonTerminal::Assignment(
@@ -6723,13 +10910,10 @@ for DefaultBuilder {
                 }
             }
             ProdKind::PlainAssignmentP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::Terminal(Terminal::Name(p0)),
+                        Symbol::Terminal(Terminal::Name(_, p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::GrammarSymbolRef(p1)),
                     ) => {
@@ -6741,13 +10925,10 @@ for DefaultBuilder {
                 }
             }
             ProdKind::BoolAssignmentP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::Terminal(Terminal::Name(p0)),
+                        Symbol::Terminal(Terminal::Name(_, p0)),
                         _,
                         Symbol::NonTerminal(NonTerminal::GrammarSymbolRef(p1)),
                     ) => {
@@ -6759,10 +10940,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::ProductionGroupP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::NonTerminal(NonTerminal::GrammarRuleRHS(p0)), _) => {
                         NonTerminal::ProductionGroup(
@@ -6773,10 +10951,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::GrammarSymbolRefP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::GrammarSymbol(p0)),
@@ -6790,10 +10965,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::RepetitionOperatorOptP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::RepetitionOperator(p0)) => {
                         NonTerminal::RepetitionOperatorOpt(
@@ -6812,10 +10984,7 @@ for DefaultBuilder {
                 )
             }
             ProdKind::GrammarSymbolRefP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::ProductionGroup(p0)),
@@ -6828,11 +10997,37 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::GrammarSymbolRefP3 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::MacroCall(p0)),
+                        Symbol::NonTerminal(NonTerminal::RepetitionOperatorOpt(p1)),
+                    ) => {
+                        NonTerminal::GrammarSymbolRef(
+                            rustemo_actions::grammar_symbol_ref_c3(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::MacroCallP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::MacroName(_, p0)),
+                        Symbol::NonTerminal(NonTerminal::Name1(p1)),
+                        _,
+                    ) => {
+                        NonTerminal::MacroCall(
+                            rustemo_actions::macro_call_c1(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::RepetitionOperatorP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::RepetitionOperatorOp(p0)),
@@ -6846,10 +11041,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::RepetitionModifiersOptP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::RepetitionModifiers(p0)) => {
                         NonTerminal::RepetitionModifiersOpt(
@@ -6868,64 +11060,43 @@ for DefaultBuilder {
                 )
             }
             ProdKind::RepetitionOperatorOpZeroOrMore => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::RepetitionOperatorOp(
                     rustemo_actions::repetition_operator_op_zero_or_more(context),
                 )
             }
             ProdKind::RepetitionOperatorOpZeroOrMoreGreedy => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::RepetitionOperatorOp(
                     rustemo_actions::repetition_operator_op_zero_or_more_greedy(context),
                 )
             }
             ProdKind::RepetitionOperatorOpOneOrMore => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::RepetitionOperatorOp(
                     rustemo_actions::repetition_operator_op_one_or_more(context),
                 )
             }
             ProdKind::RepetitionOperatorOpOneOrMoreGreedy => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::RepetitionOperatorOp(
                     rustemo_actions::repetition_operator_op_one_or_more_greedy(context),
                 )
             }
             ProdKind::RepetitionOperatorOpOptional => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::RepetitionOperatorOp(
                     rustemo_actions::repetition_operator_op_optional(context),
                 )
             }
             ProdKind::RepetitionOperatorOpOptionalGreedy => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::RepetitionOperatorOp(
                     rustemo_actions::repetition_operator_op_optional_greedy(context),
                 )
             }
             ProdKind::RepetitionModifiersP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (_, Symbol::NonTerminal(NonTerminal::RepetitionModifier1(p0)), _) => {
                         NonTerminal::RepetitionModifiers(
@@ -6939,10 +11110,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::RepetitionModifier1P1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::RepetitionModifier1(p0)),
@@ -6957,10 +11125,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::RepetitionModifier1P2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::RepetitionModifier(p0)) => {
                         NonTerminal::RepetitionModifier1(
@@ -6974,12 +11139,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::RepetitionModifierP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::Name(p0)) => {
+                    Symbol::Terminal(Terminal::Name(_, p0)) => {
                         NonTerminal::RepetitionModifier(
                             rustemo_actions::repetition_modifier_name(&*context, p0),
                         )
@@ -6988,12 +11150,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::GrammarSymbolP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::Name(p0)) => {
+                    Symbol::Terminal(Terminal::Name(_, p0)) => {
                         NonTerminal::GrammarSymbol(
                             rustemo_actions::grammar_symbol_name(&*context, p0),
                         )
@@ -7002,12 +11161,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::GrammarSymbolP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::StrConst(p0)) => {
+                    Symbol::Terminal(Terminal::StrConst(_, p0)) => {
                         NonTerminal::GrammarSymbol(
                             rustemo_actions::grammar_symbol_str_const(&*context, p0),
                         )
@@ -7016,12 +11172,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::RecognizerP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::StrConst(p0)) => {
+                    Symbol::Terminal(Terminal::StrConst(_, p0)) => {
                         NonTerminal::Recognizer(
                             rustemo_actions::recognizer_str_const(&*context, p0),
                         )
@@ -7030,12 +11183,9 @@ for DefaultBuilder {
                 }
             }
             ProdKind::RecognizerP2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::RegexTerm(p0)) => {
+                    Symbol::Terminal(Terminal::RegexTerm(_, p0)) => {
                         NonTerminal::Recognizer(
                             rustemo_actions::recognizer_regex_term(&*context, p0),
                         )
@@ -7043,6 +11193,112 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::RecognizerP3 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::RawTerm(p0)) => {
+                        NonTerminal::Recognizer(
+                            rustemo_actions::recognizer_raw_term(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RecognizerP4 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::StrAlternatives(p0)) => {
+                        NonTerminal::Recognizer(
+                            rustemo_actions::recognizer_str_alternatives(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StrAlternativesP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::StrAlternatives(p0)),
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(_, p1)),
+                    ) => {
+                        NonTerminal::StrAlternatives(
+                            rustemo_actions::str_alternatives_c1(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StrAlternativesP2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::StrConst(_, p0)),
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(_, p1)),
+                    ) => {
+                        NonTerminal::StrAlternatives(
+                            rustemo_actions::str_alternatives_c2(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RawTermP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 6usize..);
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(_, p0)),
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(_, p1)),
+                        _,
+                    ) => {
+                        NonTerminal::RawTerm(
+                            rustemo_actions::raw_term_c1(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RawTermP2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 8usize..);
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        _,
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(_, p0)),
+                        _,
+                        Symbol::Terminal(Terminal::StrConst(_, p1)),
+                        _,
+                        _,
+                        _,
+                    ) => {
+                        NonTerminal::RawTerm(
+                            rustemo_actions::raw_term_c2(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             _ => panic!("Reduce of unreachable nonterminal!"),
         };
         self.res_stack.push(Symbol::NonTerminal(prod));
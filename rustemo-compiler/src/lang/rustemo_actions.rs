@@ -2,7 +2,7 @@ use ::rustemo::Context;
 /// This file is maintained by rustemo but can be modified manually.
 /// All manual changes will be preserved except non-doc comments.
 use super::rustemo::{self, TokenKind};
-use crate::rustemo::{Token as BaseToken, ValLoc};
+use crate::rustemo::{Location, Token as BaseToken, ValLoc};
 use std::collections::BTreeMap;
 pub type Name = ValLoc<String>;
 pub type Ctx<'i> = rustemo::Context<'i, str>;
@@ -10,6 +10,19 @@ pub type Token<'i> = BaseToken<'i, str, TokenKind>;
 pub fn name(ctx: &Ctx, token: Token) -> Name {
     Name::new(token.value.into(), Some(ctx.location()))
 }
+pub type MacroName = ValLoc<String>;
+pub fn macro_name(ctx: &Ctx, token: Token) -> MacroName {
+    // Trailing open bracket is a part of the token.
+    MacroName::new(
+        token.value[..token.value.len() - 1].into(),
+        Some(ctx.location()),
+    )
+}
+pub type RustType = ValLoc<String>;
+pub fn rust_type(ctx: &Ctx, token: Token) -> RustType {
+    // Layout after the type is matched by the type regex.
+    RustType::new(token.value.trim_end().into(), Some(ctx.location()))
+}
 pub type RegexTerm = ValLoc<String>;
 pub fn regex_term(ctx: &Ctx, token: Token) -> RegexTerm {
     RegexTerm::new(
@@ -45,9 +58,29 @@ pub type Annotation = ValLoc<String>;
 pub fn annotation(ctx: &Ctx, token: Token) -> Annotation {
     Annotation::new(token.value[1..].into(), Some(ctx.location()))
 }
+/// The text of a `///` comment line after the slashes.
+pub type DocComment = String;
+pub fn doc_comment(_ctx: &Ctx, token: Token) -> DocComment {
+    token.value[3..].trim_end_matches('\r').into()
+}
+/// The Rust code of `%{ ... %}` block without the delimiters.
+pub type ActionsCode = ValLoc<String>;
+pub fn actions_code(ctx: &Ctx, token: Token) -> ActionsCode {
+    ActionsCode::new(
+        token.value[2..token.value.len() - 2].into(),
+        Some(ctx.location()),
+    )
+}
 #[derive(Debug, Clone, Default)]
 pub struct File {
     pub imports: Option<Imports>,
+    pub comment_directives: Option<CommentDirectives>,
+    pub keywords_directives: Option<KeywordsDirectives>,
+    pub start_directives: Option<StartDirectives>,
+    pub ignore_directives: Option<IgnoreDirectives>,
+    pub trivia_directives: Option<TriviaDirectives>,
+    pub terminals_directives: Option<TerminalsDirectives>,
+    pub actions_codes: Option<ActionsCodes>,
     pub grammar_rules: Option<GrammarRules>,
     pub terminal_rules: Option<TerminalRules>,
 }
@@ -57,11 +90,14 @@ pub fn file_grammar_rule1(_ctx: &Ctx, grammar_rule1: GrammarRule1) -> File {
         ..Default::default()
     }
 }
-pub fn file_c2(_ctx: &Ctx, imports: Imports, grammar_rules: GrammarRules) -> File {
+pub fn file_c2(
+    _ctx: &Ctx,
+    headers: FileHeaders,
+    grammar_rules: GrammarRules,
+) -> File {
     File {
-        imports: Some(imports),
         grammar_rules: Some(grammar_rules),
-        terminal_rules: None,
+        ..split_headers(headers)
     }
 }
 pub fn file_c3(
@@ -72,19 +108,19 @@ pub fn file_c3(
     File {
         grammar_rules: Some(grammar_rules),
         terminal_rules: Some(terminal_rules),
-        imports: None,
+        ..Default::default()
     }
 }
 pub fn file_c4(
     _ctx: &Ctx,
-    imports: Imports,
+    headers: FileHeaders,
     grammar_rules: GrammarRules,
     terminal_rules: TerminalRules,
 ) -> File {
     File {
-        imports: Some(imports),
         grammar_rules: Some(grammar_rules),
         terminal_rules: Some(terminal_rules),
+        ..split_headers(headers)
     }
 }
 pub fn file_terminal_rule1(_ctx: &Ctx, terminal_rule1: TerminalRule1) -> File {
@@ -93,20 +129,134 @@ pub fn file_terminal_rule1(_ctx: &Ctx, terminal_rule1: TerminalRule1) -> File {
         ..Default::default()
     }
 }
-pub type ImportStm1 = Vec<ImportStm>;
-pub type Imports = ImportStm1;
-pub fn import_stm1_c1(
+/// Splits file headers to imports, comment, keywords, start, ignore, trivia,
+/// terminals directives and actions code blocks.
+fn split_headers(headers: FileHeaders) -> File {
+    let mut imports = Imports::new();
+    let mut comment_directives = CommentDirectives::new();
+    let mut keywords_directives = KeywordsDirectives::new();
+    let mut start_directives = StartDirectives::new();
+    let mut ignore_directives = IgnoreDirectives::new();
+    let mut trivia_directives = TriviaDirectives::new();
+    let mut terminals_directives = TerminalsDirectives::new();
+    let mut actions_codes = ActionsCodes::new();
+    for header in headers {
+        match header {
+            FileHeader::ImportStm(import) => imports.push(import),
+            FileHeader::CommentDirective(directive) => {
+                comment_directives.push(directive)
+            }
+            FileHeader::KeywordsDirective(directive) => {
+                keywords_directives.push(directive)
+            }
+            FileHeader::StartDirective(directive) => {
+                start_directives.push(directive)
+            }
+            FileHeader::IgnoreDirective(directive) => {
+                ignore_directives.push(directive)
+            }
+            FileHeader::TriviaDirective(directive) => {
+                trivia_directives.push(directive)
+            }
+            FileHeader::TerminalsDirective(directive) => {
+                terminals_directives.push(directive)
+            }
+            FileHeader::ActionsCode(code) => actions_codes.push(code),
+        }
+    }
+    File {
+        imports: (!imports.is_empty()).then_some(imports),
+        comment_directives: (!comment_directives.is_empty())
+            .then_some(comment_directives),
+        keywords_directives: (!keywords_directives.is_empty())
+            .then_some(keywords_directives),
+        start_directives: (!start_directives.is_empty())
+            .then_some(start_directives),
+        ignore_directives: (!ignore_directives.is_empty())
+            .then_some(ignore_directives),
+        trivia_directives: (!trivia_directives.is_empty())
+            .then_some(trivia_directives),
+        terminals_directives: (!terminals_directives.is_empty())
+            .then_some(terminals_directives),
+        actions_codes: (!actions_codes.is_empty()).then_some(actions_codes),
+        ..Default::default()
+    }
+}
+pub type FileHeader1 = Vec<FileHeader>;
+pub type FileHeaders = FileHeader1;
+pub fn file_header1_c1(
     _ctx: &Ctx,
-    mut import_stm1: ImportStm1,
-    import_stm: ImportStm,
-) -> ImportStm1 {
-    import_stm1.push(import_stm);
-    import_stm1
+    mut file_header1: FileHeader1,
+    file_header: FileHeader,
+) -> FileHeader1 {
+    file_header1.push(file_header);
+    file_header1
 }
-pub fn import_stm1_import_stm(_ctx: &Ctx, import_stm: ImportStm) -> ImportStm1 {
-    vec![import_stm]
+pub fn file_header1_file_header(
+    _ctx: &Ctx,
+    file_header: FileHeader,
+) -> FileHeader1 {
+    vec![file_header]
 }
 #[derive(Debug, Clone)]
+pub enum FileHeader {
+    ImportStm(ImportStm),
+    CommentDirective(CommentDirective),
+    KeywordsDirective(KeywordsDirective),
+    StartDirective(StartDirective),
+    IgnoreDirective(IgnoreDirective),
+    TriviaDirective(TriviaDirective),
+    TerminalsDirective(TerminalsDirective),
+    ActionsCode(ActionsCode),
+}
+pub fn file_header_import_stm(_ctx: &Ctx, import_stm: ImportStm) -> FileHeader {
+    FileHeader::ImportStm(import_stm)
+}
+pub fn file_header_comment_directive(
+    _ctx: &Ctx,
+    comment_directive: CommentDirective,
+) -> FileHeader {
+    FileHeader::CommentDirective(comment_directive)
+}
+pub fn file_header_keywords_directive(
+    _ctx: &Ctx,
+    keywords_directive: KeywordsDirective,
+) -> FileHeader {
+    FileHeader::KeywordsDirective(keywords_directive)
+}
+pub fn file_header_start_directive(
+    _ctx: &Ctx,
+    start_directive: StartDirective,
+) -> FileHeader {
+    FileHeader::StartDirective(start_directive)
+}
+pub fn file_header_ignore_directive(
+    _ctx: &Ctx,
+    ignore_directive: IgnoreDirective,
+) -> FileHeader {
+    FileHeader::IgnoreDirective(ignore_directive)
+}
+pub fn file_header_trivia_directive(
+    _ctx: &Ctx,
+    trivia_directive: TriviaDirective,
+) -> FileHeader {
+    FileHeader::TriviaDirective(trivia_directive)
+}
+pub fn file_header_terminals_directive(
+    _ctx: &Ctx,
+    terminals_directive: TerminalsDirective,
+) -> FileHeader {
+    FileHeader::TerminalsDirective(terminals_directive)
+}
+pub fn file_header_actions_code(
+    _ctx: &Ctx,
+    actions_code: ActionsCode,
+) -> FileHeader {
+    FileHeader::ActionsCode(actions_code)
+}
+pub type ActionsCodes = Vec<ActionsCode>;
+pub type Imports = Vec<ImportStm>;
+#[derive(Debug, Clone)]
 pub struct ImportStm {
     pub path: StrConst,
     pub name: Option<Name>,
@@ -120,6 +270,70 @@ pub fn import_stm_c2(_ctx: &Ctx, path: StrConst, name: Name) -> ImportStm {
         name: Some(name),
     }
 }
+pub type CommentDirectives = Vec<CommentDirective>;
+#[derive(Debug, Clone)]
+pub enum CommentDirective {
+    Line { start: StrConst },
+    Block { start: StrConst, end: StrConst },
+}
+pub fn comment_directive_c1(_ctx: &Ctx, start: StrConst) -> CommentDirective {
+    CommentDirective::Line { start }
+}
+pub fn comment_directive_c2(
+    _ctx: &Ctx,
+    start: StrConst,
+    end: StrConst,
+) -> CommentDirective {
+    CommentDirective::Block { start, end }
+}
+pub type KeywordsDirectives = Vec<KeywordsDirective>;
+#[derive(Debug, Clone)]
+pub struct KeywordsDirective {
+    pub ident: Name,
+    pub keywords: Name1,
+}
+pub fn keywords_directive_c1(
+    _ctx: &Ctx,
+    ident: Name,
+    keywords: Name1,
+) -> KeywordsDirective {
+    KeywordsDirective { ident, keywords }
+}
+pub type StartDirectives = Vec<StartDirective>;
+#[derive(Debug, Clone)]
+pub struct StartDirective {
+    pub start: Name,
+}
+pub fn start_directive_c1(_ctx: &Ctx, start: Name) -> StartDirective {
+    StartDirective { start }
+}
+pub type IgnoreDirectives = Vec<IgnoreDirective>;
+#[derive(Debug, Clone)]
+pub struct IgnoreDirective {
+    pub terminals: Name1,
+}
+pub fn ignore_directive_c1(_ctx: &Ctx, terminals: Name1) -> IgnoreDirective {
+    IgnoreDirective { terminals }
+}
+pub type TriviaDirectives = Vec<TriviaDirective>;
+#[derive(Debug, Clone)]
+pub struct TriviaDirective {
+    pub terminals: Name1,
+}
+pub fn trivia_directive_c1(_ctx: &Ctx, terminals: Name1) -> TriviaDirective {
+    TriviaDirective { terminals }
+}
+pub type TerminalsDirectives = Vec<TerminalsDirective>;
+#[derive(Debug, Clone)]
+pub struct TerminalsDirective {
+    pub path: StrConst,
+}
+pub fn terminals_directive_c1(
+    _ctx: &Ctx,
+    path: StrConst,
+) -> TerminalsDirective {
+    TerminalsDirective { path }
+}
 pub type GrammarRule1 = Vec<GrammarRule>;
 pub type GrammarRules = GrammarRule1;
 pub fn grammar_rule1_c1(
@@ -138,38 +352,98 @@ pub fn grammar_rule1_grammar_rule(
 }
 #[derive(Debug, Clone)]
 pub struct GrammarRule {
+    /// Lines of the doc comments preceding the rule.
+    pub doc: DocComment1,
     pub annotation: Option<Annotation>,
     pub name: Name,
+    /// Parameters of the rule macro. `None` for regular rules.
+    pub params: Option<Name1>,
     pub rhs: GrammarRuleRHS,
     pub meta: ProdMetaDatas,
 }
 pub fn grammar_rule_c1(
     _ctx: &Ctx,
+    doc: DocComment0,
     annotation: Option<Annotation>,
     name: Name,
     rhs: GrammarRuleRHS,
 ) -> GrammarRule {
     GrammarRule {
+        doc: doc.unwrap_or_default(),
         annotation,
         name,
+        params: None,
         rhs,
         meta: ProdMetaDatas::new(),
     }
 }
 pub fn grammar_rule_c2(
     _ctx: &Ctx,
+    doc: DocComment0,
     annotation: Option<Annotation>,
     name: Name,
     meta: ProdMetaDatas,
     rhs: GrammarRuleRHS,
 ) -> GrammarRule {
     GrammarRule {
+        doc: doc.unwrap_or_default(),
         annotation,
         name,
+        params: None,
         rhs,
         meta,
     }
 }
+pub fn grammar_rule_c3(
+    _ctx: &Ctx,
+    doc: DocComment0,
+    annotation: Option<Annotation>,
+    name: MacroName,
+    params: Name1,
+    rhs: GrammarRuleRHS,
+) -> GrammarRule {
+    GrammarRule {
+        doc: doc.unwrap_or_default(),
+        annotation,
+        name,
+        params: Some(params),
+        rhs,
+        meta: ProdMetaDatas::new(),
+    }
+}
+pub type Name1 = Vec<Name>;
+pub fn name1_c1(_ctx: &Ctx, mut name1: Name1, name: Name) -> Name1 {
+    name1.push(name);
+    name1
+}
+pub fn name1_name(_ctx: &Ctx, name: Name) -> Name1 {
+    vec![name]
+}
+pub type DocComment1 = Vec<DocComment>;
+pub fn doc_comment1_c1(
+    _ctx: &Ctx,
+    mut doc_comment1: DocComment1,
+    doc_comment: DocComment,
+) -> DocComment1 {
+    doc_comment1.push(doc_comment);
+    doc_comment1
+}
+pub fn doc_comment1_doc_comment(
+    _ctx: &Ctx,
+    doc_comment: DocComment,
+) -> DocComment1 {
+    vec![doc_comment]
+}
+pub type DocComment0 = Option<DocComment1>;
+pub fn doc_comment0_doc_comment1(
+    _ctx: &Ctx,
+    doc_comment1: DocComment1,
+) -> DocComment0 {
+    Some(doc_comment1)
+}
+pub fn doc_comment0_empty(_ctx: &Ctx) -> DocComment0 {
+    None
+}
 pub type AnnotationOpt = Option<Annotation>;
 pub fn annotation_opt_annotation(_ctx: &Ctx, annotation: Annotation) -> AnnotationOpt {
     Some(annotation)
@@ -196,19 +470,25 @@ pub fn grammar_rule_rhs_production(
 pub struct Production {
     pub assignments: Assignments,
     pub meta: ProdMetaDatas,
+    pub location: Option<Location>,
 }
-pub fn production_assignment1(_ctx: &Ctx, assignments: Assignment1) -> Production {
+pub fn production_assignment1(ctx: &Ctx, assignments: Assignment1) -> Production {
     Production {
         assignments,
         meta: ProdMetaDatas::new(),
+        location: Some(ctx.location()),
     }
 }
 pub fn production_c2(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     assignments: Assignments,
     meta: ProdMetaDatas,
 ) -> Production {
-    Production { assignments, meta }
+    Production {
+        assignments,
+        meta,
+        location: Some(ctx.location()),
+    }
 }
 pub type TerminalRule1 = Vec<TerminalRule>;
 pub type TerminalRules = TerminalRule1;
@@ -228,63 +508,97 @@ pub fn terminal_rule1_terminal_rule(
 }
 #[derive(Debug, Clone)]
 pub struct TerminalRule {
+    /// Lines of the doc comments preceding the rule.
+    pub doc: DocComment1,
     pub name: Name,
     pub annotation: Option<Annotation>,
     pub recognizer: Option<Recognizer>,
+    pub terminal_type: Option<Box<TerminalType>>,
     pub meta: TermMetaDatas,
 }
 pub fn terminal_rule_c1(
     _ctx: &Ctx,
+    doc: DocComment0,
     annotation: Option<Annotation>,
     name: Name,
     recognizer: Recognizer,
+    terminal_type: Option<TerminalType>,
 ) -> TerminalRule {
     TerminalRule {
+        doc: doc.unwrap_or_default(),
         annotation,
         name,
         recognizer: Some(recognizer),
+        terminal_type: terminal_type.map(Box::new),
         meta: TermMetaDatas::new(),
     }
 }
 pub fn terminal_rule_c2(
     _ctx: &Ctx,
+    doc: DocComment0,
     annotation: Option<Annotation>,
     name: Name,
 ) -> TerminalRule {
     TerminalRule {
+        doc: doc.unwrap_or_default(),
         annotation,
         name,
         recognizer: None,
+        terminal_type: None,
         meta: TermMetaDatas::new(),
     }
 }
 pub fn terminal_rule_c3(
     _ctx: &Ctx,
+    doc: DocComment0,
     annotation: Option<Annotation>,
     name: Name,
     recognizer: Recognizer,
+    terminal_type: Option<TerminalType>,
     meta: TermMetaDatas,
 ) -> TerminalRule {
     TerminalRule {
+        doc: doc.unwrap_or_default(),
         annotation,
         name,
         recognizer: Some(recognizer),
+        terminal_type: terminal_type.map(Box::new),
         meta,
     }
 }
 pub fn terminal_rule_c4(
     _ctx: &Ctx,
+    doc: DocComment0,
     annotation: Option<Annotation>,
     name: Name,
     meta: TermMetaDatas,
 ) -> TerminalRule {
     TerminalRule {
+        doc: doc.unwrap_or_default(),
         annotation,
         name,
         recognizer: None,
+        terminal_type: None,
         meta,
     }
 }
+pub type TerminalTypeOpt = Option<TerminalType>;
+pub fn terminal_type_opt_terminal_type(
+    _ctx: &Ctx,
+    terminal_type: TerminalType,
+) -> TerminalTypeOpt {
+    Some(terminal_type)
+}
+pub fn terminal_type_opt_empty(_ctx: &Ctx) -> TerminalTypeOpt {
+    None
+}
+pub type TerminalType = RustType;
+pub fn terminal_type_rust_type(
+    _ctx: &Ctx,
+    rust_type: RustType,
+) -> TerminalType {
+    rust_type
+}
 pub type ProdMetaData = BTreeMap<String, ConstVal>;
 pub fn prod_meta_data_left(_ctx: &Ctx) -> ProdMetaData {
     ProdMetaData::from([("left".into(), ConstVal::Bool(true.into()))])
@@ -307,9 +621,24 @@ pub fn prod_meta_data_nops(_ctx: &Ctx) -> ProdMetaData {
 pub fn prod_meta_data_nopse(_ctx: &Ctx) -> ProdMetaData {
     ProdMetaData::from([("nopse".into(), ConstVal::Bool(true.into()))])
 }
+pub fn prod_meta_data_no_layout(_ctx: &Ctx) -> ProdMetaData {
+    ProdMetaData::from([("nolayout".into(), ConstVal::Bool(true.into()))])
+}
+pub fn prod_meta_data_predicate(_ctx: &Ctx, predicate: Name) -> ProdMetaData {
+    ProdMetaData::from([("predicate".into(), ConstVal::String(predicate))])
+}
 pub fn prod_meta_data_priority(_ctx: &Ctx, prio: IntConst) -> ProdMetaData {
     ProdMetaData::from([("priority".into(), ConstVal::Int(prio))])
 }
+pub fn prod_meta_data_prec(_ctx: &Ctx, prio: IntConst) -> ProdMetaData {
+    ProdMetaData::from([("priority".into(), ConstVal::Int(prio))])
+}
+pub fn prod_meta_data_assoc_left(_ctx: &Ctx) -> ProdMetaData {
+    ProdMetaData::from([("left".into(), ConstVal::Bool(true.into()))])
+}
+pub fn prod_meta_data_assoc_right(_ctx: &Ctx) -> ProdMetaData {
+    ProdMetaData::from([("right".into(), ConstVal::Bool(true.into()))])
+}
 pub fn prod_meta_data_user_meta_data(_ctx: &Ctx, user: UserMetaData) -> ProdMetaData {
     ProdMetaData::from([(user.name.into(), user.value)])
 }
@@ -475,6 +804,9 @@ pub struct GrammarSymbolRef {
     pub repetition_op: RepetitionOperatorOpt,
     #[allow(dead_code)]
     pub production_group: Option<ProductionGroup>,
+    /// Rule macro instantiation. Replaced by the reference to the
+    /// instantiated rule during grammar construction.
+    pub macro_call: Option<Box<MacroCall>>,
 }
 pub fn grammar_symbol_ref_c1(
     _ctx: &Ctx,
@@ -485,6 +817,7 @@ pub fn grammar_symbol_ref_c1(
         gsymbol: Some(gsymbol),
         repetition_op: repetition_operator,
         production_group: None,
+        macro_call: None,
     }
 }
 pub fn grammar_symbol_ref_c2(
@@ -496,9 +829,30 @@ pub fn grammar_symbol_ref_c2(
         gsymbol: None,
         repetition_op: repetition_operator,
         production_group: Some(production_group),
+        macro_call: None,
+    }
+}
+pub fn grammar_symbol_ref_c3(
+    _ctx: &Ctx,
+    macro_call: MacroCall,
+    repetition_operator: RepetitionOperatorOpt,
+) -> GrammarSymbolRef {
+    GrammarSymbolRef {
+        gsymbol: None,
+        repetition_op: repetition_operator,
+        production_group: None,
+        macro_call: Some(Box::new(macro_call)),
     }
 }
 #[derive(Debug, Clone)]
+pub struct MacroCall {
+    pub name: MacroName,
+    pub args: Name1,
+}
+pub fn macro_call_c1(_ctx: &Ctx, name: MacroName, args: Name1) -> MacroCall {
+    MacroCall { name, args }
+}
+#[derive(Debug, Clone)]
 pub struct RepetitionOperator {
     pub rep_op: RepetitionOperatorOp,
     pub rep_modifiers: Option<RepetitionModifiers>,
@@ -601,6 +955,8 @@ pub fn grammar_symbol_str_const(_ctx: &Ctx, str_const: StrConst) -> GrammarSymbo
 pub enum Recognizer {
     StrConst(StrConst),
     RegexTerm(RegexTerm),
+    RawTerm(Box<RawTerm>),
+    StrAlternatives(StrAlternatives),
 }
 pub fn recognizer_str_const(_ctx: &Ctx, str_const: StrConst) -> Recognizer {
     Recognizer::StrConst(str_const)
@@ -608,3 +964,48 @@ pub fn recognizer_str_const(_ctx: &Ctx, str_const: StrConst) -> Recognizer {
 pub fn recognizer_regex_term(_ctx: &Ctx, regex_term: RegexTerm) -> Recognizer {
     Recognizer::RegexTerm(regex_term)
 }
+pub fn recognizer_raw_term(_ctx: &Ctx, raw_term: RawTerm) -> Recognizer {
+    Recognizer::RawTerm(Box::new(raw_term))
+}
+pub fn recognizer_str_alternatives(
+    _ctx: &Ctx,
+    str_alternatives: StrAlternatives,
+) -> Recognizer {
+    Recognizer::StrAlternatives(str_alternatives)
+}
+pub type StrAlternatives = Vec<StrConst>;
+pub fn str_alternatives_c1(
+    _ctx: &Ctx,
+    mut str_alternatives: StrAlternatives,
+    str_const: StrConst,
+) -> StrAlternatives {
+    str_alternatives.push(str_const);
+    str_alternatives
+}
+pub fn str_alternatives_c2(
+    _ctx: &Ctx,
+    str_const_1: StrConst,
+    str_const_3: StrConst,
+) -> StrAlternatives {
+    vec![str_const_1, str_const_3]
+}
+#[derive(Debug, Clone)]
+pub struct RawTerm {
+    pub start: StrConst,
+    pub end: StrConst,
+    pub nested: bool,
+}
+pub fn raw_term_c1(_ctx: &Ctx, start: StrConst, end: StrConst) -> RawTerm {
+    RawTerm {
+        start,
+        end,
+        nested: false,
+    }
+}
+pub fn raw_term_c2(_ctx: &Ctx, start: StrConst, end: StrConst) -> RawTerm {
+    RawTerm {
+        start,
+        end,
+        nested: true,
+    }
+}
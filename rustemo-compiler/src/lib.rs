@@ -18,7 +18,8 @@
 //! # Processing grammars
 //!
 //! For default settings there are [process_crate_dir], [process_dir] and
-//! [process_grammar] shortcut functions.
+//! [process_grammar] shortcut functions. Grammars embedded in the Rust source
+//! can be processed with [generate_parser_from_source].
 //!
 //! ## Example
 //!
@@ -36,11 +37,12 @@ extern crate rustemo;
 pub mod grammar;
 pub mod utils;
 
+pub use crate::generator::generate_parser_from_source;
 pub use crate::settings::{
     process_crate_dir, process_dir, process_grammar, BuilderType,
-    GeneratorTableType, LexerType, ParserAlgo, Settings,
+    GeneratorTableType, LexerType, ParserAlgo, Settings, Visibility,
 };
-pub use crate::table::TableType;
+pub use crate::table::{TableMetrics, TableType};
 
 pub use crate::error::Error;
 pub use crate::error::Result;
@@ -6,6 +6,7 @@ use clap::Parser;
 use colored::Colorize;
 use rustemo_compiler::{
     BuilderType, GeneratorTableType, LexerType, ParserAlgo, Settings, TableType,
+    Visibility,
 };
 
 #[derive(Parser)]
@@ -24,6 +25,14 @@ struct Cli {
     #[clap(long, action)]
     dot: bool,
 
+    /// Write LR states to a human-readable .automaton file
+    #[clap(long, action)]
+    dump_automaton: bool,
+
+    /// Cache the LR table next to the generated parser
+    #[clap(long, action)]
+    table_cache: bool,
+
     /// Do not generate actions
     #[clap(short, long, action)]
     noactions: bool,
@@ -52,6 +61,10 @@ struct Cli {
     #[clap(long)]
     no_shifts_over_empty: bool,
 
+    /// Resolve reduce/reduce conflicts by production order.
+    #[clap(long)]
+    resolve_rr_by_order: bool,
+
     /// The type of LR table
     #[clap(short, long, arg_enum, default_value_t)]
     table_type: TableType,
@@ -60,6 +73,55 @@ struct Cli {
     #[clap(short, long, arg_enum, default_value_t)]
     parser_algo: ParserAlgo,
 
+    /// Generate GLR parser if the LR table has conflicts.
+    #[clap(long)]
+    glr_fallback: bool,
+
+    /// Report parse stack inconsistencies as errors instead of panicking.
+    #[clap(long)]
+    no_panic: bool,
+
+    /// Generate actions returning `Result` for semantic validation.
+    #[clap(long)]
+    fallible_actions: bool,
+
+    /// Generate `parse_iter` yielding the items of the start rule repetition.
+    #[clap(long)]
+    streaming: bool,
+
+    /// Generate `parse_<rule>` test methods for each grammar rule.
+    #[clap(long)]
+    rule_parsers: bool,
+
+    /// Generate `parse_generic` method building the generic tree.
+    #[clap(long)]
+    generic_tree: bool,
+
+    /// Generate async `parse_stream` method reading an async byte stream to
+    /// its end and then parsing it.
+    #[clap(long)]
+    async_stream: bool,
+
+    /// Content terminals borrow from the input instead of allocating.
+    #[clap(long)]
+    borrowed_terminals: bool,
+
+    /// Derive `PartialEq`, `Eq` and `Hash` for the generated AST types.
+    #[clap(long)]
+    derive_hash_eq: bool,
+
+    /// Implement `FromStr` for the type of the root rule.
+    #[clap(long)]
+    impl_fromstr: bool,
+
+    /// Name production kinds by the kind given in the grammar only.
+    #[clap(long)]
+    bare_prod_kinds: bool,
+
+    /// Collect parsing statistics in the generated parser.
+    #[clap(long)]
+    profile: bool,
+
     /// Parser generator table type
     #[clap(short, long, arg_enum, default_value_t)]
     generator_table_type: GeneratorTableType,
@@ -72,6 +134,31 @@ struct Cli {
     #[clap(short, long, default_value = "str")]
     input_type: String,
 
+    /// The type of token attributes provided by the custom lexer.
+    #[clap(long)]
+    token_attr_type: Option<String>,
+
+    /// Inner attributes and doc comments added at the top of the generated
+    /// files.
+    #[clap(long)]
+    file_header: Option<String>,
+
+    /// Strip the leading byte order mark in `parse_preprocessed`.
+    #[clap(long)]
+    strip_bom: bool,
+
+    /// Replace CRLF line endings with LF in `parse_preprocessed`.
+    #[clap(long)]
+    normalize_newlines: bool,
+
+    /// Normalize the input to Unicode NFC in `parse_normalized`.
+    #[clap(long)]
+    normalize_nfc: bool,
+
+    /// Visibility of the generated parser items.
+    #[clap(long, arg_enum, default_value_t)]
+    visibility: Visibility,
+
     /// Generated builder type.
     #[clap(short, long, arg_enum, default_value_t)]
     builder_type: BuilderType,
@@ -92,6 +179,38 @@ struct Cli {
     #[clap(long)]
     fancy_regex: bool,
 
+    /// Compile regex terminals into a single RegexSet for faster lexing.
+    #[clap(long)]
+    regex_set: bool,
+
+    /// Recognize terminals without recognizer by user provided functions.
+    #[clap(long)]
+    custom_recognizers: bool,
+
+    /// Generate partial token recognition for the incremental lexer.
+    #[clap(long)]
+    incremental_lexer: bool,
+
+    /// Generate Cargo.toml and lib.rs to make the output dir a crate.
+    #[clap(long)]
+    standalone_crate: bool,
+
+    /// Exclude synthetic non-terminals (EMPTY, AUG, AUGL) from NonTermKind.
+    #[clap(long)]
+    exclude_synthetic: bool,
+
+    /// Dispatch reductions of the default builder through a function table.
+    #[clap(long)]
+    reduce_dispatch_table: bool,
+
+    /// Collapse chain productions in the generic tree.
+    #[clap(long)]
+    collapse_chains: bool,
+
+    /// Generate no_std compatible code. Requires custom lexer.
+    #[clap(long)]
+    no_std: bool,
+
     /// Parser can succeed without consuming the whole input.
     #[clap(long)]
     partial_parse: bool,
@@ -104,10 +223,22 @@ struct Cli {
     #[clap(long)]
     print_table: bool,
 
+    /// Rename grammar symbols. Given as `from=to`.
+    #[clap(long, value_parser)]
+    rename: Vec<String>,
+
     /// Exclude dirs containing these parts. Used with dir processing.
     #[clap(short, long, value_parser)]
     exclude: Vec<String>,
 
+    /// The extension of grammar files. Used with dir processing.
+    #[clap(long, default_value = "rustemo")]
+    grammar_extension: String,
+
+    /// Skip files which are not valid grammars. Used with dir processing.
+    #[clap(long)]
+    skip_invalid_grammars: bool,
+
     /// Verbosity level 0-2
     #[clap(short, long, parse(from_occurrences))]
     verbosity: usize,
@@ -119,22 +250,57 @@ fn main() {
     let mut settings = Settings::new()
         .force(cli.force)
         .dot(cli.dot)
+        .dump_automaton(cli.dump_automaton)
+        .table_cache(cli.table_cache)
         .actions(!cli.noactions)
         .notrace(cli.notrace)
         .exclude(cli.exclude)
+        .grammar_extension(cli.grammar_extension)
+        .skip_invalid_grammars(cli.skip_invalid_grammars)
         .prefer_shifts(cli.prefer_shifts)
         .prefer_shifts_over_empty(!cli.no_shifts_over_empty)
+        .resolve_rr_by_order(cli.resolve_rr_by_order)
         .fancy_regex(cli.fancy_regex)
+        .regex_set(cli.regex_set)
+        .custom_recognizers(cli.custom_recognizers)
+        .incremental_lexer(cli.incremental_lexer)
+        .standalone_crate(cli.standalone_crate)
+        .exclude_synthetic(cli.exclude_synthetic)
+        .reduce_dispatch_table(cli.reduce_dispatch_table)
+        .collapse_chains(cli.collapse_chains)
+        .no_std(cli.no_std)
         .partial_parse(cli.partial_parse)
         .skip_ws(!cli.no_skip_ws)
         .table_type(cli.table_type)
         .print_table(cli.print_table)
         .parser_algo(cli.parser_algo)
+        .glr_fallback(cli.glr_fallback)
+        .no_panic(cli.no_panic)
+        .fallible_actions(cli.fallible_actions)
+        .streaming(cli.streaming)
+        .rule_parsers(cli.rule_parsers)
+        .generic_tree(cli.generic_tree)
+        .async_stream(cli.async_stream)
+        .borrowed_terminals(cli.borrowed_terminals)
+        .derive_hash_eq(cli.derive_hash_eq)
+        .impl_fromstr(cli.impl_fromstr)
+        .bare_prod_kinds(cli.bare_prod_kinds)
+        .profile(cli.profile)
         .generator_table_type(cli.generator_table_type)
         .lexer_type(cli.lexer_type)
         .builder_type(cli.builder_type)
-        .input_type(cli.input_type);
-
+        .visibility(cli.visibility)
+        .input_type(cli.input_type)
+        .strip_bom(cli.strip_bom)
+        .normalize_newlines(cli.normalize_newlines)
+        .normalize_nfc(cli.normalize_nfc);
+
+    if let Some(token_attr_type) = cli.token_attr_type {
+        settings = settings.token_attr_type(token_attr_type)
+    }
+    if let Some(file_header) = cli.file_header {
+        settings = settings.file_header(file_header)
+    }
     if let Some(most_specific) = cli.lexical_disamb_most_specific {
         settings = settings.lexical_disamb_most_specific(most_specific)
     }
@@ -145,6 +311,18 @@ fn main() {
         settings = settings.lexical_disamb_grammar_order(grammar_order)
     }
 
+    for rename in cli.rename {
+        match rename.split_once('=') {
+            Some((from, to)) => {
+                settings = settings.rename(from.into(), to.into())
+            }
+            None => {
+                println!("{}", format!("Invalid rename '{rename}'.").red());
+                return;
+            }
+        }
+    }
+
     if let Some(outdir_root) = cli.outdir_root {
         settings = settings.out_dir_root(outdir_root);
     }
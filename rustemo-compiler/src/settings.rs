@@ -1,11 +1,18 @@
 use clap::clap_derive::ArgEnum;
+use colored::Colorize;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+#[cfg(feature = "watch")]
+use std::collections::BTreeSet;
 use std::fs;
+#[cfg(feature = "watch")]
+use std::{ops::ControlFlow, sync::mpsc, time::Duration};
 
-use crate::table::TableType;
+use crate::table::{TableMetrics, TableType};
 use crate::{Error, Result};
 use std::path::{Path, PathBuf};
 
-use crate::generator::generate_parser;
+use crate::generator::{generate_parser, suggest_fixes, table_metrics};
 
 /// The parsing algorithm used
 #[derive(Debug, Default, Clone, ArgEnum)]
@@ -37,6 +44,19 @@ pub enum BuilderType {
     Custom,
 }
 
+/// Visibility of the items in the generated parser
+#[derive(Debug, Default, Clone, ArgEnum)]
+pub enum Visibility {
+    /// Items are public
+    #[default]
+    Pub,
+    /// Items are visible only in the crate where the parser is included
+    PubCrate,
+    /// Items are visible only in the module where the parser module is
+    /// declared (e.g. with `rustemo_mod!`) and its submodules
+    Private,
+}
+
 /// Different generated parser table variants with different trade-offs
 #[derive(Debug, Default, Clone, ArgEnum)]
 pub enum GeneratorTableType {
@@ -71,7 +91,7 @@ pub enum GeneratorTableType {
 ///
 /// ## Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// rustemo_compiler::Settings::new().parser_algo(ParserAlgo::GLR).process_crate_dir()
 /// ```
 #[derive(Debug, Clone)]
@@ -82,10 +102,13 @@ pub struct Settings {
 
     pub(crate) prefer_shifts: bool,
     pub(crate) prefer_shifts_over_empty: bool,
+    pub(crate) resolve_rr_by_order: bool,
     pub(crate) table_type: TableType,
     pub(crate) parser_algo: ParserAlgo,
     pub(crate) print_table: bool,
     pub(crate) exclude: Vec<String>,
+    pub(crate) grammar_extension: String,
+    pub(crate) skip_invalid_grammars: bool,
     pub(crate) actions: bool,
     pub(crate) notrace: bool,
 
@@ -93,6 +116,11 @@ pub struct Settings {
     pub(crate) builder_type: BuilderType,
     pub(crate) generator_table_type: GeneratorTableType,
     pub(crate) input_type: String,
+    pub(crate) token_attr_type: Option<String>,
+    pub(crate) file_header: Option<String>,
+    pub(crate) strip_bom: bool,
+    pub(crate) normalize_newlines: bool,
+    pub(crate) normalize_nfc: bool,
 
     pub(crate) lexical_disamb_most_specific: bool,
     pub(crate) lexical_disamb_longest_match: bool,
@@ -100,12 +128,37 @@ pub struct Settings {
 
     pub(crate) partial_parse: bool,
     pub(crate) skip_ws: bool,
+    pub(crate) glr_fallback: bool,
+    pub(crate) no_panic: bool,
+    pub(crate) fallible_actions: bool,
+    pub(crate) streaming: bool,
+    pub(crate) rule_parsers: bool,
+    pub(crate) generic_tree: bool,
+    pub(crate) async_stream: bool,
+    pub(crate) borrowed_terminals: bool,
+    pub(crate) derive_hash_eq: bool,
+    pub(crate) impl_fromstr: bool,
+    pub(crate) bare_prod_kinds: bool,
+    pub(crate) profile: bool,
 
     pub(crate) force: bool,
     force_explicit: bool,
 
+    pub(crate) visibility: Visibility,
+
     pub(crate) dot: bool,
+    pub(crate) dump_automaton: bool,
+    pub(crate) table_cache: bool,
     pub(crate) fancy_regex: bool,
+    pub(crate) regex_set: bool,
+    pub(crate) custom_recognizers: bool,
+    pub(crate) incremental_lexer: bool,
+    pub(crate) standalone_crate: bool,
+    pub(crate) exclude_synthetic: bool,
+    pub(crate) reduce_dispatch_table: bool,
+    pub(crate) collapse_chains: bool,
+    pub(crate) no_std: bool,
+    pub(crate) rename: BTreeMap<String, String>,
 }
 
 impl Default for Settings {
@@ -125,6 +178,7 @@ impl Default for Settings {
             out_dir_actions_root: out_dir_root,
             prefer_shifts: false,
             prefer_shifts_over_empty: true,
+            resolve_rr_by_order: false,
             table_type: Default::default(),
             parser_algo: Default::default(),
             print_table: false,
@@ -134,16 +188,47 @@ impl Default for Settings {
             builder_type: Default::default(),
             generator_table_type: Default::default(),
             input_type: "str".into(),
+            token_attr_type: None,
+            file_header: None,
+            strip_bom: false,
+            normalize_newlines: false,
+            normalize_nfc: false,
             lexical_disamb_most_specific: true,
             lexical_disamb_longest_match: true,
             lexical_disamb_grammar_order: true,
             partial_parse: false,
             skip_ws: true,
+            glr_fallback: false,
+            no_panic: false,
+            fallible_actions: false,
+            streaming: false,
+            rule_parsers: false,
+            generic_tree: false,
+            async_stream: false,
+            borrowed_terminals: false,
+            derive_hash_eq: false,
+            impl_fromstr: false,
+            bare_prod_kinds: false,
+            profile: false,
             force: true, // Overwriting actions by default
             force_explicit: false,
             exclude: vec![],
+            grammar_extension: "rustemo".into(),
+            skip_invalid_grammars: false,
+            visibility: Default::default(),
             dot: false,
+            dump_automaton: false,
+            table_cache: false,
             fancy_regex: false,
+            regex_set: false,
+            custom_recognizers: false,
+            incremental_lexer: false,
+            standalone_crate: false,
+            exclude_synthetic: false,
+            reduce_dispatch_table: false,
+            collapse_chains: false,
+            no_std: false,
+            rename: BTreeMap::new(),
         }
     }
 }
@@ -210,6 +295,24 @@ impl Settings {
         self
     }
 
+    /// The extension of the files treated as grammars during dir processing.
+    /// Given without the leading dot. `rustemo` by default.
+    pub fn grammar_extension(mut self, grammar_extension: String) -> Self {
+        self.grammar_extension = grammar_extension;
+        self
+    }
+
+    /// During dir processing, skip the files which can't be parsed as Rustemo
+    /// grammars instead of aborting. The skipped files are reported as warnings
+    /// at the end of processing. `false` by default.
+    pub fn skip_invalid_grammars(
+        mut self,
+        skip_invalid_grammars: bool,
+    ) -> Self {
+        self.skip_invalid_grammars = skip_invalid_grammars;
+        self
+    }
+
     /// When there are competing REDUCE and SHIFT operations, this settings will
     /// always favor SHIFT.
     pub fn prefer_shifts(mut self, prefer: bool) -> Self {
@@ -224,6 +327,14 @@ impl Settings {
         self
     }
 
+    /// Resolve REDUCE/REDUCE conflicts which can't be resolved by priorities in
+    /// favor of the production defined first in the grammar (the lowest
+    /// production index), like yacc does. Each resolution is reported.
+    pub fn resolve_rr_by_order(mut self, resolve: bool) -> Self {
+        self.resolve_rr_by_order = resolve;
+        self
+    }
+
     /// LR table type to construct.
     pub fn table_type(mut self, table_type: TableType) -> Self {
         self.table_type = table_type;
@@ -276,6 +387,51 @@ impl Settings {
         self
     }
 
+    /// Sets the type of token attributes provided by the custom lexer. The
+    /// attributes are available in actions as `token.attr`. Used only with the
+    /// custom lexer and LR algorithm. By default tokens have no attributes.
+    pub fn token_attr_type(mut self, token_attr_type: String) -> Self {
+        self.token_attr_type = Some(token_attr_type);
+        self
+    }
+
+    /// Sets the header of the generated files, e.g. `#![allow(clippy::all)]` or
+    /// `//! Parser of the foo language.`. The header may contain only inner
+    /// attributes and inner doc comments which are added at the top of the
+    /// generated parser file and newly created actions file. By default there
+    /// is no header.
+    pub fn file_header(mut self, file_header: String) -> Self {
+        self.file_header = Some(file_header);
+        self
+    }
+
+    /// Strip the leading byte order mark (BOM) from the input. Used by the
+    /// generated `parse_preprocessed` method. Used only with `str` input.
+    /// `false` by default.
+    pub fn strip_bom(mut self, strip_bom: bool) -> Self {
+        self.strip_bom = strip_bom;
+        self
+    }
+
+    /// Replace CRLF line endings in the input with LF. Used by the generated
+    /// `parse_preprocessed` method. Used only with `str` input. `false` by
+    /// default.
+    pub fn normalize_newlines(mut self, normalize_newlines: bool) -> Self {
+        self.normalize_newlines = normalize_newlines;
+        self
+    }
+
+    /// Normalize the input to Unicode NFC before lexing so that canonically
+    /// equivalent texts (e.g. identifiers in NFC and NFD) are parsed the same.
+    /// Used by the generated `parse_normalized` method which also returns the
+    /// map of positions back to the input. Used only with `str` input and
+    /// requires `unicode-normalization` feature of `rustemo`. `false` by
+    /// default.
+    pub fn normalize_nfc(mut self, normalize_nfc: bool) -> Self {
+        self.normalize_nfc = normalize_nfc;
+        self
+    }
+
     /// Lexical disambiguation using most specific match strategy.
     pub fn lexical_disamb_most_specific(mut self, most_specific: bool) -> Self {
         self.lexical_disamb_most_specific = most_specific;
@@ -306,6 +462,87 @@ impl Settings {
         self
     }
 
+    /// Compile all regex terminals into a single
+    /// [`RegexSet`](https://docs.rs/regex/latest/regex/struct.RegexSet.html).
+    /// The default lexer scans the input once with the set at each position
+    /// and tries only the regex terminals matched by the set. Useful for
+    /// grammars with many regex terminals. Can't be used with `fancy_regex`.
+    /// `false` by default.
+    pub fn regex_set(mut self, regex_set: bool) -> Self {
+        self.regex_set = regex_set;
+        self
+    }
+
+    /// Terminals without a recognizer (e.g. `Body: ;`) are recognized by the
+    /// user provided functions from `<grammar>_recognizers` module. The
+    /// functions get the parsing context and the whole input and can be used
+    /// for context-sensitive lexing. Used only with the default lexer. `false`
+    /// by default.
+    pub fn custom_recognizers(mut self, custom_recognizers: bool) -> Self {
+        self.custom_recognizers = custom_recognizers;
+        self
+    }
+
+    /// Generate the implementation of `PartialTokenRecognizer` for the token
+    /// recognizers so that they can be used with `IncrementalLexer` for the
+    /// input arriving in chunks. Used only with the default lexer. `false` by
+    /// default.
+    pub fn incremental_lexer(mut self, incremental_lexer: bool) -> Self {
+        self.incremental_lexer = incremental_lexer;
+        self
+    }
+
+    /// Generate `Cargo.toml` and `lib.rs` in the parser output dir so that the
+    /// output dir is a crate which can be distributed on its own. The actions
+    /// must be generated in the same dir as the parser. `false` by default.
+    pub fn standalone_crate(mut self, standalone_crate: bool) -> Self {
+        self.standalone_crate = standalone_crate;
+        self
+    }
+
+    /// Exclude synthetic non-terminals (`EMPTY`, `AUG` and `AUGL`) from the
+    /// generated `NonTermKind`. They are internal to the LR table and never
+    /// reduced by the parser. The remaining variants keep their discriminants.
+    /// `false` by default.
+    pub fn exclude_synthetic(mut self, exclude_synthetic: bool) -> Self {
+        self.exclude_synthetic = exclude_synthetic;
+        self
+    }
+
+    /// Generate a function for each reduction in the default builder and
+    /// dispatch reductions through an array of these functions indexed by the
+    /// production instead of a single `match`. May be faster for grammars with
+    /// many productions. `false` by default.
+    pub fn reduce_dispatch_table(mut self, reduce_dispatch_table: bool) -> Self {
+        self.reduce_dispatch_table = reduce_dispatch_table;
+        self
+    }
+
+    /// Collapse chain productions (e.g. `E: T;`) in the generic tree. The node
+    /// of the single non-terminal child is used instead of a new node. Can be
+    /// used only with the generic builder. `false` by default.
+    pub fn collapse_chains(mut self, collapse_chains: bool) -> Self {
+        self.collapse_chains = collapse_chains;
+        self
+    }
+
+    /// Generate `no_std` compatible code which needs only `core` and `alloc`.
+    /// The `rustemo` dependency should be used without default features. Can
+    /// be used only with custom lexer and LR algorithm. `false` by default.
+    pub fn no_std(mut self, no_std: bool) -> Self {
+        self.no_std = no_std;
+        self
+    }
+
+    /// Renames the grammar symbol `from` to `to` before the parser generation.
+    /// The names of the generated types and functions are derived from the new
+    /// name. Used for symbols whose names collide with Rust keywords or names
+    /// used in the generated code.
+    pub fn rename(mut self, from: String, to: String) -> Self {
+        self.rename.insert(from, to);
+        self
+    }
+
     pub fn print_table(mut self, print_table: bool) -> Self {
         self.print_table = print_table;
         self
@@ -319,6 +556,123 @@ impl Settings {
         self
     }
 
+    /// If the LR table has conflicts generate GLR parser instead of reporting
+    /// an error. The generated parser provides `parse_all` method, for both LR
+    /// and GLR, which returns all outputs so the calling code doesn't need to
+    /// know which algorithm was used. Used only if LR algorithm is configured.
+    pub fn glr_fallback(mut self, glr_fallback: bool) -> Self {
+        self.glr_fallback = glr_fallback;
+        self
+    }
+
+    /// Generate parser which reports internal inconsistencies of the parse
+    /// stack as errors instead of panicking. Also generates
+    /// `parse_bytes_lossy` for parsers using the default lexer and builder.
+    /// `false` by default. Used only with LR algorithm.
+    pub fn no_panic(mut self, no_panic: bool) -> Self {
+        self.no_panic = no_panic;
+        self
+    }
+
+    /// Generate actions returning `Result`. If an action returns an error the
+    /// parsing stops and the error is returned from the parser. If the error
+    /// has no location, the location of the reduction is attached. Used for
+    /// semantic validation. Can be used only with the default builder and LR
+    /// algorithm. `false` by default.
+    pub fn fallible_actions(mut self, fallible_actions: bool) -> Self {
+        self.fallible_actions = fallible_actions;
+        self
+    }
+
+    /// Generate `parse_iter` method for grammars whose start rule is a
+    /// repetition of items (e.g. `File: Record*;`). The returned iterator
+    /// yields each item as soon as it is reduced so the whole output is never
+    /// kept in memory. Can be used only with the default builder and LR
+    /// algorithm. `false` by default.
+    pub fn streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Generate `parse_<rule>` method for each grammar rule which parses the
+    /// input as that rule. Used to test the rules in isolation. The methods are
+    /// generated behind `#[cfg(test)]` but the LR table has additional start
+    /// states in all builds. Can be used only with the default builder and LR
+    /// algorithm. `false` by default.
+    pub fn rule_parsers(mut self, rule_parsers: bool) -> Self {
+        self.rule_parsers = rule_parsers;
+        self
+    }
+
+    /// Generate `parse_generic` method which builds the generic tree (see
+    /// [BuilderType::Generic]) in addition to the output of the configured
+    /// builder. The tables and the lexer are shared by both entry points. Can't
+    /// be used with the generic builder, token attributes or GLR algorithm.
+    /// `false` by default.
+    pub fn generic_tree(mut self, generic_tree: bool) -> Self {
+        self.generic_tree = generic_tree;
+        self
+    }
+
+    /// Generate async `parse_stream` method which reads an async byte stream
+    /// (`rustemo::Stream<Item = u8>`) to its end and then parses the collected
+    /// input. Lexing and parsing don't progress while the bytes arrive.
+    /// Requires `async` feature of `rustemo`. Can be used only with the default
+    /// lexer, default builder and LR algorithm. `false` by default.
+    pub fn async_stream(mut self, async_stream: bool) -> Self {
+        self.async_stream = async_stream;
+        self
+    }
+
+    /// The actions of content terminals without an inline type return a slice
+    /// of the input (`&'i Input`) instead of an owned `String`. The generated
+    /// types which contain such terminals get the input lifetime `'i`. Can be
+    /// used only with the default builder. `false` by default.
+    pub fn borrowed_terminals(mut self, borrowed_terminals: bool) -> Self {
+        self.borrowed_terminals = borrowed_terminals;
+        self
+    }
+
+    /// Derive `PartialEq`, `Eq` and `Hash` for the generated AST types so that
+    /// they can be used as keys of hash maps and sets. All content types, i.e.
+    /// the types of terminals, must implement these traits, thus e.g. terminals
+    /// of type `f32` can't be used. Used only with the default builder.
+    /// `false` by default.
+    pub fn derive_hash_eq(mut self, derive_hash_eq: bool) -> Self {
+        self.derive_hash_eq = derive_hash_eq;
+        self
+    }
+
+    /// Implement `FromStr` for the type of the root rule by parsing the whole
+    /// string with the generated parser, so that the output can be created
+    /// with `str::parse`. The root type must be a struct or an enum generated
+    /// in the actions and can't borrow from the input. Can be used only with
+    /// LR algorithm, default lexer and default builder over `str` input.
+    /// `false` by default.
+    pub fn impl_fromstr(mut self, impl_fromstr: bool) -> Self {
+        self.impl_fromstr = impl_fromstr;
+        self
+    }
+
+    /// Name the `ProdKind` variants of the productions with a kind given in the
+    /// grammar (e.g. `E: E '+' E {Add};`) by the kind alone (`ProdKind::Add`)
+    /// instead of prefixing it with the rule name (`ProdKind::EAdd`). The
+    /// kinds must be unique across the grammar. `false` by default.
+    pub fn bare_prod_kinds(mut self, bare_prod_kinds: bool) -> Self {
+        self.bare_prod_kinds = bare_prod_kinds;
+        self
+    }
+
+    /// Generate parser which collects statistics of the parsing process (how
+    /// many times each state is entered, each production reduced and each
+    /// terminal recognized). The generated parser provides `parse_with_stats`
+    /// method which returns the statistics alongside the result. `false` by
+    /// default. Used only with LR algorithm.
+    pub fn profile(mut self, profile: bool) -> Self {
+        self.profile = profile;
+        self
+    }
+
     /// Should whitespaces be skipped. `true` by default. Not used if Layout
     /// rule exists in the Grammar. Used only in the default lexer.
     pub fn skip_ws(mut self, skip_ws: bool) -> Self {
@@ -355,6 +709,14 @@ impl Settings {
         self
     }
 
+    /// Visibility of the generated parser items. By default all items are
+    /// public. Use [Visibility::PubCrate] or [Visibility::Private] to keep the
+    /// parser out of the crate public API.
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
     /// If this is set a .dot file with automata visualization will be produced during
     /// compiling.
     pub fn dot(mut self, dot: bool) -> Self {
@@ -362,6 +724,303 @@ impl Settings {
         self
     }
 
+    /// If this is set a human-readable `.automaton` file listing the LR states
+    /// with their items, actions and GOTOs will be produced during compiling.
+    pub fn dump_automaton(mut self, dump_automaton: bool) -> Self {
+        self.dump_automaton = dump_automaton;
+        self
+    }
+
+    /// If this is set the calculated LR table is stored in a `.table` file
+    /// next to the generated parser and reused in the next generation if the
+    /// grammar and the table related settings are not changed. `false` by
+    /// default.
+    pub fn table_cache(mut self, table_cache: bool) -> Self {
+        self.table_cache = table_cache;
+        self
+    }
+
+    /// Checks that the configured settings can be used together.
+    ///
+    /// Called at the beginning of the grammar processing. Returns an error
+    /// describing the first invalid combination found.
+    pub fn validate(&self) -> Result<()> {
+        if let LexerType::Default = self.lexer_type {
+            if self.input_type != "str" {
+                return Err(Error::Error(format!(
+                    "Default lexer can be used only with 'str' input but \
+                     input type is '{}'. Use custom lexer instead.",
+                    self.input_type
+                )));
+            }
+            if self.token_attr_type.is_some() {
+                return Err(Error::Error(
+                    "Token attributes can be used only with custom lexer."
+                        .to_string(),
+                ));
+            }
+            if self.incremental_lexer && self.custom_recognizers {
+                return Err(Error::Error(
+                    "Incremental lexer can't be used with custom recognizers."
+                        .to_string(),
+                ));
+            }
+            if self.regex_set && self.fancy_regex {
+                return Err(Error::Error(
+                    "Regex set can't be used with fancy regex.".to_string(),
+                ));
+            }
+        } else if self.custom_recognizers {
+            return Err(Error::Error(
+                "Custom recognizers can be used only with default lexer."
+                    .to_string(),
+            ));
+        } else if self.incremental_lexer {
+            return Err(Error::Error(
+                "Incremental lexer can be used only with default lexer."
+                    .to_string(),
+            ));
+        } else if self.regex_set {
+            return Err(Error::Error(
+                "Regex set can be used only with default lexer.".to_string(),
+            ));
+        }
+        if self.no_std {
+            if let LexerType::Default = self.lexer_type {
+                return Err(Error::Error(
+                    "No std mode can be used only with custom lexer."
+                        .to_string(),
+                ));
+            }
+            if let ParserAlgo::GLR = self.parser_algo {
+                return Err(Error::Error(
+                    "No std mode can be used only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.glr_fallback {
+                return Err(Error::Error(
+                    "GLR fallback can't be used in no std mode.".to_string(),
+                ));
+            }
+        }
+        if (self.strip_bom || self.normalize_newlines || self.normalize_nfc)
+            && self.input_type != "str"
+        {
+            return Err(Error::Error(
+                "Input preprocessing can be used only with 'str' input."
+                    .to_string(),
+            ));
+        }
+        if let BuilderType::Generic = self.builder_type {
+            if self.token_attr_type.is_some() {
+                return Err(Error::Error(
+                    "Token attributes can't be used with generic builder."
+                        .to_string(),
+                ));
+            }
+        } else if self.collapse_chains {
+            return Err(Error::Error(
+                "Chain collapsing can be used only with generic builder."
+                    .to_string(),
+            ));
+        }
+        if self.fallible_actions {
+            if !matches!(self.builder_type, BuilderType::Default) {
+                return Err(Error::Error(
+                    "Fallible actions can be used only with default builder."
+                        .to_string(),
+                ));
+            }
+            if self.glr_fallback {
+                return Err(Error::Error(
+                    "GLR fallback can't be used with fallible actions."
+                        .to_string(),
+                ));
+            }
+        }
+        if self.streaming {
+            if !matches!(self.builder_type, BuilderType::Default) {
+                return Err(Error::Error(
+                    "Streaming can be used only with default builder."
+                        .to_string(),
+                ));
+            }
+            if self.glr_fallback {
+                return Err(Error::Error(
+                    "GLR fallback can't be used with streaming.".to_string(),
+                ));
+            }
+        }
+        if self.rule_parsers {
+            if !matches!(self.builder_type, BuilderType::Default) {
+                return Err(Error::Error(
+                    "Rule parsers can be used only with default builder."
+                        .to_string(),
+                ));
+            }
+            if self.glr_fallback {
+                return Err(Error::Error(
+                    "GLR fallback can't be used with rule parsers.".to_string(),
+                ));
+            }
+        }
+        if self.generic_tree {
+            if let BuilderType::Generic = self.builder_type {
+                return Err(Error::Error(
+                    "Generic tree can't be additionally built with generic \
+                     builder."
+                        .to_string(),
+                ));
+            }
+            if self.token_attr_type.is_some() {
+                return Err(Error::Error(
+                    "Token attributes can't be used with generic tree."
+                        .to_string(),
+                ));
+            }
+        }
+        if self.async_stream {
+            if !matches!(self.lexer_type, LexerType::Default) {
+                return Err(Error::Error(
+                    "Async stream parsing can be used only with default lexer."
+                        .to_string(),
+                ));
+            }
+            if !matches!(self.builder_type, BuilderType::Default) {
+                return Err(Error::Error(
+                    "Async stream parsing can be used only with default \
+                     builder."
+                        .to_string(),
+                ));
+            }
+            if self.borrowed_terminals {
+                return Err(Error::Error(
+                    "Borrowed terminals can't be used with async stream \
+                     parsing."
+                        .to_string(),
+                ));
+            }
+            if self.glr_fallback {
+                return Err(Error::Error(
+                    "GLR fallback can't be used with async stream parsing."
+                        .to_string(),
+                ));
+            }
+        }
+        if self.borrowed_terminals
+            && !matches!(self.builder_type, BuilderType::Default)
+        {
+            return Err(Error::Error(
+                "Borrowed terminals can be used only with default builder."
+                    .to_string(),
+            ));
+        }
+        if self.impl_fromstr {
+            if !matches!(self.builder_type, BuilderType::Default) {
+                return Err(Error::Error(
+                    "FromStr can be implemented only with default builder."
+                        .to_string(),
+                ));
+            }
+            if !matches!(self.lexer_type, LexerType::Default)
+                || self.input_type != "str"
+            {
+                return Err(Error::Error(
+                    "FromStr can be implemented only with default lexer and \
+                     str input."
+                        .to_string(),
+                ));
+            }
+            if self.borrowed_terminals {
+                return Err(Error::Error(
+                    "FromStr can't be implemented with borrowed terminals."
+                        .to_string(),
+                ));
+            }
+            if self.glr_fallback {
+                return Err(Error::Error(
+                    "GLR fallback can't be used with FromStr.".to_string(),
+                ));
+            }
+        }
+        if let ParserAlgo::LR = self.parser_algo {
+            if !self.lexical_disamb_grammar_order {
+                return Err(Error::Error(
+                    "Can't disable grammar order strategy for LR.".to_string(),
+                ));
+            }
+        }
+        if let ParserAlgo::GLR = self.parser_algo {
+            if self.glr_fallback {
+                return Err(Error::Error(
+                    "GLR fallback can be used only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.no_panic {
+                return Err(Error::Error(
+                    "No panic mode can be used only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.profile {
+                return Err(Error::Error(
+                    "Profiling can be used only with LR algorithm.".to_string(),
+                ));
+            }
+            if self.fallible_actions {
+                return Err(Error::Error(
+                    "Fallible actions can be used only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.streaming {
+                return Err(Error::Error(
+                    "Streaming can be used only with LR algorithm.".to_string(),
+                ));
+            }
+            if self.rule_parsers {
+                return Err(Error::Error(
+                    "Rule parsers can be used only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.generic_tree {
+                return Err(Error::Error(
+                    "Generic tree can be used only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.async_stream {
+                return Err(Error::Error(
+                    "Async stream parsing can be used only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.impl_fromstr {
+                return Err(Error::Error(
+                    "FromStr can be implemented only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.resolve_rr_by_order {
+                return Err(Error::Error(
+                    "Resolving R/R conflicts by production order can be used \
+                     only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+            if self.token_attr_type.is_some() {
+                return Err(Error::Error(
+                    "Token attributes can be used only with LR algorithm."
+                        .to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Recursively traverse the root dir and process each Rustemo grammar found.
     /// Used as the last call to the configured [Settings] value.
     pub fn process_dir(&self) -> Result<()> {
@@ -372,12 +1031,28 @@ impl Settings {
                 )));
             }
 
+            let warnings = RefCell::new(vec![]);
             let visitor = |grammar: &Path| -> Result<()> {
-                self.process_grammar(grammar)?;
-                Ok(())
+                match self.process_grammar(grammar) {
+                    // Errors in grammar parsing and building are reported by
+                    // Rustemo. Other errors (e.g. conflicts) always abort.
+                    Err(Error::RustemoError(e))
+                        if self.skip_invalid_grammars =>
+                    {
+                        warnings
+                            .borrow_mut()
+                            .push(format!("Skipped grammar {grammar:?}: {e}"));
+                        Ok(())
+                    }
+                    result => result,
+                }
             };
 
-            self.visit_dirs(root_dir, &visitor)
+            self.visit_dirs(root_dir, &visitor)?;
+            for warning in warnings.into_inner() {
+                println!("{}", format!("WARNING: {warning}").yellow());
+            }
+            Ok(())
         } else {
             Err(Error::Error("Root dir must be set!".to_string()))
         }
@@ -387,6 +1062,7 @@ impl Settings {
     /// default builder is used). Used as the last call to the configured
     /// [Settings] value.
     pub fn process_grammar(&self, grammar: &Path) -> Result<()> {
+        self.validate()?;
         println!("Generating parser for grammar {:?}", grammar);
         let relative_outdir = |p: &Path| -> Result<PathBuf> {
             Ok(p.join(
@@ -425,6 +1101,95 @@ impl Settings {
         )
     }
 
+    /// Watches the grammars in the root dir and regenerates the parser of each
+    /// grammar which is created or modified. Used in the development loop
+    /// instead of the [Settings::process_dir] call.
+    ///
+    /// File system events are debounced, i.e. collected until no event arrives
+    /// for the `debounce` duration, so that a grammar saved several times in a
+    /// row is regenerated only once. `on_change` is called with the path and
+    /// the result of each regeneration. Watching stops when `on_change` returns
+    /// [ControlFlow::Break]. Available with the `watch` feature.
+    #[cfg(feature = "watch")]
+    pub fn watch_dir<F>(
+        &self,
+        debounce: Duration,
+        mut on_change: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&Path, Result<()>) -> ControlFlow<()>,
+    {
+        use notify::Watcher;
+
+        let root_dir = self
+            .root_dir
+            .as_ref()
+            .ok_or_else(|| Error::Error("Root dir must be set!".to_string()))?;
+        let watch_error =
+            |e: notify::Error| Error::Error(format!("Watching failed: {e}"));
+
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher =
+            notify::recommended_watcher(sender).map_err(watch_error)?;
+        watcher
+            .watch(root_dir, notify::RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+
+        while let Ok(event) = receiver.recv() {
+            let mut changed = BTreeSet::new();
+            self.changed_grammars(event.map_err(watch_error)?, &mut changed);
+            while let Ok(event) = receiver.recv_timeout(debounce) {
+                self.changed_grammars(
+                    event.map_err(watch_error)?,
+                    &mut changed,
+                );
+            }
+            // Grammars removed in the meantime are skipped.
+            for grammar in changed.into_iter().filter(|g| g.exists()) {
+                let result = self.process_grammar(&grammar);
+                if on_change(&grammar, result).is_break() {
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Collects the grammars created or modified by the given event.
+    #[cfg(feature = "watch")]
+    fn changed_grammars(
+        &self,
+        event: notify::Event,
+        changed: &mut BTreeSet<PathBuf>,
+    ) {
+        if !matches!(
+            event.kind,
+            notify::EventKind::Create(_) | notify::EventKind::Modify(_)
+        ) {
+            return;
+        }
+        changed.extend(event.paths.into_iter().filter(|path| {
+            let path_name = path.to_string_lossy();
+            !self.exclude.iter().any(|e| path_name.contains(e))
+                && matches!(path.extension(),
+                            Some(ext) if ext == self.grammar_extension.as_str())
+        }));
+    }
+
+    /// Calculates the LR table for the given grammar and returns its metrics
+    /// (the number of states, conflicts etc.). The parser is not generated.
+    /// Useful for tracking the grammar complexity in CI.
+    pub fn table_metrics(&self, grammar: &Path) -> Result<TableMetrics> {
+        table_metrics(grammar, self)
+    }
+
+    /// Tries to remove each production of the given grammar in turn and
+    /// returns the descriptions of the removals which leave the LR table
+    /// without conflicts. A debugging aid for ambiguous grammars.
+    pub fn suggest_fixes(&self, grammar: &Path) -> Result<Vec<String>> {
+        suggest_fixes(grammar, self)
+    }
+
     /// Recursively visits dirs starting from the given `dir` and calls
     /// `visitor` for each Rustemo grammar found.
     fn visit_dirs(
@@ -446,7 +1211,8 @@ impl Settings {
 
                 if path.is_dir() {
                     self.visit_dirs(&path, visitor)?;
-                } else if matches!(path.extension(), Some(ext) if ext == "rustemo")
+                } else if matches!(path.extension(),
+                                   Some(ext) if ext == self.grammar_extension.as_str())
                 {
                     visitor(&path)?
                 }
@@ -461,7 +1227,7 @@ impl Settings {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// rustemo_compiler::process_dir("~/my_project")
 /// ```
 ///
@@ -491,3 +1257,396 @@ pub fn process_grammar<P: AsRef<Path>>(grammar: P) -> Result<()> {
     Settings::new().process_grammar(grammar.as_ref())?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_methods() {
+        let settings = Settings::new()
+            .root_dir("root".into())
+            .out_dir_root("out".into())
+            .out_dir_actions_root("out_actions".into())
+            .exclude(vec!["excluded".into()])
+            .prefer_shifts(true)
+            .prefer_shifts_over_empty(false)
+            .table_type(TableType::LALR)
+            .parser_algo(ParserAlgo::LR)
+            .lexer_type(LexerType::Custom)
+            .builder_type(BuilderType::Generic)
+            .generator_table_type(GeneratorTableType::Arrays)
+            .input_type("[u8]".into())
+            .lexical_disamb_most_specific(false)
+            .lexical_disamb_longest_match(false)
+            .lexical_disamb_grammar_order(true)
+            .fancy_regex(true)
+            .print_table(true)
+            .partial_parse(true)
+            .glr_fallback(true)
+            .no_panic(true)
+            .profile(true)
+            .skip_ws(false)
+            .actions(false)
+            .force(false)
+            .visibility(Visibility::PubCrate)
+            .dot(true)
+            .dump_automaton(true)
+            .table_cache(true)
+            .exclude_synthetic(true)
+            .reduce_dispatch_table(true)
+            .collapse_chains(true);
+
+        assert_eq!(settings.root_dir, Some("root".into()));
+        assert_eq!(settings.out_dir_root, Some("out".into()));
+        assert_eq!(settings.out_dir_actions_root, Some("out_actions".into()));
+        assert_eq!(settings.exclude, vec!["excluded".to_string()]);
+        assert!(settings.prefer_shifts);
+        assert!(!settings.prefer_shifts_over_empty);
+        assert!(matches!(settings.table_type, TableType::LALR));
+        assert!(matches!(settings.parser_algo, ParserAlgo::LR));
+        assert!(matches!(settings.lexer_type, LexerType::Custom));
+        assert!(matches!(settings.builder_type, BuilderType::Generic));
+        assert!(matches!(
+            settings.generator_table_type,
+            GeneratorTableType::Arrays
+        ));
+        assert_eq!(settings.input_type, "[u8]");
+        assert!(!settings.lexical_disamb_most_specific);
+        assert!(!settings.lexical_disamb_longest_match);
+        assert!(settings.lexical_disamb_grammar_order);
+        assert!(settings.fancy_regex);
+        assert!(settings.print_table);
+        assert!(settings.partial_parse);
+        assert!(settings.glr_fallback);
+        assert!(settings.no_panic);
+        assert!(settings.profile);
+        assert!(!settings.skip_ws);
+        assert!(!settings.actions);
+        assert!(!settings.force);
+        assert!(settings.force_explicit);
+        assert!(matches!(settings.visibility, Visibility::PubCrate));
+        assert!(settings.dot);
+        assert!(settings.dump_automaton);
+        assert!(settings.table_cache);
+        assert!(settings.exclude_synthetic);
+        assert!(settings.reduce_dispatch_table);
+        assert!(settings.collapse_chains);
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn glr_algo_settings() {
+        let settings = Settings::new()
+            .prefer_shifts(true)
+            .parser_algo(ParserAlgo::GLR);
+        assert!(matches!(settings.table_type, TableType::LALR_RN));
+        assert!(!settings.prefer_shifts);
+        assert!(!settings.prefer_shifts_over_empty);
+        assert!(!settings.lexical_disamb_grammar_order);
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn source_tree_settings() {
+        let settings = Settings::new()
+            .out_dir_root("out".into())
+            .out_dir_actions_root("out".into())
+            .in_source_tree();
+        assert!(settings.out_dir_root.is_none());
+        assert!(settings.out_dir_actions_root.is_none());
+        assert!(!settings.force);
+
+        let settings = Settings::new()
+            .out_dir_root("out".into())
+            .out_dir_actions_root("out".into())
+            .force(true)
+            .actions_in_source_tree();
+        assert!(settings.out_dir_root.is_some());
+        assert!(settings.out_dir_actions_root.is_none());
+        assert!(settings.force);
+    }
+
+    #[test]
+    fn invalid_combinations() {
+        let result = Settings::new().input_type("[u8]".into()).validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Default lexer")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .lexical_disamb_grammar_order(false)
+            .parser_algo(ParserAlgo::LR)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("grammar order")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .glr_fallback(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("GLR fallback")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .no_panic(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("No panic")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .profile(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Profiling")));
+
+        let result = Settings::new().token_attr_type("u8".into()).validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("only with custom lexer")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .builder_type(BuilderType::Generic)
+            .token_attr_type("u8".into())
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("generic builder")));
+
+        let result = Settings::new().no_std(true).validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("No std mode can be used only with custom")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .parser_algo(ParserAlgo::GLR)
+            .no_std(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("No std mode can be used only with LR")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .glr_fallback(true)
+            .no_std(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("no std mode")));
+
+        let result = Settings::new().collapse_chains(true).validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Chain collapsing")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .parser_algo(ParserAlgo::GLR)
+            .token_attr_type("u8".into())
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Token attributes can be used only with LR")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .custom_recognizers(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Custom recognizers")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .incremental_lexer(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("only with default lexer")));
+
+        let result = Settings::new()
+            .custom_recognizers(true)
+            .incremental_lexer(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Incremental lexer")));
+
+        let result = Settings::new()
+            .fancy_regex(true)
+            .regex_set(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("fancy regex")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .regex_set(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Regex set can be used only")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .input_type("[u8]".into())
+            .normalize_newlines(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Input preprocessing")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .input_type("[u8]".into())
+            .normalize_nfc(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Input preprocessing")));
+
+        let result = Settings::new()
+            .builder_type(BuilderType::Generic)
+            .fallible_actions(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Fallible actions can be used only with default")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .fallible_actions(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Fallible actions can be used only with LR")));
+
+        let result = Settings::new()
+            .glr_fallback(true)
+            .fallible_actions(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("fallible actions")));
+
+        let result = Settings::new()
+            .builder_type(BuilderType::Generic)
+            .streaming(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Streaming can be used only with default")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .streaming(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Streaming can be used only with LR")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .resolve_rr_by_order(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("by production order")));
+
+        let result = Settings::new()
+            .builder_type(BuilderType::Generic)
+            .rule_parsers(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Rule parsers can be used only with default")));
+
+        let result = Settings::new()
+            .glr_fallback(true)
+            .rule_parsers(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("with rule parsers")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .rule_parsers(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Rule parsers can be used only with LR")));
+
+        let result = Settings::new()
+            .builder_type(BuilderType::Generic)
+            .generic_tree(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("with generic builder")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .token_attr_type("u32".into())
+            .generic_tree(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("used with generic tree")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .generic_tree(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Generic tree can be used only with LR")));
+
+        let result = Settings::new()
+            .lexer_type(LexerType::Custom)
+            .async_stream(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Async stream parsing can be used only with default lexer")));
+
+        let result = Settings::new()
+            .builder_type(BuilderType::Generic)
+            .async_stream(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Async stream parsing can be used only with default builder")));
+
+        let result = Settings::new()
+            .borrowed_terminals(true)
+            .async_stream(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("with async stream")));
+
+        let result = Settings::new()
+            .glr_fallback(true)
+            .async_stream(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("GLR fallback can't be used with async")));
+
+        let result = Settings::new()
+            .parser_algo(ParserAlgo::GLR)
+            .async_stream(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Async stream parsing can be used only with LR")));
+
+        let result = Settings::new().glr_fallback(true).streaming(true).validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("with streaming")));
+
+        let result = Settings::new()
+            .builder_type(BuilderType::Generic)
+            .borrowed_terminals(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("Borrowed terminals")));
+
+        let result = Settings::new()
+            .impl_fromstr(true)
+            .parser_algo(ParserAlgo::GLR)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("FromStr can be implemented only with LR")));
+
+        let result = Settings::new()
+            .impl_fromstr(true)
+            .builder_type(BuilderType::Generic)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("FromStr can be implemented only with default builder")));
+
+        let result = Settings::new()
+            .impl_fromstr(true)
+            .borrowed_terminals(true)
+            .validate();
+        assert!(matches!(result, Err(Error::Error(m))
+                         if m.contains("FromStr can't be implemented with borrowed")));
+    }
+}
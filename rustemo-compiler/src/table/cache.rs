@@ -0,0 +1,306 @@
+//! Caching of calculated LR tables.
+//!
+//! The calculation of LR tables may take a while for bigger grammars. The
+//! calculated table is stored in a simple line-oriented text format together
+//! with a hash of the grammar and the settings used in the calculation. If the
+//! hash is the same in the next generation the table is loaded from the cache.
+use std::{
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    fs,
+    hash::{Hash, Hasher},
+    path::Path,
+};
+
+use crate::{
+    error::Result,
+    grammar::Grammar,
+    index::{ProdIndex, StateIndex, StateVec, SymbolIndex, TermIndex},
+    settings::Settings,
+};
+
+use super::{Action, LRItem, LRState, LRTable};
+
+impl<'g, 's> LRTable<'g, 's> {
+    /// Loads the table from the given cache file if the cache is up-to-date.
+    /// Otherwise, calculates the table and stores it in the cache file.
+    pub fn with_cache(
+        grammar: &'g Grammar,
+        settings: &'s Settings,
+        cache_file: &Path,
+    ) -> Result<Self> {
+        let key = cache_key(grammar, settings);
+        if let Some(table) = fs::read_to_string(cache_file)
+            .ok()
+            .and_then(|cache| Self::from_cache(grammar, settings, &key, &cache))
+        {
+            if settings.print_table {
+                println!("LR TABLE:");
+                println!("{}", table);
+            }
+            return Ok(table);
+        }
+
+        let table = Self::new(grammar, settings)?;
+        if let Some(dir) = cache_file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(cache_file, table.to_cache(&key))?;
+        Ok(table)
+    }
+
+    fn to_cache(&self, key: &str) -> String {
+        let mut cache = String::new();
+        let list = |items: &mut dyn Iterator<Item = String>| {
+            items.map(|i| format!(" {i}")).collect::<String>()
+        };
+        let opt = |value: Option<usize>| {
+            value.map_or_else(|| "-".to_string(), |v| v.to_string())
+        };
+
+        // Writing to a String can't fail.
+        writeln!(cache, "rustemo-table {key}").unwrap();
+        writeln!(cache, "layout {}", opt(self.layout_state.map(|s| s.0)))
+            .unwrap();
+        match &self.production_rn_lengths {
+            Some(lengths) => writeln!(
+                cache,
+                "rn{}",
+                list(&mut lengths.iter().map(|l| l.to_string()))
+            ),
+            None => writeln!(cache, "rn -"),
+        }
+        .unwrap();
+        writeln!(cache, "firsts {}", self.first_sets.len()).unwrap();
+        for firsts in &self.first_sets {
+            writeln!(
+                cache,
+                "first{}",
+                list(&mut firsts.iter().map(|s| s.to_string()))
+            )
+            .unwrap();
+        }
+        writeln!(cache, "states {}", self.states.len()).unwrap();
+        for state in &self.states {
+            writeln!(cache, "state {} {}", state.idx, state.symbol).unwrap();
+            writeln!(cache, "items {}", state.items.len()).unwrap();
+            for item in &state.items {
+                writeln!(
+                    cache,
+                    "item {} {} {} {}{}",
+                    item.prod,
+                    item.prod_len,
+                    opt(item.rn_len),
+                    item.position,
+                    list(
+                        &mut item.follow.borrow().iter().map(|s| s.to_string())
+                    )
+                )
+                .unwrap();
+            }
+            writeln!(
+                cache,
+                "actions{}",
+                list(&mut state.actions.iter().map(|actions| {
+                    if actions.is_empty() {
+                        "-".to_string()
+                    } else {
+                        actions
+                            .iter()
+                            .map(|action| match action {
+                                Action::Shift(state) => format!("s{state}"),
+                                Action::Reduce(prod, len) => {
+                                    format!("r{prod}/{len}")
+                                }
+                                Action::Accept => "a".to_string(),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    }
+                }))
+            )
+            .unwrap();
+            writeln!(
+                cache,
+                "gotos{}",
+                list(&mut state.gotos.iter().map(|g| opt(g.map(|s| s.0))))
+            )
+            .unwrap();
+            writeln!(
+                cache,
+                "sorted{}",
+                list(&mut state.sorted_terminals.iter().map(
+                    |(term, finish)| format!("{term}:{}", u8::from(*finish))
+                ))
+            )
+            .unwrap();
+            writeln!(
+                cache,
+                "prio{}",
+                list(
+                    &mut state
+                        .max_prior_for_term
+                        .iter()
+                        .map(|(term, prio)| format!("{term}:{prio}"))
+                )
+            )
+            .unwrap();
+        }
+        cache
+    }
+
+    /// Reconstructs the table from the cache content. Returns `None` if the
+    /// cache is not valid for the given key.
+    fn from_cache(
+        grammar: &'g Grammar,
+        settings: &'s Settings,
+        key: &str,
+        cache: &str,
+    ) -> Option<Self> {
+        let mut lines = cache.lines();
+        let mut line = |tag: &str| -> Option<Vec<&str>> {
+            let mut tokens = lines.next()?.split_whitespace();
+            (tokens.next()? == tag).then(|| tokens.collect())
+        };
+
+        if line("rustemo-table")? != [key] {
+            return None;
+        }
+        let layout_state = parse_opt(single(line("layout")?)?)?.map(StateIndex);
+        let production_rn_lengths = match line("rn")?.as_slice() {
+            ["-"] => None,
+            lengths => Some(parse_all(lengths)?),
+        };
+        let firsts = single(line("firsts")?)?.parse().ok()?;
+        let first_sets = (0..firsts)
+            .map(|_| parse_all(&line("first")?))
+            .collect::<Option<_>>()?;
+
+        let states_len = single(line("states")?)?.parse().ok()?;
+        let mut states = StateVec::new();
+        for _ in 0..states_len {
+            let mut state = match line("state")?.as_slice() {
+                [idx, symbol] => LRState::new(
+                    grammar,
+                    StateIndex(idx.parse().ok()?),
+                    SymbolIndex(symbol.parse().ok()?),
+                ),
+                _ => return None,
+            };
+            let items_len = single(line("items")?)?.parse().ok()?;
+            for _ in 0..items_len {
+                let item = line("item")?;
+                if item.len() < 4 {
+                    return None;
+                }
+                state.items.push(LRItem {
+                    prod: ProdIndex(item[0].parse().ok()?),
+                    prod_len: item[1].parse().ok()?,
+                    rn_len: parse_opt(item[2])?,
+                    position: item[3].parse().ok()?,
+                    follow: RefCell::new(parse_all(&item[4..])?),
+                });
+            }
+            state.actions = line("actions")?
+                .iter()
+                .map(|actions| match *actions {
+                    "-" => Some(vec![]),
+                    actions => actions.split(',').map(parse_action).collect(),
+                })
+                .collect::<Option<_>>()?;
+            state.gotos = line("gotos")?
+                .iter()
+                .map(|goto| parse_opt(goto).map(|g| g.map(StateIndex)))
+                .collect::<Option<_>>()?;
+            state.sorted_terminals = line("sorted")?
+                .iter()
+                .map(|sorted| {
+                    let (term, finish) = sorted.split_once(':')?;
+                    Some((TermIndex(term.parse().ok()?), finish == "1"))
+                })
+                .collect::<Option<_>>()?;
+            state.max_prior_for_term = line("prio")?
+                .iter()
+                .map(|prio| {
+                    let (term, prio) = prio.split_once(':')?;
+                    Some((TermIndex(term.parse().ok()?), prio.parse().ok()?))
+                })
+                .collect::<Option<_>>()?;
+            states.push(state);
+        }
+
+        Some(Self {
+            states,
+            layout_state,
+            grammar,
+            settings,
+            first_sets,
+            production_rn_lengths,
+            computed: false,
+            excluded: Default::default(),
+        })
+    }
+}
+
+/// The hash of the grammar and the settings which influence the table
+/// calculation.
+fn cache_key(grammar: &Grammar, settings: &Settings) -> String {
+    let mut hasher = DefaultHasher::new();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    format!("{grammar:?}").hash(&mut hasher);
+    format!(
+        "{:?}",
+        (
+            &settings.table_type,
+            &settings.parser_algo,
+            settings.prefer_shifts,
+            settings.prefer_shifts_over_empty,
+            settings.resolve_rr_by_order,
+            settings.lexical_disamb_most_specific,
+            settings.lexical_disamb_longest_match,
+            settings.lexical_disamb_grammar_order,
+        )
+    )
+    .hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+fn single(tokens: Vec<&str>) -> Option<&str> {
+    match tokens.as_slice() {
+        [token] => Some(*token),
+        _ => None,
+    }
+}
+
+fn parse_opt(token: &str) -> Option<Option<usize>> {
+    match token {
+        "-" => Some(None),
+        value => value.parse().ok().map(Some),
+    }
+}
+
+fn parse_all<C, I>(tokens: &[&str]) -> Option<C>
+where
+    C: FromIterator<I>,
+    I: From<usize>,
+{
+    tokens
+        .iter()
+        .map(|t| t.parse::<usize>().ok().map(I::from))
+        .collect()
+}
+
+fn parse_action(action: &str) -> Option<Action> {
+    if action == "a" {
+        return Some(Action::Accept);
+    }
+    if let Some(state) = action.strip_prefix('s') {
+        return Some(Action::Shift(StateIndex(state.parse().ok()?)));
+    }
+    let (prod, len) = action.strip_prefix('r')?.split_once('/')?;
+    Some(Action::Reduce(
+        ProdIndex(prod.parse().ok()?),
+        len.parse().ok()?,
+    ))
+}
@@ -17,7 +17,9 @@ use rustemo::log;
 use crate::{
     create_index,
     error::{Error, Result},
-    grammar::{Associativity, Priority, Terminal, DEFAULT_PRIORITY},
+    grammar::{
+        lint, Associativity, Lint, Priority, Terminal, DEFAULT_PRIORITY,
+    },
     index::{
         NonTermIndex, NonTermVec, ProdIndex, ProdVec, StateIndex, StateVec,
         SymbolIndex, SymbolVec, TermIndex, TermVec,
@@ -28,6 +30,8 @@ use crate::{
 
 use super::grammar::{res_symbol, Grammar};
 
+mod cache;
+
 #[derive(Debug, Clone)]
 pub enum Action {
     Shift(StateIndex),
@@ -53,7 +57,7 @@ pub enum TableType {
 }
 
 type Firsts = BTreeSet<SymbolIndex>;
-type FirstSets = SymbolVec<Firsts>;
+pub(crate) type FirstSets = SymbolVec<Firsts>;
 
 create_index!(ItemIndex, ItemVec);
 
@@ -188,6 +192,16 @@ impl<'g> LRState<'g> {
         self
     }
 
+    /// True if layout should not be parsed in this state. That is the case
+    /// if the dot of all kernel items is inside of `nolayout` productions.
+    pub fn no_layout(&self) -> bool {
+        let kernel_items = self.kernel_items();
+        !kernel_items.is_empty()
+            && kernel_items.iter().all(|i| {
+                self.grammar.productions[i.prod].nolayout && !i.is_reducing()
+            })
+    }
+
     fn kernel_items(&self) -> Vec<&LRItem> {
         self.items.iter().filter(|i| i.is_kernel()).collect()
     }
@@ -206,6 +220,7 @@ impl<'g> LRState<'g> {
         &mut self,
         first_sets: &FirstSets,
         prod_rn_lengths: &Option<ProdVec<usize>>,
+        excluded: &BTreeSet<ProdIndex>,
     ) {
         loop {
             // This is OK as Hash uses only non-interior-mutable part of the
@@ -245,8 +260,10 @@ impl<'g> LRState<'g> {
                         // create LR items with the calculated follow.
                         let nonterm =
                             self.grammar.symbol_to_nonterm_index(symbol);
-                        for prod in
-                            &self.grammar.nonterminals[nonterm].productions
+                        for prod in self.grammar.nonterminals[nonterm]
+                            .productions
+                            .iter()
+                            .filter(|prod| !excluded.contains(prod))
                         {
                             new_items.insert(LRItem::with_follow(
                                 self.grammar,
@@ -359,7 +376,7 @@ impl PartialEq for LRItem {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// // If prod with index 5 is A: B a C;
 /// let item = LRItem::new(5)
 ///                 .next_item().unwrap()
@@ -474,6 +491,20 @@ impl Display for Conflict<'_, '_> {
     }
 }
 
+/// Aggregate metrics of the LR table. Used to track the complexity of the
+/// grammar over time (e.g. in CI).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableMetrics {
+    /// The number of LR states.
+    pub states: usize,
+    /// The number of unresolved Shift/Reduce conflicts.
+    pub shift_reduce_conflicts: usize,
+    /// The number of unresolved Reduce/Reduce conflicts.
+    pub reduce_reduce_conflicts: usize,
+    /// Maximal number of LR items in a state.
+    pub max_items: usize,
+}
+
 pub struct LRTable<'g, 's> {
     pub states: StateVec<LRState<'g>>,
     pub layout_state: Option<StateIndex>,
@@ -485,33 +516,122 @@ pub struct LRTable<'g, 's> {
     /// position in a production after which all the remaining symbols can
     /// derive EMPTY.
     pub production_rn_lengths: Option<ProdVec<usize>>,
+
+    /// `true` if the table is calculated, `false` if it is loaded from the
+    /// cache.
+    pub computed: bool,
+
+    /// Productions left out of the table. Used for trying grammar fixes.
+    excluded: BTreeSet<ProdIndex>,
+}
+
+/// A change of the grammar which resolves all LR conflicts. See
+/// [`LRTable::suggest_fixes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fix {
+    /// Remove the production.
+    RemoveProduction(ProdIndex),
+}
+
+impl Fix {
+    pub fn to_string(self, grammar: &Grammar) -> String {
+        match self {
+            Fix::RemoveProduction(prod) => format!(
+                "Remove production '{}'.",
+                grammar.productions[prod].to_string(grammar)
+            ),
+        }
+    }
 }
 
 impl<'g, 's> LRTable<'g, 's> {
     pub fn new(grammar: &'g Grammar, settings: &'s Settings) -> Result<Self> {
-        let first_sets = first_sets(grammar);
+        let mut table = Self::init(grammar, settings, BTreeSet::new());
+
+        table.check_empty_sets()?;
+        table.check_left_recursion();
+
+        table.calc();
+
+        println!("Terminals: {}", grammar.terminals.len());
+        println!("Non-terminals: {}", grammar.nonterminals().len());
+        println!("Productions: {}", grammar.productions().len());
+        println!("States: {}", table.states.len());
+
+        if settings.print_table {
+            println!("LR TABLE:");
+            println!("{}", table);
+        }
+
+        Ok(table)
+    }
+
+    /// Suggests fixes of the LR conflicts of the given grammar. Each
+    /// production is left out in turn and the fix is suggested if the table
+    /// calculated without the production has no conflicts. Other changes of
+    /// the grammar are not tried.
+    pub fn suggest_fixes(
+        grammar: &'g Grammar,
+        settings: &'s Settings,
+    ) -> Vec<Fix> {
+        grammar
+            .productions()
+            .into_iter()
+            .filter(|prod| {
+                let mut table =
+                    Self::init(grammar, settings, BTreeSet::from([prod.idx]));
+                // Removing the only production of a rule is not a fix.
+                if lint::lint(grammar, &table.first_sets)
+                    .iter()
+                    .any(Lint::is_error)
+                {
+                    return false;
+                }
+                table.calc();
+                table.get_conflicts().is_empty()
+            })
+            .map(|prod| Fix::RemoveProduction(prod.idx))
+            .collect()
+    }
+
+    /// Creates the table for the given grammar without the given productions.
+    /// The states are calculated by `calc`.
+    fn init(
+        grammar: &'g Grammar,
+        settings: &'s Settings,
+        excluded: BTreeSet<ProdIndex>,
+    ) -> Self {
+        let first_sets = first_sets_without(grammar, &excluded);
         let production_rn_lengths = if settings.table_type == TableType::LALR_RN
         {
             Some(production_rn_lengths(&first_sets, grammar))
         } else {
             None
         };
-        let mut table = Self {
+        Self {
             grammar,
             settings,
             states: StateVec::new(),
             layout_state: None,
             first_sets,
             production_rn_lengths,
-        };
-
-        table.check_empty_sets()?;
+            computed: true,
+            excluded,
+        }
+    }
 
+    /// Calculates the states with their actions and gotos.
+    fn calc(&mut self) {
+        let grammar = self.grammar;
+        let table = self;
         table.calc_states(grammar.augmented_index);
         if let Some(augmented_layout_index) = grammar.augmented_layout_index {
             table.layout_state = Some(StateIndex(table.states.len()));
             table.calc_states(augmented_layout_index)
         }
+        for &(augmented_rule_index, _) in &grammar.augmented_rule_indexes {
+            table.calc_states(augmented_rule_index)
+        }
 
         log!("LR states constructed. Updating follows.");
         table.propagate_follows();
@@ -521,21 +641,10 @@ impl<'g, 's> LRTable<'g, 's> {
             possible conflicts."
         );
         table.calculate_reductions();
+        table.add_guarded_reductions();
 
         log!("Sort terminals for lexical disambiguation");
         table.sort_terminals();
-
-        println!("Terminals: {}", grammar.terminals.len());
-        println!("Non-terminals: {}", grammar.nonterminals().len());
-        println!("Productions: {}", grammar.productions().len());
-        println!("States: {}", table.states.len());
-
-        if settings.print_table {
-            println!("LR TABLE:");
-            println!("{}", table);
-        }
-
-        Ok(table)
     }
 
     /// Calculate LR states with GOTOs and ACTIONs for the given Grammar.
@@ -570,7 +679,11 @@ impl<'g, 's> LRTable<'g, 's> {
             // called "kernel items" expand collection with non-kernel items. We
             // will also calculate GOTO and ACTIONS dicts for each state. These
             // dicts will be keyed by a grammar symbol.
-            state.closure(&self.first_sets, &self.production_rn_lengths);
+            state.closure(
+                &self.first_sets,
+                &self.production_rn_lengths,
+                &self.excluded,
+            );
 
             // To find out other states we examine following grammar symbols in the
             // current state (symbols following current position/"dot") and group
@@ -736,7 +849,11 @@ impl<'g, 's> LRTable<'g, 's> {
                 // Refresh closure to propagate follows from kernel items to
                 // non-kernel of the same state as the merge is done only for kernel
                 // items.
-                state.closure(&self.first_sets, &self.production_rn_lengths);
+                state.closure(
+                    &self.first_sets,
+                    &self.production_rn_lengths,
+                    &self.excluded,
+                );
             }
 
             for state in self.states.iter() {
@@ -793,10 +910,21 @@ impl<'g, 's> LRTable<'g, 's> {
         if let Some(layout_index) = self.grammar.augmented_layout_index {
             aug_symbols.push(layout_index);
         }
+        aug_symbols.extend(
+            self.grammar
+                .augmented_rule_indexes
+                .iter()
+                .map(|&(aug, _)| aug),
+        );
         for state in &mut self.states {
             for item in state.items.iter().filter(|x| x.is_reducing()) {
                 let prod = &self.grammar.productions[item.prod];
 
+                // Reductions guarded by predicates are added afterwards.
+                if prod.predicate.is_some() {
+                    continue;
+                }
+
                 // Accept if reducing by augmented productions for STOP lookahead
                 if aug_symbols.contains(
                     &self.grammar.nonterm_to_symbol_index(prod.nonterminal),
@@ -929,12 +1057,12 @@ impl<'g, 's> LRTable<'g, 's> {
                                     });
                                     actions.push(new_reduce.clone())
                                 } else {
-                                    // For LR parsing non-empty reductions are
-                                    // preferred over empty...
                                     if let ParserAlgo::LR =
                                         self.settings.parser_algo
                                     {
-                                        // ... so remove all empty reductions.
+                                        // For LR parsing non-empty reductions
+                                        // are preferred over empty so remove
+                                        // all empty reductions.
                                         actions.retain(|x| !matches!(x, Action::Reduce(_, len) if *len == 0));
 
                                         if item.prod_len > 0
@@ -943,6 +1071,41 @@ impl<'g, 's> LRTable<'g, 's> {
                                             // If current reduction is non-empty add it.
                                             actions.push(new_reduce.clone())
                                         }
+
+                                        if self.settings.resolve_rr_by_order {
+                                            // The conflicts left are resolved
+                                            // in favor of the production
+                                            // defined first in the grammar
+                                            // (yacc style).
+                                            let prods = actions
+                                                .iter()
+                                                .filter_map(|x| match x {
+                                                    Action::Reduce(prod, _) => {
+                                                        Some(*prod)
+                                                    }
+                                                    _ => None,
+                                                })
+                                                .collect::<Vec<_>>();
+                                            if let Some(&first) =
+                                                prods.iter().min()
+                                            {
+                                                for &lost in prods
+                                                    .iter()
+                                                    .filter(|&&p| p != first)
+                                                {
+                                                    report_rr_by_order(
+                                                        self.grammar,
+                                                        state.idx,
+                                                        follow_term,
+                                                        first,
+                                                        lost,
+                                                    );
+                                                }
+                                                actions.retain(|x| {
+                                                    !matches!(x, Action::Reduce(prod, _) if *prod != first)
+                                                });
+                                            }
+                                        }
                                     } else {
                                         // This R/R conflict can't be resolved.
                                         // Just add the new reduction and GLR
@@ -959,18 +1122,54 @@ impl<'g, 's> LRTable<'g, 's> {
         }
     }
 
+    /// Adds reductions guarded by semantic predicates. These reductions don't
+    /// take part in conflict resolution. They are placed in front of other
+    /// actions so that LR parser tries them first and falls back to other
+    /// actions if the predicate doesn't hold.
+    fn add_guarded_reductions(&mut self) {
+        let is_guarded = |action: &Action| {
+            matches!(action, Action::Reduce(prod, _)
+                     if self.grammar.productions[*prod].predicate.is_some())
+        };
+        for state in &mut self.states {
+            for item in state.items.iter().filter(|x| {
+                x.is_reducing()
+                    && self.grammar.productions[x.prod].predicate.is_some()
+            }) {
+                for follow_symbol in item.follow.borrow().iter() {
+                    let follow_term =
+                        self.grammar.symbol_to_term(*follow_symbol);
+                    let actions = &mut state.actions[follow_term.idx];
+                    let guarded = actions
+                        .iter()
+                        .take_while(|&action| is_guarded(action))
+                        .count();
+                    actions.insert(
+                        guarded,
+                        Action::Reduce(item.prod, item.position),
+                    );
+                }
+            }
+        }
+    }
+
     /// Sort terminals for each state according to explicit priority and
     /// terminal recognizer type. String recognizers have precedence over regex
     /// recognizers if most specific match strategy is enabled. For most
     /// specific match, longer string recognizers have precedence over shorter.
     fn sort_terminals(&mut self) {
         for state in &mut self.states {
+            // Ignored terminals are recognized in each state to be skipped
+            // where not expected.
             let mut terminals = state
                 .actions
                 .iter()
                 .enumerate()
-                .filter(|(_, actions)| !actions.is_empty())
-                .map(|(idx, _)| self.grammar.term_by_index(TermIndex(idx)))
+                .map(|(idx, actions)| {
+                    (self.grammar.term_by_index(TermIndex(idx)), actions)
+                })
+                .filter(|(term, actions)| !actions.is_empty() || term.ignored)
+                .map(|(term, _)| term)
                 .collect::<Vec<_>>();
 
             let term_prio = |term: &Terminal| -> u32 {
@@ -982,7 +1181,9 @@ impl<'g, 's> LRTable<'g, 's> {
                                     Recognizer::StrConst(str_rec) => {
                                         str_rec.as_ref().len()
                                     }
-                                    Recognizer::RegexTerm(_) => 0,
+                                    Recognizer::RegexTerm(_)
+                                    | Recognizer::RawTerm(_)
+                                    | Recognizer::StrAlternatives(_) => 0,
                                 }) as u32
                             }
                             None => 0,
@@ -1043,24 +1244,45 @@ impl<'g, 's> LRTable<'g, 's> {
 
     /// Check for states with GOTO links but without SHIFT links.
     ///
-    /// This is invalid as GOTO links will never be traversed.
+    /// This is invalid as GOTO links will never be traversed. All such symbols
+    /// are reported as an error. Other grammar lints are printed as warnings.
     fn check_empty_sets(&self) -> Result<()> {
-        if let Some((idx, _)) = self
-            .first_sets
-            .iter()
-            .enumerate()
-            .find(|(_, s)| s.is_empty())
+        let (errors, warnings): (Vec<_>, Vec<_>) =
+            lint::lint(self.grammar, &self.first_sets)
+                .into_iter()
+                .partition(Lint::is_error);
+        for warning in warnings {
+            println!("{}", format!("WARNING: {warning}").yellow());
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Error(errors.iter().join("\n")))
+        }
+    }
+
+    /// Warn about left recursion in GLR partial parsing as it can lead to a
+    /// huge number of solutions.
+    fn check_left_recursion(&self) {
+        if !matches!(self.settings.parser_algo, ParserAlgo::GLR)
+            || !self.settings.partial_parse
         {
-            return Err(Error::Error(format!(
-                "First set empty for grammar symbol {:?}.\n\
-                 An infinite recursion on the grammar symbol.",
-                &self.grammar.symbol_name(SymbolIndex(idx))
-            )));
+            return;
+        }
+        for nonterm in lint::left_recursive(self.grammar, &self.first_sets) {
+            println!(
+                "{}",
+                format!(
+                    "WARNING: Non-terminal {nonterm:?} is left-recursive. \
+                     GLR partial parse may yield a huge number of solutions. \
+                     Consider limiting them with `max_solutions`."
+                )
+                .yellow()
+            );
         }
-        Ok(())
     }
 
-    pub fn get_conflicts(&'s self) -> Vec<Conflict<'g, 's>> {
+    pub fn get_conflicts(&self) -> Vec<Conflict<'g, '_>> {
         self.states.iter().flat_map(|state| {
             state
                 .actions
@@ -1070,6 +1292,11 @@ impl<'g, 's> LRTable<'g, 's> {
                 .flat_map(|(idx, actions)| {
                     actions.iter().combinations(2).map(move |conflict| (idx, conflict))
                 })
+                // Guarded reductions are resolved by predicates during parsing.
+                .filter(|(_, conflict)| {
+                    !conflict.iter().any(|action| matches!(action, Action::Reduce(prod, _)
+                                                           if self.grammar.productions[*prod].predicate.is_some()))
+                })
                 .map(|(term_index, conflict)| {
                     let kind = match &conflict[..] {
                         [Action::Shift(_), Action::Reduce(prod, _)]
@@ -1092,7 +1319,7 @@ impl<'g, 's> LRTable<'g, 's> {
         }).collect()
     }
 
-    pub fn print_conflicts_report(&self, conflicts: &Vec<Conflict<'g, 's>>) {
+    pub fn print_conflicts_report(&self, conflicts: &Vec<Conflict<'g, '_>>) {
         for conflict in conflicts {
             println!("{} {}", "In".green().bold(), conflict.state);
             print!(
@@ -1142,6 +1369,26 @@ impl<'g, 's> LRTable<'g, 's> {
         );
     }
 
+    /// Calculates aggregate metrics of this table.
+    pub fn metrics(&self) -> TableMetrics {
+        let conflicts = self.get_conflicts();
+        let shift_reduce_conflicts = conflicts
+            .iter()
+            .filter(|c| matches!(c.kind, ConflictKind::ShiftReduce(..)))
+            .count();
+        TableMetrics {
+            states: self.states.len(),
+            shift_reduce_conflicts,
+            reduce_reduce_conflicts: conflicts.len() - shift_reduce_conflicts,
+            max_items: self
+                .states
+                .iter()
+                .map(|state| state.items.len())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
     /// Maximal number of actions per state/token. For LR can't be >1.
     #[inline]
     pub fn max_actions(&self) -> usize {
@@ -1158,7 +1405,7 @@ impl<'g, 's> LRTable<'g, 's> {
     pub fn max_recognizers(&self) -> usize {
         self.states
             .iter()
-            .map(|state| state.actions.iter().filter(|a| !a.is_empty()).count())
+            .map(|state| state.sorted_terminals.len())
             .max()
             .unwrap()
     }
@@ -1293,6 +1540,105 @@ impl<'g, 's> LRTable<'g, 's> {
         dot += "\n}\n";
         dot
     }
+
+    /// Returns a human-readable description of the LR automaton. For each
+    /// state kernel and closure items, actions and GOTOs are listed using
+    /// grammar symbol names.
+    pub fn to_automaton(&self) -> String {
+        colored::control::set_override(false);
+
+        let mut automaton = String::new();
+        for state in &self.states {
+            automaton += &format!(
+                "State {}:{}\n",
+                state.idx,
+                self.grammar.symbol_name(state.symbol)
+            );
+            automaton += "  Kernel items:\n";
+            for item in state.kernel_items() {
+                automaton += &format!("    {}\n", item.to_string(self.grammar));
+            }
+            let nonkernel_items = state.nonkernel_items();
+            if !nonkernel_items.is_empty() {
+                automaton += "  Closure items:\n";
+                for item in nonkernel_items {
+                    automaton +=
+                        &format!("    {}\n", item.to_string(self.grammar));
+                }
+            }
+
+            let actions = self
+                .grammar
+                .terminals
+                .iter()
+                .flat_map(|term| {
+                    state.actions[term.idx].iter().map(|action| {
+                        let action = match action {
+                            Action::Shift(s) => format!("Shift to {s}"),
+                            Action::Reduce(p, l) => format!(
+                                "Reduce for len {l} by: {}",
+                                self.grammar.productions[*p]
+                                    .to_string(self.grammar)
+                            ),
+                            Action::Accept => "Accept".into(),
+                        };
+                        format!("    {} => {action}\n", term.name)
+                    })
+                })
+                .collect::<String>();
+            if !actions.is_empty() {
+                automaton += "  Actions:\n";
+                automaton += &actions;
+            }
+
+            let gotos = self
+                .grammar
+                .nonterminals
+                .iter()
+                .filter_map(|nonterm| {
+                    state.gotos[nonterm.idx].map(|target| {
+                        format!(
+                            "    {} => State {}:{}\n",
+                            nonterm.name,
+                            target,
+                            self.grammar
+                                .symbol_name(self.states[target].symbol)
+                        )
+                    })
+                })
+                .collect::<String>();
+            if !gotos.is_empty() {
+                automaton += "  GOTOs:\n";
+                automaton += &gotos;
+            }
+            automaton += "\n";
+        }
+        colored::control::unset_override();
+
+        automaton
+    }
+}
+
+/// Reports the R/R conflict resolved in favor of the production defined first.
+fn report_rr_by_order(
+    grammar: &Grammar,
+    state: StateIndex,
+    lookahead: &Terminal,
+    first: ProdIndex,
+    lost: ProdIndex,
+) {
+    println!(
+        "{}",
+        format!(
+            "R/R conflict in state {} for lookahead {} resolved by order: \
+             reduce by '{}' instead of '{}'.",
+            state,
+            lookahead.name,
+            grammar.productions[first].to_string(grammar),
+            grammar.productions[lost].to_string(grammar),
+        )
+        .yellow()
+    );
 }
 
 fn production_rn_lengths(
@@ -1397,7 +1743,16 @@ impl Display for LRTable<'_, '_> {
 /// grammar symbols.
 ///
 /// The Dragon book p. 221.
-fn first_sets(grammar: &Grammar) -> FirstSets {
+pub(crate) fn first_sets(grammar: &Grammar) -> FirstSets {
+    first_sets_without(grammar, &BTreeSet::new())
+}
+
+/// Calculates the first sets as if the given productions are not in the
+/// grammar.
+fn first_sets_without(
+    grammar: &Grammar,
+    excluded: &BTreeSet<ProdIndex>,
+) -> FirstSets {
     let mut first_sets = SymbolVec::new();
 
     // First set for each terminal contains only the terminal itself.
@@ -1419,7 +1774,11 @@ fn first_sets(grammar: &Grammar) -> FirstSets {
     let mut additions = true;
     while additions {
         additions = false;
-        for production in &grammar.productions {
+        for production in grammar
+            .productions
+            .iter()
+            .filter(|p| !excluded.contains(&p.idx))
+        {
             let lhs_nonterm =
                 grammar.nonterm_to_symbol_index(production.nonterminal);
 
@@ -1551,7 +1910,7 @@ mod tests {
 
     use crate::index::{ProdIndex, StateIndex, SymbolIndex};
     use crate::table::{
-        first_sets, follow_sets, ItemIndex, LRTable, TableType,
+        first_sets, follow_sets, ItemIndex, LRTable, TableMetrics, TableType,
     };
     use crate::{
         grammar::Grammar,
@@ -1560,7 +1919,7 @@ mod tests {
         table::{Follow, LRItem},
     };
 
-    use super::{production_rn_lengths, LRState};
+    use super::{production_rn_lengths, Action, LRState};
 
     fn follow<T, I>(indexes: T) -> BTreeSet<SymbolIndex>
     where
@@ -1950,7 +2309,7 @@ mod tests {
                     follow([grammar.stop_index]),
                 ));
 
-        lr_state.closure(&firsts, &None);
+        lr_state.closure(&firsts, &None, &BTreeSet::new());
 
         let prods = [1, 4, 7, 8];
         let follow_sets = [
@@ -2023,6 +2382,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_table_metrics() {
+        let grammar: Grammar = r#"
+            E: E "+" E | E "*" E | "(" E ")" | "id";
+            terminals
+            Plus: "+";
+            Mul: "*";
+            LParen: "(";
+            RParen: ")";
+            id: "id";
+            "#
+        .parse()
+        .unwrap();
+
+        let settings = Settings::new();
+        let table = LRTable::new(&grammar, &settings).unwrap();
+        // Both `+` and `*` can be shifted or reduced after `E + E` and `E * E`.
+        assert_eq!(
+            table.metrics(),
+            TableMetrics {
+                states: 10,
+                shift_reduce_conflicts: 4,
+                reduce_reduce_conflicts: 0,
+                max_items: 5,
+            }
+        );
+
+        // Priorities and associativities resolve all conflicts.
+        let grammar = test_ambiguous_grammar();
+        let table = LRTable::new(&grammar, &settings).unwrap();
+        let metrics = table.metrics();
+        assert_eq!(metrics.shift_reduce_conflicts, 0);
+        assert_eq!(metrics.reduce_reduce_conflicts, 0);
+
+        // See `test_lr_states_for_non_lalr_grammar`.
+        let grammar = test_non_lalr_grammar();
+        let settings = Settings::new().table_type(TableType::LALR);
+        let table = LRTable::new(&grammar, &settings).unwrap();
+        let metrics = table.metrics();
+        assert_eq!(metrics.states, 12);
+        assert_eq!(metrics.reduce_reduce_conflicts, 2);
+    }
+
+    #[test]
+    fn test_rr_by_order_prefers_non_empty() {
+        // After `Id` both `A: EMPTY` and `B: Id` can be reduced on `C`. The
+        // non-empty reduction wins even though `A` is defined first.
+        let grammar: Grammar = r#"
+            S: Id A C | B C;
+            A: EMPTY;
+            B: Id;
+            terminals
+            Id: "id";
+            C: "c";
+            "#
+        .parse()
+        .unwrap();
+
+        let settings = Settings::new().resolve_rr_by_order(true);
+        let table = LRTable::new(&grammar, &settings).unwrap();
+        assert_eq!(table.metrics().reduce_reduce_conflicts, 0);
+
+        let c = grammar.term_by_name("C").idx;
+        let reductions = table
+            .states
+            .iter()
+            .flat_map(|state| &state.actions[c])
+            .filter_map(|action| match action {
+                Action::Reduce(prod, _) => {
+                    Some(grammar.productions[*prod].to_string(&grammar))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(reductions, ["B: Id"]);
+    }
+
+    #[test]
+    fn test_suggest_fixes() {
+        let grammar: Grammar = r#"
+            S: If S Else S | If S | A;
+            terminals
+            If: "if";
+            Else: "else";
+            A: "a";
+            "#
+        .parse()
+        .unwrap();
+
+        let settings = Settings::new();
+        let table = LRTable::new(&grammar, &settings).unwrap();
+        assert_eq!(table.metrics().shift_reduce_conflicts, 1);
+
+        // Removing `S: A` leaves `S` without a finite derivation so it is not
+        // suggested.
+        let fixes = LRTable::suggest_fixes(&grammar, &settings)
+            .iter()
+            .map(|fix| fix.to_string(&grammar))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fixes,
+            [
+                "Remove production 'S: If S Else S'.",
+                "Remove production 'S: If S'."
+            ]
+        );
+    }
+
     #[test]
     fn test_sorted_terminals() {
         let grammar: Grammar = r#"
@@ -2047,4 +2514,35 @@ mod tests {
             &vec![2, 3, 1]
         );
     }
+
+    #[test]
+    fn test_table_cache() {
+        let grammar = test_grammar_2();
+        let cache_file =
+            std::env::temp_dir().join("rustemo_test_table_cache.table");
+        let _ = std::fs::remove_file(&cache_file);
+
+        let settings = Settings::new().table_type(TableType::LALR_RN);
+        let table =
+            LRTable::with_cache(&grammar, &settings, &cache_file).unwrap();
+        assert!(table.computed);
+
+        // Unchanged grammar and settings use the cached table.
+        let cached_table =
+            LRTable::with_cache(&grammar, &settings, &cache_file).unwrap();
+        assert!(!cached_table.computed);
+        assert_eq!(
+            format!("{:#?}", cached_table.states),
+            format!("{:#?}", table.states)
+        );
+        assert_eq!(format!("{cached_table}"), format!("{table}"));
+
+        // Changed settings recalculate the table.
+        let settings = Settings::new().table_type(TableType::LALR);
+        let table =
+            LRTable::with_cache(&grammar, &settings, &cache_file).unwrap();
+        assert!(table.computed);
+
+        let _ = std::fs::remove_file(&cache_file);
+    }
 }
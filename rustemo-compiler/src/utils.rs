@@ -24,7 +24,7 @@ pub fn string_difference(a: &str, b: &str) -> Option<(usize, (char, char))> {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// let states = lr_states_for_grammar(&grammar, &settings);
 /// output_cmp!("grammar.expected.txt", format!("{states:#?}"));
 /// ```
@@ -70,7 +70,7 @@ pub use output_cmp;
 /// Requires call to `file!()` as a first parameter.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// MyParser::parse_file(local_file!(file!(), "my_local_file.txt"));
 /// ```
 #[macro_export]
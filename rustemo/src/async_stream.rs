@@ -0,0 +1,32 @@
+use crate::{
+    error::{Error, Result},
+    location::{Location, Position},
+};
+use core::{future::poll_fn, pin::pin};
+use futures_core::Stream;
+
+/// Awaits the bytes of the given stream until it ends and returns them as a
+/// string. Used by the `parse_stream` method of the generated parsers.
+///
+/// An error located at the first invalid byte is returned if the bytes are
+/// not valid UTF-8.
+pub async fn read_stream(stream: impl Stream<Item = u8>) -> Result<String> {
+    let mut stream = pin!(stream);
+    let mut input = Vec::with_capacity(stream.size_hint().0);
+    while let Some(byte) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+        input.push(byte);
+    }
+    String::from_utf8(input).map_err(|e| {
+        let valid_up_to = e.utf8_error().valid_up_to();
+        Error::Error {
+            message: format!(
+                "Invalid UTF-8 in the input stream at byte {valid_up_to}."
+            ),
+            file: None,
+            location: Some(Location {
+                start: Position::Position(valid_up_to),
+                end: None,
+            }),
+        }
+    })
+}
@@ -1,7 +1,15 @@
+use crate::Result;
+
 /// Builds output during parsing by using semantic actions.
 ///
 /// This trait is implemented by types that are in charge of building output of
 /// the parsing process (e.g. a parse tree).
+///
+/// Builders which don't need any arguments to be constructed (e.g. the
+/// generated `DefaultBuilder` or [`TreeBuilder`](crate::TreeBuilder)) provide
+/// an inherent `new()` and implement [`Default`] which is the same as `new()`.
+/// Builders which need the input (e.g. [`SliceBuilder`](crate::SliceBuilder))
+/// provide only `new(input)`.
 pub trait Builder {
     /// A type produced by this builder. See `get_result`.
     type Output;
@@ -9,4 +17,21 @@ pub trait Builder {
     /// Returns the product of parsing. Usually the one and only element left on
     /// the result stack.
     fn get_result(&mut self) -> Self::Output;
+
+    /// Returns the product of parsing or an error if the result can't be
+    /// produced. Used by the LR parser. By default calls `get_result`.
+    fn try_get_result(&mut self) -> Result<Self::Output> {
+        Ok(self.get_result())
+    }
+}
+
+/// A builder which hands over the parts of the output as soon as they are
+/// built. Used by [`LRParser::parse_iter`](crate::LRParser::parse_iter) to
+/// produce the items while parsing.
+pub trait StreamBuilder: Builder {
+    /// The type of the items handed over by this builder.
+    type Item;
+
+    /// Takes the item completed by the last builder action if any.
+    fn take_item(&mut self) -> Option<Self::Item>;
 }
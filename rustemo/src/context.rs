@@ -1,4 +1,4 @@
-use std::ops::Range;
+use core::ops::Range;
 
 use crate::{input::Input, lexer::Token, location::Location, parser::State};
 
@@ -30,4 +30,12 @@ pub trait Context<'i, I: Input + ?Sized, S: State, TK> {
     /// A layout before the token ahead
     fn layout_ahead(&self) -> Option<&'i I>;
     fn set_layout_ahead(&mut self, layout: Option<&'i I>);
+
+    /// The whole input being parsed. Used by actions to slice the input around
+    /// the current `position` or `range`. `None` if the context doesn't keep
+    /// the input.
+    fn input(&self) -> Option<&'i I> {
+        None
+    }
+    fn set_input(&mut self, _input: &'i I) {}
 }
@@ -1,22 +1,22 @@
 #[allow(unused_macros)]
-/// Prints without newline to stdout in debug profile
+/// Prints without newline to stdout in debug profile with `std`
 ///
 /// See <https://stackoverflow.com/questions/38141056/does-rust-have-a-debug-macro>
 #[macro_export]
-#[cfg(debug_assertions)]
+#[cfg(all(debug_assertions, feature = "std"))]
 macro_rules! logn {
     ($( $args:expr ),*) => { if std::env::var("RUSTEMO_NOTRACE").is_err() { eprint!( $( $args ),* )}; }
 }
 
-/// Prints with newline to stdout in debug profile
+/// Prints with newline to stdout in debug profile with `std`
 #[macro_export]
-#[cfg(debug_assertions)]
+#[cfg(all(debug_assertions, feature = "std"))]
 macro_rules! log {
     ($( $args:expr ),*) => { if std::env::var("RUSTEMO_NOTRACE").is_err() { eprintln!( $( $args ),* )}; }
 }
 
 #[macro_export]
-#[cfg(not(debug_assertions))]
+#[cfg(not(all(debug_assertions, feature = "std")))]
 macro_rules! log {
     ($( $args:expr ),*) => {
         ()
@@ -24,7 +24,7 @@ macro_rules! log {
 }
 
 #[macro_export]
-#[cfg(not(debug_assertions))]
+#[cfg(not(all(debug_assertions, feature = "std")))]
 macro_rules! logn {
     ($( $args:expr ),*) => {
         ()
@@ -1,7 +1,15 @@
-use crate::{location::Location, Context, Input, State};
-use std::fmt::{Debug, Display};
+use crate::{
+    location::{Location, Position},
+    Context, Input, State,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Debug, Display};
 
-pub type Result<R> = std::result::Result<R, Error>;
+pub type Result<R> = core::result::Result<R, Error>;
 
 /// Error type returned in `Err` variant of `Result` type from the parser.
 // ANCHOR: parser-error
@@ -12,11 +20,54 @@ pub enum Error {
         file: Option<String>,
         location: Option<Location>,
     },
+    /// The lexing budget of the parser (see
+    /// [`LRParser::max_lex_bytes`](crate::LRParser::max_lex_bytes)) is
+    /// exhausted.
+    Timeout {
+        message: String,
+        file: Option<String>,
+        location: Option<Location>,
+    },
+    /// The number of active GLR heads exceeds the limit (see
+    /// [`GlrParser::max_heads`](crate::GlrParser::max_heads)), i.e. the input
+    /// is too ambiguous to be parsed within the bounded memory.
+    TooAmbiguous {
+        message: String,
+        file: Option<String>,
+        location: Option<Location>,
+    },
+    #[cfg(feature = "std")]
     IOError(std::io::Error),
 }
 // ANCHOR_END: parser-error
 
 impl Error {
+    /// Sets the location of the error if it is not already set. Used to
+    /// attach the location of the reduction to the errors returned from
+    /// fallible actions.
+    pub fn or_location(self, location: Location) -> Self {
+        match self {
+            Error::Error {
+                message,
+                file,
+                location: None,
+            } => Error::Error {
+                message,
+                file,
+                location: Some(location),
+            },
+            error => error,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Error::Timeout { .. } => "Timeout",
+            Error::TooAmbiguous { .. } => "TooAmbiguous",
+            _ => "Error",
+        }
+    }
+
     /// A string representation of the error without the full file path.
     /// Used in tests to yield the same results at different location.
     pub fn to_locfile_str(&self) -> String {
@@ -25,8 +76,18 @@ impl Error {
                 message,
                 file,
                 location,
+            }
+            | Error::Timeout {
+                message,
+                file,
+                location,
+            }
+            | Error::TooAmbiguous {
+                message,
+                file,
+                location,
             } => {
-                let mut loc_str = String::from("Error");
+                let mut loc_str = String::from(self.kind());
                 if file.is_some() || location.is_some() {
                     loc_str.push_str(" at ");
                 }
@@ -45,20 +106,31 @@ impl Error {
                 }
                 format!("{}:\n\t{}", loc_str, message.replace('\n', "\n\t"))
             }
+            #[cfg(feature = "std")]
             Error::IOError(e) => format!("IOError: {}", e),
         }
     }
 }
 
 impl Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::Error {
                 message,
                 file,
                 location,
+            }
+            | Error::Timeout {
+                message,
+                file,
+                location,
+            }
+            | Error::TooAmbiguous {
+                message,
+                file,
+                location,
             } => {
-                let mut loc_str = String::from("Error");
+                let mut loc_str = String::from(self.kind());
                 if file.is_some() || location.is_some() {
                     loc_str.push_str(" at ");
                 }
@@ -73,17 +145,90 @@ impl Display for Error {
                 }
                 write!(f, "{}:\n\t{}", loc_str, message.replace('\n', "\n\t"))
             }
+            #[cfg(feature = "std")]
             Error::IOError(e) => write!(f, "IOError: {}", e),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IOError(e)
     }
 }
 
+/// Parse error with a known location in the input.
+///
+/// Used to render the offending input line with a caret under the error
+/// column (see [`ParseError::render`]). Constructed from [`Error`] which has a
+/// location.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub message: String,
+    pub file: Option<String>,
+    pub location: Location,
+}
+
+impl ParseError {
+    /// Renders the error followed by the line of the `source` where the error
+    /// occurred and a caret under the error column.
+    pub fn render(&self, source: &str) -> String {
+        let (line, column) = match self.location.start {
+            Position::LineBased(lc) => (lc.line, lc.column),
+            Position::Position(pos) => {
+                let before = source.get(..pos).unwrap_or(source);
+                (
+                    before.matches('\n').count() + 1,
+                    before.len() - before.rfind('\n').map_or(0, |nl| nl + 1),
+                )
+            }
+        };
+        let text = source.lines().nth(line.saturating_sub(1)).unwrap_or("");
+        // Keep tabs so that the caret is aligned with the error column.
+        let indent: String = text
+            .get(..column)
+            .unwrap_or(text)
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let gutter = " ".repeat(line.to_string().len());
+        format!("{self}\n{gutter} |\n{line} | {text}\n{gutter} | {indent}^")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut loc_str = String::from("Error at ");
+        if let Some(file) = &self.file {
+            loc_str.push_str(file);
+            loc_str.push(':');
+        }
+        loc_str.push_str(&format!("{:?}", self.location));
+        write!(f, "{}:\n\t{}", loc_str, self.message.replace('\n', "\n\t"))
+    }
+}
+
+impl TryFrom<Error> for ParseError {
+    type Error = Error;
+
+    /// Fails, returning the original error, if the error has no location.
+    fn try_from(error: Error) -> core::result::Result<Self, Error> {
+        match error {
+            Error::Error {
+                message,
+                file,
+                location: Some(location),
+            } => Ok(ParseError {
+                message,
+                file,
+                location,
+            }),
+            error => Err(error),
+        }
+    }
+}
+
 impl<R> From<Error> for Result<R> {
     fn from(value: Error) -> Self {
         Self::Err(value)
@@ -1,7 +1,8 @@
 use std::{
     cell::RefCell,
-    collections::{HashSet, VecDeque},
-    fmt::Debug,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Debug, Display},
+    iter,
     ops::Range,
     rc::Rc,
 };
@@ -181,6 +182,10 @@ where
     /// Token found ahead of this node. At first it is initialized to `None`.
     /// Finding more than one token at the current position will split the head.
     token_ahead: Option<Token<'i, I, TK>>,
+
+    /// The input the tree is built from. Set by [`Tree::build_with_input`],
+    /// the heads of the parser don't keep it.
+    input: Option<&'i I>,
 }
 
 impl<I, S, TK> Clone for GssHead<'_, I, S, TK>
@@ -202,6 +207,7 @@ where
             location_pos_before: self.location_pos_before,
             layout_ahead: self.layout_ahead,
             token_ahead: self.token_ahead().cloned(),
+            input: self.input,
         }
     }
 }
@@ -220,6 +226,7 @@ impl<I: Input + ?Sized, S: Default, TK> Default for GssHead<'_, I, S, TK> {
             location_pos_before: I::start_location().start,
             layout_ahead: Default::default(),
             token_ahead: Default::default(),
+            input: None,
         }
     }
 }
@@ -256,6 +263,7 @@ where
             location_pos_before,
             layout_ahead,
             token_ahead,
+            input: None,
         }
     }
     pub fn with_tok_state(
@@ -272,6 +280,8 @@ where
     }
     pub fn with_tok(&self, token_ahead: Token<'i, I, TK>) -> Self {
         Self {
+            position_ahead: self.position,
+            location_pos_ahead: token_ahead.location.start,
             token_ahead: Some(token_ahead),
             range: self.range(),
             ..*self
@@ -331,6 +341,8 @@ where
 
     #[inline]
     fn set_token_ahead(&mut self, token: Token<'i, I, TK>) {
+        self.position_ahead = self.position;
+        self.location_pos_ahead = token.location.start;
         self.token_ahead = Some(token)
     }
 
@@ -343,6 +355,16 @@ where
     fn set_layout_ahead(&mut self, layout: Option<&'i I>) {
         self.layout_ahead = layout
     }
+
+    #[inline]
+    fn input(&self) -> Option<&'i I> {
+        self.input
+    }
+
+    #[inline]
+    fn set_input(&mut self, input: &'i I) {
+        self.input = Some(input)
+    }
 }
 
 /// A node of the Shared Packed Parse Forest (SPPF) (sub)tree
@@ -381,6 +403,20 @@ where
         }
     }
 
+    fn solutions_saturating(
+        &self,
+        memo: &mut HashMap<*const Parent<'i, I, P, TK>, usize>,
+    ) -> usize {
+        match self {
+            SPPFTree::Term { .. } => 1,
+            SPPFTree::NonTerm { children, .. } => {
+                children.borrow().iter().fold(1usize, |solutions, p| {
+                    solutions.saturating_mul(p.solutions_saturating(memo))
+                })
+            }
+        }
+    }
+
     #[allow(clippy::mutable_key_type)]
     fn ambiguities(
         &self,
@@ -404,6 +440,274 @@ where
     }
 }
 
+impl<'i, I, P, TK> SPPFTree<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+    TK: Copy,
+{
+    /// Resolves ambiguities in this subtree bottom-up using the given policy.
+    #[allow(clippy::mutable_key_type)]
+    fn disambiguate(
+        &self,
+        policy: Policy,
+        visited: &mut HashSet<Rc<Parent<'i, I, P, TK>>>,
+    ) {
+        if let SPPFTree::NonTerm { children, .. } = self {
+            for child in children.borrow().iter() {
+                if visited.insert(Rc::clone(child)) {
+                    child.disambiguate(policy, visited);
+                }
+            }
+        }
+    }
+
+    /// Finds the first place, in depth-first left-to-right order, where more
+    /// than one of the given subtrees is possible.
+    #[allow(clippy::mutable_key_type)]
+    fn first_ambiguity(
+        possibilities: &[Rc<SPPFTree<'i, I, P, TK>>],
+        visited: &mut HashSet<Rc<Parent<'i, I, P, TK>>>,
+    ) -> Option<Ambiguity<P>>
+    where
+        P: Copy,
+    {
+        if possibilities.len() > 1 {
+            let data = match &*possibilities[0] {
+                SPPFTree::Term { data, .. }
+                | SPPFTree::NonTerm { data, .. } => data,
+            };
+            return Some(Ambiguity {
+                range: data.range.clone(),
+                productions: possibilities
+                    .iter()
+                    .filter_map(|possibility| match &**possibility {
+                        SPPFTree::Term { .. } => None,
+                        SPPFTree::NonTerm { prod, .. } => Some(*prod),
+                    })
+                    .collect(),
+            });
+        }
+        if let Some(SPPFTree::NonTerm { children, .. }) =
+            possibilities.first().map(|p| &**p)
+        {
+            for child in children.borrow().iter() {
+                if visited.insert(Rc::clone(child)) {
+                    let ambiguity = Self::first_ambiguity(
+                        &child.possibilities.borrow(),
+                        visited,
+                    );
+                    if ambiguity.is_some() {
+                        return ambiguity;
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The lowest total cost of the trees in this subtree and the index of the
+    /// cheapest tree. Terminals cost nothing.
+    fn min_cost<F>(
+        &self,
+        cost: &F,
+        memo: &mut HashMap<*const Parent<'i, I, P, TK>, (f64, usize)>,
+    ) -> (f64, usize)
+    where
+        P: Copy,
+        F: Fn(P) -> f64,
+    {
+        match self {
+            SPPFTree::Term { .. } => (0., 0),
+            SPPFTree::NonTerm { prod, children, .. } => {
+                // Child indexes are combined using the same weighted numbering
+                // system used in `Tree::children`.
+                children.borrow().iter().fold(
+                    (cost(*prod), 0),
+                    |(total, idx), child| {
+                        let (child_cost, child_idx) =
+                            child.min_cost(cost, memo);
+                        (
+                            total + child_cost,
+                            idx * child.solutions() + child_idx,
+                        )
+                    },
+                )
+            }
+        }
+    }
+
+    /// Finds the cheapest of the competing subtrees and the index of its
+    /// cheapest tree counted across all the subtrees. On a tie the first one
+    /// wins.
+    fn min_cost_possibility<F>(
+        possibilities: &[Rc<SPPFTree<'i, I, P, TK>>],
+        cost: &F,
+        memo: &mut HashMap<*const Parent<'i, I, P, TK>, (f64, usize)>,
+    ) -> (f64, usize)
+    where
+        P: Copy,
+        F: Fn(P) -> f64,
+    {
+        let mut offset = 0;
+        let mut best = (f64::INFINITY, 0);
+        for possibility in possibilities {
+            let (total, idx) = possibility.min_cost(cost, memo);
+            if total < best.0 {
+                best = (total, offset + idx);
+            }
+            offset += possibility.solutions();
+        }
+        best
+    }
+
+    /// Returns the number of tokens covered by this subtree and the sum of the
+    /// number of tokens covered by all reductions in this subtree.
+    #[allow(clippy::mutable_key_type)]
+    fn spans(
+        &self,
+        path: &mut HashSet<Rc<Parent<'i, I, P, TK>>>,
+    ) -> (usize, usize) {
+        match self {
+            SPPFTree::Term { .. } => (1, 0),
+            SPPFTree::NonTerm { .. } => {
+                let (tokens, total) = self
+                    .children_spans(path)
+                    .into_iter()
+                    .fold((0, 0), |(tokens, total), (t, s)| {
+                        (tokens + t, total + s)
+                    });
+                (tokens, total + tokens)
+            }
+        }
+    }
+
+    /// Spans of the child subtrees. Only the first possibility is followed for
+    /// ambiguous children.
+    #[allow(clippy::mutable_key_type)]
+    fn children_spans(
+        &self,
+        path: &mut HashSet<Rc<Parent<'i, I, P, TK>>>,
+    ) -> Vec<(usize, usize)> {
+        match self {
+            SPPFTree::Term { .. } => vec![],
+            SPPFTree::NonTerm { children, .. } => children
+                .borrow()
+                .iter()
+                .map(|child| {
+                    // Guard against cycles in cyclic grammars.
+                    if !path.insert(Rc::clone(child)) {
+                        return (0, 0);
+                    }
+                    let spans = child
+                        .possibilities
+                        .borrow()
+                        .first()
+                        .map_or((0, 0), |p| p.spans(path));
+                    path.remove(child);
+                    spans
+                })
+                .collect(),
+        }
+    }
+
+    /// The key used to compare competing subtrees. The greatest key wins.
+    #[allow(clippy::mutable_key_type)]
+    fn policy_key(&self, policy: Policy) -> Vec<usize> {
+        match policy {
+            Policy::LongestReduction => {
+                let spans = self.children_spans(&mut HashSet::new());
+                let (tokens, total) =
+                    spans.iter().fold((0, 0), |(tokens, total), (t, s)| {
+                        (tokens + t, total + s)
+                    });
+                iter::once(tokens + total)
+                    .chain(spans.iter().map(|&(tokens, _)| tokens))
+                    .collect()
+            }
+            Policy::MostSpecific => match self {
+                SPPFTree::Term { .. } => vec![0],
+                SPPFTree::NonTerm { children, .. } => {
+                    vec![children.borrow().len()]
+                }
+            },
+        }
+    }
+}
+
+impl<'i, I, P, TK> SPPFTree<'i, I, P, TK>
+where
+    I: Input + ?Sized + Display,
+    P: Copy,
+    TK: Copy,
+{
+    /// Renders this subtree. See [`Forest::to_tree_string`].
+    #[allow(clippy::mutable_key_type)]
+    fn tree_string<NTK>(
+        &self,
+        path: &mut HashSet<Rc<Parent<'i, I, P, TK>>>,
+    ) -> String
+    where
+        P: Into<NTK>,
+        NTK: Debug,
+    {
+        match self {
+            SPPFTree::Term { token, .. } => token.value.to_string(),
+            SPPFTree::NonTerm { children, .. } => {
+                let mut children: Vec<String> = children
+                    .borrow()
+                    .iter()
+                    .map(|child| {
+                        // Guard against cycles in cyclic grammars.
+                        if !path.insert(Rc::clone(child)) {
+                            return "...".to_string();
+                        }
+                        let rendered = Self::possibilities_string::<NTK>(
+                            &child.possibilities.borrow(),
+                            path,
+                        );
+                        path.remove(child);
+                        rendered
+                    })
+                    .collect();
+                if children.len() == 1 {
+                    children.pop().unwrap()
+                } else {
+                    format!("({})", children.join(" "))
+                }
+            }
+        }
+    }
+
+    /// Renders competing subtrees as an ambiguity node named by the
+    /// non-terminal they reduce to, e.g. `E[amb]{ (1 + 2) | 3 }`.
+    #[allow(clippy::mutable_key_type)]
+    fn possibilities_string<NTK>(
+        possibilities: &[Rc<SPPFTree<'i, I, P, TK>>],
+        path: &mut HashSet<Rc<Parent<'i, I, P, TK>>>,
+    ) -> String
+    where
+        P: Into<NTK>,
+        NTK: Debug,
+    {
+        let mut alternatives: Vec<String> = possibilities
+            .iter()
+            .map(|possibility| possibility.tree_string::<NTK>(path))
+            .collect();
+        match alternatives.len() {
+            0 => return String::new(),
+            1 => return alternatives.pop().unwrap(),
+            _ => (),
+        }
+        let name = match &*possibilities[0] {
+            SPPFTree::Term { token, .. } => token.value.to_string(),
+            SPPFTree::NonTerm { prod, .. } => {
+                format!("{:?}", Into::<NTK>::into(*prod))
+            }
+        };
+        format!("{name}[amb]{{ {} }}", alternatives.join(" | "))
+    }
+}
+
 impl<I, P, TK> Clone for SPPFTree<'_, I, P, TK>
 where
     I: Input + ?Sized,
@@ -526,6 +830,67 @@ where
             .sum()
     }
 
+    /// Number of possible solutions saturating at `usize::MAX`. Counts of the
+    /// shared parent links are calculated only once.
+    pub(crate) fn solutions_saturating(
+        &self,
+        memo: &mut HashMap<*const Self, usize>,
+    ) -> usize {
+        if let Some(&solutions) = memo.get(&(self as *const Self)) {
+            return solutions;
+        }
+        let solutions =
+            self.possibilities
+                .borrow()
+                .iter()
+                .fold(0usize, |solutions, n| {
+                    solutions.saturating_add(n.solutions_saturating(memo))
+                });
+        memo.insert(self as *const Self, solutions);
+        solutions
+    }
+
+    /// Resolves ambiguities of the child subtrees and then keeps only the
+    /// possibilities preferred by the given policy.
+    #[allow(clippy::mutable_key_type)]
+    fn disambiguate(
+        &self,
+        policy: Policy,
+        visited: &mut HashSet<Rc<Parent<'i, I, P, TK>>>,
+    ) {
+        for possibility in self.possibilities.borrow().iter() {
+            possibility.disambiguate(policy, visited);
+        }
+        let possibilities = self.possibilities.take();
+        *self.possibilities.borrow_mut() = policy.select(possibilities);
+    }
+
+    /// The lowest total cost of the trees in this parent link and the index of
+    /// the cheapest tree. Costs of the shared parent links are calculated only
+    /// once.
+    fn min_cost<F>(
+        &self,
+        cost: &F,
+        memo: &mut HashMap<*const Self, (f64, usize)>,
+    ) -> (f64, usize)
+    where
+        P: Copy,
+        F: Fn(P) -> f64,
+    {
+        if let Some(&best) = memo.get(&(self as *const Self)) {
+            return best;
+        }
+        // Guard against cycles in cyclic grammars.
+        memo.insert(self as *const Self, (f64::INFINITY, 0));
+        let best = SPPFTree::min_cost_possibility(
+            &self.possibilities.borrow(),
+            cost,
+            memo,
+        );
+        memo.insert(self as *const Self, best);
+        best
+    }
+
     /// Number of ambiguous nodes in the span covered by this parent link.
     /// If there is more than one possibility this parent link is ambiguous.
     #[allow(clippy::mutable_key_type)]
@@ -629,7 +994,28 @@ where
         P: Copy,
     {
         let mut context = C::default();
-        self.build_inner(&mut context, builder);
+        self.build_inner(&mut context, builder, false);
+        builder.get_result()
+    }
+
+    /// Build an output of the tree using the given builder like
+    /// [`Tree::build`], but give the actions the `input` the tree is parsed
+    /// from through the context. Before each action the context is set to the
+    /// position, range and location of the node, e.g. for slicing the matched
+    /// input.
+    pub fn build_with_input<B: LRBuilder<'i, I, C, S, P, TK>, C, S>(
+        &self,
+        input: &'i I,
+        builder: &mut B,
+    ) -> B::Output
+    where
+        C: Context<'i, I, S, TK> + Default,
+        S: State,
+        P: Copy,
+    {
+        let mut context = C::default();
+        context.set_input(input);
+        self.build_inner(&mut context, builder, true);
         builder.get_result()
     }
 
@@ -637,20 +1023,31 @@ where
         &self,
         context: &mut C,
         builder: &mut B,
+        with_data: bool,
     ) where
         C: Context<'i, I, S, TK> + Default,
         S: State,
         P: Copy,
     {
         match &*self.root {
-            SPPFTree::Term { token, .. } => {
+            SPPFTree::Term { token, data } => {
+                if with_data {
+                    context.set_position(data.range.start);
+                    context.set_range(data.range.clone());
+                    context.set_location(data.location);
+                }
                 builder.shift_action(context, token.clone())
             }
-            SPPFTree::NonTerm { prod, .. } => {
+            SPPFTree::NonTerm { prod, data, .. } => {
                 let children = self.children();
                 children.iter().for_each(|c| {
-                    c.build_inner(context, builder);
+                    c.build_inner(context, builder, with_data);
                 });
+                if with_data {
+                    context.set_position(data.range.end);
+                    context.set_range(data.range.clone());
+                    context.set_location(data.location);
+                }
                 builder.reduce_action(context, *prod, children.len())
             }
         }
@@ -684,6 +1081,60 @@ where
     // TODO: Implement iteration
 }
 
+impl<I, P, TK> Tree<'_, I, P, TK>
+where
+    I: Input + ?Sized + PartialEq,
+    P: PartialEq,
+    TK: Copy + PartialEq,
+{
+    /// Compares trees structurally, i.e. production kinds, token kinds and
+    /// matched values recursively. Locations are compared only if `locations`
+    /// is `true`.
+    pub fn tree_eq(&self, other: &Self, locations: bool) -> bool {
+        match (&*self.root, &*other.root) {
+            (
+                SPPFTree::Term { token, .. },
+                SPPFTree::Term { token: other, .. },
+            ) => {
+                token.kind == other.kind
+                    && token.value == other.value
+                    && (!locations || token.location == other.location)
+            }
+            (
+                SPPFTree::NonTerm { prod, data, .. },
+                SPPFTree::NonTerm {
+                    prod: other_prod,
+                    data: other_data,
+                    ..
+                },
+            ) => {
+                let children = self.children();
+                let other_children = other.children();
+                prod == other_prod
+                    && (!locations || data.location == other_data.location)
+                    && children.len() == other_children.len()
+                    && children
+                        .iter()
+                        .zip(&other_children)
+                        .all(|(c, o)| c.tree_eq(o, locations))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Structural equality which ignores locations. See [`Tree::tree_eq`].
+impl<I, P, TK> PartialEq for Tree<'_, I, P, TK>
+where
+    I: Input + ?Sized + PartialEq,
+    P: PartialEq,
+    TK: Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.tree_eq(other, false)
+    }
+}
+
 /// Shared Packed Parse Forest (SPPF) returned by the GLR parser.
 ///
 /// A forest is an ordered collection of trees. Basically, a wrapper around GSS
@@ -736,6 +1187,78 @@ where
         self.results.iter().map(|n| n.solutions()).sum()
     }
 
+    /// The number of solutions. Same as [`Forest::solutions`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.solutions()
+    }
+
+    /// Extracts the solution with the given index, or `None` if the index is
+    /// not less than [`Forest::len`].
+    ///
+    /// The indices are stable, i.e. the same input parsed by the same parser
+    /// always gives the same solution for the same index, so they can be used
+    /// to refer to the solutions, e.g. for paging through the interpretations
+    /// of an ambiguous input. Solutions are numbered by the root trees first.
+    /// Within a tree, the combinations of the children possibilities are
+    /// numbered with the last child varying fastest, and the possibilities of
+    /// an ambiguous node are taken in the order they were found by the parser.
+    /// The iteration over the forest yields the solutions in the same order.
+    pub fn solution(&self, index: usize) -> Option<Tree<'i, I, P, TK>> {
+        self.get_tree(index)
+    }
+
+    /// Resolves ambiguities of the forest using the given policy.
+    ///
+    /// Competing subtrees covering the same input span are compared
+    /// bottom-up and only the ones preferred by the policy are kept. If the
+    /// policy can't decide between some subtrees all of them are kept, thus
+    /// the resulting forest has a single tree only if all ambiguities are
+    /// resolved.
+    ///
+    /// The forest is pruned in place, i.e. trees sharing nodes with this forest
+    /// are affected.
+    pub fn disambiguate(mut self, policy: Policy) -> Self {
+        #[allow(clippy::mutable_key_type)]
+        let mut visited: HashSet<Rc<Parent<'i, I, P, TK>>> = HashSet::new();
+        for root in &self.results {
+            root.disambiguate(policy, &mut visited);
+        }
+        self.results = policy.select(std::mem::take(&mut self.results));
+        self
+    }
+
+    /// Extracts the tree with the lowest total cost where the cost of a tree is
+    /// the sum of the costs of the productions used in its reductions. The
+    /// cheapest tree is found bottom-up over the shared forest without
+    /// enumerating all the trees. On a tie, the tree with the lower index wins.
+    ///
+    /// Useful for selecting the most probable interpretation of the ambiguous
+    /// input by penalizing some productions.
+    pub fn min_cost_tree<F>(&self, cost: F) -> Option<Tree<'i, I, P, TK>>
+    where
+        P: Copy,
+        F: Fn(P) -> f64,
+    {
+        let (_, idx) = SPPFTree::min_cost_possibility(
+            &self.results,
+            &cost,
+            &mut HashMap::new(),
+        );
+        self.get_tree(idx)
+    }
+
+    /// The first ambiguous place of this forest in depth-first left-to-right
+    /// order, or `None` if the forest has a single tree.
+    pub fn first_ambiguity(&self) -> Option<Ambiguity<P>>
+    where
+        P: Copy,
+    {
+        #[allow(clippy::mutable_key_type)]
+        let mut visited: HashSet<Rc<Parent<'i, I, P, TK>>> = HashSet::new();
+        SPPFTree::first_ambiguity(&self.results, &mut visited)
+    }
+
     /// Total number of ambiguous places/nodes in this forest.
     ///
     /// Extracted trees are unambiguous but forests may have ambiguities.
@@ -752,6 +1275,83 @@ where
     }
 }
 
+impl<I, P, TK> Forest<'_, I, P, TK>
+where
+    I: Input + ?Sized + Display,
+    P: Copy,
+    TK: Copy,
+{
+    /// Renders the forest as a single string with the ambiguities shown
+    /// explicitly. Used for debugging ambiguous grammars.
+    ///
+    /// Terminals are rendered by their values and non-terminals as their
+    /// parenthesized children (single child is rendered without parentheses).
+    /// Places with more than one possibility are rendered as alternatives
+    /// separated by `|`, e.g. `E[amb]{ ((1 + 2) * 3) | (1 + (2 * 3)) }`.
+    ///
+    /// Ambiguous places are named by the `Debug` output of the non-terminal
+    /// kind `NTK` the competing productions reduce to, e.g.
+    /// `forest.to_tree_string::<NonTermKind>()`.
+    pub fn to_tree_string<NTK>(&self) -> String
+    where
+        P: Into<NTK>,
+        NTK: Debug,
+    {
+        #[allow(clippy::mutable_key_type)]
+        let mut path: HashSet<Rc<Parent<'_, I, P, TK>>> = HashSet::new();
+        SPPFTree::possibilities_string::<NTK>(&self.results, &mut path)
+    }
+}
+
+/// An ambiguous place of a forest. See [`Forest::first_ambiguity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ambiguity<P> {
+    /// The span of the input covered by the competing subtrees. The layout
+    /// before the first token is included.
+    pub range: Range<usize>,
+    /// The productions of the competing subtrees. Competing terminals, i.e.
+    /// lexical ambiguities, have no production.
+    pub productions: Vec<P>,
+}
+
+/// A policy used to resolve ambiguities in [`Forest::disambiguate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Prefer the subtree with the greatest total span of its reductions, i.e.
+    /// the deepest reductions. On a tie, prefer the subtree whose children
+    /// reductions are longer going from left to right.
+    LongestReduction,
+    /// Prefer the subtree reduced by the production with the most symbols on
+    /// the right-hand side.
+    MostSpecific,
+}
+
+impl Policy {
+    /// Keeps only the subtrees preferred by this policy.
+    fn select<'i, I, P, TK>(
+        self,
+        trees: Vec<Rc<SPPFTree<'i, I, P, TK>>>,
+    ) -> Vec<Rc<SPPFTree<'i, I, P, TK>>>
+    where
+        I: Input + ?Sized,
+        TK: Copy,
+    {
+        if trees.len() < 2 {
+            return trees;
+        }
+        let keys = trees
+            .iter()
+            .map(|tree| tree.policy_key(self))
+            .collect::<Vec<_>>();
+        let best = keys.iter().max().cloned().unwrap_or_default();
+        trees
+            .into_iter()
+            .zip(keys)
+            .filter_map(|(tree, key)| (key == best).then_some(tree))
+            .collect()
+    }
+}
+
 /// Support for into_iter, i.e. iteration in for loops
 pub struct ForestIntoIter<'i, I, P, TK>
 where
@@ -794,6 +1394,51 @@ where
     }
 }
 
+impl<'i, I, P, TK> Forest<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+    TK: Copy,
+{
+    /// Consumes the forest and builds the output of each tree/solution. A new
+    /// builder for each tree is made by the given factory, e.g.
+    /// `DefaultBuilder::new`, so that semantic actions are run per solution.
+    pub fn into_outputs<B, C, S, F>(
+        self,
+        mut new_builder: F,
+    ) -> impl Iterator<Item = B::Output> + 'i
+    where
+        B: LRBuilder<'i, I, C, S, P, TK>,
+        C: Context<'i, I, S, TK> + Default,
+        S: State,
+        P: Copy + 'i,
+        TK: 'i,
+        F: FnMut() -> B + 'i,
+    {
+        self.into_iter()
+            .map(move |tree| tree.build::<B, C, S>(&mut new_builder()))
+    }
+
+    /// Like [`Forest::into_outputs`] but each tree is built with
+    /// [`Tree::build_with_input`] from the `input` the forest is parsed from.
+    pub fn into_outputs_with_input<B, C, S, F>(
+        self,
+        input: &'i I,
+        mut new_builder: F,
+    ) -> impl Iterator<Item = B::Output> + 'i
+    where
+        B: LRBuilder<'i, I, C, S, P, TK>,
+        C: Context<'i, I, S, TK> + Default,
+        S: State,
+        P: Copy + 'i,
+        TK: 'i,
+        F: FnMut() -> B + 'i,
+    {
+        self.into_iter().map(move |tree| {
+            tree.build_with_input::<B, C, S>(input, &mut new_builder())
+        })
+    }
+}
+
 /// Support for iter()
 impl<'i, I, P, TK> Forest<'i, I, P, TK>
 where
@@ -21,13 +21,13 @@ use crate::{
     utils::Dedup,
     Error, Result,
 };
-#[cfg(debug_assertions)]
+#[cfg(all(debug_assertions, feature = "std"))]
 use colored::*;
 use petgraph::prelude::*;
 use std::{
     borrow::Borrow,
     cell::RefCell,
-    collections::{BTreeMap, VecDeque},
+    collections::{BTreeMap, HashMap, VecDeque},
     fmt::{Debug, Display},
     marker::PhantomData,
     ops::Range,
@@ -142,6 +142,17 @@ pub struct GlrParser<
     /// consumed. Use with care in GLR as it can lead to a *huge* number of
     /// possible solutions/trees.
     partial_parse: bool,
+
+    /// The maximal number of solutions. If exceeded, parsing is aborted with
+    /// an error.
+    max_solutions: Option<usize>,
+
+    /// The maximal number of active heads in a frontier. If exceeded, parsing
+    /// is aborted with [`Error::TooAmbiguous`].
+    max_heads: Option<usize>,
+
+    /// Is an ambiguous parse reported as an error.
+    ambiguity_is_error: bool,
     start_position: usize,
     has_layout: bool,
     lexer: Rc<L>,
@@ -170,6 +181,9 @@ where
             layout_parser: RefCell::new(None),
             definition,
             partial_parse,
+            max_solutions: None,
+            max_heads: None,
+            ambiguity_is_error: false,
             start_position: 0,
             has_layout,
             lexer: Rc::new(lexer),
@@ -177,6 +191,69 @@ where
         }
     }
 
+    /// Sets the maximal number of solutions. Parsing is aborted with an error
+    /// as soon as the accepted solutions exceed this number. Used to prevent
+    /// runaway parsing of highly ambiguous inputs, e.g. in partial parsing.
+    pub fn max_solutions(mut self, max_solutions: usize) -> Self {
+        self.max_solutions = Some(max_solutions);
+        self
+    }
+
+    /// Sets the maximal number of active heads in a frontier, i.e. the number
+    /// of parses carried on in parallel at a position of the input. Parsing
+    /// is aborted with [`Error::TooAmbiguous`] located at the position where
+    /// the limit is exceeded. Used to bound the memory used for highly
+    /// ambiguous or adversarial inputs.
+    pub fn max_heads(mut self, max_heads: usize) -> Self {
+        self.max_heads = Some(max_heads);
+        self
+    }
+
+    /// Fails with [`Error::TooAmbiguous`] if the given number of active heads
+    /// exceeds the limit. The error is located at the given head.
+    fn check_heads(
+        &self,
+        heads: usize,
+        head: &GssHead<'i, I, S, TK>,
+    ) -> Result<()> {
+        match self.max_heads {
+            Some(max_heads) if heads > max_heads => Err(Error::TooAmbiguous {
+                message: format!(
+                    "Number of GLR heads exceeds the maximum of {max_heads}."
+                ),
+                file: Some(self.file_name.clone()),
+                location: Some(head.location()),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports an ambiguous parse as an error instead of returning a forest
+    /// with multiple trees. The error is located at the first ambiguous place
+    /// and lists the competing productions. Used for grammars which are
+    /// supposed to be unambiguous.
+    pub fn ambiguity_is_error(mut self, ambiguity_is_error: bool) -> Self {
+        self.ambiguity_is_error = ambiguity_is_error;
+        self
+    }
+
+    /// The number of solutions of the accepted heads saturating at
+    /// `usize::MAX`.
+    fn solutions(
+        &self,
+        gss: &GssGraph<'i, I, S, P, TK>,
+        accepted_heads: &[NodeIndex],
+    ) -> usize {
+        let mut memo = HashMap::new();
+        accepted_heads
+            .iter()
+            .flat_map(|&head| gss.backedges(head))
+            .fold(0usize, |solutions, p| {
+                solutions
+                    .saturating_add(p.weight().solutions_saturating(&mut memo))
+            })
+    }
+
     /// Create pending shifts and reduction for the initial frontier.
     fn initial_process_frontier(
         &self,
@@ -280,7 +357,7 @@ where
         gss: &mut GssGraph<'i, I, S, P, TK>,
         frontier_base: &Vec<NodeIndex>,
         input: &'i I,
-    ) -> BTreeMap<(usize, TK), BTreeMap<S, NodeIndex>> {
+    ) -> Result<BTreeMap<(usize, TK), BTreeMap<S, NodeIndex>>> {
         let mut frontier: BTreeMap<(usize, TK), BTreeMap<S, NodeIndex>> =
             BTreeMap::new();
         for &head_idx in frontier_base {
@@ -336,7 +413,13 @@ where
                 }
             }
         }
-        frontier
+        let heads = frontier.values().map(BTreeMap::len).sum();
+        if let Some(&head_idx) =
+            frontier.values().flat_map(|s| s.values()).next()
+        {
+            self.check_heads(heads, gss.head(head_idx))?;
+        }
+        Ok(frontier)
     }
 
     /// Find all possible lookahead tokens for the given head. There can be more
@@ -379,11 +462,12 @@ where
                         // Longest match strategy for lexical disambiguation
                         let longest_len = tokens
                             .iter()
-                            .max_by_key(|token| token.value.len())
+                            .max_by_key(|token| token.consumed_len())
                             .unwrap()
-                            .value
-                            .len();
-                        tokens.retain(|token| token.value.len() == longest_len);
+                            .consumed_len();
+                        tokens.retain(|token| {
+                            token.consumed_len() == longest_len
+                        });
                         log!("{} {:?}", "Tokens retained:".green(), &tokens);
                     }
 
@@ -398,8 +482,16 @@ where
                         tokens.truncate(1)
                     }
                 }
+                #[cfg(feature = "tracing")]
+                for token in &tokens {
+                    tracing::trace!(
+                        kind = ?token.kind,
+                        position = head.position(),
+                        "token"
+                    );
+                }
                 return tokens;
-            } else if layout_parsing {
+            } else if layout_parsing && !head.state().no_layout() {
                 layout_parsing = false;
                 if let Some(layout_parser) =
                     self.layout_parser.borrow_mut().as_ref()
@@ -433,6 +525,8 @@ where
                 kind: stop_kind,
                 value: &input[0..0],
                 location: head.location(),
+                attr: None,
+                consumed: None,
             }]
         } else {
             vec![]
@@ -495,7 +589,8 @@ where
         pending_shifts: &mut Vec<(NodeIndex, S)>,
         accepted_heads: &mut Vec<NodeIndex>,
         subfrontier: &mut BTreeMap<S, NodeIndex>,
-    ) {
+        heads: &mut usize,
+    ) -> Result<()> {
         log!(
             "\n{}{}",
             "Reducing".red(),
@@ -529,6 +624,12 @@ where
                 },
                 reduction.length
             );
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                production = ?production,
+                len = reduction.length,
+                "reduce"
+            );
             for path in self.find_reduction_paths(gss, &reduction) {
                 log!("  {} {path}", "Reducing over path:".green());
                 let token_kind_ahead =
@@ -569,6 +670,8 @@ where
                         let new_head_str = format!("{:?}", new_head);
                         let new_head_idx = gss.add_head(new_head);
                         subfrontier.insert(next_state, new_head_idx);
+                        *heads += 1;
+                        self.check_heads(*heads, gss.head(new_head_idx))?;
                         log!(
                             "    {} {}: {}",
                             "Created reduced head".green(),
@@ -667,16 +770,32 @@ where
 
                         let root_head = gss.head(path.root_head);
                         let start_head = gss.head(start_head);
+                        // EMPTY reductions, and reductions of EMPTY
+                        // non-terminals only, span no input and are placed at
+                        // the start of the token ahead.
+                        let (end, end_pos) = if start_head.position_before
+                            > root_head.position_ahead
+                        {
+                            (
+                                start_head.position_before,
+                                start_head.location_pos_before,
+                            )
+                        } else {
+                            (
+                                root_head.position_ahead,
+                                root_head.location_pos_ahead,
+                            )
+                        };
                         let solution = Rc::new(SPPFTree::NonTerm {
                             prod: production,
                             data: TreeData {
                                 range: Range {
                                     start: root_head.position_ahead,
-                                    end: start_head.position_before,
+                                    end,
                                 },
                                 location: Location {
                                     start: root_head.location_pos_ahead,
-                                    end: Some(start_head.location_pos_before),
+                                    end: Some(end_pos),
                                 },
                                 layout: root_head.layout_ahead(),
                             },
@@ -760,12 +879,14 @@ where
                 }
             }
         }
+        Ok(())
     }
 
     /// Do all pending shifts and create the next frontier base.
     fn shifter(
         &self,
         gss: &mut GssGraph<'i, I, S, P, TK>,
+        input: &'i I,
         pending_shifts: &mut Vec<(NodeIndex, S)>,
         frontier_idx: usize,
     ) -> Vec<NodeIndex> {
@@ -786,7 +907,14 @@ where
         while let Some((head_idx, state)) = pending_shifts.pop() {
             let head = gss.head(head_idx);
             let token = head.token_ahead().cloned().unwrap();
-            let position = head.position() + token.value.len();
+            let position = head.position() + token.consumed_len();
+            let data = TreeData {
+                range: head.position()..position,
+                location: token.location,
+                layout: head.layout_ahead(),
+            };
+            #[cfg(feature = "tracing")]
+            tracing::trace!(state = ?state, token = ?token.kind, "shift");
             log!(
                 "{}",
                 format!(
@@ -811,11 +939,12 @@ where
                         // FIXME
                         position,
                         head.position()..position,
-                        token.value.location_after(head.location()),
+                        input[head.position()..position]
+                            .location_after(head.location()),
                         position,
                         position,
                         Default::default(),
-                        Default::default(),
+                        data.location.end.unwrap_or(data.location.start),
                         None,
                         None,
                     );
@@ -837,18 +966,7 @@ where
             gss.add_solution(
                 shifted_head_idx,
                 head_idx,
-                Rc::new(SPPFTree::Term {
-                    token,
-                    data: TreeData {
-                        // FIXME:
-                        range: Default::default(),
-                        location: Location {
-                            start: Default::default(),
-                            end: Default::default(),
-                        },
-                        layout: None,
-                    },
-                }),
+                Rc::new(SPPFTree::Term { token, data }),
             );
         }
         frontier_base.into_values().collect()
@@ -1037,6 +1155,9 @@ where
         context: &mut GssHead<'i, I, S, TK>,
         input: &'i I,
     ) -> Result<Self::Output> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("parse", file = %self.file_name).entered();
         let mut gss: GssGraph<'i, I, S, P, TK> = GssGraph::new();
         let start_head = gss.add_head(context.clone());
         if self.has_layout {
@@ -1083,7 +1204,8 @@ where
 
         while !frontier_base.is_empty() {
             let mut frontier =
-                self.create_frontier(&mut gss, &frontier_base, input);
+                self.create_frontier(&mut gss, &frontier_base, input)?;
+            let mut heads = frontier.values().map(BTreeMap::len).sum();
             // Create initial shifts/reductions for this frontier
             self.initial_process_frontier(
                 &mut gss,
@@ -1109,15 +1231,34 @@ where
                     &mut pending_shifts,
                     &mut accepted_heads,
                     subfrontier,
-                );
+                    &mut heads,
+                )?;
             }
             frontier_idx += 1;
             // Do shifts and create the next base frontier
-            let fb = self.shifter(&mut gss, &mut pending_shifts, frontier_idx);
+            let fb = self.shifter(
+                &mut gss,
+                input,
+                &mut pending_shifts,
+                frontier_idx,
+            );
             if fb.is_empty() {
                 last_frontier_base = frontier_base;
             }
             frontier_base = fb;
+
+            if let Some(max_solutions) = self.max_solutions {
+                if self.solutions(&gss, &accepted_heads) > max_solutions {
+                    return Err(Error::Error {
+                        message: format!(
+                            "Number of solutions exceeds the maximum \
+                             of {max_solutions}."
+                        ),
+                        file: Some(self.file_name.clone()),
+                        location: None,
+                    });
+                }
+            }
         }
 
         if !accepted_heads.is_empty() {
@@ -1128,6 +1269,30 @@ where
                 "Finished".red(),
                 format!("{} solutions found.", forest.solutions()).green()
             );
+            #[cfg(feature = "tracing")]
+            tracing::debug!(solutions = forest.solutions(), "accept");
+            if self.ambiguity_is_error {
+                if let Some(ambiguity) = forest.first_ambiguity() {
+                    let Range { start, end } = ambiguity.range;
+                    let start = start + input[start..end].whitespace_len();
+                    let location = input[start..end].location_span(
+                        input[0..start].location_after(I::start_location()),
+                    );
+                    return Err(Error::Error {
+                        message: format!(
+                            "Ambiguous input. Competing productions: {}.",
+                            ambiguity
+                                .productions
+                                .iter()
+                                .map(|prod| format!("{prod:?}"))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                        file: Some(self.file_name.clone()),
+                        location: Some(location),
+                    });
+                }
+            }
             Ok(forest)
         } else {
             Err(self.make_error(gss, input, last_frontier_base))
@@ -1141,9 +1306,31 @@ where
     where
         'a: 'i,
     {
-        self.content = Some(I::read_file(file.as_ref())?);
         self.file_name = file.as_ref().to_string_lossy().into();
-        let parsed = self.parse(self.content.as_ref().unwrap().borrow());
-        parsed
+        self.parse_owned(I::read_file(file.as_ref())?)
+    }
+}
+
+impl<'i, I, S, TK, NTK, L, P, D, B> GlrParser<'i, S, L, P, TK, NTK, D, I, B>
+where
+    I: Input + ?Sized + Debug,
+    L: Lexer<'i, GssHead<'i, I, S, TK>, S, TK, Input = I>,
+    S: State + Debug + Ord,
+    P: Copy + Debug + Into<NTK> + PartialEq,
+    TK: Copy + Debug + Ord + Default + 'i,
+    D: ParserDefinition<S, P, TK, NTK>,
+{
+    /// Parses the given owned content (e.g. a preprocessed input). Like in
+    /// `parse_file`, the parser owns the content and thus has to outlive the
+    /// forest.
+    pub fn parse_owned<'a>(
+        &'a mut self,
+        content: I::Owned,
+    ) -> Result<Forest<'i, I, P, TK>>
+    where
+        'a: 'i,
+    {
+        self.content = Some(content);
+        self.parse(self.content.as_ref().unwrap().borrow())
     }
 }
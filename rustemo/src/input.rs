@@ -1,14 +1,45 @@
-use crate::{
-    error::Result,
-    location::{LineColumn, Location, Position},
+use crate::location::{LineColumn, Location, Position};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    format,
+    string::{String, ToString},
+    vec::Vec,
 };
-use std::{
-    borrow::ToOwned,
+use core::{
     cmp::min,
     iter::once,
     ops::{Deref, Index, Range},
-    path::Path,
 };
+#[cfg(feature = "std")]
+use {crate::error::Result, std::path::Path};
+/// Preprocesses the string input before parsing.
+///
+/// If `strip_bom` is set, the leading byte order mark (`U+FEFF`) is removed. If
+/// `normalize_newlines` is set, each `\r\n` sequence is replaced by `\n`. The
+/// input is borrowed if nothing is changed.
+///
+/// Positions reported by the parser are positions in the preprocessed text. To
+/// map them back to the original text: a stripped BOM shifts all byte positions
+/// by 3 (and the columns in the first line by 1), and each replaced `\r\n`
+/// before the position shifts byte positions by 1. Lines and the columns of
+/// other lines are the same as in the original text.
+pub fn preprocess(
+    input: &str,
+    strip_bom: bool,
+    normalize_newlines: bool,
+) -> Cow<'_, str> {
+    let input = if strip_bom {
+        input.strip_prefix('\u{feff}').unwrap_or(input)
+    } else {
+        input
+    };
+    if normalize_newlines && input.contains("\r\n") {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
 /// Input is a sliceable sequence-like type with a concept of length.
 ///
 /// This trait must be implemented by all types that should be parsed by
@@ -35,7 +66,20 @@ pub trait Input: ToOwned + Index<Range<usize>, Output = Self> {
         &self[range]
     }
 
+    /// Returns the length of the leading whitespace. Used by the lexers which
+    /// skip whitespaces. By default, nothing is skipped.
+    fn whitespace_len(&self) -> usize {
+        0
+    }
+
+    /// Determines if the input can be split at the given position, e.g. if it
+    /// is a char boundary for `str`. By default, all positions are boundaries.
+    fn is_boundary(&self, _position: usize) -> bool {
+        true
+    }
+
     /// Read the file from the given path into owned version of the input.
+    #[cfg(feature = "std")]
     fn read_file<P: AsRef<Path>>(path: P) -> Result<Self::Owned>;
 
     fn start_location() -> Location {
@@ -95,6 +139,18 @@ impl Input for str {
                     .unwrap_or(range.start)]
     }
 
+    fn whitespace_len(&self) -> usize {
+        self.chars()
+            .take_while(|x| x.is_whitespace())
+            .map(|c| c.len_utf8())
+            .sum()
+    }
+
+    #[inline]
+    fn is_boundary(&self, position: usize) -> bool {
+        self.is_char_boundary(position)
+    }
+
     fn start_location() -> Location {
         Location {
             start: Position::LineBased(LineColumn { line: 1, column: 0 }),
@@ -125,6 +181,7 @@ impl Input for str {
         }
     }
 
+    #[cfg(feature = "std")]
     fn read_file<P: AsRef<Path>>(path: P) -> Result<Self::Owned> {
         Ok(std::fs::read_to_string(path)?)
     }
@@ -165,6 +222,7 @@ impl Input for [u8] {
         }
     }
 
+    #[cfg(feature = "std")]
     fn read_file<P: AsRef<Path>>(path: P) -> Result<Self::Owned> {
         Ok(std::fs::read(path)?)
     }
@@ -187,6 +245,7 @@ where
         (**self).len()
     }
 
+    #[cfg(feature = "std")]
     fn read_file<P: AsRef<Path>>(path: P) -> Result<Self::Owned> {
         I::read_file(path)
     }
@@ -196,3 +255,19 @@ where
         (**self).location_after(location)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::preprocess;
+
+    #[test]
+    pub fn test_preprocess() {
+        let input = "\u{feff}a\r\nb\n";
+        assert_eq!(preprocess(input, true, true), "a\nb\n");
+        assert_eq!(preprocess(input, true, false), "a\r\nb\n");
+        assert_eq!(preprocess(input, false, true), "\u{feff}a\nb\n");
+        assert!(matches!(preprocess("a\nb", true, true), Cow::Borrowed(_)));
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
     context::Context, input::Input, location::Location, log, parser::State,
 };
-#[cfg(debug_assertions)]
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+#[cfg(all(debug_assertions, feature = "std"))]
 use colored::*;
 use core::fmt::Debug;
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 /// The trait implemented by all Rustemo lexers
 ///
@@ -18,8 +19,10 @@ use std::marker::PhantomData;
 /// - `TK` - token kind type. This is generated by the parser generator from the
 ///   grammar. This is the type that describes the kinds of token lexer can
 ///   produce.
+/// - `A` - the type of token attributes. Custom lexers may attach additional
+///   information to tokens which is then available in the builder.
 ///
-pub trait Lexer<'i, C, S, TK>
+pub trait Lexer<'i, C, S, TK, A = ()>
 where
     C: Context<'i, Self::Input, S, TK>,
     S: State,
@@ -28,7 +31,7 @@ where
 
     // TODO: Optimization. Find an ergonomic way to return iterator from
     // next_tokens without boxing and using an indirection through trait object
-    //type TokenIterator: Iterator<Item = Token<'i, Self::Input, TK>>;
+    //type TokenIterator: Iterator<Item = Token<'i, Self::Input, TK, A>>;
 
     /// Given the current context, this method yield an iterator over possible
     /// tokens found at the current location where the order and kinds of token
@@ -42,33 +45,238 @@ where
         context: &mut C,
         input: &'i Self::Input,
         expected_tokens: Vec<(TK, bool)>,
-    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TK>> + 'i>;
+    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TK, A>> + 'i>;
 }
 
-/// The trait implemented by types used to recognize tokens in string inputs.
-/// Used by [`StringLexer`].
-pub trait TokenRecognizer<'i> {
-    fn recognize(&self, _input: &'i str) -> Option<&'i str> {
-        panic!("Recognize is not defined.")
+/// The trait implemented by types used to recognize tokens. Used by
+/// [`StringLexer`]. `C` is the parsing context type and `I` is the type of the
+/// input which is `str` by default.
+pub trait TokenRecognizer<'i, C, I: Input + ?Sized = str> {
+    /// Recognizes the token at the `position` of the whole `input` and returns
+    /// the length of the consumed input and the value of the token. The value
+    /// may differ from the consumed input, e.g. a hex literal without its `0x`
+    /// prefix. Context-sensitive recognizers may use the parsing context to
+    /// inspect the already parsed part of the input. [`StringLexer`] calls
+    /// this method for each expected token.
+    fn recognize_value(
+        &self,
+        context: &C,
+        input: &'i I,
+        position: usize,
+    ) -> Option<(usize, &'i I)>;
+
+    /// Does the recognizer need the parsing context? If none of the expected
+    /// tokens needs the context, [`StringLexer`] recognizes the tokens lazily,
+    /// i.e. only until the parser accepts a token. The recognizers then get a
+    /// copy of the state, position, location, range, layout and input of the
+    /// parsing context but not the rest of it (e.g. user state).
+    fn needs_context(&self) -> bool {
+        false
+    }
+
+    /// Recognizes the token at the start of the `input` with the default
+    /// context and returns its value. Calls `recognize_value`.
+    fn recognize(&self, input: &'i I) -> Option<&'i I>
+    where
+        C: Default,
+    {
+        self.recognize_value(&C::default(), input, 0)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The result of a token recognizer. Either the recognized part of the input
+/// which is both consumed and used as the token value, or the length of the
+/// consumed input together with the token value.
+pub trait RecognizedValue<'i, I: Input + ?Sized> {
+    /// Returns the length of the consumed input and the token value.
+    fn consumed_and_value(self) -> (usize, &'i I);
+}
+
+impl<'i, I: Input + ?Sized> RecognizedValue<'i, I> for &'i I {
+    fn consumed_and_value(self) -> (usize, &'i I) {
+        (self.len(), self)
     }
 }
 
-/// A lexer that operates over string inputs and uses generated string and regex
-/// recognizers provided by the parser table.
-pub struct StringLexer<C, S, TK, TR: 'static, const TERMINAL_COUNT: usize> {
+impl<'i, I: Input + ?Sized> RecognizedValue<'i, I> for (usize, &'i I) {
+    fn consumed_and_value(self) -> (usize, &'i I) {
+        self
+    }
+}
+
+/// The result of recognizing a token in an input which may continue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recognized<T> {
+    /// The token is recognized.
+    Match(T),
+    /// The token can't be recognized at the current position.
+    NoMatch,
+    /// The input ends inside a possible match. More input is needed.
+    Incomplete,
+}
+
+/// The trait implemented by token recognizers which can recognize tokens in
+/// the input arriving in chunks. Used by [`IncrementalLexer`].
+pub trait PartialTokenRecognizer {
+    /// Recognizes the token at the start of the `input`. If `complete` is
+    /// `false` more input may follow and [`Recognized::Incomplete`] is
+    /// returned if the `input` ends inside a possible match.
+    fn recognize_partial<'i>(
+        &self,
+        input: &'i str,
+        complete: bool,
+    ) -> Recognized<&'i str>;
+}
+
+/// A lexer for the input arriving in chunks, e.g. from the network, where a
+/// token may be split across reads.
+///
+/// The input is fed to the lexer using [`IncrementalLexer::feed`] and the
+/// recognition resumes from the current position. Until
+/// [`IncrementalLexer::finish`] is called, the tokens which could still
+/// continue are reported as [`Recognized::Incomplete`].
+pub struct IncrementalLexer<TK, TR: 'static, const TERMINAL_COUNT: usize> {
     skip_ws: bool,
     token_recognizers: &'static [TR; TERMINAL_COUNT],
+    buffer: String,
+    position: usize,
+    location: Location,
+    complete: bool,
+    phantom: PhantomData<TK>,
+}
+
+impl<TK, TR, const TERMINAL_COUNT: usize>
+    IncrementalLexer<TK, TR, TERMINAL_COUNT>
+where
+    TK: Debug + Into<usize> + Copy,
+    TR: PartialTokenRecognizer,
+{
+    pub fn new(
+        skip_ws: bool,
+        token_recognizers: &'static [TR; TERMINAL_COUNT],
+    ) -> Self {
+        Self {
+            skip_ws,
+            token_recognizers,
+            buffer: String::new(),
+            position: 0,
+            location: str::start_location(),
+            complete: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Appends the next chunk of the input. The already recognized part of
+    /// the input is dropped from the buffer.
+    pub fn feed(&mut self, chunk: &str) {
+        self.buffer.drain(..self.position);
+        self.position = 0;
+        self.buffer.push_str(chunk);
+    }
+
+    /// Marks the end of the input. The tokens at the end of the input are
+    /// recognized as there is no more input to wait for.
+    pub fn finish(&mut self) {
+        self.complete = true;
+    }
+
+    /// The part of the input fed to the lexer which is not recognized yet.
+    pub fn rest(&self) -> &str {
+        &self.buffer[self.position..]
+    }
+
+    /// Tries to recognize one of the `expected_tokens` at the current
+    /// position. The tokens are tried in the given order and the first
+    /// recognized is returned. If any of the tokens may still be recognized
+    /// differently when more input arrives, [`Recognized::Incomplete`] is
+    /// returned and the position is unchanged.
+    pub fn next_token(
+        &mut self,
+        expected_tokens: &[TK],
+    ) -> Recognized<Token<'_, str, TK>> {
+        if self.skip_ws {
+            let skipped_len: usize = self
+                .rest()
+                .chars()
+                .take_while(|x| x.is_whitespace())
+                .map(|c| c.len_utf8())
+                .sum();
+            let skipped =
+                &self.buffer[self.position..self.position + skipped_len];
+            self.location = skipped.location_after(self.location);
+            self.position += skipped_len;
+        }
+        log!("  {} {:?}", "Trying recognizers:".green(), expected_tokens);
+
+        let input = &self.buffer[self.position..];
+        let mut recognized = None;
+        for &token_kind in expected_tokens {
+            match self.token_recognizers[token_kind.into()]
+                .recognize_partial(input, self.complete)
+            {
+                Recognized::Match(value) => {
+                    recognized = recognized.or(Some((token_kind, value)))
+                }
+                Recognized::NoMatch => (),
+                Recognized::Incomplete => return Recognized::Incomplete,
+            }
+        }
+
+        match recognized {
+            Some((kind, value)) => {
+                let location = value.location_span(self.location);
+                self.location = value.location_after(self.location);
+                self.position += value.len();
+                Recognized::Match(Token {
+                    kind,
+                    value,
+                    location,
+                    attr: None,
+                    consumed: None,
+                })
+            }
+            None => Recognized::NoMatch,
+        }
+    }
+}
+
+/// A function which, given the input at the current position, returns for each
+/// terminal whether it may be recognized. Used by [`StringLexer`] to skip the
+/// recognizers which can't succeed, e.g. regex terminals not matched by a
+/// single scan of a `RegexSet`.
+pub type Prefilter<const TERMINAL_COUNT: usize, I = str> =
+    fn(&I) -> [bool; TERMINAL_COUNT];
+
+/// A lexer that uses generated string and regex recognizers provided by the
+/// parser table.
+///
+/// The lexer operates over string inputs by default but can be used with any
+/// [`Input`] (e.g. a rope) given the recognizers for that input type.
+pub struct StringLexer<
+    C,
+    S,
+    TK,
+    TR: 'static,
+    const TERMINAL_COUNT: usize,
+    I: ?Sized = str,
+> {
+    skip_ws: bool,
+    token_recognizers: &'static [TR; TERMINAL_COUNT],
+    prefilter: Option<Prefilter<TERMINAL_COUNT, I>>,
     phantom: PhantomData<(C, S, TK)>,
+    input: PhantomData<fn(&I)>,
 }
 
 impl<
         'i,
-        C: Context<'i, str, S, TK>,
+        C: Context<'i, I, S, TK>,
         S: State,
         TK,
-        TR: TokenRecognizer<'i>,
+        TR: TokenRecognizer<'i, C, I>,
         const TERMINAL_COUNT: usize,
-    > StringLexer<C, S, TK, TR, TERMINAL_COUNT>
+        I: Input + ?Sized,
+    > StringLexer<C, S, TK, TR, TERMINAL_COUNT, I>
 {
     pub fn new(
         skip_ws: bool,
@@ -77,16 +285,25 @@ impl<
         Self {
             skip_ws,
             token_recognizers,
+            prefilter: None,
             phantom: PhantomData,
+            input: PhantomData,
         }
     }
 
-    fn skip(input: &'i str, context: &mut C) {
-        let skipped_len: usize = input[context.position()..]
-            .chars()
-            .take_while(|x| x.is_whitespace())
-            .map(|c| c.len_utf8())
-            .sum();
+    /// Sets the prefilter called once per lexing position. Recognizers of
+    /// the terminals rejected by the prefilter are not tried.
+    pub fn prefilter(
+        mut self,
+        prefilter: Prefilter<TERMINAL_COUNT, I>,
+    ) -> Self {
+        self.prefilter = Some(prefilter);
+        self
+    }
+
+    fn skip(input: &'i I, context: &mut C) {
+        let skipped_len =
+            input[context.position()..input.len()].whitespace_len();
         if skipped_len > 0 {
             let skipped =
                 &input[context.position()..context.position() + skipped_len];
@@ -100,100 +317,260 @@ impl<
     }
 }
 
-struct TokenIterator<'i, TR: 'static, TK> {
-    input: &'i str,
+impl<'i, C, S, TK, TR, const TERMINAL_COUNT: usize, I> Lexer<'i, C, S, TK>
+    for StringLexer<C, S, TK, TR, TERMINAL_COUNT, I>
+where
+    C: Context<'i, I, S, TK> + Default + 'i,
+    S: State + Into<usize>,
+    TK: Debug + Into<usize> + Copy + 'i,
+    TR: TokenRecognizer<'i, C, I>,
+    I: Input + ?Sized,
+{
+    type Input = I;
+
+    fn next_tokens(
+        &self,
+        context: &mut C,
+        input: &'i Self::Input,
+        expected_tokens: Vec<(TK, bool)>,
+    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TK>> + 'i> {
+        if self.skip_ws {
+            Self::skip(input, context);
+        }
+        log!("  {} {:?}", "Trying recognizers:".green(), expected_tokens);
+
+        let candidates = self.prefilter.map(|prefilter| {
+            prefilter(&input[context.position()..input.len()])
+        });
+        let expected_tokens = expected_tokens
+            .into_iter()
+            .filter(|&(token_kind, _)| {
+                let candidate =
+                    candidates.as_ref().map_or(true, |c| c[token_kind.into()]);
+                if !candidate {
+                    log!("{} {:?}", "    Skipping".green(), token_kind);
+                }
+                candidate
+            })
+            .collect::<Vec<_>>();
+        if !expected_tokens.iter().any(|&(token_kind, _)| {
+            self.token_recognizers[token_kind.into()].needs_context()
+        }) {
+            let mut recognition_context = C::default();
+            recognition_context.set_state(context.state());
+            recognition_context.set_position(context.position());
+            recognition_context.set_location(context.location());
+            recognition_context.set_range(context.range());
+            recognition_context.set_layout_ahead(context.layout_ahead());
+            if let Some(input) = context.input() {
+                recognition_context.set_input(input);
+            }
+            return Box::new(TokenIterator {
+                input,
+                position: context.position(),
+                location: context.location(),
+                context: recognition_context,
+                token_recognizers: expected_tokens
+                    .into_iter()
+                    .map(|(token_kind, finish)| {
+                        (
+                            &self.token_recognizers[token_kind.into()],
+                            token_kind,
+                            finish,
+                        )
+                    })
+                    .collect(),
+                index: 0,
+                finish: false,
+            });
+        }
+
+        // Recognizers have access to the context so all tokens are recognized
+        // before returning. Recognition stops at the first recognized token
+        // with the finish flag set.
+        let mut tokens = vec![];
+        for (token_kind, finish) in expected_tokens {
+            let recognizer = &self.token_recognizers[token_kind.into()];
+            if let Some(recognized) =
+                recognizer.recognize_value(context, input, context.position())
+            {
+                tokens.push(recognized_token(
+                    input,
+                    context.position(),
+                    context.location(),
+                    token_kind,
+                    recognized,
+                ));
+                if finish {
+                    break;
+                }
+            }
+        }
+        Box::new(tokens.into_iter())
+    }
+}
+
+/// Makes the token of the given kind from the length of the consumed input
+/// and the value returned by a recognizer at the given position.
+fn recognized_token<'i, I, TK>(
+    input: &'i I,
+    position: usize,
+    location: Location,
+    kind: TK,
+    (consumed, value): (usize, &'i I),
+) -> Token<'i, I, TK>
+where
+    I: Input + ?Sized,
+{
+    Token {
+        kind,
+        value,
+        location: input[position..position + consumed].location_span(location),
+        attr: None,
+        consumed: (consumed != value.len()).then_some(consumed),
+    }
+}
+
+/// Recognizes the expected tokens lazily, i.e. each time the next token is
+/// requested. Used by [`StringLexer`] if none of the expected tokens needs the
+/// parsing context.
+struct TokenIterator<'i, C, TR: 'static, TK, I: ?Sized> {
+    input: &'i I,
     position: usize,
     location: Location,
+    /// A copy of the parsing context given to the recognizers.
+    context: C,
     token_recognizers: Vec<(&'static TR, TK, bool)>,
     index: usize,
     finish: bool,
 }
 
-impl<'i, TR, TK> TokenIterator<'i, TR, TK> {
-    fn new(
-        input: &'i str,
-        position: usize,
-        location: Location,
-        token_recognizers: Vec<(&'static TR, TK, bool)>,
-    ) -> Self {
-        Self {
-            input,
-            position,
-            location,
-            token_recognizers,
-            index: 0,
-            finish: false,
-        }
-    }
-}
-
-impl<'i, TK, TR> Iterator for TokenIterator<'i, TR, TK>
+impl<'i, C, TR, TK, I> Iterator for TokenIterator<'i, C, TR, TK, I>
 where
-    TR: TokenRecognizer<'i>,
+    TR: TokenRecognizer<'i, C, I>,
     TK: Copy,
+    I: Input + ?Sized,
 {
-    type Item = Token<'i, str, TK>;
+    type Item = Token<'i, I, TK>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if !self.finish && self.index < self.token_recognizers.len() {
-                let (recognizer, token_kind, finish) =
-                    &self.token_recognizers[self.index];
-                self.index += 1;
-                if let Some(recognized) =
-                    recognizer.recognize(&self.input[self.position..])
-                {
-                    self.finish = *finish;
-                    return Some(Token {
-                        kind: *token_kind,
-                        value: recognized,
-                        location: recognized.location_span(self.location),
-                    });
-                }
-            } else {
-                return None;
+        while !self.finish && self.index < self.token_recognizers.len() {
+            let (recognizer, token_kind, finish) =
+                self.token_recognizers[self.index];
+            self.index += 1;
+            if let Some(recognized) = recognizer.recognize_value(
+                &self.context,
+                self.input,
+                self.position,
+            ) {
+                self.finish = finish;
+                return Some(recognized_token(
+                    self.input,
+                    self.position,
+                    self.location,
+                    token_kind,
+                    recognized,
+                ));
             }
         }
+        None
+    }
+}
+
+/// A lexer which replays the tokens produced by an external tokenizer instead
+/// of recognizing them. The parsed input must be the concatenation of the
+/// values of the tokens (see [`TokensLexer::input`]) so that the builder gets
+/// the token values. The locations are taken from the tokens.
+pub struct TokensLexer<I: ?Sized, TK, A = ()> {
+    /// The kind, the start position in the concatenated input, the length,
+    /// the location and the attribute of each token.
+    tokens: Vec<(TK, usize, usize, Location, Option<A>)>,
+    input: PhantomData<fn(&I)>,
+}
+
+impl<I: Input + ?Sized, TK: Copy, A: Clone> TokensLexer<I, TK, A> {
+    pub fn new(tokens: &[Token<'_, I, TK, A>]) -> Self {
+        let mut position = 0;
+        let tokens = tokens
+            .iter()
+            .map(|token| {
+                let start = position;
+                position += token.value.len();
+                (
+                    token.kind,
+                    start,
+                    token.value.len(),
+                    token.location,
+                    token.attr.clone(),
+                )
+            })
+            .collect();
+        Self {
+            tokens,
+            input: PhantomData,
+        }
     }
 }
 
-impl<'i, C, S, TK, TR, const TERMINAL_COUNT: usize> Lexer<'i, C, S, TK>
-    for StringLexer<C, S, TK, TR, TERMINAL_COUNT>
+impl<TK: Copy, A: Clone> TokensLexer<str, TK, A> {
+    /// Returns the input to parse with this lexer, i.e. the concatenation of
+    /// the values of the given string tokens.
+    pub fn input(tokens: &[Token<'_, str, TK, A>]) -> String {
+        tokens.iter().map(|token| token.value).collect()
+    }
+}
+
+impl<'i, C, S, TK, A, I> Lexer<'i, C, S, TK, A> for TokensLexer<I, TK, A>
 where
-    C: Context<'i, str, S, TK>,
-    S: State + Into<usize>,
-    TK: Debug + Into<usize> + Copy + 'i,
-    TR: TokenRecognizer<'i>,
+    C: Context<'i, I, S, TK>,
+    S: State,
+    TK: Debug + Default + PartialEq + Copy + 'i,
+    A: Clone + 'i,
+    I: Input + ?Sized,
 {
-    type Input = str;
+    type Input = I;
 
     fn next_tokens(
         &self,
         context: &mut C,
         input: &'i Self::Input,
         expected_tokens: Vec<(TK, bool)>,
-    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TK>> + 'i> {
-        if self.skip_ws {
-            Self::skip(input, context);
+    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TK, A>> + 'i> {
+        let position = context.position();
+        let idx = self.tokens.partition_point(|t| t.1 < position);
+        let token = match self.tokens.get(idx) {
+            Some((kind, start, len, location, attr)) if *start == position => {
+                // The skipped input between the tokens is reflected in the
+                // locations given by the tokenizer.
+                context.set_location(*location);
+                Token {
+                    kind: *kind,
+                    value: &input[position..position + len],
+                    location: *location,
+                    attr: attr.clone(),
+                    consumed: None,
+                }
+            }
+            _ if position == input.len() => Token {
+                kind: TK::default(),
+                value: &input[position..position],
+                location: context.location(),
+                attr: None,
+                consumed: None,
+            },
+            _ => return Box::new(core::iter::empty()),
+        };
+        log!("  {} {:?}", "Replaying token:".green(), token.kind);
+        if expected_tokens.iter().any(|&(kind, _)| kind == token.kind) {
+            Box::new(core::iter::once(token))
+        } else {
+            Box::new(core::iter::empty())
         }
-        log!("  {} {:?}", "Trying recognizers:".green(), expected_tokens);
-
-        Box::new(TokenIterator::new(
-            input,
-            context.position(),
-            context.location(),
-            expected_tokens
-                .iter()
-                .map(|&tok| {
-                    (&self.token_recognizers[tok.0.into()], tok.0, tok.1)
-                })
-                .collect::<Vec<_>>(),
-        ))
     }
 }
 
 /// Represents a single token from the input stream.
-pub struct Token<'i, I: Input + ?Sized, TK> {
+pub struct Token<'i, I: Input + ?Sized, TK, A = ()> {
     pub kind: TK,
 
     /// The part of the input stream that this token represents.
@@ -201,28 +578,46 @@ pub struct Token<'i, I: Input + ?Sized, TK> {
 
     /// Location (with span) in the input file where this token is found.
     pub location: Location,
+
+    /// Additional information attached to the token by a custom lexer.
+    pub attr: Option<A>,
+
+    /// The length of the consumed input if it differs from the length of the
+    /// value (see [`RecognizedValue`]). `None` if the value is the consumed
+    /// input.
+    pub consumed: Option<usize>,
+}
+
+impl<I: Input + ?Sized, TK, A> Token<'_, I, TK, A> {
+    /// The length of the input consumed by this token.
+    pub fn consumed_len(&self) -> usize {
+        self.consumed.unwrap_or_else(|| self.value.len())
+    }
 }
 
-impl<I: Input + ?Sized, TK: Copy> Clone for Token<'_, I, TK> {
+impl<I: Input + ?Sized, TK: Copy, A: Clone> Clone for Token<'_, I, TK, A> {
     fn clone(&self) -> Self {
         Self {
             kind: self.kind,
             value: self.value,
             location: self.location,
+            attr: self.attr.clone(),
+            consumed: self.consumed,
         }
     }
 }
 
-impl<I, TK> Debug for Token<'_, I, TK>
+impl<I, TK, A> Debug for Token<'_, I, TK, A>
 where
     I: Input + ?Sized,
     I::Output: Debug,
     TK: Debug,
+    A: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "{:?}({:?} {:?})",
+            "{:?}({:?} {:?}{})",
             self.kind,
             if self.value.len() > 50 {
                 format!(
@@ -234,7 +629,11 @@ where
             } else {
                 format!("{:?}", self.value)
             },
-            self.location
+            self.location,
+            self.attr
+                .as_ref()
+                .map(|attr| format!(" {attr:?}"))
+                .unwrap_or_default()
         )
     }
 }
@@ -1,45 +1,75 @@
 //! This crate is the runtime for the generated Rustemo parsers.
+#![cfg_attr(not(feature = "std"), no_std)]
 // See: https://github.com/rust-lang/rfcs/issues/2324
 // For local std docs browsing
 // #[doc(inline)]
 // pub use std;
 
+extern crate alloc;
+
 #[macro_use]
 mod common;
 #[macro_use]
 pub mod debug;
 
+#[cfg(feature = "async")]
+mod async_stream;
 mod builder;
 mod context;
 mod error;
 mod input;
 mod lexer;
 mod location;
+#[cfg(feature = "logos")]
+mod logos_lexer;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
 mod parser;
+#[cfg(feature = "glr")]
 mod utils;
 
-mod lr;
-//#[cfg(feature = "glr")]
+#[cfg(feature = "glr")]
 mod glr;
+mod lr;
 
 // Public API
+#[cfg(feature = "async")]
+pub use crate::async_stream::read_stream;
 pub use crate::context::Context;
 pub use crate::error::Error;
+pub use crate::error::ParseError;
 pub use crate::error::Result;
-pub use crate::input::Input;
+pub use crate::input::{preprocess, Input};
 pub use crate::location::{LineColumn, Location, Position, ValLoc};
+#[cfg(feature = "unicode-normalization")]
+pub use crate::normalize::{normalize_nfc, OffsetMap};
 
-pub use crate::builder::Builder;
-pub use crate::lexer::{Lexer, StringLexer, Token, TokenRecognizer};
+pub use crate::builder::{Builder, StreamBuilder};
+pub use crate::lexer::{
+    IncrementalLexer, Lexer, PartialTokenRecognizer, Prefilter, Recognized,
+    RecognizedValue, StringLexer, Token, TokenRecognizer, TokensLexer,
+};
+#[cfg(feature = "logos")]
+pub use crate::logos_lexer::LogosLexer;
 pub use crate::lr::{
-    builder::{LRBuilder, SliceBuilder, TreeBuilder, TreeNode},
+    builder::{
+        LRBuilder, LosslessBuilder, LosslessNode, LosslessTree, SliceBuilder,
+        TokensBuilder, TreeBuilder, TreeNode, TriviaBuilder, TriviaNode,
+        TriviaTree,
+    },
     context::LRContext,
-    parser::{Action, LRParser, ParserDefinition},
+    parser::{
+        Action, LRParseIter, LRParser, LayoutSegment, ParseDriver,
+        ParserDefinition, Predicate, Recovery,
+    },
+    stats::ParseStats,
 };
-pub use crate::parser::{Parser, State};
+pub use crate::parser::{ParseOpts, Parser, State};
+#[cfg(feature = "async")]
+pub use futures_core::Stream;
 
-//#[cfg(feature = "glr")]
+#[cfg(feature = "glr")]
 pub use crate::glr::{
-    gss::{Forest, GssHead},
+    gss::{Ambiguity, Forest, GssHead, Policy},
     parser::GlrParser,
 };
@@ -0,0 +1,94 @@
+use crate::{
+    context::Context,
+    input::Input,
+    lexer::{Lexer, Token},
+    parser::State,
+};
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(all(debug_assertions, feature = "std"))]
+use colored::*;
+use core::{fmt::Debug, marker::PhantomData};
+use logos::Logos;
+
+/// A lexer adapter for the lexers generated by [`logos`].
+///
+/// The tokens produced by `logos` are mapped to the token kinds of the parser
+/// by the given function. The input skipped by `logos` (e.g. whitespaces) is
+/// reported as layout. At the end of the input the default token kind (`STOP`
+/// in generated parsers) is produced.
+///
+/// Can be used with parsers generated with custom lexer type over `str`
+/// input.
+pub struct LogosLexer<T, TK> {
+    token_kind: fn(&T) -> TK,
+    phantom: PhantomData<T>,
+}
+
+impl<T, TK> LogosLexer<T, TK> {
+    pub fn new(token_kind: fn(&T) -> TK) -> Self {
+        Self {
+            token_kind,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'i, C, S, TK, T> Lexer<'i, C, S, TK> for LogosLexer<T, TK>
+where
+    C: Context<'i, str, S, TK>,
+    S: State,
+    TK: Debug + Default + PartialEq + Copy + 'i,
+    T: Logos<'i, Source = str>,
+    T::Extras: Default,
+{
+    type Input = str;
+
+    fn next_tokens(
+        &self,
+        context: &mut C,
+        input: &'i Self::Input,
+        expected_tokens: Vec<(TK, bool)>,
+    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TK>> + 'i> {
+        let position = context.position();
+        let mut lexer = T::lexer(&input[position..]);
+        let next = lexer.next();
+        // For the end of input the rest of the input is skipped.
+        let start = match next {
+            Some(_) => lexer.span().start,
+            None => input.len() - position,
+        };
+        if start > 0 {
+            let skipped = &input[position..position + start];
+            log!("\t{} {}", "Skipped:".bold().green(), start);
+            context.set_layout_ahead(Some(skipped));
+            context.set_position(position + start);
+            context.set_location(skipped.location_after(context.location()));
+        } else {
+            context.set_layout_ahead(None);
+        }
+
+        let token = match next {
+            Some(Ok(token)) => {
+                let value =
+                    &input[position + start..position + lexer.span().end];
+                Some(((self.token_kind)(&token), value))
+            }
+            // Invalid input. No tokens are produced.
+            Some(Err(_)) => None,
+            None => Some((TK::default(), &input[input.len()..])),
+        };
+        let token = token
+            .filter(|(kind, _)| {
+                expected_tokens.iter().any(|(expected, _)| expected == kind)
+            })
+            .map(|(kind, value)| Token {
+                kind,
+                value,
+                location: value.location_span(context.location()),
+                attr: None,
+                consumed: None,
+            });
+        log!("  {} {:?}", "Recognized:".green(), token);
+        Box::new(token.into_iter())
+    }
+}
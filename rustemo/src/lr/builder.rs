@@ -1,14 +1,16 @@
+use crate::Result;
 use crate::{
     builder::Builder, context::Context, input::Input, lexer::Token,
-    location::Location, parser::State,
+    location::Location, location::Position, parser::State,
 };
+use alloc::{vec, vec::Vec};
 use core::fmt::Debug;
 
 /// A builder variant for LR parsing.
 ///
 /// Builder should keep its internal stack of subresults, similar to the way LR
-/// parsing operates.
-pub trait LRBuilder<'i, I, C, S, P, TK>: Builder
+/// parsing operates. `A` is the type of token attributes provided by the lexer.
+pub trait LRBuilder<'i, I, C, S, P, TK, A = ()>: Builder
 where
     I: Input + ?Sized,
     C: Context<'i, I, S, TK>,
@@ -20,7 +22,7 @@ where
     ///
     /// * `term_idx` - A terminal unique identifier - index.
     /// * `token` - A token recognized in the input.
-    fn shift_action(&mut self, context: &mut C, token: Token<'i, I, TK>);
+    fn shift_action(&mut self, context: &mut C, token: Token<'i, I, TK, A>);
 
     /// Called when LR reduce is taking place.
     ///
@@ -31,6 +33,36 @@ where
     /// * `prod_len` - A RHS length, used to pop appropriate number of
     ///                subresults from the stack
     fn reduce_action(&mut self, context: &mut C, prod: P, prod_len: usize);
+
+    /// Called by the LR parser for each trivia token (see `%trivia` grammar
+    /// directive) skipped before the next token. Trivia are dropped by
+    /// default. See [`TriviaBuilder`].
+    fn trivia_action(&mut self, _context: &mut C, _token: Token<'i, I, TK, A>) {
+    }
+
+    /// Called by the LR parser when shifting is taking place. Builders which
+    /// can detect inconsistent input should override this method to report an
+    /// error instead of panicking. By default calls `shift_action`.
+    fn try_shift_action(
+        &mut self,
+        context: &mut C,
+        token: Token<'i, I, TK, A>,
+    ) -> Result<()> {
+        self.shift_action(context, token);
+        Ok(())
+    }
+
+    /// Called by the LR parser when reduce is taking place. See
+    /// `try_shift_action`. By default calls `reduce_action`.
+    fn try_reduce_action(
+        &mut self,
+        context: &mut C,
+        prod: P,
+        prod_len: usize,
+    ) -> Result<()> {
+        self.reduce_action(context, prod, prod_len);
+        Ok(())
+    }
 }
 
 /// TreeBuilder is a builder that builds a generic concrete parse tree.
@@ -39,6 +71,8 @@ where
     I: Input + ?Sized,
 {
     res_stack: Vec<TreeNode<'i, I, P, TK>>,
+    collapse_chains: bool,
+    token_filter: Option<fn(&TK) -> bool>,
 }
 
 impl<I, P, TK> TreeBuilder<'_, I, P, TK>
@@ -46,7 +80,25 @@ where
     I: Input + ?Sized,
 {
     pub fn new() -> Self {
-        Self { res_stack: vec![] }
+        Self {
+            res_stack: vec![],
+            collapse_chains: false,
+            token_filter: None,
+        }
+    }
+
+    /// If enabled, reductions of a single non-terminal (chain productions
+    /// like `E: T;`) don't create a new node. The child node is used instead.
+    pub fn collapse_chains(mut self, collapse_chains: bool) -> Self {
+        self.collapse_chains = collapse_chains;
+        self
+    }
+
+    /// Sets a predicate on token kinds. Terminal leaves whose kind is rejected
+    /// by the predicate (e.g. punctuation) are left out of the tree.
+    pub fn filter_tokens(mut self, token_filter: fn(&TK) -> bool) -> Self {
+        self.token_filter = Some(token_filter);
+        self
     }
 }
 
@@ -85,15 +137,40 @@ where
     }
 
     fn reduce_action(&mut self, context: &mut C, prod: P, prod_len: usize) {
-        let children;
+        if self.collapse_chains
+            && prod_len == 1
+            && matches!(
+                self.res_stack.last(),
+                Some(TreeNode::NonTermNode { .. })
+            )
+        {
+            return;
+        }
+        let mut children;
         let layout;
         if prod_len > 0 {
             children =
                 self.res_stack.split_off(self.res_stack.len() - prod_len);
-            layout = match children[0] {
-                TreeNode::TermNode { layout, .. } => layout,
-                TreeNode::NonTermNode { layout, .. } => layout,
-            };
+            // The layout is taken from the first child which matched some
+            // input as the leading EMPTY reductions have no layout.
+            layout = children
+                .iter()
+                .find_map(|child| match child {
+                    TreeNode::TermNode { layout, .. } => Some(*layout),
+                    TreeNode::NonTermNode {
+                        location, layout, ..
+                    } => (location.end != Some(location.start))
+                        .then_some(*layout),
+                })
+                .flatten();
+            if let Some(token_filter) = self.token_filter {
+                children.retain(|child| match child {
+                    TreeNode::TermNode { token, .. } => {
+                        token_filter(&token.kind)
+                    }
+                    TreeNode::NonTermNode { .. } => true,
+                });
+            }
         } else {
             children = vec![];
             layout = None;
@@ -125,6 +202,113 @@ where
     },
 }
 
+impl<I, P, TK> TreeNode<'_, I, P, TK>
+where
+    I: Input + ?Sized + PartialEq,
+    P: PartialEq,
+    TK: PartialEq,
+{
+    /// Compares trees structurally, i.e. production kinds, token kinds and
+    /// matched values recursively. Locations are compared only if `locations`
+    /// is `true`. Layout is never compared.
+    pub fn tree_eq(&self, other: &Self, locations: bool) -> bool {
+        match (self, other) {
+            (
+                TreeNode::TermNode { token, .. },
+                TreeNode::TermNode { token: other, .. },
+            ) => {
+                token.kind == other.kind
+                    && token.value == other.value
+                    && (!locations || token.location == other.location)
+            }
+            (
+                TreeNode::NonTermNode {
+                    prod,
+                    location,
+                    children,
+                    ..
+                },
+                TreeNode::NonTermNode {
+                    prod: other_prod,
+                    location: other_location,
+                    children: other_children,
+                    ..
+                },
+            ) => {
+                prod == other_prod
+                    && (!locations || location == other_location)
+                    && children.len() == other_children.len()
+                    && children
+                        .iter()
+                        .zip(other_children)
+                        .all(|(c, o)| c.tree_eq(o, locations))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Structural equality which ignores locations. See [`TreeNode::tree_eq`].
+impl<'i, I, P, TK> TreeNode<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+    P: Copy,
+    TK: Copy,
+{
+    /// Builds an output of the tree using the given builder by replaying the
+    /// shifts and reductions bottom-up. E.g. with the default builder of a
+    /// generated parser the actions are called to produce the typed output.
+    /// The tree must contain all the tokens, i.e. it must be built without
+    /// collapsing chains and token filtering.
+    pub fn build<B, C, S>(&self, builder: &mut B) -> B::Output
+    where
+        B: LRBuilder<'i, I, C, S, P, TK>,
+        C: Context<'i, I, S, TK> + Default,
+        S: State,
+    {
+        let mut context = C::default();
+        self.build_inner(&mut context, builder);
+        builder.get_result()
+    }
+
+    fn build_inner<B, C, S>(&self, context: &mut C, builder: &mut B)
+    where
+        B: LRBuilder<'i, I, C, S, P, TK>,
+        C: Context<'i, I, S, TK> + Default,
+        S: State,
+    {
+        match self {
+            TreeNode::TermNode { token, .. } => {
+                context.set_location(token.location);
+                builder.shift_action(context, token.clone())
+            }
+            TreeNode::NonTermNode {
+                prod,
+                location,
+                children,
+                ..
+            } => {
+                children
+                    .iter()
+                    .for_each(|child| child.build_inner(context, builder));
+                context.set_location(*location);
+                builder.reduce_action(context, *prod, children.len())
+            }
+        }
+    }
+}
+
+impl<I, P, TK> PartialEq for TreeNode<'_, I, P, TK>
+where
+    I: Input + ?Sized + PartialEq,
+    P: PartialEq,
+    TK: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.tree_eq(other, false)
+    }
+}
+
 /// Returns a slice of the matched input. If no match is possible `None` is
 /// returned.
 ///
@@ -156,13 +340,14 @@ where
     }
 }
 
-impl<'i, I, C, S, P, TK> LRBuilder<'i, I, C, S, P, TK> for SliceBuilder<'i, I>
+impl<'i, I, C, S, P, TK, A> LRBuilder<'i, I, C, S, P, TK, A>
+    for SliceBuilder<'i, I>
 where
     I: Input + ?Sized,
     C: Context<'i, I, S, TK>,
     S: State,
 {
-    fn shift_action(&mut self, _context: &mut C, _token: Token<'i, I, TK>) {
+    fn shift_action(&mut self, _context: &mut C, _token: Token<'i, I, TK, A>) {
         // We do nothing on shift
     }
 
@@ -171,3 +356,339 @@ where
         self.slice = Some(&self.input[context.range()]);
     }
 }
+
+/// A builder which collects the shifted tokens and ignores reductions. Used
+/// for tokenizing the input driven by the parser, e.g. for syntax highlighting.
+/// Tokens of the layout are not collected.
+pub struct TokensBuilder<'i, I, TK, A = ()>
+where
+    I: Input + ?Sized,
+{
+    tokens: Vec<Token<'i, I, TK, A>>,
+}
+
+impl<I, TK, A> TokensBuilder<'_, I, TK, A>
+where
+    I: Input + ?Sized,
+{
+    pub fn new() -> Self {
+        Self { tokens: vec![] }
+    }
+}
+
+impl<I, TK, A> Default for TokensBuilder<'_, I, TK, A>
+where
+    I: Input + ?Sized,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'i, I, TK, A> Builder for TokensBuilder<'i, I, TK, A>
+where
+    I: Input + ?Sized,
+{
+    type Output = Vec<Token<'i, I, TK, A>>;
+
+    fn get_result(&mut self) -> Self::Output {
+        core::mem::take(&mut self.tokens)
+    }
+}
+
+impl<'i, I, C, S, P, TK, A> LRBuilder<'i, I, C, S, P, TK, A>
+    for TokensBuilder<'i, I, TK, A>
+where
+    I: Input + ?Sized,
+    C: Context<'i, I, S, TK>,
+    S: State,
+{
+    fn shift_action(&mut self, _context: &mut C, token: Token<'i, I, TK, A>) {
+        self.tokens.push(token)
+    }
+
+    fn reduce_action(&mut self, _context: &mut C, _prod: P, _prod_len: usize) {
+        // Reductions are not needed for tokenization.
+    }
+}
+
+/// Builds a lossless concrete syntax tree which keeps all tokens and the
+/// layout (trivia) between them.
+///
+/// Concatenating all leaves of the produced tree (see [`LosslessTree::leaves`])
+/// reproduces the parsed input exactly. The builder needs the input to extract
+/// the trivia and it must be the same input given to the parser. Used only with
+/// LR parsing.
+pub struct LosslessBuilder<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    input: &'i I,
+    /// The end position of the last shifted token.
+    position: usize,
+    res_stack: Vec<LosslessNode<'i, I, P, TK>>,
+}
+
+impl<'i, I, P, TK> LosslessBuilder<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    pub fn new(input: &'i I) -> Self {
+        Self {
+            input,
+            position: 0,
+            res_stack: vec![],
+        }
+    }
+}
+
+impl<'i, I, P, TK> Builder for LosslessBuilder<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    type Output = LosslessTree<'i, I, P, TK>;
+
+    fn get_result(&mut self) -> Self::Output {
+        LosslessTree {
+            root: self.res_stack.pop().unwrap(),
+            trailing: &self.input[self.position..self.input.len()],
+        }
+    }
+}
+
+impl<'i, I, C, S, P, TK> LRBuilder<'i, I, C, S, P, TK>
+    for LosslessBuilder<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+    C: Context<'i, I, S, TK>,
+    S: State,
+{
+    fn shift_action(&mut self, context: &mut C, token: Token<'i, I, TK>) {
+        let range = context.range();
+        self.res_stack.push(LosslessNode::TermNode {
+            token,
+            trivia: &self.input[self.position..range.start],
+            range: range.clone(),
+        });
+        self.position = range.end;
+    }
+
+    fn reduce_action(&mut self, context: &mut C, prod: P, prod_len: usize) {
+        let children =
+            self.res_stack.split_off(self.res_stack.len() - prod_len);
+        self.res_stack.push(LosslessNode::NonTermNode {
+            prod,
+            range: context.range(),
+            children,
+        });
+    }
+}
+
+/// The result of [`LosslessBuilder`].
+#[derive(Debug)]
+pub struct LosslessTree<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    pub root: LosslessNode<'i, I, P, TK>,
+    /// The trivia after the last token, i.e. the rest of the input.
+    pub trailing: &'i I,
+}
+
+impl<'i, I, P, TK> LosslessTree<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    /// Returns all trivia and token values in the input order.
+    pub fn leaves(&self) -> Vec<&'i I> {
+        let mut leaves = vec![];
+        self.root.collect_leaves(&mut leaves);
+        leaves.push(self.trailing);
+        leaves
+    }
+}
+
+/// A node in the tree produced by [`LosslessBuilder`]
+#[derive(Debug)]
+pub enum LosslessNode<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    TermNode {
+        token: Token<'i, I, TK>,
+        /// The trivia preceding the token. Empty if there is none.
+        trivia: &'i I,
+        range: core::ops::Range<usize>,
+    },
+    NonTermNode {
+        prod: P,
+        range: core::ops::Range<usize>,
+        children: Vec<LosslessNode<'i, I, P, TK>>,
+    },
+}
+
+impl<'i, I, P, TK> LosslessNode<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    fn collect_leaves(&self, leaves: &mut Vec<&'i I>) {
+        match self {
+            LosslessNode::TermNode { token, trivia, .. } => {
+                leaves.push(*trivia);
+                leaves.push(token.value);
+            }
+            LosslessNode::NonTermNode { children, .. } => children
+                .iter()
+                .for_each(|child| child.collect_leaves(leaves)),
+        }
+    }
+}
+
+/// Builds a concrete syntax tree where the trivia tokens (see `%trivia`
+/// grammar directive) are attached to the adjacent significant tokens.
+///
+/// The trivia following a token up to and including the first trivia which
+/// ends the line are its trailing trivia. The rest of the trivia are the
+/// leading trivia of the next token. The trivia after the last token which are
+/// not trailing are kept in [`TriviaTree::trailing`]. Used only with LR
+/// parsing.
+pub struct TriviaBuilder<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    res_stack: Vec<TriviaNode<'i, I, P, TK>>,
+    /// The trivia collected for the next token.
+    leading: Vec<Token<'i, I, TK>>,
+    /// Do the trivia still belong to the last shifted token.
+    trailing_open: bool,
+}
+
+impl<I, P, TK> TriviaBuilder<'_, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    pub fn new() -> Self {
+        Self {
+            res_stack: vec![],
+            leading: vec![],
+            trailing_open: false,
+        }
+    }
+}
+
+impl<I, P, TK> Default for TriviaBuilder<'_, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'i, I, P, TK> Builder for TriviaBuilder<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    type Output = TriviaTree<'i, I, P, TK>;
+
+    fn get_result(&mut self) -> Self::Output {
+        self.trailing_open = false;
+        TriviaTree {
+            root: self.res_stack.pop().unwrap(),
+            trailing: core::mem::take(&mut self.leading),
+        }
+    }
+}
+
+impl<'i, I, C, S, P, TK> LRBuilder<'i, I, C, S, P, TK>
+    for TriviaBuilder<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+    C: Context<'i, I, S, TK>,
+    S: State,
+{
+    fn shift_action(&mut self, _context: &mut C, token: Token<'i, I, TK>) {
+        self.res_stack.push(TriviaNode::TermNode {
+            token,
+            leading: core::mem::take(&mut self.leading),
+            trailing: vec![],
+        });
+        self.trailing_open = true;
+    }
+
+    fn reduce_action(&mut self, context: &mut C, prod: P, prod_len: usize) {
+        let children =
+            self.res_stack.split_off(self.res_stack.len() - prod_len);
+        self.res_stack.push(TriviaNode::NonTermNode {
+            prod,
+            location: context.location(),
+            children,
+        });
+    }
+
+    fn trivia_action(&mut self, _context: &mut C, token: Token<'i, I, TK>) {
+        let ends_line = matches!(
+            token.value.location_after(I::start_location()).start,
+            Position::LineBased(lc) if lc.line > 1
+        );
+        let trailing = self
+            .trailing_open
+            .then(|| TriviaNode::last_trailing(&mut self.res_stack))
+            .flatten();
+        match trailing {
+            Some(trailing) => {
+                trailing.push(token);
+                self.trailing_open = !ends_line;
+            }
+            None => self.leading.push(token),
+        }
+    }
+}
+
+/// The result of [`TriviaBuilder`].
+#[derive(Debug)]
+pub struct TriviaTree<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    pub root: TriviaNode<'i, I, P, TK>,
+    /// The trivia after the last token which are not its trailing trivia.
+    pub trailing: Vec<Token<'i, I, TK>>,
+}
+
+/// A node in the tree produced by [`TriviaBuilder`]
+#[derive(Debug)]
+pub enum TriviaNode<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    TermNode {
+        token: Token<'i, I, TK>,
+        /// The trivia preceding the token.
+        leading: Vec<Token<'i, I, TK>>,
+        /// The trivia following the token up to the end of the line.
+        trailing: Vec<Token<'i, I, TK>>,
+    },
+    NonTermNode {
+        prod: P,
+        location: Location,
+        children: Vec<TriviaNode<'i, I, P, TK>>,
+    },
+}
+
+impl<'i, I, P, TK> TriviaNode<'i, I, P, TK>
+where
+    I: Input + ?Sized,
+{
+    /// The trailing trivia of the last token in the given nodes.
+    fn last_trailing<'a>(
+        nodes: &'a mut [Self],
+    ) -> Option<&'a mut Vec<Token<'i, I, TK>>> {
+        nodes.iter_mut().rev().find_map(|node| match node {
+            TriviaNode::TermNode { trailing, .. } => Some(trailing),
+            TriviaNode::NonTermNode { children, .. } => {
+                Self::last_trailing(children)
+            }
+        })
+    }
+}
@@ -1,4 +1,5 @@
-use std::ops::Range;
+use alloc::rc::Rc;
+use core::{any::Any, ops::Range};
 
 use crate::{
     context::Context, input::Input, lexer::Token, location::Location,
@@ -25,6 +26,12 @@ pub struct LRContext<'i, I: Input + ?Sized, S, TK> {
     token_ahead: Option<Token<'i, I, TK>>,
 
     state: S,
+
+    /// User state available to actions and predicates (e.g. a symbol table).
+    user_state: Option<Rc<dyn Any>>,
+
+    /// The input being parsed.
+    input: Option<&'i I>,
 }
 
 impl<I: Input + ?Sized, S: Default, TK> Default for LRContext<'_, I, S, TK> {
@@ -42,10 +49,25 @@ impl<I: Input + ?Sized, S: Default, TK> LRContext<'_, I, S, TK> {
             range: 0..0,
             token_ahead: None,
             state: S::default(),
+            user_state: None,
+            input: None,
         }
     }
 }
 
+impl<I: Input + ?Sized, S, TK> LRContext<'_, I, S, TK> {
+    /// Sets the user state. Use interior mutability (e.g. `RefCell`) if the
+    /// state should change during parsing.
+    pub fn set_user_state<T: Any>(&mut self, state: Rc<T>) {
+        self.user_state = Some(state)
+    }
+
+    /// Returns the user state if it is set and is of type `T`.
+    pub fn user_state<T: Any>(&self) -> Option<&T> {
+        self.user_state.as_ref()?.downcast_ref()
+    }
+}
+
 impl<'i, I, S, TK> Context<'i, I, S, TK> for LRContext<'i, I, S, TK>
 where
     I: Input + ?Sized,
@@ -110,4 +132,14 @@ where
     fn set_layout_ahead(&mut self, layout: Option<&'i I>) {
         self.layout_ahead = layout
     }
+
+    #[inline]
+    fn input(&self) -> Option<&'i I> {
+        self.input
+    }
+
+    #[inline]
+    fn set_input(&mut self, input: &'i I) {
+        self.input = Some(input)
+    }
 }
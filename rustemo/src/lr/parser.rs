@@ -1,3 +1,4 @@
+use crate::builder::StreamBuilder;
 use crate::context::Context;
 use crate::debug::log;
 use crate::error::{error_expected, Result};
@@ -5,19 +6,24 @@ use crate::input::Input;
 use crate::lexer::{Lexer, Token};
 use crate::location::Location;
 use crate::lr::builder::SliceBuilder;
-use crate::parser::{Parser, State};
+use crate::parser::{ParseOpts, Parser, State};
 use crate::{err, Error};
-#[cfg(debug_assertions)]
+use alloc::rc::Rc;
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
+#[cfg(all(debug_assertions, feature = "std"))]
 use colored::*;
-use std::borrow::Borrow;
-use std::cell::RefCell;
-use std::fmt::Debug;
-use std::marker::PhantomData;
-use std::ops::Range;
+use core::borrow::Borrow;
+use core::cell::RefCell;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Range;
+#[cfg(feature = "std")]
 use std::path::Path;
-use std::rc::Rc;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
 
 use super::builder::LRBuilder;
+use super::stats::ParseStats;
 
 /// Provides LR actions and GOTOs given the state and term/nonterm.
 pub trait ParserDefinition<S, P, TK, NTK> {
@@ -26,6 +32,141 @@ pub trait ParserDefinition<S, P, TK, NTK> {
     fn expected_token_kinds(&self, state: S) -> Vec<(TK, bool)>;
     fn longest_match() -> bool;
     fn grammar_order() -> bool;
+    /// Is the token of the given kind skipped where not expected by the
+    /// parser (see `%ignore` grammar directive).
+    fn ignored(_token: TK) -> bool {
+        false
+    }
+    /// Is the token of the given kind a trivia (see `%trivia` grammar
+    /// directive). Skipped trivia tokens are given to the builder by
+    /// [`LRBuilder::trivia_action`].
+    fn trivia(_token: TK) -> bool {
+        false
+    }
+}
+
+/// A semantic predicate consulted by [`LRParser`] before reducing by a
+/// production guarded by a predicate. The range of the context is the range of
+/// the reduction. If it returns `false` the parser falls back to the next
+/// action for the token ahead.
+pub type Predicate<'i, C, P, I, TK, A> =
+    fn(&C, P, &'i I, &Token<'i, I, TK, A>) -> bool;
+
+/// Drives a parse started by [`LRParser::parse_with_driver`]. The driver is
+/// notified of each shift and reduce and is consulted when no expected token
+/// can be recognized ahead, which is where custom error recovery is done.
+pub trait ParseDriver<'i, C, P, I, TK, A = ()>
+where
+    I: Input + ?Sized,
+{
+    /// Called before the given token is shifted.
+    fn shift(&mut self, _context: &C, _token: &Token<'i, I, TK, A>) {}
+
+    /// Called before the reduction by the given production of the given
+    /// length. The range of the context is the range of the reduction.
+    fn reduce(&mut self, _context: &C, _prod: P, _prod_len: usize) {}
+
+    /// Called when none of the `expected` token kinds can be recognized at the
+    /// position of the context. The returned [`Recovery`] decides how the
+    /// parse continues. By default the parse fails with the given error.
+    fn error(
+        &mut self,
+        _context: &C,
+        _expected: &[TK],
+        _error: &Error,
+    ) -> Recovery<TK> {
+        Recovery::Fail
+    }
+}
+
+/// A driver that never intervenes.
+impl<'i, C, P, I: Input + ?Sized, TK, A> ParseDriver<'i, C, P, I, TK, A>
+    for ()
+{
+}
+
+/// Follows the stack of LR states while the input is consumed. See
+/// [`LRParser::completions`].
+struct CompletionDriver<'d, S, D, NTK> {
+    definition: &'d D,
+    end: usize,
+    /// The states of the parse stack.
+    states: Vec<S>,
+    /// The changes of the stack since the last shift. A popped state or
+    /// `None` for a pushed state.
+    changes: Vec<Option<S>>,
+    /// Has the parse failed at the end of the input.
+    incomplete: bool,
+    nonterm: PhantomData<NTK>,
+}
+
+impl<S: State, D, NTK> CompletionDriver<'_, S, D, NTK> {
+    /// Reverts the reductions done after the last shift, i.e. returns the
+    /// stack of states after the last consumed token.
+    fn states_after_shift(mut self) -> Vec<S> {
+        while let Some(change) = self.changes.pop() {
+            match change {
+                Some(state) => self.states.push(state),
+                None => {
+                    self.states.pop();
+                }
+            }
+        }
+        self.states
+    }
+}
+
+impl<'i, C, S, P, I, TK, NTK, D, A> ParseDriver<'i, C, P, I, TK, A>
+    for CompletionDriver<'_, S, D, NTK>
+where
+    C: Context<'i, I, S, TK>,
+    S: State,
+    P: Into<NTK>,
+    I: Input + ?Sized,
+    D: ParserDefinition<S, P, TK, NTK>,
+{
+    fn shift(&mut self, context: &C, _token: &Token<'i, I, TK, A>) {
+        self.states.push(context.state());
+        self.changes.clear();
+    }
+
+    fn reduce(&mut self, _context: &C, prod: P, prod_len: usize) {
+        for _ in 0..prod_len {
+            self.changes.push(self.states.pop());
+        }
+        let from_state = *self.states.last().expect("Stack can't be empty.");
+        self.states
+            .push(self.definition.goto(from_state, prod.into()));
+        self.changes.push(None);
+    }
+
+    fn error(
+        &mut self,
+        context: &C,
+        _expected: &[TK],
+        _error: &Error,
+    ) -> Recovery<TK> {
+        if context.position() == self.end {
+            self.incomplete = true;
+        }
+        Recovery::Fail
+    }
+}
+
+/// The decision of a [`ParseDriver`] on a parse error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Recovery<TK> {
+    /// Continue as if a token of the given kind with an empty value is found
+    /// at the current position.
+    Insert(TK),
+    /// Skip the given number of input elements (bytes for `str`) and try
+    /// again. The skip is extended to the next boundary of the input (see
+    /// [`Input::is_boundary`](crate::Input::is_boundary)), e.g. to skip a whole
+    /// multibyte char. Skipping nothing or past the end of input fails the
+    /// parse.
+    Skip(usize),
+    /// Fail the parse with the error.
+    Fail,
 }
 
 /// An action executed by the (G)LR Parser during parsing
@@ -37,6 +178,17 @@ pub enum Action<S, P> {
     Error,
 }
 
+/// A part of the input skipped by the `Layout` rule (e.g. a comment). See
+/// [`LRParser::parse_with_layout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutSegment<'i, I: Input + ?Sized> {
+    /// The skipped input.
+    pub value: &'i I,
+    /// The range of the input skipped.
+    pub range: Range<usize>,
+    pub location: Location,
+}
+
 struct StackItem<S> {
     state: S,
     range: Range<usize>,
@@ -44,7 +196,7 @@ struct StackItem<S> {
 }
 
 impl<S: Debug> Debug for StackItem<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "State({:?}, {:?} {:?})",
@@ -59,7 +211,7 @@ struct ParseStack<S, I: ?Sized, C, TK> {
 }
 
 impl<S: Debug, I: ?Sized, C, TK> Debug for ParseStack<S, I, C, TK> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ParseStack")
             .field("stack", &self.stack)
             .finish()
@@ -88,6 +240,11 @@ where
         self.stack.last().unwrap().state
     }
 
+    #[inline]
+    fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
     #[inline]
     fn push_state(&mut self, context: &mut C, state: S) {
         self.stack.push(StackItem {
@@ -98,6 +255,15 @@ where
         context.set_state(state);
     }
 
+    /// The range of the input covered by the given number of top states.
+    fn range(&self, context: &C, states: usize) -> Range<usize> {
+        match &self.stack[self.stack.len() - states..] {
+            [] => context.position()..context.position(),
+            [first, .., last] => first.range.start..last.range.end,
+            [only] => only.range.clone(),
+        }
+    }
+
     fn pop_states(
         &mut self,
         context: &mut C,
@@ -138,35 +304,97 @@ pub struct LRParser<
     TK: Default,
     NTK,
     D: ParserDefinition<S, P, TK, NTK>,
-    L: Lexer<'i, C, S, TK, Input = I>,
+    L: Lexer<'i, C, S, TK, A, Input = I>,
     B,
     I: Input + ?Sized,
+    A = (),
 > {
     definition: &'i D,
     file_name: String,
-    content: Option<<<L as Lexer<'i, C, S, TK>>::Input as ToOwned>::Owned>,
+    #[allow(clippy::type_complexity)]
+    content: Option<<<L as Lexer<'i, C, S, TK, A>>::Input as ToOwned>::Owned>,
     partial_parse: bool,
     start_position: usize,
     start_state: S,
     has_layout: bool,
     lexer: Rc<L>,
     builder: RefCell<B>,
-    phantom: PhantomData<(P, NTK, I)>,
+    /// Statistics of the last parse. `None` if profiling is not enabled.
+    stats: Option<RefCell<ParseStats>>,
+    predicate: Option<Predicate<'i, C, P, I, TK, A>>,
+    /// Maximal depth of the parse stack. `None` if not limited.
+    max_depth: Option<usize>,
+    /// Maximal number of bytes lexed in a single parse. `None` if not limited.
+    max_lex_bytes: Option<usize>,
+    /// Maximal duration of a single parse. `None` if not limited.
+    #[cfg(feature = "std")]
+    lex_timeout: Option<Duration>,
+    /// The layout collected in the current parse. `None` if not collected.
+    #[allow(clippy::type_complexity)]
+    layout: RefCell<Option<Vec<LayoutSegment<'i, I>>>>,
+    phantom: PhantomData<(P, NTK, A, I)>,
 }
 
-type LayoutParser<'i, C, S, P, TK, NTK, D, L, I> =
-    Option<LRParser<'i, C, S, P, TK, NTK, D, L, SliceBuilder<'i, I>, I>>;
+type LayoutParser<'i, C, S, P, TK, NTK, D, L, I, A> =
+    Option<LRParser<'i, C, S, P, TK, NTK, D, L, SliceBuilder<'i, I>, I, A>>;
 
-impl<'i, C, S, P, I, TK, NTK, D, L, B>
-    LRParser<'i, C, S, P, TK, NTK, D, L, B, I>
+/// The state of a started parse which is continued by [`LRParser::run`].
+struct ParseRun<'i, C, S, P, TK, NTK, D, L, I, A>
+where
+    C: Context<'i, I, S, TK>,
+    S: State,
+    TK: Default,
+    D: ParserDefinition<S, P, TK, NTK>,
+    L: Lexer<'i, C, S, TK, A, Input = I>,
+    I: Input + ?Sized,
+{
+    parse_stack: ParseStack<S, I, C, TK>,
+    layout_parser: LayoutParser<'i, C, S, P, TK, NTK, D, L, I, A>,
+    /// The token ahead. `None` before the first token is recognized.
+    next_token: Option<Token<'i, I, TK, A>>,
+    lex_work: LexWork,
+    /// Is STOP accepted at any position where the parse can be completed.
+    partial_parse: bool,
+}
+
+/// The lexing work done so far in a parse. Checked against the limits given
+/// by [`LRParser::max_lex_bytes`] and [`LRParser::lex_timeout`].
+#[derive(Default)]
+struct LexWork {
+    bytes: usize,
+    #[cfg(feature = "std")]
+    deadline: Option<Instant>,
+}
+
+/// An iterator over the items built while parsing. See
+/// [`LRParser::parse_iter`].
+pub struct LRParseIter<'i, C, S, P, TK, NTK, D, L, B, I, A = ()>
+where
+    C: Context<'i, I, S, TK>,
+    S: State,
+    TK: Default,
+    D: ParserDefinition<S, P, TK, NTK>,
+    L: Lexer<'i, C, S, TK, A, Input = I>,
+    I: Input + ?Sized,
+{
+    parser: LRParser<'i, C, S, P, TK, NTK, D, L, B, I, A>,
+    input: &'i I,
+    context: C,
+    /// `None` when parsing is finished.
+    #[allow(clippy::type_complexity)]
+    run: Option<ParseRun<'i, C, S, P, TK, NTK, D, L, I, A>>,
+}
+
+impl<'i, C, S, P, I, TK, NTK, D, L, B, A>
+    LRParser<'i, C, S, P, TK, NTK, D, L, B, I, A>
 where
     C: Context<'i, I, S, TK>,
     S: State,
     I: Input + ?Sized,
     TK: Default,
     D: ParserDefinition<S, P, TK, NTK>,
-    L: Lexer<'i, C, S, TK, Input = I>,
-    B: LRBuilder<'i, I, C, S, P, TK>,
+    L: Lexer<'i, C, S, TK, A, Input = I>,
+    B: LRBuilder<'i, I, C, S, P, TK, A>,
 {
     pub fn new(
         definition: &'i D,
@@ -204,10 +432,64 @@ where
             has_layout,
             lexer,
             builder,
+            stats: None,
+            predicate: None,
+            max_depth: None,
+            max_lex_bytes: None,
+            #[cfg(feature = "std")]
+            lex_timeout: None,
+            layout: RefCell::new(None),
             phantom: PhantomData,
         }
     }
 
+    /// Enables collecting of [`ParseStats`] during parsing.
+    pub fn profile(mut self, profile: bool) -> Self {
+        self.stats = profile.then(Default::default);
+        self
+    }
+
+    /// Sets the predicate called for reductions by productions guarded by
+    /// semantic predicates.
+    pub fn predicate(
+        mut self,
+        predicate: Predicate<'i, C, P, I, TK, A>,
+    ) -> Self {
+        self.predicate = Some(predicate);
+        self
+    }
+
+    /// Limits the depth of the parse stack. Parsing fails with an error if the
+    /// limit is exceeded, e.g. for deeply nested untrusted input.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Limits the total number of bytes lexed in a single parse. The bytes of
+    /// the recognized token and the input skipped by the lexer are counted for
+    /// each call of the lexer, thus the tokens lexed again after reductions
+    /// are counted again. Parsing fails with [`Error::Timeout`] if the limit is
+    /// exceeded, e.g. for untrusted input causing excessive lexing.
+    pub fn max_lex_bytes(mut self, max_lex_bytes: usize) -> Self {
+        self.max_lex_bytes = Some(max_lex_bytes);
+        self
+    }
+
+    /// Limits the duration of a single parse. The deadline is checked before
+    /// each call of the lexer and parsing fails with [`Error::Timeout`] if it
+    /// has passed.
+    #[cfg(feature = "std")]
+    pub fn lex_timeout(mut self, lex_timeout: Duration) -> Self {
+        self.lex_timeout = Some(lex_timeout);
+        self
+    }
+
+    /// Returns statistics of the last parse if profiling is enabled.
+    pub fn stats(&self) -> Option<ParseStats> {
+        self.stats.as_ref().map(|stats| stats.borrow().clone())
+    }
+
     #[inline]
     pub fn location_str(&self, file: &str, location: Location) -> String {
         format!("{}:{:?}", file.to_owned(), location)
@@ -217,8 +499,11 @@ where
         &self,
         input: &'i I,
         context: &mut C,
-        layout_parser: &LayoutParser<'i, C, S, P, TK, NTK, D, L, I>,
-    ) -> Result<Token<'i, I, TK>>
+        layout_parser: &LayoutParser<'i, C, S, P, TK, NTK, D, L, I, A>,
+        lex_work: &mut LexWork,
+        builder: &mut B,
+        partial_parse: bool,
+    ) -> Result<Token<'i, I, TK, A>>
     where
         // Needed for calling parse_with_context
         P: Debug + Into<NTK> + Copy,
@@ -226,15 +511,41 @@ where
         I: Debug,
         TK: Debug + Copy + PartialEq + 'i,
         C: Default,
+        A: Debug,
     {
         // Get next tokens (lexer should skip ws if configured to do so).
         // If error run layout_parser. If there is layout try next tokens again.
         // If no next token can be returned report error returned from the lexer.
         loop {
+            #[cfg(feature = "std")]
+            if lex_work.deadline.is_some_and(|d| Instant::now() > d) {
+                return Err(Error::Timeout {
+                    message: format!(
+                        "Parsing time limit of {:?} exceeded.",
+                        self.lex_timeout.unwrap()
+                    ),
+                    file: Some(self.file_name.clone()),
+                    location: Some(context.location()),
+                });
+            }
             let expected_tokens =
                 self.definition.expected_token_kinds(context.state());
-            let mut next_tokens =
-                self.lexer.next_tokens(context, input, expected_tokens);
+            // Lexing is directed by the parser state. Tokens of kinds not
+            // expected in the current state are never accepted even if
+            // returned by the lexer. Among the tokens of the same length the
+            // one preferred by the state (the earlier one) is taken.
+            let expected = expected_tokens
+                .iter()
+                .map(|&(token_kind, _)| token_kind)
+                .collect::<Vec<_>>();
+            let state_order = |token: &Token<'i, I, TK, A>| {
+                expected.iter().position(|&t| t == token.kind)
+            };
+            let position = context.position();
+            let mut next_tokens = self
+                .lexer
+                .next_tokens(context, input, expected_tokens)
+                .filter(|token| state_order(token).is_some());
             let next_token = if D::longest_match() {
                 let mut tokens = next_tokens.collect::<Vec<_>>();
                 if tokens.len() > 1 {
@@ -249,22 +560,64 @@ where
                     );
                     let longest_len = tokens
                         .iter()
-                        .max_by_key(|token| token.value.len())
+                        .max_by_key(|token| token.consumed_len())
                         .unwrap()
-                        .value
-                        .len();
-                    tokens.retain(|token| token.value.len() == longest_len);
+                        .consumed_len();
+                    tokens.retain(|token| token.consumed_len() == longest_len);
                 }
-                tokens.into_iter().next()
+                tokens.into_iter().min_by_key(state_order)
             } else {
                 next_tokens.next()
             };
+            if let Some(max_lex_bytes) = self.max_lex_bytes {
+                lex_work.bytes += context.position() - position
+                    + next_token.as_ref().map_or(0, |t| t.consumed_len());
+                if lex_work.bytes > max_lex_bytes {
+                    return Err(Error::Timeout {
+                        message: format!(
+                            "Lexing limit of {max_lex_bytes} bytes exceeded."
+                        ),
+                        file: Some(self.file_name.clone()),
+                        location: Some(context.location()),
+                    });
+                }
+            }
             if let Some(next_token) = next_token {
+                if D::ignored(next_token.kind)
+                    && self
+                        .definition
+                        .actions(context.state(), next_token.kind)
+                        .is_empty()
+                {
+                    log!("{} {:?}", "Skipping ignored:".green(), next_token);
+                    let consumed = &input[context.position()
+                        ..context.position() + next_token.consumed_len()];
+                    if D::trivia(next_token.kind) {
+                        builder.trivia_action(context, next_token);
+                    }
+                    context.set_position(context.position() + consumed.len());
+                    context.set_location(
+                        consumed.location_after(context.location()),
+                    );
+                    continue;
+                }
+                #[cfg(feature = "tracing")]
+                tracing::trace!(
+                    kind = ?next_token.kind,
+                    position = context.position(),
+                    "token"
+                );
                 return Ok(next_token);
             } else {
-                // No token found at current position. Try layout if configured.
-                if let Some(layout_parser) = layout_parser {
+                // No token found at current position. Try layout if configured
+                // and allowed in the current state.
+                if let Some(layout_parser) = layout_parser
+                    .as_ref()
+                    .filter(|_| !context.state().no_layout())
+                {
                     log!("\n{}", "*** Parsing layout".red().bold());
+                    let (position, location) =
+                        (context.position(), context.location());
                     let current_state = context.state();
                     context.set_state(S::default_layout().unwrap());
                     let p = layout_parser.parse_with_context(context, input);
@@ -274,6 +627,18 @@ where
                         if layout.len() > 0 {
                             log!("Skipping layout: {layout:?}");
                             context.set_layout_ahead(Some(layout));
+                            if let Some(segments) =
+                                self.layout.borrow_mut().as_mut()
+                            {
+                                segments.push(LayoutSegment {
+                                    value: layout,
+                                    range: position..context.position(),
+                                    location: Location {
+                                        start: location.start,
+                                        end: Some(context.location().start),
+                                    },
+                                });
+                            }
                             log!("\n{}", "*** Parsing content".red().bold());
                             continue;
                         }
@@ -283,21 +648,27 @@ where
                 // This can be Ok if partial parse is configured and STOP is expected.
                 // Otherwise we should report error with expected tokens at this position.
                 let stop_kind = <TK as Default>::default();
-                let expected = self
-                    .definition
-                    .expected_token_kinds(context.state())
-                    .into_iter()
-                    .map(|t| t.0)
-                    .collect::<Vec<_>>();
-                if self.partial_parse
-                    && expected.iter().any(|&t| t == stop_kind)
-                {
+                if partial_parse && expected.iter().any(|&t| t == stop_kind) {
                     return Ok(Token {
                         kind: stop_kind,
                         value: &input[context.position()..context.position()],
                         location: context.location(),
+                        attr: None,
+                        consumed: None,
                     });
                 } else {
+                    // Ignored tokens are not reported unless expected.
+                    let expected = expected
+                        .iter()
+                        .copied()
+                        .filter(|&t| {
+                            !D::ignored(t)
+                                || !self
+                                    .definition
+                                    .actions(context.state(), t)
+                                    .is_empty()
+                        })
+                        .collect::<Vec<_>>();
                     return Err(error_expected(
                         input,
                         &self.file_name,
@@ -310,8 +681,8 @@ where
     }
 }
 
-impl<'i, C, S, P, I, TK, NTK, D, L, B> Parser<'i, I, C, S, TK>
-    for LRParser<'i, C, S, P, TK, NTK, D, L, B, I>
+impl<'i, C, S, P, I, TK, NTK, D, L, B, A> Parser<'i, I, C, S, TK>
+    for LRParser<'i, C, S, P, TK, NTK, D, L, B, I, A>
 where
     C: Context<'i, I, S, TK> + Default,
     S: State + Debug,
@@ -319,8 +690,9 @@ where
     I: Input + ?Sized + Debug + 'i,
     TK: Debug + Copy + Default + PartialEq + 'i,
     D: ParserDefinition<S, P, TK, NTK>,
-    L: Lexer<'i, C, S, TK, Input = I>,
-    B: LRBuilder<'i, I, C, S, P, TK>,
+    L: Lexer<'i, C, S, TK, A, Input = I>,
+    B: LRBuilder<'i, I, C, S, P, TK, A>,
+    A: Debug,
 {
     type Output = B::Output;
 
@@ -337,14 +709,93 @@ where
         context: &mut C,
         input: &'i I,
     ) -> Result<Self::Output> {
-        let mut parse_stack: ParseStack<S, I, C, TK> =
-            ParseStack::new(context, self.start_state);
+        let mut run = self.start(context, input);
+        let mut builder = self.builder.borrow_mut();
+        self.run(&mut run, context, input, &mut *builder, &mut (), |_| {
+            None::<()>
+        })?;
+        builder.try_get_result()
+    }
 
+    #[cfg(feature = "std")]
+    fn parse_file<'a, F: AsRef<Path>>(
+        &'a mut self,
+        file: F,
+    ) -> Result<Self::Output>
+    where
+        'a: 'i,
+    {
+        self.file_name = file.as_ref().to_string_lossy().into();
+        self.parse_owned(I::read_file(file.as_ref())?)
+    }
+}
+
+impl<'i, C, S, P, I, TK, NTK, D, L, B, A>
+    LRParser<'i, C, S, P, TK, NTK, D, L, B, I, A>
+where
+    C: Context<'i, I, S, TK> + Default,
+    S: State + Debug,
+    P: Debug + Copy + Into<NTK>,
+    I: Input + ?Sized + Debug + 'i,
+    TK: Debug + Copy + Default + PartialEq + 'i,
+    D: ParserDefinition<S, P, TK, NTK>,
+    L: Lexer<'i, C, S, TK, A, Input = I>,
+    B: LRBuilder<'i, I, C, S, P, TK, A>,
+    A: Debug,
+{
+    /// Parses the given input with the options overriding the configuration
+    /// of the parser for this parse only. E.g. if `require_eof` is `false` the
+    /// parse is partial even if the parser is not configured for partial
+    /// parsing.
+    pub fn parse_opts(
+        &self,
+        input: &'i I,
+        opts: ParseOpts,
+    ) -> Result<B::Output> {
+        let mut context = C::default();
+        context.set_position(self.start_position);
+        let mut run = self.start(&mut context, input);
+        run.partial_parse = !opts.require_eof;
         let mut builder = self.builder.borrow_mut();
+        self.run(
+            &mut run,
+            &mut context,
+            input,
+            &mut *builder,
+            &mut (),
+            |_| None::<()>,
+        )?;
+        builder.try_get_result()
+    }
+
+    /// Parses the given input and returns the output together with the
+    /// segments of the input skipped by the `Layout` rule (e.g. comments) in
+    /// the input order.
+    #[allow(clippy::type_complexity)]
+    pub fn parse_with_layout(
+        &self,
+        input: &'i I,
+    ) -> Result<(B::Output, Vec<LayoutSegment<'i, I>>)> {
+        self.layout.replace(Some(vec![]));
+        let result = self.parse(input);
+        let layout = self.layout.take().unwrap_or_default();
+        Ok((result?, layout))
+    }
+
+    /// Starts parsing of the given input. The parse is continued by `run`.
+    fn start(
+        &self,
+        context: &mut C,
+        input: &'i I,
+    ) -> ParseRun<'i, C, S, P, TK, NTK, D, L, I, A> {
+        context.set_input(input);
+        context.set_state(self.start_state);
+        let parse_stack: ParseStack<S, I, C, TK> =
+            ParseStack::new(context, self.start_state);
 
         // Layout parser is the sajme as Self except it uses SliceBulder to
         // produce the output and it never uses partial parse.
-        let layout_parser: LayoutParser<'i, C, S, P, TK, NTK, D, L, I> =
+        let layout_parser: LayoutParser<'i, C, S, P, TK, NTK, D, L, I, A> =
             self.has_layout.then(|| {
                 LRParser::new_default(
                     self.definition,
@@ -364,22 +815,162 @@ where
             input.context_str(context.position())
         );
 
-        let mut state = parse_stack.state();
+        let state = parse_stack.state();
+
+        if let Some(stats) = &self.stats {
+            let mut stats = stats.borrow_mut();
+            *stats = ParseStats::default();
+            stats.state_entered(state);
+        }
 
         log!("{}: {:#?}", "Stack".green(), parse_stack);
         log!("{}: {:?}", "Current state".green(), state);
 
-        let mut next_token = self.next_token(input, context, &layout_parser)?;
+        ParseRun {
+            parse_stack,
+            layout_parser,
+            next_token: None,
+            lex_work: LexWork {
+                #[cfg(feature = "std")]
+                deadline: self.lex_timeout.map(|t| Instant::now() + t),
+                ..Default::default()
+            },
+            partial_parse: self.partial_parse,
+        }
+    }
+
+    /// Recognizes the next token consulting the driver if none of the
+    /// expected tokens is found.
+    #[allow(clippy::too_many_arguments)]
+    fn driven_token(
+        &self,
+        input: &'i I,
+        context: &mut C,
+        layout_parser: &LayoutParser<'i, C, S, P, TK, NTK, D, L, I, A>,
+        lex_work: &mut LexWork,
+        builder: &mut B,
+        driver: &mut impl ParseDriver<'i, C, P, I, TK, A>,
+        partial_parse: bool,
+    ) -> Result<Token<'i, I, TK, A>> {
+        loop {
+            let error = match self.next_token(
+                input,
+                context,
+                layout_parser,
+                lex_work,
+                builder,
+                partial_parse,
+            ) {
+                Err(error @ Error::Error { .. }) => error,
+                result => return result,
+            };
+            let expected = self
+                .definition
+                .expected_token_kinds(context.state())
+                .into_iter()
+                .map(|(token_kind, _)| token_kind)
+                .collect::<Vec<_>>();
+            let position = context.position();
+            match driver.error(context, &expected, &error) {
+                Recovery::Insert(kind) => {
+                    log!("{} {:?}", "Inserting token".yellow(), kind);
+                    return Ok(Token {
+                        kind,
+                        value: &input[position..position],
+                        location: context.location(),
+                        attr: None,
+                        consumed: None,
+                    });
+                }
+                Recovery::Skip(len)
+                    if len > 0 && position + len <= input.len() =>
+                {
+                    let end = (position + len..input.len())
+                        .find(|&end| input.is_boundary(end))
+                        .unwrap_or(input.len());
+                    log!("{} {} elements", "Skipping".yellow(), end - position);
+                    let skipped = &input[position..end];
+                    context.set_position(end);
+                    context.set_location(
+                        skipped.location_after(context.location()),
+                    );
+                }
+                Recovery::Skip(_) | Recovery::Fail => return Err(error),
+            }
+        }
+    }
+
+    /// Continues the started parse until the input is accepted or `emit`
+    /// returns an item after a reduction.
+    fn run<T>(
+        &self,
+        run: &mut ParseRun<'i, C, S, P, TK, NTK, D, L, I, A>,
+        context: &mut C,
+        input: &'i I,
+        builder: &mut B,
+        driver: &mut impl ParseDriver<'i, C, P, I, TK, A>,
+        mut emit: impl FnMut(&mut B) -> Option<T>,
+    ) -> Result<Option<T>> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("parse", file = %self.file_name).entered();
+        let parse_stack = &mut run.parse_stack;
+        let layout_parser = &run.layout_parser;
+        let lex_work = &mut run.lex_work;
+        let partial_parse = run.partial_parse;
+        let mut state = parse_stack.state();
+        let mut stats = self.stats.as_ref().map(|stats| stats.borrow_mut());
+
+        let mut next_token = match run.next_token.take() {
+            Some(next_token) => next_token,
+            None => self.driven_token(
+                input,
+                context,
+                layout_parser,
+                lex_work,
+                builder,
+                driver,
+                partial_parse,
+            )?,
+        };
         log!("{}: {:?}", "Token ahead".green(), &next_token);
 
         loop {
-            let action = self.definition.actions(state, next_token.kind)[0];
+            // Reductions guarded by predicates come first. Skip those whose
+            // predicate doesn't hold.
+            let mut action = Action::Error;
+            for candidate in self.definition.actions(state, next_token.kind) {
+                if let (Action::Reduce(prod, prod_len), Some(predicate)) =
+                    (candidate, self.predicate)
+                {
+                    let range = context.range();
+                    context.set_range(parse_stack.range(context, prod_len));
+                    let holds = predicate(context, prod, input, &next_token);
+                    context.set_range(range);
+                    if !holds {
+                        log!("{} for {:?}", "Predicate failed".red(), prod);
+                        continue;
+                    }
+                }
+                action = candidate;
+                break;
+            }
+
+            if let Some(max_depth) = self.max_depth {
+                if parse_stack.depth() > max_depth {
+                    return err!(
+                        format!("Maximum parse stack depth of {max_depth} exceeded."),
+                        Some(self.file_name.clone()),
+                        Some(context.location())
+                    );
+                }
+            }
 
             match action {
                 Action::Shift(state_id) => {
                     state = state_id;
-                    context.set_range(context.position()..(context.position() + next_token.value.len()));
-                    let new_location = next_token.value.location_after(context.location());
+                    context.set_range(context.position()..(context.position() + next_token.consumed_len()));
+                    let new_location = input[context.range()].location_after(context.location());
                     context.set_location(Location{
                         start: context.location().start,
                         end: Some(new_location.start),
@@ -392,7 +983,18 @@ where
                         &next_token
                     );
                     parse_stack.push_state(context, state);
-                    builder.shift_action(context, next_token);
+                    #[cfg(feature = "tracing")]
+                    tracing::trace!(
+                        state = ?state,
+                        token = ?next_token.kind,
+                        "shift"
+                    );
+                    if let Some(stats) = &mut stats {
+                        stats.state_entered(state);
+                        stats.shifted(next_token.kind);
+                    }
+                    driver.shift(context, &next_token);
+                    builder.try_shift_action(context, next_token)?;
 
                     context.set_position(context.range().end);
                     context.set_location(new_location);
@@ -403,7 +1005,7 @@ where
                         context.location(),
                         input.context_str(context.position())
                     );
-                    next_token = self.next_token(input, context, &layout_parser)?;
+                    next_token = self.driven_token(input, context, layout_parser, lex_work, builder, driver, partial_parse)?;
                     log!("{}: {:?}", "Token ahead".green(), next_token);
                 }
                 Action::Reduce(prod, prod_len) => {
@@ -416,12 +1018,24 @@ where
                     let (from_state, range, location) =
                         parse_stack.pop_states(context, prod_len);
                     context.set_range(range);
+                    driver.reduce(context, prod, prod_len);
                     state = self.definition.goto(from_state, prod.into());
                     let context_location = context.location();
                     context.set_location(location);
                     parse_stack.push_state(context, state);
+                    if let Some(stats) = &mut stats {
+                        stats.state_entered(state);
+                        stats.reduced(prod);
+                    }
                     log!("{} {:?} -> {:?}", "GOTO".green(), from_state, state);
-                    builder.reduce_action(context, prod, prod_len);
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(
+                        production = ?prod,
+                        len = prod_len,
+                        goto = ?state,
+                        "reduce"
+                    );
+                    builder.try_reduce_action(context, prod, prod_len)?;
                     context.set_location(context_location);
 
                     // After the reduction we need to run lexer again as the set
@@ -430,13 +1044,20 @@ where
                     // TODO: This should be optimized to prevent repetitions of the same
                     //       tokens recognitions.
                     let layout = context.layout_ahead();
-                    next_token = self.next_token(input, context, &layout_parser)?;
+                    next_token = self.driven_token(input, context, layout_parser, lex_work, builder, driver, partial_parse)?;
                     context.set_layout_ahead(layout);
                     log!("{}: {:?}", "Token ahead".green(), next_token);
+
+                    if let Some(item) = emit(builder) {
+                        run.next_token = Some(next_token);
+                        return Ok(Some(item));
+                    }
                 }
                 Action::Accept => {
                     log!("{}", "Accept".green().bold());
-                    break
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!("accept");
+                    return Ok(None);
                 },
                 // This can't happen for context-aware lexing. If there is no
                 // action for a lookahead then the lookahead would not be found.
@@ -449,19 +1070,148 @@ where
             log!("{}: {:#?}", "Stack".green(), parse_stack);
             log!("{}: {:?}", "Current state".green(), state);
         }
-        Ok(builder.get_result())
     }
 
-    fn parse_file<'a, F: AsRef<Path>>(
-        &'a mut self,
-        file: F,
-    ) -> Result<Self::Output>
+    /// Parses the given input consulting the given driver on each shift and
+    /// reduce and on each parse error. See [`ParseDriver`].
+    pub fn parse_with_driver(
+        &self,
+        input: &'i I,
+        driver: &mut impl ParseDriver<'i, C, P, I, TK, A>,
+    ) -> Result<B::Output> {
+        let mut context = C::default();
+        context.set_position(self.start_position);
+        let mut run = self.start(&mut context, input);
+        let mut builder = self.builder.borrow_mut();
+        self.run(&mut run, &mut context, input, &mut *builder, driver, |_| {
+            None::<()>
+        })?;
+        builder.try_get_result()
+    }
+
+    /// Returns the kinds of the tokens which can follow the given prefix of
+    /// the input, e.g. for autocompletion in editors. The prefix is parsed up
+    /// to its end which may be incomplete. The result contains the STOP token
+    /// kind if the prefix is a complete input. Fails with the parse error if
+    /// the prefix can't be parsed up to its end.
+    ///
+    /// The token kinds are the expected token kinds of the state reached at
+    /// the end of the prefix for which the parser, after the reductions they
+    /// trigger, would shift or accept.
+    pub fn completions(&self, input: &'i I) -> Result<Vec<TK>> {
+        let mut driver = CompletionDriver {
+            definition: self.definition,
+            end: input.len(),
+            states: vec![self.start_state],
+            changes: vec![],
+            incomplete: false,
+            nonterm: PhantomData,
+        };
+        match self.parse_with_driver(input, &mut driver) {
+            Err(error) if !driver.incomplete => return Err(error),
+            _ => {}
+        }
+        let states = driver.states_after_shift();
+        let state = *states.last().expect("Stack can't be empty.");
+        Ok(self
+            .definition
+            .expected_token_kinds(state)
+            .into_iter()
+            .map(|(token_kind, _)| token_kind)
+            .filter(|&t| {
+                !D::ignored(t) || !self.definition.actions(state, t).is_empty()
+            })
+            .filter(|&t| self.is_shifted(states.clone(), t))
+            .collect())
+    }
+
+    /// Runs the reductions for the token of the given kind ahead on the given
+    /// stack of states. Returns `true` if the token is then shifted or the
+    /// input accepted.
+    fn is_shifted(&self, mut states: Vec<S>, token_kind: TK) -> bool {
+        loop {
+            let state = *states.last().expect("Stack can't be empty.");
+            match self.definition.actions(state, token_kind).first() {
+                Some(Action::Shift(_) | Action::Accept) => return true,
+                Some(&Action::Reduce(prod, prod_len)) => {
+                    states.truncate(states.len() - prod_len);
+                    let from_state =
+                        *states.last().expect("Stack can't be empty.");
+                    states.push(self.definition.goto(from_state, prod.into()));
+                }
+                Some(Action::Error) | None => return false,
+            }
+        }
+    }
+
+    /// Parses the given input lazily. The items handed over by the builder are
+    /// returned by the iterator as soon as they are reduced so the whole
+    /// output is never kept in memory. The rest of the output is dropped.
+    pub fn parse_iter(
+        self,
+        input: &'i I,
+    ) -> LRParseIter<'i, C, S, P, TK, NTK, D, L, B, I, A>
+    where
+        B: StreamBuilder,
+    {
+        let mut context = C::default();
+        context.set_position(self.start_position);
+        let run = self.start(&mut context, input);
+        LRParseIter {
+            parser: self,
+            input,
+            context,
+            run: Some(run),
+        }
+    }
+
+    /// Parses the given owned content (e.g. a preprocessed input). Like in
+    /// `parse_file`, the parser owns the content and thus has to outlive the
+    /// output if it borrows from the content.
+    pub fn parse_owned<'a>(&'a mut self, content: I::Owned) -> Result<B::Output>
     where
         'a: 'i,
     {
-        self.content = Some(I::read_file(file.as_ref())?);
-        self.file_name = file.as_ref().to_string_lossy().into();
-        let parsed = self.parse(self.content.as_ref().unwrap().borrow());
-        parsed
+        self.content = Some(content);
+        self.parse(self.content.as_ref().unwrap().borrow())
+    }
+}
+
+impl<'i, C, S, P, I, TK, NTK, D, L, B, A> Iterator
+    for LRParseIter<'i, C, S, P, TK, NTK, D, L, B, I, A>
+where
+    C: Context<'i, I, S, TK> + Default,
+    S: State + Debug,
+    P: Debug + Copy + Into<NTK>,
+    I: Input + ?Sized + Debug + 'i,
+    TK: Debug + Copy + Default + PartialEq + 'i,
+    D: ParserDefinition<S, P, TK, NTK>,
+    L: Lexer<'i, C, S, TK, A, Input = I>,
+    B: LRBuilder<'i, I, C, S, P, TK, A> + StreamBuilder,
+    A: Debug,
+{
+    type Item = Result<B::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let run = self.run.as_mut()?;
+        let mut builder = self.parser.builder.borrow_mut();
+        match self.parser.run(
+            run,
+            &mut self.context,
+            self.input,
+            &mut *builder,
+            &mut (),
+            B::take_item,
+        ) {
+            Ok(Some(item)) => Some(Ok(item)),
+            Ok(None) => {
+                self.run = None;
+                builder.try_get_result().err().map(Err)
+            }
+            Err(e) => {
+                self.run = None;
+                Some(Err(e))
+            }
+        }
     }
 }
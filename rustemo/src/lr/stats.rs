@@ -0,0 +1,30 @@
+use alloc::{collections::BTreeMap, format, string::String};
+use core::fmt::Debug;
+
+/// Counters collected by the LR parser if profiling is enabled.
+///
+/// States, productions and terminals are keyed by their debug representation
+/// so the statistics can be inspected without knowing the generated types.
+#[derive(Debug, Default, Clone)]
+pub struct ParseStats {
+    /// How many times each state is entered.
+    pub states: BTreeMap<String, usize>,
+    /// How many times each production is reduced.
+    pub productions: BTreeMap<String, usize>,
+    /// How many times each terminal is recognized and shifted.
+    pub terminals: BTreeMap<String, usize>,
+}
+
+impl ParseStats {
+    pub(crate) fn state_entered<S: Debug>(&mut self, state: S) {
+        *self.states.entry(format!("{state:?}")).or_default() += 1;
+    }
+
+    pub(crate) fn reduced<P: Debug>(&mut self, prod: P) {
+        *self.productions.entry(format!("{prod:?}")).or_default() += 1;
+    }
+
+    pub(crate) fn shifted<TK: Debug>(&mut self, token_kind: TK) {
+        *self.terminals.entry(format!("{token_kind:?}")).or_default() += 1;
+    }
+}
@@ -0,0 +1,98 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{iter::once, ops::Range};
+use unicode_normalization::{
+    char::canonical_combining_class, is_nfc, is_nfc_quick, IsNormalized,
+    UnicodeNormalization,
+};
+
+/// Normalizes the string input to Unicode NFC. Used by the `parse_normalized`
+/// method of the generated parsers.
+///
+/// Returns the normalized text, borrowed if the input is already in NFC, and
+/// the map of positions in the normalized text back to positions in the
+/// input.
+pub fn normalize_nfc(input: &str) -> (Cow<'_, str>, OffsetMap) {
+    if is_nfc(input) {
+        return (Cow::Borrowed(input), OffsetMap::default());
+    }
+    let mut text = String::with_capacity(input.len());
+    let mut segments = Vec::new();
+    let mut start = 0;
+    while start < input.len() {
+        // A segment ends before a character which can't combine with the
+        // preceding ones so each segment is normalized independently.
+        let end = input[start..]
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| {
+                canonical_combining_class(c) == 0
+                    && is_nfc_quick(once(c)) == IsNormalized::Yes
+            })
+            .map_or(input.len(), |(i, _)| start + i);
+        let segment = &input[start..end];
+        let normalized_start = text.len();
+        text.extend(segment.nfc());
+        if &text[normalized_start..] != segment {
+            segments.push((normalized_start..text.len(), start..end));
+        }
+        start = end;
+    }
+    (Cow::Owned(text), OffsetMap { segments })
+}
+
+/// Maps positions in the text normalized by [`normalize_nfc`] back to
+/// positions in the original text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OffsetMap {
+    /// Normalized and original ranges of the segments changed by the
+    /// normalization in the text order.
+    segments: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl OffsetMap {
+    /// The position in the original text of the given position in the
+    /// normalized text. A position inside of a changed segment is mapped to
+    /// the start of the segment.
+    pub fn original(&self, position: usize) -> usize {
+        let idx = self
+            .segments
+            .partition_point(|(normalized, _)| normalized.start <= position);
+        match idx.checked_sub(1).map(|idx| &self.segments[idx]) {
+            None => position,
+            Some((normalized, original)) if position < normalized.end => {
+                original.start
+            }
+            Some((normalized, original)) => {
+                original.end + position - normalized.end
+            }
+        }
+    }
+
+    /// The range in the original text of the given range in the normalized
+    /// text (e.g. a span of a token).
+    pub fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        self.original(range.start)..self.original(range.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::normalize_nfc;
+
+    #[test]
+    fn test_normalize_nfc() {
+        let (text, map) = normalize_nfc("cafe\u{301} e\u{301}t\u{e9}");
+        assert_eq!(text, "caf\u{e9} \u{e9}t\u{e9}");
+        // The span of "été".
+        assert_eq!(map.original(6), 7);
+        assert_eq!(map.original_range(6..11), 7..13);
+        // A position inside of "é" maps to its start.
+        assert_eq!(map.original(4), 3);
+
+        let (text, map) = normalize_nfc("caf\u{e9}");
+        assert!(matches!(text, Cow::Borrowed(_)));
+        assert_eq!(map.original(5), 5);
+    }
+}
@@ -1,3 +1,4 @@
+#[cfg(feature = "std")]
 use std::path::Path;
 
 use crate::{context::Context, error::Result, input::Input};
@@ -30,6 +31,7 @@ where
     /// calling `parse`. The parser will own the content being parsed and thus
     /// has to outlive `Self::Output` if it borrows from the content loaded from
     /// the file.
+    #[cfg(feature = "std")]
     fn parse_file<'a, F: AsRef<Path>>(
         &'a mut self,
         file: F,
@@ -38,8 +40,30 @@ where
         'a: 'i;
 }
 
+/// Options of a single parse which override the configuration of the parser.
+/// See [`LRParser::parse_opts`](crate::LRParser::parse_opts).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOpts {
+    /// Must the whole input be consumed. If `false`, parsing stops with
+    /// success at the position where no expected token can be recognized if
+    /// the input parsed so far is valid, i.e. the parse is partial.
+    pub require_eof: bool,
+}
+
+impl Default for ParseOpts {
+    fn default() -> Self {
+        Self { require_eof: true }
+    }
+}
+
 /// This trait must be implemented by the parser state type.
 pub trait State: Default + Copy {
     /// Returns the default layout state.
     fn default_layout() -> Option<Self>;
+
+    /// Returns `true` if layout should not be parsed in this state. These are
+    /// the states inside productions annotated with `nolayout`.
+    fn no_layout(&self) -> bool {
+        false
+    }
 }
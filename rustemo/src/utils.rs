@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 /// Simple deduplication.
 /// See: https://stackoverflow.com/a/57889826/2024430
 pub trait Dedup<T: PartialEq + Clone> {
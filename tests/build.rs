@@ -1,6 +1,8 @@
 use std::{env, path::PathBuf, process::exit};
 
-use rustemo_compiler::{BuilderType, LexerType, ParserAlgo, Settings};
+use rustemo_compiler::{
+    BuilderType, LexerType, ParserAlgo, Settings, Visibility,
+};
 
 fn main() {
     fn default_settings() -> Settings {
@@ -22,9 +24,28 @@ fn main() {
         ("rule_patterns", Box::new(|s| s)),
         ("sugar", Box::new(|s| s)),
         ("unicode", Box::new(|s| s)),
+        ("terminal_type", Box::new(|s| s)),
+        (
+            "terminal_type_fallible",
+            Box::new(|s| s.fallible_actions(true)),
+        ),
+        ("max_depth", Box::new(|s| s)),
+        ("lex_budget", Box::new(|s| s)),
+        ("labels", Box::new(|s| s)),
+        ("streaming", Box::new(|s| s.streaming(true))),
+        ("rule_parsers", Box::new(|s| s.rule_parsers(true))),
+        ("no_panic", Box::new(|s| s.no_panic(true))),
+        ("tracing_events", Box::new(|s| s)),
+        ("profile", Box::new(|s| s.profile(true))),
         ("fancy_regex", Box::new(|s| s.fancy_regex(true))),
+        ("regex_set", Box::new(|s| s.regex_set(true))),
         ("errors/syntax_errors", Box::new(|s| s)),
+        ("actions_code", Box::new(|s| s)),
+        ("doc_comments", Box::new(|s| s)),
         ("ambiguity", Box::new(|s| s.prefer_shifts(true))),
+        ("async_stream", Box::new(|s| s.async_stream(true))),
+        ("bare_prod_kinds", Box::new(|s| s.bare_prod_kinds(true))),
+        ("rr_by_order", Box::new(|s| s.resolve_rr_by_order(true))),
         // LR lexical ambiguities
         ("lexical_ambiguity/priorities", Box::new(|s| s)),
         ("lexical_ambiguity/most_specific", Box::new(|s| s)),
@@ -48,21 +69,114 @@ fn main() {
             "partial",
             Box::new(|s| s.prefer_shifts(true).partial_parse(true)),
         ),
+        ("parse_all", Box::new(|s| s.glr_fallback(true))),
+        ("parse_driver", Box::new(|s| s)),
+        ("completions", Box::new(|s| s)),
+        ("parse_opts", Box::new(|s| s)),
+        ("columns", Box::new(|s| s)),
+        ("copy_symbols", Box::new(|s| s)),
+        ("terminals_include", Box::new(|s| s)),
+        (
+            "borrowed_terminals",
+            Box::new(|s| s.borrowed_terminals(true)),
+        ),
+        ("derive_hash_eq", Box::new(|s| s.derive_hash_eq(true))),
+        ("impl_fromstr", Box::new(|s| s.impl_fromstr(true))),
+        (
+            "grammar_extension",
+            Box::new(|s| {
+                s.grammar_extension("rgr".into()).skip_invalid_grammars(true)
+            }),
+        ),
+        ("string_alternatives", Box::new(|s| s)),
+        ("string_terminals", Box::new(|s| s)),
+        ("text_annotation/owned", Box::new(|s| s)),
+        (
+            "text_annotation/borrowed",
+            Box::new(|s| s.borrowed_terminals(true)),
+        ),
+        ("normalize_nfc", Box::new(|s| s.normalize_nfc(true))),
+        ("prod_kind", Box::new(|s| s)),
+        ("predicates", Box::new(|s| s)),
+        (
+            "rename",
+            Box::new(|s| s.rename("type".into(), "Type_".into())),
+        ),
+        (
+            "reduce_dispatch",
+            Box::new(|s| s.reduce_dispatch_table(true)),
+        ),
+        ("start", Box::new(|s| s)),
+        ("synthetic", Box::new(|s| s.exclude_synthetic(true))),
+        ("tokenize", Box::new(|s| s)),
+        (
+            "visibility",
+            Box::new(|s| s.visibility(Visibility::PubCrate)),
+        ),
+        (
+            "preprocess",
+            Box::new(|s| s.strip_bom(true).normalize_newlines(true)),
+        ),
+        (
+            "alloc",
+            Box::new(|s| s.force(false).actions_in_source_tree()),
+        ),
+        (
+            "fallible_actions",
+            Box::new(|s| {
+                s.fallible_actions(true)
+                    .force(false)
+                    .actions_in_source_tree()
+            }),
+        ),
         // Layout
         ("layout/ast", Box::new(|s| s)),
+        ("layout/comment_directives", Box::new(|s| s)),
+        ("layout/doc_comments", Box::new(|s| s)),
+        ("layout/nolayout", Box::new(|s| s)),
         (
             "layout/generic_tree",
             Box::new(|s| s.builder_type(BuilderType::Generic)),
         ),
         // Builders
+        ("builder/ast_constructors", Box::new(|s| s)),
+        ("builder/from_variants", Box::new(|s| s)),
+        (
+            "builder/collapse_chains",
+            Box::new(|s| {
+                s.builder_type(BuilderType::Generic).collapse_chains(true)
+            }),
+        ),
         (
             "builder/generic_tree",
             Box::new(|s| s.builder_type(BuilderType::Generic)),
         ),
+        ("builder/parse_generic", Box::new(|s| s.generic_tree(true))),
+        ("builder/symbol_kind", Box::new(|s| s)),
         (
             "builder/custom_builder",
             Box::new(|s| s.builder_type(BuilderType::Custom)),
         ),
+        (
+            "builder/token_filter",
+            Box::new(|s| s.builder_type(BuilderType::Custom)),
+        ),
+        (
+            "builder/lossless",
+            Box::new(|s| s.builder_type(BuilderType::Custom)),
+        ),
+        (
+            "builder/trivia",
+            Box::new(|s| s.builder_type(BuilderType::Custom).skip_ws(false)),
+        ),
+        (
+            "builder/context_input",
+            Box::new(|s| s.force(false).actions_in_source_tree()),
+        ),
+        (
+            "builder/tree_actions",
+            Box::new(|s| s.force(false).actions_in_source_tree()),
+        ),
         (
             "builder/use_context",
             Box::new(|s| {
@@ -71,6 +185,13 @@ fn main() {
             }),
         ),
         // Lexer
+        ("lexer/raw", Box::new(|s| s)),
+        ("lexer/heredoc", Box::new(|s| s.custom_recognizers(true))),
+        ("lexer/hex_literal", Box::new(|s| s.custom_recognizers(true))),
+        ("lexer/incremental", Box::new(|s| s.incremental_lexer(true))),
+        ("lexer/context_keywords", Box::new(|s| s)),
+        ("lexer/ignore", Box::new(|s| s)),
+        ("lexer/keywords", Box::new(|s| s)),
         (
             "lexer/custom_lexer",
             Box::new(|s| {
@@ -80,15 +201,49 @@ fn main() {
                     .actions_in_source_tree()
             }),
         ),
+        (
+            "lexer/logos_lexer",
+            Box::new(|s| s.lexer_type(LexerType::Custom)),
+        ),
+        (
+            "lexer/rope_input",
+            Box::new(|s| {
+                s.lexer_type(LexerType::Custom)
+                    .input_type("super::rope::Rope".into())
+                    .builder_type(BuilderType::Generic)
+            }),
+        ),
+        (
+            "lexer/token_attr",
+            Box::new(|s| {
+                s.lexer_type(LexerType::Custom)
+                    .token_attr_type("super::token_attr_lexer::Radix".into())
+                    .force(false)
+                    .actions_in_source_tree()
+            }),
+        ),
         // Special
         ("special/lalr_reduce_reduce_conflict", Box::new(|s| s)),
         ("special/nondeterministic_palindromes", Box::new(|s| s)),
         ("special/pager_g1", Box::new(|s| s)),
         ("special/lalrpop768", Box::new(|s| s)),
         // GLR
+        (
+            "glr/ambiguity_is_error",
+            Box::new(|s| s.parser_algo(ParserAlgo::GLR)),
+        ),
         ("glr/errors", Box::new(|s| s.parser_algo(ParserAlgo::GLR))),
         ("glr/forest", Box::new(|s| s.parser_algo(ParserAlgo::GLR))),
         ("glr/build", Box::new(|s| s.parser_algo(ParserAlgo::GLR))),
+        ("glr/min_cost", Box::new(|s| s.parser_algo(ParserAlgo::GLR))),
+        (
+            "glr/max_heads",
+            Box::new(|s| s.parser_algo(ParserAlgo::GLR)),
+        ),
+        (
+            "glr/max_solutions",
+            Box::new(|s| s.parser_algo(ParserAlgo::GLR).partial_parse(true)),
+        ),
         // GLR lexical ambiguities
         (
             "glr/lexical_ambiguity/priorities",
@@ -218,6 +373,51 @@ fn main() {
         }
     }
 
+    // The same grammar with reductions dispatched by `match` to compare with
+    // the dispatch table.
+    let dispatch_match = out_dir.join("src/reduce_dispatch/match");
+    if let Err(e) = default_settings()
+        .out_dir_root(dispatch_match.clone())
+        .out_dir_actions_root(dispatch_match)
+        .root_dir(root_dir.join("src/reduce_dispatch"))
+        .process_grammar(&root_dir.join("src/reduce_dispatch/keywords.rustemo"))
+    {
+        eprintln!("{}", e);
+        exit(1);
+    }
+
+    // The same grammar with a custom lexer returning also the tokens not
+    // expected by the parser.
+    let custom = out_dir.join("src/lexer/context_keywords/custom");
+    if let Err(e) = default_settings()
+        .lexer_type(LexerType::Custom)
+        .out_dir_root(custom.clone())
+        .out_dir_actions_root(custom)
+        .root_dir(root_dir.join("src/lexer/context_keywords"))
+        .process_grammar(
+            &root_dir
+                .join("src/lexer/context_keywords/context_keywords.rustemo"),
+        )
+    {
+        eprintln!("{}", e);
+        exit(1);
+    }
+
+    // The same grammar without collapsing to compare the tree depth.
+    let nocollapse = out_dir.join("src/builder/collapse_chains/nocollapse");
+    if let Err(e) = default_settings()
+        .builder_type(BuilderType::Generic)
+        .out_dir_root(nocollapse.clone())
+        .out_dir_actions_root(nocollapse)
+        .root_dir(root_dir.join("src/builder/collapse_chains"))
+        .process_grammar(
+            &root_dir.join("src/builder/collapse_chains/calc.rustemo"),
+        )
+    {
+        eprintln!("{}", e);
+        exit(1);
+    }
+
     // Testing code generation in the source tree
     if let Err(e) = default_settings()
         .in_source_tree()
@@ -232,4 +432,34 @@ fn main() {
         eprintln!("{}", e);
         exit(1);
     }
+
+    // Inner attributes can't be used in included files, thus the files with a
+    // custom header are generated in the source tree.
+    if let Err(e) = default_settings()
+        .in_source_tree()
+        .file_header(
+            "#![allow(clippy::all)]\n//! Parser with a custom header.".into(),
+        )
+        .process_grammar(&root_dir.join("src/file_header/header.rustemo"))
+    {
+        eprintln!("{}", e);
+        exit(1);
+    }
+
+    // Testing code generation from the grammar embedded in the source
+    if let Err(e) = rustemo_compiler::generate_parser_from_source(
+        r#"
+            Sum: Sum '+' Num | Num;
+
+            terminals
+            Plus: '+';
+            Num: /\d+/;
+        "#,
+        "sum",
+        &out_dir.join("src/from_source"),
+        &default_settings(),
+    ) {
+        eprintln!("{}", e);
+        exit(1);
+    }
 }
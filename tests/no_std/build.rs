@@ -0,0 +1,14 @@
+use std::process::exit;
+
+use rustemo_compiler::{LexerType, Settings};
+
+fn main() {
+    if let Err(e) = Settings::new()
+        .lexer_type(LexerType::Custom)
+        .no_std(true)
+        .process_dir()
+    {
+        eprintln!("{}", e);
+        exit(1);
+    }
+}
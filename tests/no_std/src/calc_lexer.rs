@@ -0,0 +1,55 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::iter;
+
+use rustemo::{Context, LRContext, Lexer, Location, Position, Token};
+
+use super::calc::{State, TokenKind};
+
+pub type Input = str;
+pub type Ctx<'i> = LRContext<'i, Input, State, TokenKind>;
+
+/// Recognizes numbers and single character operators. Whitespaces are not
+/// allowed.
+pub struct CalcLexer();
+
+impl CalcLexer {
+    pub fn new() -> Self {
+        CalcLexer()
+    }
+}
+
+impl<'i> Lexer<'i, Ctx<'i>, State, TokenKind> for CalcLexer {
+    type Input = Input;
+
+    fn next_tokens(
+        &self,
+        context: &mut Ctx<'i>,
+        input: &'i Self::Input,
+        _token_kinds: Vec<(TokenKind, bool)>,
+    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TokenKind>> + 'i> {
+        let start = context.position();
+        let rest = &input[start..];
+        let (kind, len) = match rest.chars().next() {
+            None => (TokenKind::STOP, 0),
+            Some('+') => (TokenKind::Plus, 1),
+            Some('*') => (TokenKind::Mul, 1),
+            Some('(') => (TokenKind::LParen, 1),
+            Some(')') => (TokenKind::RParen, 1),
+            Some(_) => (
+                TokenKind::Num,
+                rest.find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len()),
+            ),
+        };
+        Box::new(iter::once(Token {
+            kind,
+            value: &rest[..len],
+            location: Location {
+                start: Position::Position(start),
+                end: Some(Position::Position(start + len)),
+            },
+            attr: None,
+            consumed: None,
+        }))
+    }
+}
@@ -0,0 +1,38 @@
+//! A parser generated in no_std mode. The crate uses only `core` and `alloc`.
+#![no_std]
+#![allow(clippy::new_without_default)]
+
+extern crate alloc;
+
+use rustemo::rustemo_mod;
+
+pub mod calc_lexer;
+
+rustemo_mod!(pub calc, "/src");
+rustemo_mod!(pub calc_actions, "/src");
+
+#[cfg(test)]
+mod tests {
+    use super::{calc::CalcParser, calc_actions::E, calc_lexer::CalcLexer};
+
+    fn eval(e: &E) -> i32 {
+        match e {
+            E::Add(add) => eval(&add.e_1) + eval(&add.e_3),
+            E::Mul(mul) => eval(&mul.e_1) * eval(&mul.e_3),
+            E::Paren(e) => eval(e),
+            E::Num(n) => n.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn no_std_parse() {
+        let result = CalcParser::new(CalcLexer::new()).parse("2+3*(4+1)");
+        assert_eq!(eval(&result.unwrap()), 17);
+    }
+
+    #[test]
+    fn no_std_parse_error() {
+        let result = CalcParser::new(CalcLexer::new()).parse("2+*3");
+        assert!(result.is_err());
+    }
+}
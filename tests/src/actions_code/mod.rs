@@ -0,0 +1,51 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::Settings;
+
+use self::pairs::PairsParser;
+use self::pairs_actions::to_map;
+
+rustemo_mod!(pairs, "/src/actions_code");
+rustemo_mod!(pairs_actions, "/src/actions_code");
+
+#[test]
+fn actions_code_helper() {
+    let result = PairsParser::new().parse("a = 1_000 b = 2").unwrap();
+    assert_eq!(
+        to_map(result),
+        HashMap::from([("a".to_string(), 1000), ("b".to_string(), 2)])
+    );
+}
+
+/// The code is spliced after the imports and is not repeated when the
+/// actions file is regenerated.
+#[test]
+fn actions_code_regenerate() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("actions_code");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let grammar = dir.join("pairs.rustemo");
+    fs::copy(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/actions_code/pairs.rustemo"),
+        &grammar,
+    )
+    .unwrap();
+
+    let settings = Settings::new().in_source_tree();
+    settings.process_grammar(&grammar).unwrap();
+    let actions = fs::read_to_string(dir.join("pairs_actions.rs")).unwrap();
+    settings.process_grammar(&grammar).unwrap();
+    assert_eq!(
+        fs::read_to_string(dir.join("pairs_actions.rs")).unwrap(),
+        actions
+    );
+
+    assert_eq!(actions.matches("fn parse_num").count(), 1);
+    assert_eq!(actions.matches("pub fn num").count(), 1);
+    let token = actions.find("pub type Token").unwrap();
+    let import = actions.find("use std::collections::HashMap;").unwrap();
+    let pairs = actions.find("pub struct Pair").unwrap();
+    assert!(token < import && import < pairs);
+}
@@ -0,0 +1,80 @@
+//! Tests of the heap allocations done by the generated parsers. A separate
+//! test binary is used as the allocations are counted by the global allocator.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use rustemo::{
+    rustemo_mod, Builder, LRBuilder, LRContext, Location, Position, Token,
+};
+
+use self::nested::{DefaultBuilder, ProdKind, TokenKind};
+
+rustemo_mod!(nested, "/src/alloc");
+#[rustfmt::skip]
+mod nested_actions;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts allocations done by the current thread.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|a| a.set(a.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|a| a.get())
+}
+
+/// Drives the default builder as the parser would for `((...(1)...))` and
+/// counts the allocations done by the builder.
+#[test]
+fn builder_reduce_no_alloc() {
+    const DEPTH: u32 = 1000;
+    let mut context = LRContext::new(0);
+    let token = |kind, value| Token {
+        kind,
+        value,
+        location: Location {
+            start: Position::Position(0),
+            end: None,
+        },
+        attr: None,
+        consumed: None,
+    };
+    let mut builder = DefaultBuilder::new();
+
+    let before = allocations();
+    for _ in 0..DEPTH {
+        builder.shift_action(&mut context, token(TokenKind::OParen, "("));
+    }
+    builder.shift_action(&mut context, token(TokenKind::Num, "1"));
+    builder.reduce_action(&mut context, ProdKind::ENum, 1);
+    for _ in 0..DEPTH {
+        builder.shift_action(&mut context, token(TokenKind::CParen, ")"));
+        builder.reduce_action(&mut context, ProdKind::EParen, 3);
+    }
+    let builder_allocations = allocations() - before;
+
+    assert_eq!(builder.get_result(), DEPTH);
+    // Only the result stack grows. Before, each reduction allocated a new
+    // `Vec` for the RHS results.
+    assert!(
+        builder_allocations < 20,
+        "{builder_allocations} allocations"
+    );
+}
@@ -0,0 +1,21 @@
+/// This file is maintained by rustemo but can be modified manually.
+/// All manual changes will be preserved except non-doc comments.
+use rustemo::Token as RustemoToken;
+use super::nested::{TokenKind, Context};
+pub type Input = str;
+pub type Ctx<'i> = Context<'i, Input>;
+#[allow(dead_code)]
+pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+pub type Num = u32;
+pub fn num(_ctx: &Ctx, token: Token) -> Num {
+    token.value.parse().unwrap()
+}
+/// The nesting depth is counted instead of building the tree so that the
+/// actions don't allocate.
+pub type E = u32;
+pub fn e_paren(_ctx: &Ctx, e: E) -> E {
+    e + 1
+}
+pub fn e_num(_ctx: &Ctx, _num: Num) -> E {
+    0
+}
@@ -1,9 +1,12 @@
 use rustemo::{rustemo_mod, Parser};
 use rustemo_compiler::{local_file, output_cmp};
 
+use self::prec_assoc_prod::PrecAssocProdParser;
 use self::reduce_empty_1::ReduceEmpty1Parser;
 use self::reduce_empty_2::ReduceEmpty2Parser;
 
+rustemo_mod!(prec_assoc_prod, "/src/ambiguity");
+rustemo_mod!(prec_assoc_prod_actions, "/src/ambiguity");
 rustemo_mod!(reduce_empty_1, "/src/ambiguity");
 rustemo_mod!(reduce_empty_1_actions, "/src/ambiguity");
 rustemo_mod!(reduce_empty_2, "/src/ambiguity");
@@ -30,6 +33,15 @@ fn prod_assoc_prio() {
     .unwrap();
 }
 
+#[test]
+fn prod_prec_assoc() {
+    let result = PrecAssocProdParser::new().parse("- 1 - 2");
+    output_cmp!(
+        "src/ambiguity/prec_assoc_prod.ast",
+        format!("{:#?}", result)
+    );
+}
+
 #[test]
 fn term_assoc_prod_prio() {
     rustemo_compiler::process_grammar(local_file!(
@@ -0,0 +1,82 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use rustemo::{Parser, Stream};
+
+use self::calc::CalcParser;
+
+rustemo::rustemo_mod!(calc, "/src/async_stream");
+rustemo::rustemo_mod!(calc_actions, "/src/async_stream");
+
+/// A stream of bytes where each byte arrives only after the consumer has
+/// waited for it once.
+struct Arriving<'a> {
+    bytes: &'a [u8],
+    ready: bool,
+}
+
+impl<'a> Arriving<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            ready: false,
+        }
+    }
+}
+
+impl Stream for Arriving<'_> {
+    type Item = u8;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        if !self.ready {
+            self.ready = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        self.ready = false;
+        let byte = self.bytes.first().copied();
+        if byte.is_some() {
+            self.bytes = &self.bytes[1..];
+        }
+        Poll::Ready(byte)
+    }
+}
+
+// ANCHOR: async-stream
+#[test]
+fn async_stream_parse() {
+    let input = "2 * (3 + 4) + 5";
+    let result = pollster::block_on(CalcParser::parse_stream(Arriving::new(
+        input.as_bytes(),
+    )))
+    .unwrap();
+    assert_eq!(
+        format!("{result:?}"),
+        format!("{:?}", CalcParser::new().parse(input).unwrap())
+    );
+}
+// ANCHOR_END: async-stream
+
+#[test]
+fn async_stream_syntax_error() {
+    let result =
+        pollster::block_on(CalcParser::parse_stream(Arriving::new(b"2 * + 5")));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        CalcParser::new().parse("2 * + 5").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn async_stream_invalid_utf8() {
+    let result = pollster::block_on(CalcParser::parse_stream(Arriving::new(
+        b"2 + \xff",
+    )));
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("Invalid UTF-8 in the input stream at byte 4."));
+}
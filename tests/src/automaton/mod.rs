@@ -0,0 +1,36 @@
+use std::{env, fs};
+
+use rustemo_compiler::Settings;
+
+#[test]
+fn dump_automaton() {
+    let out_dir = env::temp_dir().join("rustemo_dump_automaton");
+    let _ = fs::remove_dir_all(&out_dir);
+    let generate = || {
+        rustemo_compiler::generate_parser_from_source(
+            include_str!("calc.rustemo"),
+            "calc",
+            &out_dir,
+            &Settings::new().dump_automaton(true),
+        )
+        .unwrap();
+        fs::read_to_string(out_dir.join("calc.automaton")).unwrap()
+    };
+
+    let automaton = generate();
+    assert!(automaton.starts_with(
+        "State 0:AUG\n  \
+           Kernel items:\n    \
+             0: AUG: . E    {STOP}\n  \
+           Closure items:\n    \
+             1: E: . E Plus Num    {STOP, Plus}\n    \
+             2: E: . Num    {STOP, Plus}\n  \
+           Actions:\n    \
+             Num => Shift to 1\n  \
+           GOTOs:\n    \
+             E => State 2:E\n"
+    ));
+
+    // The output is deterministic.
+    assert_eq!(generate(), automaton);
+}
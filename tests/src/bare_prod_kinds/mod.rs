@@ -0,0 +1,27 @@
+use rustemo::Parser;
+
+use self::calc::{CalcParser, ProdKind};
+use self::calc_actions::E;
+
+rustemo::rustemo_mod!(calc, "/src/bare_prod_kinds");
+rustemo::rustemo_mod!(calc_actions, "/src/bare_prod_kinds");
+
+// ANCHOR: bare-prod-kinds
+#[test]
+fn bare_prod_kinds() {
+    // Productions with a kind are named by the kind alone.
+    assert_eq!(format!("{:?}", ProdKind::Add), "E: E Plus E");
+    assert_eq!(format!("{:?}", ProdKind::Mul), "E: E Mul E");
+    assert_eq!(format!("{:?}", ProdKind::Paren), "E: LParen E RParen");
+    // Productions without a kind are numbered as usual.
+    assert_eq!(format!("{:?}", ProdKind::EP4), "E: Num");
+
+    assert!(matches!(ProdKind::Add.nonterminal(), calc::NonTermKind::E));
+}
+// ANCHOR_END: bare-prod-kinds
+
+#[test]
+fn bare_prod_kinds_parse() {
+    let result = CalcParser::new().parse("1 + 2 * (3 + 4)").unwrap();
+    assert!(matches!(result, E::Add(_)));
+}
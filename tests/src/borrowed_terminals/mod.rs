@@ -0,0 +1,33 @@
+use rustemo::Parser;
+
+use self::idents::IdentsParser;
+use self::idents_actions::{Flag, Value};
+
+rustemo::rustemo_mod!(idents, "/src/borrowed_terminals");
+rustemo::rustemo_mod!(idents_actions, "/src/borrowed_terminals");
+
+#[test]
+fn borrowed_terminals() {
+    // ANCHOR: borrowed-terminals
+    let input = "a = b; c = 42; d = on; e = [f, g];";
+    let assignments = IdentsParser::new().parse(input).unwrap();
+    let names: Vec<&str> = assignments.iter().map(|a| a.name).collect();
+    // ANCHOR_END: borrowed-terminals
+    assert_eq!(names, ["a", "c", "d", "e"]);
+
+    // Identifiers are slices of the input, not allocated copies.
+    let input_range = input.as_bytes().as_ptr_range();
+    for name in names {
+        assert!(input_range.contains(&name.as_ptr()));
+    }
+
+    assert!(matches!(assignments[0].value, Value::Ident("b")));
+    assert!(matches!(assignments[1].value, Value::Num(42)));
+    assert!(matches!(assignments[2].value, Value::Flag(Flag::On)));
+    match &assignments[3].value {
+        Value::C4(list) => {
+            assert_eq!(list.items.as_deref(), Some(&["f", "g"][..]))
+        }
+        value => panic!("Unexpected value {value:?}"),
+    }
+}
@@ -0,0 +1,51 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::calc::CalcParser;
+use self::calc_actions::{Add, E, F, T};
+
+rustemo_mod!(calc, "/src/builder/ast_constructors");
+rustemo_mod!(calc_actions, "/src/builder/ast_constructors");
+
+/// Evaluates a parsed expression.
+fn eval(e: &E) -> u32 {
+    fn term(t: &T) -> u32 {
+        match t {
+            T::Mul(mul) => term(&mul.left) * factor(&mul.right),
+            T::F(f) => factor(f),
+        }
+    }
+    fn factor(f: &F) -> u32 {
+        match f {
+            F::Num(num) => num.parse().unwrap(),
+            F::E(e) => eval(e),
+        }
+    }
+    match e {
+        E::Add(add) => eval(&add.left) + term(&add.right),
+        E::T(t) => term(t),
+    }
+}
+
+#[test]
+fn ast_constructors() {
+    // ANCHOR: ast-constructors
+    let num = |n: &str| T::new_f(F::new_num(n.into()));
+    // 2 * (3 + 4)
+    let sum = Add::new(E::new_t(num("3")), num("4"));
+    let expr = E::new_t(T::new_mul(num("2"), F::new_e(E::Add(sum))));
+    assert_eq!(eval(&expr), 14);
+    // ANCHOR_END: ast-constructors
+
+    let parsed = CalcParser::new().parse("2 * (3 + 4)").unwrap();
+    assert_eq!(format!("{expr:?}"), format!("{parsed:?}"));
+    assert_eq!(eval(&parsed), 14);
+}
+
+#[test]
+fn ast_constructors_variant() {
+    let num = |n: &str| T::new_f(F::new_num(n.into()));
+    let expr = E::new_add(E::new_add(E::new_t(num("1")), num("2")), num("3"));
+    let parsed = CalcParser::new().parse("1 + 2 + 3").unwrap();
+    assert_eq!(format!("{expr:?}"), format!("{parsed:?}"));
+    assert_eq!(eval(&expr), 6);
+}
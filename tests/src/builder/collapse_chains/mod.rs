@@ -0,0 +1,44 @@
+use rustemo::{rustemo_mod, Parser, TreeNode};
+
+use self::calc::CalcParser;
+use self::nocollapse::CalcParser as NoCollapseParser;
+
+// Only parser, no actions are generated for generic builder.
+rustemo_mod!(calc, "/src/builder/collapse_chains");
+
+mod nocollapse {
+    pub use self::calc::CalcParser;
+
+    rustemo::rustemo_mod!(calc, "/src/builder/collapse_chains/nocollapse");
+}
+
+fn depth<I: rustemo::Input + ?Sized, P, TK>(
+    node: &TreeNode<I, P, TK>,
+) -> usize {
+    match node {
+        TreeNode::TermNode { .. } => 1,
+        TreeNode::NonTermNode { children, .. } => {
+            1 + children.iter().map(depth).max().unwrap_or(0)
+        }
+    }
+}
+
+#[test]
+fn collapse_chains() {
+    let tree = CalcParser::new().parse("1 + 2 * (3)").unwrap();
+    let expanded = NoCollapseParser::new().parse("1 + 2 * (3)").unwrap();
+    // E: E + T -> T: T * F -> F: ( E ) -> F: Num -> Num
+    assert_eq!(depth(&tree), 5);
+    // The parenthesized E adds chain nodes E: T and T: F.
+    assert_eq!(depth(&expanded), 7);
+}
+
+#[test]
+fn collapse_chains_single_term() {
+    let tree = CalcParser::new().parse("42").unwrap();
+    assert!(matches!(
+        tree,
+        TreeNode::NonTermNode { ref children, .. } if children.len() == 1
+    ));
+    assert_eq!(depth(&tree), 2);
+}
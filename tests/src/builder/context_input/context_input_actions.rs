@@ -0,0 +1,38 @@
+/// This file is maintained by rustemo but can be modified manually.
+/// All manual changes will be preserved except non-doc comments.
+use rustemo::Token as RustemoToken;
+use rustemo::Context as ContextT;
+use super::context_input::{TokenKind, Context};
+pub type Input = str;
+pub type Ctx<'i> = Context<'i, Input>;
+#[allow(dead_code)]
+pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+pub type RawStart = usize;
+pub fn raw_start(ctx: &Ctx, _token: Token) -> RawStart {
+    ctx.range().start
+}
+pub type RawEnd = usize;
+pub fn raw_end(ctx: &Ctx, _token: Token) -> RawEnd {
+    ctx.range().end
+}
+pub type Word = String;
+pub fn word(_ctx: &Ctx, token: Token) -> Word {
+    token.value.into()
+}
+#[derive(Debug, Clone)]
+pub struct Raw {
+    pub words: Word1,
+    pub text: String,
+}
+pub fn raw_c1(ctx: &Ctx, raw_start: RawStart, word1: Word1, raw_end: RawEnd) -> Raw {
+    let text = ctx.input().unwrap()[raw_start..raw_end].to_string();
+    Raw { words: word1, text }
+}
+pub type Word1 = Vec<Word>;
+pub fn word1_c1(_ctx: &Ctx, mut word1: Word1, word: Word) -> Word1 {
+    word1.push(word);
+    word1
+}
+pub fn word1_word(_ctx: &Ctx, word: Word) -> Word1 {
+    vec![word]
+}
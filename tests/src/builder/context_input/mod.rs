@@ -0,0 +1,16 @@
+use rustemo::{rustemo_mod, Parser};
+#[rustfmt::skip]
+mod context_input_actions;
+
+rustemo_mod!(context_input, "/src/builder/context_input");
+
+use self::context_input::ContextInputParser;
+
+#[test]
+fn context_input() {
+    let result = ContextInputParser::new()
+        .parse("  r#\"raw   string\"#  ")
+        .unwrap();
+    assert_eq!(result.words, ["raw", "string"]);
+    assert_eq!(result.text, "r#\"raw   string\"#");
+}
@@ -18,6 +18,12 @@ impl MyCustomBuilder {
     }
 }
 
+impl Default for MyCustomBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Builder for MyCustomBuilder {
     // Result of the build process will be a number
     type Output = E;
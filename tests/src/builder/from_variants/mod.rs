@@ -0,0 +1,36 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::statements::StatementsParser;
+use self::statements_actions::{Assign, Call, Print, Statement};
+
+rustemo_mod!(statements, "/src/builder/from_variants");
+rustemo_mod!(statements_actions, "/src/builder/from_variants");
+
+#[test]
+fn from_variants() {
+    let result = StatementsParser::new()
+        .parse("a = 1 print a 2 skip f(3)")
+        .unwrap();
+
+    // ANCHOR: from-variants
+    let expected: Vec<Statement> = vec![
+        Assign {
+            name: "a".into(),
+            value: "1".into(),
+        }
+        .into(),
+        Print {
+            name: "a".into(),
+            number: "2".into(),
+        }
+        .into(),
+        Statement::Skip,
+        Call {
+            name: "f".into(),
+            number: "3".into(),
+        }
+        .into(),
+    ];
+    // ANCHOR_END: from-variants
+    assert_eq!(format!("{result:?}"), format!("{expected:?}"));
+}
@@ -1,10 +1,12 @@
 use rustemo::{rustemo_mod, Parser};
 use rustemo_compiler::output_cmp;
 
+use self::empty_chain::EmptyChainParser;
 use self::generic_tree::GenericTreeParser;
 
 // Only parser, no actions are generated for generic builder.
 rustemo_mod!(generic_tree, "/src/builder/generic_tree");
+rustemo_mod!(empty_chain, "/src/builder/generic_tree");
 
 // ANCHOR: generic_tree
 #[test]
@@ -16,3 +18,29 @@ fn generic_tree() {
     );
 }
 // ANCHOR_END: generic_tree
+
+#[test]
+fn generic_tree_equality() {
+    let tree = GenericTreeParser::new().parse("a 42 a 3 b").unwrap();
+
+    // Locations are ignored by default.
+    let other = GenericTreeParser::new().parse("a 42  a 3 b").unwrap();
+    assert_eq!(tree, other);
+    assert!(!tree.tree_eq(&other, true));
+    let other = GenericTreeParser::new().parse("a 42 a 3 b").unwrap();
+    assert!(tree.tree_eq(&other, true));
+
+    let other = GenericTreeParser::new().parse("a 42 a 4 b").unwrap();
+    assert_ne!(tree, other);
+    let other = GenericTreeParser::new().parse("a 42 b").unwrap();
+    assert_ne!(tree, other);
+}
+
+#[test]
+fn generic_tree_empty_chain() {
+    let result = EmptyChainParser::new().parse("  1 ");
+    output_cmp!(
+        "src/builder/generic_tree/empty_chain.ast",
+        format!("{:#?}", result)
+    );
+}
@@ -0,0 +1,41 @@
+use rustemo::{rustemo_mod, LosslessBuilder, LosslessNode, Parser};
+
+use self::lossless::{LosslessParser, ProdKind, TokenKind};
+
+rustemo_mod!(lossless, "/src/builder/lossless");
+
+type Node<'i> = LosslessNode<'i, str, ProdKind, TokenKind>;
+
+fn first_token<'a, 'i>(node: &'a Node<'i>) -> Option<&'a Node<'i>> {
+    match node {
+        LosslessNode::TermNode { .. } => Some(node),
+        LosslessNode::NonTermNode { children, .. } => {
+            children.iter().find_map(first_token)
+        }
+    }
+}
+
+#[test]
+fn lossless_round_trip() {
+    // ANCHOR: lossless
+    let input = "  // Comment\na = 1; /* Block\n comment */ b=2;\n// End\n";
+    let tree = LosslessParser::new(LosslessBuilder::new(input))
+        .parse(input)
+        .unwrap();
+    assert_eq!(tree.leaves().concat(), input);
+    // ANCHOR_END: lossless
+
+    assert_eq!(tree.trailing, "\n// End\n");
+    assert!(matches!(
+        first_token(&tree.root),
+        Some(LosslessNode::TermNode { token, trivia, range })
+            if token.kind == TokenKind::Name
+                && *trivia == "  // Comment\n"
+                && *range == (13..14)
+    ));
+    assert!(matches!(
+        &tree.root,
+        LosslessNode::NonTermNode { prod: ProdKind::AssignmentsP1, range, .. }
+            if *range == (13..45)
+    ));
+}
@@ -1,3 +1,13 @@
+mod ast_constructors;
+mod collapse_chains;
+mod context_input;
 mod custom_builder;
+mod from_variants;
 mod generic_tree;
+mod lossless;
+mod parse_generic;
+mod symbol_kind;
+mod token_filter;
+mod tree_actions;
+mod trivia;
 mod use_context;
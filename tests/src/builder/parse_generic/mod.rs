@@ -0,0 +1,65 @@
+use rustemo::{Parser, TreeNode};
+
+use self::calc::{CalcParser, ProdKind, TokenKind};
+use self::calc_actions::{E, F, T};
+
+rustemo::rustemo_mod!(calc, "/src/builder/parse_generic");
+rustemo::rustemo_mod!(calc_actions, "/src/builder/parse_generic");
+
+fn eval_e(e: &E) -> u32 {
+    match e {
+        E::C1(sum) => eval_e(&sum.e) + eval_t(&sum.t),
+        E::T(t) => eval_t(t),
+    }
+}
+
+fn eval_t(t: &T) -> u32 {
+    match t {
+        T::C1(mul) => eval_t(&mul.t) * eval_f(&mul.f),
+        T::F(f) => eval_f(f),
+    }
+}
+
+fn eval_f(f: &F) -> u32 {
+    match f {
+        F::Num(n) => n.parse().unwrap(),
+        F::E(e) => eval_e(e),
+    }
+}
+
+fn eval_tree(node: &TreeNode<str, ProdKind, TokenKind>) -> u32 {
+    match node {
+        TreeNode::TermNode { token, .. } => token.value.parse().unwrap(),
+        TreeNode::NonTermNode { children, .. } => match &children[..] {
+            [child] => eval_tree(child),
+            [TreeNode::TermNode { .. }, inner, _] => eval_tree(inner),
+            [left, TreeNode::TermNode { token, .. }, right] => {
+                match token.kind {
+                    TokenKind::Plus => eval_tree(left) + eval_tree(right),
+                    TokenKind::Mul => eval_tree(left) * eval_tree(right),
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        },
+    }
+}
+
+// ANCHOR: parse_generic
+#[test]
+fn parse_generic() {
+    let input = "1 + 2 * (3 + 4) * 5";
+    let ast = CalcParser::new().parse(input).unwrap();
+    let tree = CalcParser::parse_generic(input).unwrap();
+    assert_eq!(eval_e(&ast), 71);
+    assert_eq!(eval_tree(&tree), eval_e(&ast));
+}
+// ANCHOR_END: parse_generic
+
+#[test]
+fn parse_generic_error() {
+    let input = "1 + 2 *";
+    let ast_err = CalcParser::new().parse(input).unwrap_err();
+    let tree_err = CalcParser::parse_generic(input).unwrap_err();
+    assert_eq!(ast_err.to_string(), tree_err.to_string());
+}
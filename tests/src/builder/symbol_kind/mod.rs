@@ -0,0 +1,88 @@
+use rustemo::{Builder, LRBuilder, LRParser, Parser, StringLexer, Token};
+
+use self::calc::{
+    Context, DefaultBuilder, ProdKind, State, TokenKind, PARSER_DEFINITION,
+    RECOGNIZERS,
+};
+
+rustemo::rustemo_mod!(calc, "/src/builder/symbol_kind");
+rustemo::rustemo_mod!(calc_actions, "/src/builder/symbol_kind");
+
+// ANCHOR: symbol-kind
+/// Logs the kind of the symbol on the top of the result stack after each
+/// action of the default builder.
+struct LoggingBuilder<'i> {
+    builder: DefaultBuilder<'i>,
+    log: Vec<&'static str>,
+}
+
+impl LoggingBuilder<'_> {
+    fn log_top(&mut self) {
+        let top = self.builder.stack().last().unwrap();
+        let kind = match (top.token_kind(), top.nonterm_kind()) {
+            (Some(kind), None) => format!("{kind:?}"),
+            (None, Some(kind)) => format!("{kind:?}"),
+            _ => unreachable!("Symbol is either terminal or non-terminal."),
+        };
+        assert_eq!(kind, top.kind_name());
+        self.log.push(top.kind_name());
+    }
+}
+
+impl Builder for LoggingBuilder<'_> {
+    type Output = (
+        <DefaultBuilder<'static> as Builder>::Output,
+        Vec<&'static str>,
+    );
+
+    fn get_result(&mut self) -> Self::Output {
+        (self.builder.get_result(), std::mem::take(&mut self.log))
+    }
+}
+
+impl<'i> LRBuilder<'i, str, Context<'i, str>, State, ProdKind, TokenKind>
+    for LoggingBuilder<'i>
+{
+    fn shift_action(
+        &mut self,
+        context: &mut Context<'i, str>,
+        token: Token<'i, str, TokenKind>,
+    ) {
+        self.builder.shift_action(context, token);
+        self.log_top();
+    }
+
+    fn reduce_action(
+        &mut self,
+        context: &mut Context<'i, str>,
+        prod: ProdKind,
+        prod_len: usize,
+    ) {
+        self.builder.reduce_action(context, prod, prod_len);
+        self.log_top();
+    }
+}
+// ANCHOR_END: symbol-kind
+
+#[test]
+fn symbol_kind_names() {
+    let parser = LRParser::new(
+        &PARSER_DEFINITION,
+        State::default(),
+        false,
+        false,
+        StringLexer::new(true, &RECOGNIZERS),
+        LoggingBuilder {
+            builder: DefaultBuilder::new(),
+            log: vec![],
+        },
+    );
+    let (_, log) = parser.parse("1 + 2 * 3").unwrap();
+    assert_eq!(
+        log,
+        [
+            "Num", "F", "T", "E", "Plus", "Num", "F", "T", "Mul", "Num", "F",
+            "T", "E"
+        ]
+    );
+}
@@ -0,0 +1,37 @@
+use rustemo::{rustemo_mod, Parser, TreeBuilder, TreeNode};
+
+use self::calc::{CalcParser, TokenKind};
+
+rustemo_mod!(calc, "/src/builder/token_filter");
+
+fn token_kinds<I: rustemo::Input + ?Sized, P>(
+    node: &TreeNode<I, P, TokenKind>,
+    kinds: &mut Vec<TokenKind>,
+) {
+    match node {
+        TreeNode::TermNode { token, .. } => kinds.push(token.kind),
+        TreeNode::NonTermNode { children, .. } => {
+            children.iter().for_each(|child| token_kinds(child, kinds))
+        }
+    }
+}
+
+#[test]
+fn token_filter() {
+    let tree = CalcParser::new(TreeBuilder::new())
+        .parse("(1 + 2)")
+        .unwrap();
+    let mut kinds = vec![];
+    token_kinds(&tree, &mut kinds);
+    assert!(kinds.contains(&TokenKind::LParen));
+    assert!(kinds.contains(&TokenKind::RParen));
+
+    let tree = CalcParser::new(TreeBuilder::new().filter_tokens(|kind| {
+        !matches!(kind, TokenKind::LParen | TokenKind::RParen)
+    }))
+    .parse("(1 + 2)")
+    .unwrap();
+    let mut kinds = vec![];
+    token_kinds(&tree, &mut kinds);
+    assert_eq!(kinds, vec![TokenKind::Num, TokenKind::Plus, TokenKind::Num]);
+}
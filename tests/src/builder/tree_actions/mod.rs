@@ -0,0 +1,27 @@
+use rustemo::{rustemo_mod, LRParser, Parser, StringLexer, TreeBuilder};
+#[rustfmt::skip]
+mod calc_actions;
+
+rustemo_mod!(calc, "/src/builder/tree_actions");
+
+use self::calc::{DefaultBuilder, State, PARSER_DEFINITION, RECOGNIZERS};
+
+#[test]
+fn tree_actions() {
+    // ANCHOR: tree-actions
+    // Parse to the generic tree using the definition of the generated parser.
+    let parser = LRParser::new(
+        &PARSER_DEFINITION,
+        State::default(),
+        false,
+        false,
+        StringLexer::new(true, &RECOGNIZERS),
+        TreeBuilder::new(),
+    );
+    let tree = parser.parse("(1 + 2) * 3 + 4").unwrap();
+
+    // Evaluate the tree by calling the actions.
+    let result = tree.build(&mut DefaultBuilder::new());
+    // ANCHOR_END: tree-actions
+    assert_eq!(result, 13);
+}
@@ -0,0 +1,84 @@
+use rustemo::{rustemo_mod, Parser, Token, TriviaBuilder, TriviaNode};
+
+use self::trivia::{ProdKind, TokenKind, TriviaParser};
+
+rustemo_mod!(trivia, "/src/builder/trivia");
+
+type Node<'i> = TriviaNode<'i, str, ProdKind, TokenKind>;
+
+fn tokens<'a, 'i>(node: &'a Node<'i>, tokens: &mut Vec<&'a Node<'i>>) {
+    match node {
+        TriviaNode::TermNode { .. } => tokens.push(node),
+        TriviaNode::NonTermNode { children, .. } => children
+            .iter()
+            .for_each(|child| self::tokens(child, tokens)),
+    }
+}
+
+fn values<'i>(trivia: &[Token<'i, str, TokenKind>]) -> Vec<&'i str> {
+    trivia.iter().map(|token| token.value).collect()
+}
+
+#[test]
+fn trivia_attached_to_tokens() {
+    // ANCHOR: trivia-builder
+    let input = "a = 1; // First\n// Second\nb = 2;\n";
+    let tree = TriviaParser::new(TriviaBuilder::new())
+        .parse(input)
+        .unwrap();
+    // ANCHOR_END: trivia-builder
+    let mut leaves = vec![];
+    tokens(&tree.root, &mut leaves);
+    assert_eq!(leaves.len(), 8);
+
+    // The comment on the same line is trailing trivia of `;` up to and
+    // including the newline.
+    let TriviaNode::TermNode {
+        token, trailing, ..
+    } = leaves[3]
+    else {
+        panic!("Token expected.");
+    };
+    assert_eq!(token.value, ";");
+    assert_eq!(values(trailing), [" ", "// First", "\n"]);
+
+    // The comment on its own line is leading trivia of the following token.
+    let TriviaNode::TermNode {
+        token,
+        leading,
+        trailing,
+    } = leaves[4]
+    else {
+        panic!("Token expected.");
+    };
+    assert_eq!(token.value, "b");
+    assert_eq!(values(leading), ["// Second", "\n"]);
+    assert_eq!(values(trailing), [" "]);
+
+    // The newline at the end is trailing trivia of the last token.
+    let TriviaNode::TermNode { trailing, .. } = leaves[7] else {
+        panic!("Token expected.");
+    };
+    assert_eq!(values(trailing), ["\n"]);
+    assert!(tree.trailing.is_empty());
+}
+
+#[test]
+fn trivia_at_input_boundaries() {
+    let input = "  // Header\na = 1;\n\n// End";
+    let tree = TriviaParser::new(TriviaBuilder::new())
+        .parse(input)
+        .unwrap();
+    let mut leaves = vec![];
+    tokens(&tree.root, &mut leaves);
+
+    let TriviaNode::TermNode { leading, .. } = leaves[0] else {
+        panic!("Token expected.");
+    };
+    assert_eq!(values(leading), ["  ", "// Header", "\n"]);
+    let TriviaNode::TermNode { trailing, .. } = leaves[3] else {
+        panic!("Token expected.");
+    };
+    assert_eq!(values(trailing), ["\n"]);
+    assert_eq!(values(&tree.trailing), ["\n", "// End"]);
+}
@@ -0,0 +1,29 @@
+use rustemo::Parser;
+
+use self::records::RecordsParser;
+
+rustemo::rustemo_mod!(records, "/src/columns");
+rustemo::rustemo_mod!(records_actions, "/src/columns");
+
+// ANCHOR: columns
+#[test]
+fn columns() {
+    let input: String = (0..10_000).map(|i| format!("r{i} = {i};\n")).collect();
+    let records = RecordsParser::new().parse(&input).unwrap();
+    assert_eq!(records.len(), 10_000);
+    // Each field of the records is a separate column.
+    let sum: u64 = records
+        .value
+        .iter()
+        .map(|v| v.parse::<u64>().unwrap())
+        .sum();
+    assert_eq!(sum, (0..10_000).sum());
+    assert_eq!(records.name[42], "r42");
+}
+// ANCHOR_END: columns
+
+#[test]
+fn columns_empty() {
+    let records = RecordsParser::new().parse("").unwrap();
+    assert!(records.is_empty());
+}
@@ -0,0 +1,48 @@
+use self::calculator::{CalculatorParser, TokenKind};
+
+rustemo::rustemo_mod!(calculator, "/src/completions");
+rustemo::rustemo_mod!(calculator_actions, "/src/completions");
+
+#[test]
+fn completions_incomplete_prefix() {
+    // ANCHOR: completions
+    let completions = CalculatorParser::new().completions("1 +");
+    assert!(completions.contains(&TokenKind::Operand));
+    assert!(!completions.contains(&TokenKind::Operator));
+    // ANCHOR_END: completions
+    assert_eq!(completions, [TokenKind::Operand]);
+
+    let parser = CalculatorParser::new();
+    assert_eq!(
+        parser.completions("(1 + 2 "),
+        [TokenKind::RParen, TokenKind::Operator]
+    );
+    assert_eq!(
+        parser.completions(""),
+        [TokenKind::LParen, TokenKind::Operand]
+    );
+}
+
+#[test]
+fn completions_complete_prefix() {
+    let parser = CalculatorParser::new();
+    // The prefix is a complete input. The closing paren is reduced ahead but
+    // can't be shifted without the open one.
+    assert_eq!(
+        parser.completions("1 + 2"),
+        [TokenKind::STOP, TokenKind::Operator]
+    );
+    assert_eq!(
+        parser.completions("1"),
+        [TokenKind::STOP, TokenKind::Operator]
+    );
+    assert_eq!(
+        parser.completions("(1 + 2"),
+        [TokenKind::RParen, TokenKind::Operator]
+    );
+}
+
+#[test]
+fn completions_invalid_prefix() {
+    assert!(CalculatorParser::new().completions("1 + + 2").is_empty());
+}
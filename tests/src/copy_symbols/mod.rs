@@ -0,0 +1,20 @@
+use rustemo::Parser;
+
+use self::punct::{PunctParser, Terminal};
+
+rustemo::rustemo_mod!(punct, "/src/copy_symbols");
+rustemo::rustemo_mod!(punct_actions, "/src/copy_symbols");
+
+fn assert_copy<T: Copy>() {}
+
+#[test]
+fn copy_symbols_terminal() {
+    // Terminals without content are plain input references.
+    assert_copy::<Terminal<'static>>();
+
+    let term = Terminal::LParen("(");
+    let copy = term;
+    assert_eq!(term.text(), copy.text());
+
+    assert!(PunctParser::new().parse("([()]())").is_ok());
+}
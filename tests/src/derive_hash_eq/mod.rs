@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+
+use rustemo::Parser;
+
+use self::exprs::ExprsParser;
+
+rustemo::rustemo_mod!(exprs, "/src/derive_hash_eq");
+rustemo::rustemo_mod!(exprs_actions, "/src/derive_hash_eq");
+
+// ANCHOR: derive-hash-eq
+#[test]
+fn derive_hash_eq() {
+    let exprs = ExprsParser::new()
+        .parse("1 + 2 * 3; (4); 1 + 2 * 3; 1 + (2 * 3)")
+        .unwrap();
+    let unique: HashSet<_> = exprs.iter().collect();
+    assert_eq!(exprs.len(), 4);
+    // Equal sub-expressions are deduplicated.
+    assert_eq!(unique.len(), 3);
+    assert_eq!(exprs[0], exprs[2]);
+    assert_ne!(exprs[0], exprs[3]);
+}
+// ANCHOR_END: derive-hash-eq
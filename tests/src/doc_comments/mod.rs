@@ -0,0 +1,48 @@
+use std::{fs, path::PathBuf};
+
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::Settings;
+
+use self::assignments::AssignmentsParser;
+
+rustemo_mod!(assignments, "/src/doc_comments");
+rustemo_mod!(assignments_actions, "/src/doc_comments");
+
+#[test]
+fn doc_comments_parse() {
+    let result = AssignmentsParser::new().parse("a = 1; b = a;").unwrap();
+    assert_eq!(result.len(), 2);
+}
+
+/// Doc comments of the rules are emitted on the generated types.
+#[test]
+fn doc_comments_generated() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("doc_comments");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let grammar = dir.join("assignments.rustemo");
+    fs::copy(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/doc_comments/assignments.rustemo"),
+        &grammar,
+    )
+    .unwrap();
+
+    Settings::new()
+        .in_source_tree()
+        .process_grammar(&grammar)
+        .unwrap();
+    let actions =
+        fs::read_to_string(dir.join("assignments_actions.rs")).unwrap();
+
+    assert!(actions.contains(
+        "/// An assignment of a value to a variable.\n///\n\
+         /// Each assignment ends with a semicolon.\n\
+         #[derive(Debug, Clone)]\npub struct Assignment {"
+    ));
+    assert!(actions.contains(
+        "/// A list of assignments.\npub type Assignments = Assignment1;"
+    ));
+    assert!(actions.contains("/// A variable name.\npub type Name = String;"));
+    assert!(!actions.contains("Four slashes"));
+}
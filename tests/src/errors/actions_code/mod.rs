@@ -0,0 +1,13 @@
+use rustemo_compiler::{local_file, output_cmp};
+
+#[test]
+fn actions_code_invalid() {
+    let result = rustemo_compiler::process_grammar(local_file!(
+        file!(),
+        "invalid.rustemo"
+    ));
+    output_cmp!(
+        "src/errors/actions_code/invalid.err",
+        result.unwrap_err().to_locfile_str()
+    );
+}
@@ -1,5 +1,9 @@
+mod actions_code;
 mod infinite_recursion;
+mod prod_kind_collision;
 mod recognizer_not_defined;
+mod reserved_name;
 mod syntax_errors;
 mod terminal_not_defined;
+mod trivia_glr;
 mod unexisting_symbol;
@@ -0,0 +1,25 @@
+use rustemo_compiler::{local_file, output_cmp, Settings};
+
+#[test]
+fn prod_kind_collision_bare() {
+    let result = Settings::new()
+        .bare_prod_kinds(true)
+        .process_grammar(local_file!(file!(), "bare.rustemo"));
+    output_cmp!(
+        "src/errors/prod_kind_collision/bare.err",
+        result.unwrap_err().to_string()
+    );
+}
+
+/// The given kind collides with the kind generated for the other production.
+#[test]
+fn prod_kind_collision_prefixed() {
+    let result = rustemo_compiler::process_grammar(local_file!(
+        file!(),
+        "prefixed.rustemo"
+    ));
+    output_cmp!(
+        "src/errors/prod_kind_collision/prefixed.err",
+        result.unwrap_err().to_string()
+    );
+}
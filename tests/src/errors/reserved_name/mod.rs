@@ -0,0 +1,22 @@
+use rustemo_compiler::{local_file, output_cmp, BuilderType::Generic};
+
+#[test]
+fn reserved_name_for_default_builder() {
+    let result = rustemo_compiler::process_grammar(local_file!(
+        file!(),
+        "reserved_name.rustemo"
+    ));
+    output_cmp!(
+        "src/errors/reserved_name/reserved_name.err",
+        result.unwrap_err().to_string()
+    );
+}
+
+/// Symbol names are not used as types by the generic builder.
+#[test]
+fn reserved_name_for_generic_builder() {
+    let result = rustemo_compiler::Settings::new()
+        .builder_type(Generic)
+        .process_grammar(local_file!(file!(), "reserved_name.rustemo"));
+    result.unwrap();
+}
@@ -1,4 +1,4 @@
-use rustemo::{rustemo_mod, Parser};
+use rustemo::{rustemo_mod, ParseError, Parser};
 use rustemo_compiler::{local_file, output_cmp};
 
 rustemo_mod!(calc, "/src/errors/syntax_errors");
@@ -27,3 +27,19 @@ fn syntax_error_incomplete() {
         format!("{:#?}", result.unwrap_err().to_string())
     );
 }
+
+#[test]
+fn syntax_error_render() {
+    let input = "1 + * 2";
+    let error: ParseError = CalcParser::new()
+        .parse(input)
+        .unwrap_err()
+        .try_into()
+        .unwrap();
+    let rendered = error.render(input);
+    let lines: Vec<_> = rendered.lines().rev().take(2).collect();
+    assert_eq!(lines[1], "1 | 1 + * 2");
+    assert_eq!(lines[0], "  |     ^");
+    assert_eq!(lines[0].find('^'), lines[1].find('*'));
+    assert!(rendered.starts_with(&error.to_string()));
+}
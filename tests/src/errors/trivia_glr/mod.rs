@@ -0,0 +1,12 @@
+use rustemo_compiler::{local_file, ParserAlgo, Settings};
+
+#[test]
+fn trivia_glr() {
+    let result = Settings::new()
+        .parser_algo(ParserAlgo::GLR)
+        .process_grammar(local_file!(file!(), "trivia.rustemo"));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error: Trivia terminals can be used only with LR algorithm."
+    );
+}
@@ -0,0 +1,27 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::object::ObjectParser;
+
+rustemo_mod!(object, "/src/fallible_actions");
+#[rustfmt::skip]
+mod object_actions;
+
+#[test]
+fn fallible_actions_ok() {
+    let result = ObjectParser::new().parse("{a = 1, b = 2}").unwrap();
+    let members: Vec<_> = result
+        .iter()
+        .flatten()
+        .map(|m| (m.name.as_str(), m.num.as_str()))
+        .collect();
+    assert_eq!(members, [("a", "1"), ("b", "2")]);
+}
+
+#[test]
+fn fallible_actions_error() {
+    let result = ObjectParser::new().parse("{a = 1, b = 2, a = 3}");
+    assert_eq!(
+        result.unwrap_err().to_locfile_str(),
+        "Error at [1,0-1,21]:\n\tDuplicate member 'a'."
+    );
+}
@@ -0,0 +1,58 @@
+/// This file is maintained by rustemo but can be modified manually.
+/// All manual changes will be preserved except non-doc comments.
+use rustemo::Token as RustemoToken;
+use rustemo::{Error, Result};
+use super::object::{TokenKind, Context};
+pub type Input = str;
+pub type Ctx<'i> = Context<'i, Input>;
+#[allow(dead_code)]
+pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+pub type Name = String;
+pub fn name(_ctx: &Ctx, token: Token) -> Name {
+    token.value.into()
+}
+pub type Num = String;
+pub fn num(_ctx: &Ctx, token: Token) -> Num {
+    token.value.into()
+}
+pub type Object = Members;
+pub fn object_members(_ctx: &Ctx, members: Members) -> Result<Object> {
+    let names = members.iter().flatten().map(|m| &m.name);
+    for (idx, name) in names.clone().enumerate() {
+        if names.clone().take(idx).any(|n| n == name) {
+            return Err(Error::Error {
+                message: format!("Duplicate member '{name}'."),
+                file: None,
+                location: None,
+            });
+        }
+    }
+    Ok(members)
+}
+pub type Members = Member0;
+pub fn members_member0(_ctx: &Ctx, member0: Member0) -> Result<Members> {
+    Ok(member0)
+}
+pub type Member1 = Vec<Member>;
+pub fn member1_c1(_ctx: &Ctx, mut member1: Member1, member: Member) -> Result<Member1> {
+    member1.push(member);
+    Ok(member1)
+}
+pub fn member1_member(_ctx: &Ctx, member: Member) -> Result<Member1> {
+    Ok(vec![member])
+}
+pub type Member0 = Option<Member1>;
+pub fn member0_member1(_ctx: &Ctx, member1: Member1) -> Result<Member0> {
+    Ok(Some(member1))
+}
+pub fn member0_empty(_ctx: &Ctx) -> Result<Member0> {
+    Ok(None)
+}
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub name: Name,
+    pub num: Num,
+}
+pub fn member_c1(_ctx: &Ctx, name: Name, num: Num) -> Result<Member> {
+    Ok(Member { name, num })
+}
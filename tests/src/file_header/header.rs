@@ -0,0 +1,574 @@
+#![allow(clippy::all)]
+//! Parser with a custom header.
+/// Generated by rustemo. Do not edit manually!
+use std::fmt::Debug;
+use std::hash::Hash;
+use rustemo::{
+    Result, Input as InputT, Lexer, Token, Parser, ParserDefinition, State as StateT,
+    Builder,
+};
+use regex::Regex;
+use once_cell::sync::Lazy;
+use rustemo::{StringLexer, TokenRecognizer as TokenRecognizerT};
+use rustemo::LRBuilder;
+use super::header_actions;
+use rustemo::{LRParser, LRContext};
+use rustemo::Action::{self, Shift, Reduce, Accept};
+#[allow(unused_imports)]
+use rustemo::debug::{log, logn};
+#[allow(unused_imports)]
+#[cfg(debug_assertions)]
+use colored::*;
+pub type Input = str;
+const STATE_COUNT: usize = 5usize;
+const MAX_RECOGNIZERS: usize = 2usize;
+#[allow(dead_code)]
+const TERMINAL_COUNT: usize = 3usize;
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenKind {
+    #[default]
+    STOP,
+    Plus,
+    Num,
+}
+use TokenKind as TK;
+impl From<TokenKind> for usize {
+    fn from(t: TokenKind) -> Self {
+        t as usize
+    }
+}
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ProdKind {
+    SumP1,
+    SumP2,
+}
+use ProdKind as PK;
+impl std::fmt::Debug for ProdKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ProdKind::SumP1 => "Sum: Sum Plus Num",
+            ProdKind::SumP2 => "Sum: Num",
+        };
+        write!(f, "{}", name)
+    }
+}
+#[allow(clippy::upper_case_acronyms)]
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub enum NonTermKind {
+    EMPTY,
+    AUG,
+    Sum,
+}
+impl From<ProdKind> for NonTermKind {
+    fn from(prod: ProdKind) -> Self {
+        match prod {
+            ProdKind::SumP1 => NonTermKind::Sum,
+            ProdKind::SumP2 => NonTermKind::Sum,
+        }
+    }
+}
+#[allow(dead_code)]
+impl ProdKind {
+    /// The non-terminal this production reduces to.
+    pub fn nonterminal(&self) -> NonTermKind {
+        (*self).into()
+    }
+    /// The number of symbols on the right-hand side of this
+    /// production.
+    pub fn rhs_len(&self) -> usize {
+        match self {
+            ProdKind::SumP1 => 3usize,
+            ProdKind::SumP2 => 1usize,
+        }
+    }
+    /// The line and the column in the grammar file where this
+    /// production is defined.
+    pub fn source_location(&self) -> (u32, u32) {
+        match self {
+            ProdKind::SumP1 => (1u32, 5u32),
+            ProdKind::SumP2 => (1u32, 19u32),
+        }
+    }
+}
+#[allow(clippy::enum_variant_names)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum State {
+    #[default]
+    AUGS0,
+    NumS1,
+    SumS2,
+    PlusS3,
+    NumS4,
+}
+impl StateT for State {
+    fn default_layout() -> Option<Self> {
+        None
+    }
+}
+impl From<State> for usize {
+    fn from(s: State) -> Self {
+        s as usize
+    }
+}
+impl std::fmt::Debug for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            State::AUGS0 => "0:AUG",
+            State::NumS1 => "1:Num",
+            State::SumS2 => "2:Sum",
+            State::PlusS3 => "3:Plus",
+            State::NumS4 => "4:Num",
+        };
+        write!(f, "{name}")
+    }
+}
+#[derive(Debug)]
+pub enum Symbol<'i> {
+    Terminal(Terminal<'i>),
+    NonTerminal(NonTerminal),
+}
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub enum Terminal<'i> {
+    Plus(&'i Input),
+    Num(&'i Input, header_actions::Num),
+}
+#[allow(dead_code)]
+impl<'i> Terminal<'i> {
+    /// The input matched by this terminal.
+    pub fn text(&self) -> &'i Input {
+        match *self {
+            Terminal::Plus(text) => text,
+            Terminal::Num(text, _) => text,
+        }
+    }
+    /// The kind of this terminal.
+    pub fn kind(&self) -> TokenKind {
+        match *self {
+            Terminal::Plus(..) => TokenKind::Plus,
+            Terminal::Num(..) => TokenKind::Num,
+        }
+    }
+}
+#[derive(Debug)]
+pub enum NonTerminal {
+    Sum(header_actions::Sum),
+}
+#[allow(dead_code)]
+impl NonTerminal {
+    /// The kind of this non-terminal.
+    pub fn kind(&self) -> NonTermKind {
+        match *self {
+            NonTerminal::Sum(_) => NonTermKind::Sum,
+        }
+    }
+}
+#[allow(dead_code)]
+impl<'i> Symbol<'i> {
+    /// The name of the grammar symbol of this symbol.
+    pub fn kind_name(&self) -> &'static str {
+        match *self {
+            Symbol::Terminal(Terminal::Plus(..)) => "Plus",
+            Symbol::Terminal(Terminal::Num(..)) => "Num",
+            Symbol::NonTerminal(NonTerminal::Sum(_)) => "Sum",
+        }
+    }
+    /// The kind of this symbol if it is a terminal.
+    pub fn token_kind(&self) -> Option<TokenKind> {
+        match self {
+            Symbol::Terminal(t) => Some(t.kind()),
+            Symbol::NonTerminal(_) => None,
+        }
+    }
+    /// The kind of this symbol if it is a non-terminal.
+    pub fn nonterm_kind(&self) -> Option<NonTermKind> {
+        match self {
+            Symbol::Terminal(_) => None,
+            Symbol::NonTerminal(nt) => Some(nt.kind()),
+        }
+    }
+}
+type ActionFn = fn(token: TokenKind) -> Vec<Action<State, ProdKind>>;
+pub struct HeaderParserDefinition {
+    actions: [ActionFn; STATE_COUNT],
+    gotos: [fn(nonterm: NonTermKind) -> State; STATE_COUNT],
+    token_kinds: [[Option<(TokenKind, bool)>; MAX_RECOGNIZERS]; STATE_COUNT],
+}
+fn action_aug_s0(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Num => Vec::from(&[Shift(State::NumS1)]),
+        _ => vec![],
+    }
+}
+fn action_num_s1(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::SumP2, 1usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::SumP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_sum_s2(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Accept]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS3)]),
+        _ => vec![],
+    }
+}
+fn action_plus_s3(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Num => Vec::from(&[Shift(State::NumS4)]),
+        _ => vec![],
+    }
+}
+fn action_num_s4(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::SumP1, 3usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::SumP1, 3usize)]),
+        _ => vec![],
+    }
+}
+#[allow(unreachable_patterns)]
+fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Sum => State::SumS2,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AUGS0
+            )
+        }
+    }
+}
+fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
+    panic!("Invalid GOTO entry!");
+}
+pub(crate) static PARSER_DEFINITION: HeaderParserDefinition = HeaderParserDefinition {
+    actions: [
+        action_aug_s0,
+        action_num_s1,
+        action_sum_s2,
+        action_plus_s3,
+        action_num_s4,
+    ],
+    gotos: [goto_aug_s0, goto_invalid, goto_invalid, goto_invalid, goto_invalid],
+    token_kinds: [
+        [Some((TK::Num, false)), None],
+        [Some((TK::STOP, true)), Some((TK::Plus, true))],
+        [Some((TK::STOP, true)), Some((TK::Plus, true))],
+        [Some((TK::Num, false)), None],
+        [Some((TK::STOP, true)), Some((TK::Plus, true))],
+    ],
+};
+impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind>
+for HeaderParserDefinition {
+    fn actions(&self, state: State, token: TokenKind) -> Vec<Action<State, ProdKind>> {
+        PARSER_DEFINITION.actions[state as usize](token)
+    }
+    fn goto(&self, state: State, nonterm: NonTermKind) -> State {
+        PARSER_DEFINITION.gotos[state as usize](nonterm)
+    }
+    fn expected_token_kinds(&self, state: State) -> Vec<(TokenKind, bool)> {
+        PARSER_DEFINITION.token_kinds[state as usize].iter().map_while(|t| *t).collect()
+    }
+    fn longest_match() -> bool {
+        true
+    }
+    fn grammar_order() -> bool {
+        true
+    }
+}
+pub(crate) type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
+pub struct HeaderParser<
+    'i,
+    I: InputT + ?Sized,
+    L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
+    B,
+>(
+    LRParser<
+        'i,
+        Context<'i, I>,
+        State,
+        ProdKind,
+        TokenKind,
+        NonTermKind,
+        HeaderParserDefinition,
+        L,
+        B,
+        I,
+    >,
+);
+#[allow(dead_code)]
+impl<
+    'i,
+> HeaderParser<
+    'i,
+    Input,
+    StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
+    DefaultBuilder<'i>,
+> {
+    pub fn new() -> Self {
+        Self(
+            LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                false,
+                StringLexer::new(true, &RECOGNIZERS),
+                DefaultBuilder::new(),
+            ),
+        )
+    }
+    pub fn parse_all(
+        &self,
+        input: &'i Input,
+    ) -> Result<Vec<<DefaultBuilder<'i> as Builder>::Output>> {
+        self.0.parse(input).map(|output| vec![output])
+    }
+    pub fn parse_str(
+        &self,
+        input: &'i str,
+    ) -> Result<
+        <Self as Parser<'i, Input, Context<'i, Input>, State, TokenKind>>::Output,
+    > {
+        self.parse(input)
+    }
+    pub fn tokenize(input: &'i Input) -> Result<Vec<Token<'i, Input, TokenKind>>> {
+        LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                false,
+                StringLexer::new(true, &RECOGNIZERS),
+                rustemo::TokensBuilder::new(),
+            )
+            .parse(input)
+    }
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self(self.0.max_depth(max_depth))
+    }
+    pub fn parse_opts(
+        &self,
+        input: &'i Input,
+        opts: rustemo::ParseOpts,
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        self.0.parse_opts(input, opts)
+    }
+    pub fn parse_with_driver(
+        &self,
+        input: &'i Input,
+        driver: &mut impl rustemo::ParseDriver<
+            'i,
+            Context<'i, Input>,
+            ProdKind,
+            Input,
+            TokenKind,
+        >,
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        self.0.parse_with_driver(input, driver)
+    }
+    pub fn completions(&self, prefix: &'i Input) -> Vec<TokenKind> {
+        self.0.completions(prefix).unwrap_or_default()
+    }
+    pub fn max_lex_bytes(self, max_lex_bytes: usize) -> Self {
+        Self(self.0.max_lex_bytes(max_lex_bytes))
+    }
+    pub fn lex_timeout(self, lex_timeout: std::time::Duration) -> Self {
+        Self(self.0.lex_timeout(lex_timeout))
+    }
+    pub fn parse_tokens(
+        &self,
+        tokens: &[Token<'_, str, TokenKind>],
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        let input = rustemo::TokensLexer::input(tokens);
+        LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                false,
+                rustemo::TokensLexer::new(tokens),
+                DefaultBuilder::new(),
+            )
+            .parse(&input)
+    }
+}
+#[allow(dead_code)]
+impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind>
+for HeaderParser<'i, I, L, B>
+where
+    I: InputT + ?Sized + Debug,
+    L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
+    B: LRBuilder<'i, I, Context<'i, I>, State, ProdKind, TokenKind>,
+{
+    type Output = B::Output;
+    fn parse(&self, input: &'i I) -> Result<Self::Output> {
+        self.0.parse(input)
+    }
+    fn parse_with_context(
+        &self,
+        context: &mut Context<'i, I>,
+        input: &'i I,
+    ) -> Result<Self::Output> {
+        self.0.parse_with_context(context, input)
+    }
+    fn parse_file<'a, F: AsRef<std::path::Path>>(
+        &'a mut self,
+        file: F,
+    ) -> Result<Self::Output>
+    where
+        'a: 'i,
+    {
+        self.0.parse_file(file)
+    }
+}
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Recognizer {
+    Stop,
+    StrMatch(&'static str),
+    RegexMatch(Lazy<Regex>),
+}
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct TokenRecognizer(TokenKind, Recognizer);
+impl<'i> TokenRecognizerT<'i, Context<'i, Input>> for TokenRecognizer {
+    #[allow(unused_variables, clippy::only_used_in_recursion)]
+    fn recognize_value(
+        &self,
+        context: &Context<'i, Input>,
+        input: &'i str,
+        position: usize,
+    ) -> Option<(usize, &'i str)> {
+        let whole_input = input;
+        let input = &input[position..];
+        let recognized: Option<&'i str> = match &self {
+            #[allow(unused_variables)]
+            TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
+                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                if input.starts_with(s) {
+                    log!("{}", "recognized".bold().green());
+                    Some(s)
+                } else {
+                    log!("{}", "not recognized".red());
+                    None
+                }
+            }
+            #[allow(unused_variables)]
+            TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
+                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                let match_str = r.find(input);
+                match match_str {
+                    Some(x) => {
+                        let x_str = x.as_str();
+                        log!("{} '{}'", "recognized".bold().green(), x_str);
+                        Some(x_str)
+                    }
+                    _ => {
+                        log!("{}", "not recognized".red());
+                        None
+                    }
+                }
+            }
+            TokenRecognizer(_, Recognizer::Stop) => {
+                logn!("{} STOP -- ", "    Recognizing".green());
+                if input.is_empty() {
+                    log!("{}", "recognized".bold().green());
+                    Some("")
+                } else {
+                    log!("{}", "not recognized".red());
+                    None
+                }
+            }
+        };
+        recognized.map(|x_str| (x_str.len(), x_str))
+    }
+}
+pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
+    TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
+    TokenRecognizer(TokenKind::Plus, Recognizer::StrMatch("+")),
+    TokenRecognizer(
+        TokenKind::Num,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\d+")).unwrap() }),
+        ),
+    ),
+];
+pub struct DefaultBuilder<'i> {
+    res_stack: Vec<Symbol<'i>>,
+}
+impl<'i> DefaultBuilder<'i> {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self { res_stack: vec![] }
+    }
+    /// The symbols on the result stack. Used to inspect the
+    /// parsing progress, e.g. from a builder wrapping this one.
+    #[allow(dead_code)]
+    pub fn stack(&self) -> &[Symbol<'i>] {
+        &self.res_stack
+    }
+}
+impl Default for DefaultBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Builder for DefaultBuilder<'_> {
+    type Output = header_actions::Sum;
+    fn get_result(&mut self) -> Self::Output {
+        match self.res_stack.pop().unwrap() {
+            Symbol::NonTerminal(NonTerminal::Sum(r)) => r,
+            _ => panic!("Invalid result on the parse stack!"),
+        }
+    }
+}
+impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
+for DefaultBuilder<'i> {
+    #![allow(unused_variables)]
+    fn shift_action(
+        &mut self,
+        context: &mut Context<'i, Input>,
+        token: Token<'i, Input, TokenKind>,
+    ) {
+        let val = match token.kind {
+            TokenKind::STOP => panic!("Cannot shift STOP token!"),
+            TokenKind::Plus => Terminal::Plus(token.value),
+            TokenKind::Num => {
+                Terminal::Num(token.value, header_actions::num(&*context, token))
+            }
+        };
+        self.res_stack.push(Symbol::Terminal(val));
+    }
+    fn reduce_action(
+        &mut self,
+        context: &mut Context<'i, Input>,
+        prod: ProdKind,
+        _prod_len: usize,
+    ) {
+        let prod = match prod {
+            ProdKind::SumP1 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 3usize..);
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Sum(p0)),
+                        _,
+                        Symbol::Terminal(Terminal::Num(_, p1)),
+                    ) => NonTerminal::Sum(header_actions::sum_c1(&*context, p0, p1)),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::SumP2 => {
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::Num(_, p0)) => {
+                        NonTerminal::Sum(header_actions::sum_num(&*context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+        };
+        self.res_stack.push(Symbol::NonTerminal(prod));
+    }
+}
@@ -0,0 +1,50 @@
+#![allow(clippy::all)]
+//! Parser with a custom header.
+/// This file is maintained by rustemo but can be modified manually.
+/// All manual changes will be preserved except non-doc comments.
+use rustemo::Token as RustemoToken;
+use super::header::{TokenKind, Context};
+pub type Input = str;
+pub type Ctx<'i> = Context<'i, Input>;
+#[allow(dead_code)]
+pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+pub type Num = String;
+pub fn num(_ctx: &Ctx, token: Token) -> Num {
+    token.value.into()
+}
+#[derive(Debug, Clone)]
+pub struct SumC1 {
+    pub sum: Box<Sum>,
+    pub num: Num,
+}
+#[derive(Debug, Clone)]
+pub enum Sum {
+    C1(SumC1),
+    Num(Num),
+}
+impl From<SumC1> for Sum {
+    fn from(value: SumC1) -> Self {
+        Sum::C1(value)
+    }
+}
+#[allow(dead_code)]
+impl SumC1 {
+    pub fn new(sum: Sum, num: Num) -> Self {
+        Self { sum: Box::new(sum), num }
+    }
+}
+#[allow(dead_code)]
+impl Sum {
+    pub fn new_c1(sum: Sum, num: Num) -> Self {
+        Self::C1(SumC1::new(sum, num))
+    }
+    pub fn new_num(num: Num) -> Self {
+        Self::Num(num)
+    }
+}
+pub fn sum_c1(_ctx: &Ctx, sum: Sum, num: Num) -> Sum {
+    Sum::C1(SumC1 { sum: Box::new(sum), num })
+}
+pub fn sum_num(_ctx: &Ctx, num: Num) -> Sum {
+    Sum::Num(num)
+}
@@ -0,0 +1,20 @@
+use rustemo::Parser;
+
+use self::header::HeaderParser;
+
+#[rustfmt::skip]
+mod header;
+#[rustfmt::skip]
+#[allow(dead_code)]
+mod header_actions;
+
+#[test]
+fn file_header() {
+    assert!(HeaderParser::new().parse("1 + 2").is_ok());
+
+    for file in [include_str!("header.rs"), include_str!("header_actions.rs")] {
+        assert!(file.starts_with(
+            "#![allow(clippy::all)]\n//! Parser with a custom header.\n"
+        ));
+    }
+}
@@ -27,3 +27,13 @@ fn parse_from_file_err() {
         result.unwrap_err().to_locfile_str()
     )
 }
+
+#[test]
+fn parse_str() {
+    let input = "2 + 3 * 4 - 1";
+    let parser = CalculatorParser::new();
+    assert_eq!(
+        format!("{:?}", parser.parse(input)),
+        format!("{:?}", parser.parse_str(input))
+    );
+}
@@ -0,0 +1,16 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+rustemo_mod!(sum, "/src/from_source");
+rustemo_mod!(sum_actions, "/src/from_source");
+
+use sum::SumParser;
+
+#[test]
+fn parse_from_source() {
+    let result = SumParser::new().parse("1 + 2 + 3");
+    output_cmp!(
+        "src/from_source/parse_from_source.ast",
+        format!("{result:#?}")
+    )
+}
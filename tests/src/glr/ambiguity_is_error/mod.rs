@@ -0,0 +1,36 @@
+use rustemo::{rustemo_mod, Parser};
+
+rustemo_mod!(statements, "/src/glr/ambiguity_is_error");
+rustemo_mod!(statements_actions, "/src/glr/ambiguity_is_error");
+use self::statements::{ProdKind, StatementsParser};
+
+#[test]
+fn glr_ambiguity_is_error_unambiguous() {
+    let forest = StatementsParser::new()
+        .ambiguity_is_error(true)
+        .parse("x if c y else z")
+        .unwrap();
+    assert_eq!(forest.solutions(), 1);
+}
+
+#[test]
+fn glr_ambiguity_is_error() {
+    let input = "x if c if d y else z w";
+    let forest = StatementsParser::new().parse(input).unwrap();
+    assert_eq!(forest.solutions(), 2);
+
+    // ANCHOR: ambiguity-is-error
+    let result = StatementsParser::new()
+        .ambiguity_is_error(true)
+        .parse(input);
+    // ANCHOR_END: ambiguity-is-error
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error at <str>:[1,2-1,20]:\n\tAmbiguous input. Competing productions: \
+         Stmt: If Cond Stmt Else Stmt, Stmt: If Cond Stmt."
+    );
+
+    let ambiguity = forest.first_ambiguity().unwrap();
+    assert_eq!(&input[ambiguity.range], "if c if d y else z");
+    assert_eq!(ambiguity.productions, [ProdKind::StmtP2, ProdKind::StmtP1]);
+}
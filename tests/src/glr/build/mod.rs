@@ -1,4 +1,4 @@
-use rustemo::{rustemo_mod, GssHead, Parser, TreeBuilder};
+use rustemo::{rustemo_mod, GssHead, Parser, TreeBuilder, TreeNode};
 use rustemo_compiler::output_cmp;
 
 rustemo_mod!(calc, "/src/glr/build");
@@ -70,3 +70,47 @@ fn glr_tree_build_generic() {
         )
     );
 }
+
+#[test]
+fn glr_tree_build_builder_default() {
+    let forest = CalcParser::new().parse("1 + 4 * 9").unwrap();
+    let tree = forest.get_first_tree().unwrap();
+
+    assert_eq!(
+        format!("{:?}", tree.build(&mut calc::DefaultBuilder::new())),
+        format!("{:?}", tree.build(&mut calc::DefaultBuilder::default())),
+    );
+
+    type Tree<'i> = TreeBuilder<'i, str, calc::ProdKind, calc::TokenKind>;
+    type Head<'i> = GssHead<'i, str, calc::State, calc::TokenKind>;
+    assert_eq!(
+        format!(
+            "{:?}",
+            tree.build::<Tree, Head, calc::State>(&mut TreeBuilder::new())
+        ),
+        format!(
+            "{:?}",
+            tree.build::<Tree, Head, calc::State>(&mut TreeBuilder::default())
+        ),
+    );
+}
+
+#[test]
+fn glr_tree_build_with_input() {
+    let input = "1 + 4 * 9";
+    let forest = CalcParser::new().parse(input).unwrap();
+    let tree = forest.get_first_tree().unwrap();
+
+    type Tree<'i> = TreeBuilder<'i, str, calc::ProdKind, calc::TokenKind>;
+    type Head<'i> = GssHead<'i, str, calc::State, calc::TokenKind>;
+
+    // Nodes get the locations of the input they are built from.
+    let root = tree.build_with_input::<Tree, Head, calc::State>(
+        input,
+        &mut TreeBuilder::new(),
+    );
+    let TreeNode::NonTermNode { location, .. } = root else {
+        panic!("Root must be a non-terminal node.")
+    };
+    assert_eq!(format!("{location:?}"), "[1,0-1,9]");
+}
@@ -6,9 +6,12 @@ rustemo_mod!(calc, "/src/glr/evaluate");
 mod calc_actions;
 rustemo_mod!(calc_eval, "/src/glr/evaluate");
 mod calc_eval_actions;
+rustemo_mod!(split, "/src/glr/evaluate");
+mod split_actions;
 
 use self::calc::CalcParser;
 use self::calc_eval::CalcEvalParser;
+use self::split::SplitParser;
 
 #[test]
 fn glr_tree_calc_eval() {
@@ -29,3 +32,39 @@ fn glr_tree_calc_eval() {
 
     output_cmp!("src/glr/evaluate/forest_eval.ast", format!("{:#?}", res));
 }
+
+// ANCHOR: into-outputs
+#[test]
+fn glr_forest_into_outputs() {
+    let forest = CalcEvalParser::new().parse("1 + 2 * 3").unwrap();
+    assert_eq!(forest.solutions(), 2);
+
+    let mut results = forest
+        .into_outputs(calc_eval::DefaultBuilder::new)
+        .collect::<Vec<_>>();
+    results.sort_by(f32::total_cmp);
+    assert_eq!(results, [7., 9.]);
+}
+// ANCHOR_END: into-outputs
+
+#[test]
+fn glr_forest_into_outputs_with_input() {
+    // The `Sum` actions slice the text of their operand from the input and
+    // the range given in the context.
+    let input = "1 + 2 + 3";
+    let forest = SplitParser::new().parse(input).unwrap();
+    assert_eq!(forest.solutions(), 2);
+
+    let mut results = forest
+        .into_outputs_with_input(input, split::DefaultBuilder::new)
+        .map(|split| (split.left, split.right))
+        .collect::<Vec<_>>();
+    results.sort();
+    assert_eq!(
+        results,
+        [
+            ("1".to_string(), "2 + 3".to_string()),
+            ("1 + 2".to_string(), "3".to_string())
+        ]
+    );
+}
@@ -0,0 +1,35 @@
+use super::split::{Context, TokenKind};
+/// This file is maintained by rustemo but can be modified manually.
+/// All manual changes will be preserved except non-doc comments.
+use rustemo::Context as ContextT;
+use rustemo::Token as RustemoToken;
+pub type Input = str;
+pub type Ctx<'i> = Context<'i, Input>;
+#[allow(dead_code)]
+pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+pub type Num = String;
+pub fn num(_ctx: &Ctx, token: Token) -> Num {
+    token.value.into()
+}
+#[derive(Debug, Clone)]
+pub struct Split {
+    pub left: Sum,
+    pub right: Sum,
+}
+#[allow(dead_code)]
+impl Split {
+    pub fn new(left: Sum, right: Sum) -> Self {
+        Self { left, right }
+    }
+}
+pub fn split_c1(_ctx: &Ctx, left: Sum, right: Sum) -> Split {
+    Split { left, right }
+}
+/// The text of the sum sliced from the input.
+pub type Sum = String;
+pub fn sum_c1(ctx: &Ctx, _sum_1: Sum, _sum_3: Sum) -> Sum {
+    ctx.input().unwrap()[ctx.range()].to_string()
+}
+pub fn sum_num(_ctx: &Ctx, num: Num) -> Sum {
+    num
+}
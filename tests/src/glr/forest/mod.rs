@@ -1,10 +1,10 @@
-use rustemo::{rustemo_mod, Parser};
+use rustemo::{rustemo_mod, Parser, Policy};
 use rustemo_compiler::output_cmp;
 
 rustemo_mod!(calc, "/src/glr/forest");
 rustemo_mod!(calc_actions, "/src/glr/forest");
 
-use self::calc::CalcParser;
+use self::calc::{CalcParser, NonTermKind};
 
 #[test]
 fn glr_calc_parse_ast() {
@@ -141,3 +141,94 @@ fn glr_forest_iter() {
     output_cmp!("src/glr/forest/forest_iter.ast", forest_iter_string);
 }
 // ANCHOR_END: forest-iter
+
+// ANCHOR: forest-solution
+#[test]
+fn glr_forest_solution() {
+    let input = "1 + 4 * 9 + 3";
+    let forest = CalcParser::new().parse(input).unwrap();
+    assert_eq!(forest.len(), 5);
+    assert!(forest.solution(5).is_none());
+
+    // Solutions are in the iteration order.
+    for (idx, tree) in forest.iter().enumerate() {
+        assert_eq!(forest.solution(idx).unwrap(), tree);
+    }
+
+    // Indices are stable across parses.
+    let other = CalcParser::new().parse(input).unwrap();
+    for idx in 0..forest.len() {
+        assert_eq!(
+            format!("{:?}", forest.solution(idx).unwrap()),
+            format!("{:?}", other.solution(idx).unwrap())
+        );
+    }
+}
+// ANCHOR_END: forest-solution
+
+#[test]
+fn glr_parse_str() {
+    let input = "1 + 4 * 9 + 3";
+    let parser = CalcParser::new();
+    let forest = parser.parse(input).unwrap();
+    let forest_str = parser.parse_str(input).unwrap();
+    assert_eq!(forest.solutions(), forest_str.solutions());
+    assert_eq!(
+        forest.iter().map(|t| format!("{t:?}")).collect::<Vec<_>>(),
+        forest_str.iter().map(|t| format!("{t:?}")).collect::<Vec<_>>()
+    );
+}
+
+// ANCHOR: forest-disambiguate
+#[test]
+fn glr_forest_disambiguate() {
+    let forest = CalcParser::new().parse("1 + 4 * 9 + 3").unwrap();
+    assert_eq!(forest.solutions(), 5);
+
+    // The longest reductions are on the left, i.e. left associativity.
+    let forest = forest.disambiguate(Policy::LongestReduction);
+    assert_eq!(forest.solutions(), 1);
+
+    let mut builder = calc::DefaultBuilder::new();
+    output_cmp!(
+        "src/glr/forest/forest_disambiguate.ast",
+        format!(
+            "{:#?}",
+            forest.get_first_tree().unwrap().build(&mut builder)
+        )
+    );
+}
+// ANCHOR_END: forest-disambiguate
+
+#[test]
+fn glr_tree_equality() {
+    let forest = CalcParser::new().parse("1 + 4 * 9").unwrap();
+    let other_forest = CalcParser::new().parse("1  +  4 * 9").unwrap();
+    let tree = forest.get_first_tree().unwrap();
+
+    // Locations are ignored by default.
+    assert_eq!(tree, other_forest.get_first_tree().unwrap());
+    assert!(!tree.tree_eq(&other_forest.get_first_tree().unwrap(), true));
+    assert!(tree.tree_eq(&forest.get_first_tree().unwrap(), true));
+
+    // Different trees of the same forest.
+    assert_ne!(tree, forest.get_tree(1).unwrap());
+
+    // Different inputs.
+    let forest = CalcParser::new().parse("1 + 4 * 8").unwrap();
+    assert_ne!(tree, forest.get_first_tree().unwrap());
+}
+
+#[test]
+fn forest_to_tree_string() {
+    // ANCHOR: forest-tree-string
+    let forest = CalcParser::new().parse("1+2*3").unwrap();
+    assert_eq!(
+        forest.to_tree_string::<NonTermKind>(),
+        "E[amb]{ ((1 + 2) * 3) | (1 + (2 * 3)) }"
+    );
+    // ANCHOR_END: forest-tree-string
+
+    let forest = CalcParser::new().parse("1+2").unwrap();
+    assert_eq!(forest.to_tree_string::<NonTermKind>(), "(1 + 2)");
+}
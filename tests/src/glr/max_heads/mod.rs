@@ -0,0 +1,28 @@
+use rustemo::{rustemo_mod, Parser};
+
+rustemo_mod!(max_heads, "/src/glr/max_heads");
+rustemo_mod!(max_heads_actions, "/src/glr/max_heads");
+use self::max_heads::MaxHeadsParser;
+
+#[test]
+fn glr_max_heads_not_exceeded() {
+    let forest = MaxHeadsParser::new()
+        .max_heads(100)
+        .parse("1 + 2 * 3 + 4")
+        .unwrap();
+    assert_eq!(forest.solutions(), 5);
+}
+
+#[test]
+fn glr_max_heads_exceeded() {
+    let input = "1 + 2 * 3 + 4 * 5 + 6";
+    // ANCHOR: max-heads
+    let result = MaxHeadsParser::new().max_heads(3).parse(input);
+    // ANCHOR_END: max-heads
+    let error = result.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "TooAmbiguous at <str>:[1,10]:\n\t\
+         Number of GLR heads exceeds the maximum of 3."
+    );
+}
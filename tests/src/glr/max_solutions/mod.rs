@@ -0,0 +1,27 @@
+use rustemo::{rustemo_mod, Parser};
+
+rustemo_mod!(max_solutions, "/src/glr/max_solutions");
+rustemo_mod!(max_solutions_actions, "/src/glr/max_solutions");
+use self::max_solutions::MaxSolutionsParser;
+
+#[test]
+fn glr_max_solutions_not_exceeded() {
+    let forest = MaxSolutionsParser::new()
+        .max_solutions(10)
+        .parse("a a a b")
+        .unwrap();
+    // Partial parse stops before "b". There are two trees for "a a a".
+    assert_eq!(forest.solutions(), 2);
+}
+
+#[test]
+fn glr_max_solutions_exceeded() {
+    let input = "a ".repeat(30);
+    // ANCHOR: max-solutions
+    let result = MaxSolutionsParser::new().max_solutions(1000).parse(&input);
+    // ANCHOR_END: max-solutions
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("exceeds the maximum of 1000"));
+}
@@ -0,0 +1,50 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+rustemo_mod!(attachment, "/src/glr/min_cost");
+rustemo_mod!(attachment_actions, "/src/glr/min_cost");
+
+use self::attachment::{AttachmentParser, ProdKind};
+
+// ANCHOR: min-cost-tree
+#[test]
+fn glr_forest_min_cost_tree() {
+    let forest = AttachmentParser::new()
+        .parse("I saw man with telescope")
+        .unwrap();
+    assert_eq!(forest.solutions(), 2);
+
+    // Penalize attaching the prepositional phrase to the verb phrase.
+    let noun_attached = forest
+        .min_cost_tree(|prod| if prod == ProdKind::VPP2 { 10. } else { 1. })
+        .unwrap();
+    // Penalize attaching the prepositional phrase to the noun phrase.
+    let verb_attached = forest
+        .min_cost_tree(|prod| if prod == ProdKind::NPP2 { 10. } else { 1. })
+        .unwrap();
+
+    assert_ne!(noun_attached, verb_attached);
+    output_cmp!(
+        "src/glr/min_cost/noun_attached.ast",
+        format!("{noun_attached:#?}")
+    );
+    output_cmp!(
+        "src/glr/min_cost/verb_attached.ast",
+        format!("{verb_attached:#?}")
+    );
+}
+// ANCHOR_END: min-cost-tree
+
+#[test]
+fn glr_forest_min_cost_tree_tie() {
+    let forest = AttachmentParser::new()
+        .parse("I saw man with telescope with telescope")
+        .unwrap();
+    assert_eq!(forest.solutions(), 5);
+
+    // On a tie the tree with the lower index wins.
+    assert_eq!(
+        forest.min_cost_tree(|_| 1.).unwrap(),
+        forest.get_first_tree().unwrap()
+    );
+}
@@ -1,6 +1,10 @@
+mod ambiguity_is_error;
 mod build;
 mod errors;
 mod evaluate;
 mod forest;
 mod lexical_ambiguity;
+mod max_heads;
+mod max_solutions;
+mod min_cost;
 mod special;
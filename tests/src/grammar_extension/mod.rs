@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use rustemo::Parser;
+
+use self::sum::SumParser;
+
+rustemo::rustemo_mod!(sum, "/src/grammar_extension");
+rustemo::rustemo_mod!(sum_actions, "/src/grammar_extension");
+
+#[test]
+fn grammar_extension() {
+    let result = SumParser::new().parse("1 + 2 + 3").unwrap();
+    assert_eq!(result, ["1", "2", "3"]);
+}
+
+#[test]
+fn grammar_extension_skip_invalid() {
+    let out_dir = Path::new(env!("OUT_DIR")).join("src/grammar_extension");
+    assert!(out_dir.join("sum.rs").exists());
+    assert!(!out_dir.join("notes.rs").exists());
+}
@@ -0,0 +1,30 @@
+use self::config_actions::{Config, Value};
+
+rustemo::rustemo_mod!(config, "/src/impl_fromstr");
+rustemo::rustemo_mod!(config_actions, "/src/impl_fromstr");
+
+#[test]
+fn impl_fromstr() -> rustemo::Result<()> {
+    // ANCHOR: impl-fromstr
+    let config: Config = r#"config server port = 8080; host = "localhost";
+                            debug = false;"#
+        .parse()?;
+    assert_eq!(config.name, "server");
+    assert_eq!(config.entries.as_ref().map(Vec::len), Some(3));
+    // ANCHOR_END: impl-fromstr
+    let entries = config.entries.unwrap();
+    assert_eq!(entries[0].key, "port");
+    assert!(matches!(&entries[0].value, Value::Number(n) if n == "8080"));
+    assert!(matches!(&entries[2].value, Value::Bool(b) if b == "false"));
+    Ok(())
+}
+
+#[test]
+fn impl_fromstr_error() {
+    let result = "config server port 8080;".parse::<Config>();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error at <str>:[1,19]:\n\t\
+         ...ig server port -->8080;...\n\tExpected Equals."
+    );
+}
@@ -0,0 +1,25 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::labels::LabelsParser;
+
+rustemo_mod!(labels, "/src/labels");
+rustemo_mod!(labels_actions, "/src/labels");
+
+#[test]
+fn labels() {
+    let result = LabelsParser::new().parse("a = 1; 1 .. b; c: d;").unwrap();
+    let stmts: Vec<String> = result
+        .iter()
+        .map(|stmt| match stmt {
+            labels_actions::Stmt::C1(s) => format!("{} = {:?}", s.lhs, s.rhs),
+            labels_actions::Stmt::C2(s) => {
+                format!("{:?} .. {:?}", s.from, s.to)
+            }
+            labels_actions::Stmt::C3(s) => {
+                format!("{}: {:?}", s.expr, s.expr_3)
+            }
+        })
+        .collect();
+    output_cmp!("src/labels/labels.ast", format!("{stmts:#?}"));
+}
@@ -0,0 +1,22 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::comments::CommentsParser;
+
+rustemo_mod!(comments, "/src/layout/comment_directives");
+rustemo_mod!(comments_actions, "/src/layout/comment_directives");
+
+#[test]
+fn layout_comment_directives() {
+    let result = CommentsParser::new().parse(
+        r#"
+        4 // Line comment
+        2 /* Block comment
+             spanning multiple lines 8 */ 6
+        /* Another */ 3 // At the end"#,
+    );
+    output_cmp!(
+        "src/layout/comment_directives/comments.ast",
+        format!("{:#?}", result)
+    )
+}
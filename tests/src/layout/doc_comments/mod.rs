@@ -0,0 +1,56 @@
+use rustemo::rustemo_mod;
+
+use self::decls::DeclsParser;
+
+rustemo_mod!(decls, "/src/layout/doc_comments");
+rustemo_mod!(decls_actions, "/src/layout/doc_comments");
+
+#[test]
+fn layout_doc_comments() {
+    let input = r#"
+        // The answer.
+        let answer = 42;
+        let nodoc = 1; /* Trailing */
+        /* Block doc. */
+        // Line doc.
+        let last = 3;"#;
+
+    // ANCHOR: parse-with-layout
+    let (decls, layout) = DeclsParser::new().parse_with_layout(input).unwrap();
+
+    // The comments of each declaration are in the layout ending where the
+    // declaration starts.
+    let docs = input
+        .match_indices("let")
+        .map(|(start, _)| {
+            layout
+                .iter()
+                .find(|segment| segment.range.end == start)
+                .map(|segment| {
+                    segment
+                        .value
+                        .lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    // ANCHOR_END: parse-with-layout
+
+    assert_eq!(decls.len(), 3);
+    assert_eq!(
+        docs,
+        [
+            vec!["// The answer."],
+            vec![],
+            vec!["/* Trailing */", "/* Block doc. */", "// Line doc."],
+        ]
+    );
+    for segment in &layout {
+        assert_eq!(&input[segment.range.clone()], segment.value);
+    }
+    assert_eq!(layout[0].location.start.line(), 1);
+    assert_eq!(layout[0].location.end.unwrap().line(), 3);
+}
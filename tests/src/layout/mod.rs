@@ -1,2 +1,5 @@
 mod ast;
+mod comment_directives;
+mod doc_comments;
 mod generic_tree;
+mod nolayout;
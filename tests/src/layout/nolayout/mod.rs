@@ -0,0 +1,24 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::versions::VersionsParser;
+
+rustemo_mod!(versions, "/src/layout/nolayout");
+rustemo_mod!(versions_actions, "/src/layout/nolayout");
+
+#[test]
+fn layout_nolayout_production() {
+    // Layout is skipped between assignment elements.
+    let result = VersionsParser::new().parse(
+        r#"
+        first = v1.2 ;  // Comment
+        second=v10.20;
+        "#,
+    );
+    assert!(result.is_ok());
+
+    // But not inside of the version.
+    let result = VersionsParser::new().parse("first = v1 .2;");
+    assert!(result.is_err());
+    let result = VersionsParser::new().parse("first = v 1.2;");
+    assert!(result.is_err());
+}
@@ -0,0 +1,41 @@
+use std::time::{Duration, Instant};
+
+use rustemo::Parser;
+
+use self::slow::SlowParser;
+
+rustemo::rustemo_mod!(slow, "/src/lex_budget");
+rustemo::rustemo_mod!(slow_actions, "/src/lex_budget");
+
+#[test]
+fn lex_budget_within_limits() {
+    let result = SlowParser::new()
+        .max_lex_bytes(1000)
+        .lex_timeout(Duration::from_secs(60))
+        .parse("aaaaaaaaaa");
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn lex_timeout_exceeded() {
+    // ANCHOR: lex-timeout
+    let input = "a".repeat(100_000);
+    let start = Instant::now();
+    let result = SlowParser::new()
+        .lex_timeout(Duration::from_millis(10))
+        .parse(&input);
+    // ANCHOR_END: lex-timeout
+    assert!(matches!(result, Err(rustemo::Error::Timeout { message, .. })
+                     if message == "Parsing time limit of 10ms exceeded."));
+    // Parsing the whole input would take minutes.
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn max_lex_bytes_exceeded() {
+    let input = "a".repeat(1000);
+    let result = SlowParser::new().max_lex_bytes(1000).parse(&input);
+    assert!(matches!(&result, Err(rustemo::Error::Timeout { message, .. })
+                     if message == "Lexing limit of 1000 bytes exceeded."));
+    assert!(result.unwrap_err().to_string().starts_with("Timeout at"));
+}
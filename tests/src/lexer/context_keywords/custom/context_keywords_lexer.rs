@@ -0,0 +1,65 @@
+use rustemo::{Context, LRContext, Lexer, Location, Position, Token};
+
+use super::context_keywords::{State, TokenKind};
+
+pub type Input = str;
+pub type Ctx<'i> = LRContext<'i, Input, State, TokenKind>;
+
+/// Returns all tokens matching at the current position regardless of the
+/// tokens expected by the parser. Identifiers are returned first.
+pub struct AllTokensLexer();
+
+impl AllTokensLexer {
+    pub fn new() -> Self {
+        AllTokensLexer()
+    }
+}
+
+impl<'i> Lexer<'i, Ctx<'i>, State, TokenKind> for AllTokensLexer {
+    type Input = Input;
+
+    fn next_tokens(
+        &self,
+        context: &mut Ctx<'i>,
+        input: &'i Self::Input,
+        _token_kinds: Vec<(TokenKind, bool)>,
+    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TokenKind>> + 'i> {
+        let ws = input[context.position()..]
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(input.len() - context.position());
+        context.set_position(context.position() + ws);
+        let start = context.position();
+        let rest = &input[start..];
+
+        let ident = rest
+            .find(|c: char| !c.is_ascii_lowercase())
+            .unwrap_or(rest.len());
+        let mut tokens = vec![];
+        if rest.is_empty() {
+            tokens.push((TokenKind::STOP, 0));
+        }
+        if ident > 0 {
+            tokens.push((TokenKind::Ident, ident));
+        }
+        for (kind, s) in [
+            (TokenKind::Var, "var"),
+            (TokenKind::Int, "int"),
+            (TokenKind::Colon, ":"),
+            (TokenKind::SemiColon, ";"),
+        ] {
+            if rest.starts_with(s) {
+                tokens.push((kind, s.len()));
+            }
+        }
+        Box::new(tokens.into_iter().map(move |(kind, len)| Token {
+            kind,
+            value: &input[start..start + len],
+            location: Location {
+                start: Position::Position(start),
+                end: Some(Position::Position(start + len)),
+            },
+            attr: None,
+            consumed: None,
+        }))
+    }
+}
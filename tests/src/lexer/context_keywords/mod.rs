@@ -0,0 +1,52 @@
+use rustemo::Parser;
+
+use self::context_keywords::ContextKeywordsParser;
+use self::context_keywords_actions::TypeRef;
+use self::custom::{AllTokensLexer, ContextKeywordsParser as CustomParser};
+
+rustemo::rustemo_mod!(context_keywords, "/src/lexer/context_keywords");
+rustemo::rustemo_mod!(context_keywords_actions, "/src/lexer/context_keywords");
+
+mod custom {
+    pub use self::context_keywords::ContextKeywordsParser;
+    pub use self::context_keywords_actions::TypeRef;
+    pub use self::context_keywords_lexer::AllTokensLexer;
+
+    mod context_keywords_lexer;
+
+    rustemo::rustemo_mod!(
+        context_keywords,
+        "/src/lexer/context_keywords/custom"
+    );
+    rustemo::rustemo_mod!(
+        context_keywords_actions,
+        "/src/lexer/context_keywords/custom"
+    );
+}
+
+#[test]
+fn context_keywords() {
+    // `var` and `int` are keywords only where the parser expects them.
+    let decls = ContextKeywordsParser::new()
+        .parse("var var: int; var int: var;")
+        .unwrap();
+    assert_eq!(decls[0].name, "var");
+    assert!(matches!(decls[0].type_ref, TypeRef::Int));
+    assert_eq!(decls[1].name, "int");
+    assert!(matches!(decls[1].type_ref, TypeRef::Ident(ref i) if i == "var"));
+
+    // Identifier is not expected at the start of the declaration.
+    assert!(ContextKeywordsParser::new().parse("int: var;").is_err());
+}
+
+#[test]
+fn context_keywords_custom_lexer() {
+    // Tokens not expected in the current state are dropped and on a tie the
+    // one preferred by the state is taken.
+    let decls = CustomParser::new(AllTokensLexer::new())
+        .parse("var var: int; var int: var;")
+        .unwrap();
+    assert_eq!(decls[0].name, "var");
+    assert!(matches!(decls[0].type_ref, custom::TypeRef::Int));
+    assert_eq!(decls[1].name, "int");
+}
@@ -49,6 +49,8 @@ impl<'i> Lexer<'i, Ctx<'i>, State, TokenKind> for MyCustomLexer1 {
                 start: Position::Position(context.position()),
                 end: Some(Position::Position(pos)),
             },
+            attr: None,
+            consumed: None,
         }))
     }
 }
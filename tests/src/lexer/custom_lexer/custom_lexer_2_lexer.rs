@@ -49,6 +49,8 @@ impl<'i> Lexer<'i, Ctx<'i>, State, TokenKind> for MyCustomLexer2 {
                 start: Position::Position(context.position()),
                 end: Some(Position::Position(context.position())),
             },
+            attr: None,
+            consumed: None,
         }))
     }
 }
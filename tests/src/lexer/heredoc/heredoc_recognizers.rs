@@ -0,0 +1,23 @@
+use super::heredoc::{Context, Input};
+use rustemo::Context as _;
+
+// ANCHOR: heredoc
+/// Recognizes the heredoc body up to and including the line with the closing
+/// tag. The tag is taken from the previous token, i.e. the heredoc start, whose
+/// range is kept in the context.
+pub fn body<'i>(
+    context: &Context<'i, Input>,
+    input: &'i str,
+) -> Option<&'i str> {
+    let tag = input[context.range()].trim_start_matches("<<").trim_end();
+    let position = context.position();
+    let mut end = position;
+    for line in input[position..].split_inclusive('\n') {
+        end += line.len();
+        if line.trim_end() == tag {
+            return Some(&input[position..end]);
+        }
+    }
+    None
+}
+// ANCHOR_END: heredoc
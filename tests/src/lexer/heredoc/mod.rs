@@ -0,0 +1,28 @@
+use rustemo::Parser;
+
+use self::heredoc::HeredocParser;
+
+mod heredoc_recognizers;
+
+rustemo::rustemo_mod!(heredoc, "/src/lexer/heredoc");
+rustemo::rustemo_mod!(heredoc_actions, "/src/lexer/heredoc");
+
+#[test]
+fn heredoc() {
+    let result = HeredocParser::new()
+        .parse("<<EOT\nfirst\nEOT is not the end\nEOT\n<<END\nsecond\nEND\n")
+        .unwrap();
+    assert_eq!(
+        result
+            .iter()
+            .map(|heredoc| heredoc.body.as_str())
+            .collect::<Vec<_>>(),
+        ["first\nEOT is not the end\nEOT\n", "second\nEND\n"]
+    );
+}
+
+#[test]
+fn heredoc_unterminated() {
+    let result = HeredocParser::new().parse("<<EOT\nfirst\nEND\n");
+    assert!(result.is_err());
+}
@@ -0,0 +1,17 @@
+use super::hex_literal::{Context, Input};
+use rustemo::Context as _;
+
+// ANCHOR: hex
+/// Recognizes a hex literal. The consumed length includes the `0x` prefix while
+/// the token value is only the hex digits.
+pub fn hex<'i>(
+    context: &Context<'i, Input>,
+    input: &'i str,
+) -> Option<(usize, &'i str)> {
+    let digits = input[context.position()..].strip_prefix("0x")?;
+    let len = digits
+        .find(|c: char| !c.is_ascii_hexdigit())
+        .unwrap_or(digits.len());
+    (len > 0).then(|| (len + 2, &digits[..len]))
+}
+// ANCHOR_END: hex
@@ -0,0 +1,91 @@
+use rustemo::Parser;
+
+use self::hex_literal::HexLiteralParser;
+use self::hex_literal_actions::Value;
+
+mod hex_literal_recognizers;
+
+rustemo::rustemo_mod!(hex_literal, "/src/lexer/hex_literal");
+rustemo::rustemo_mod!(hex_literal_actions, "/src/lexer/hex_literal");
+
+#[test]
+fn hex_literal() {
+    // `Dec` matches `0` of each hex literal but the hex literal consumes more
+    // input so it wins by the longest match.
+    let result = HexLiteralParser::new().parse("0x1F 42 0xff").unwrap();
+    assert_eq!(
+        result
+            .iter()
+            .map(|value| match value {
+                Value::Hex(digits) => u32::from_str_radix(digits, 16).unwrap(),
+                Value::Dec(digits) => digits.parse().unwrap(),
+            })
+            .collect::<Vec<_>>(),
+        [31, 42, 255]
+    );
+    assert!(matches!(&result[0], Value::Hex(digits) if digits == "1F"));
+}
+
+#[test]
+fn hex_literal_error_location() {
+    // The location advances by the consumed input, not by the value.
+    let result = HexLiteralParser::new().parse("0x1F x");
+    assert!(result.unwrap_err().to_string().contains("[1,5]"));
+}
+
+#[test]
+fn hex_literal_token_recognizer() {
+    use self::hex_literal::{
+        Context, DefaultBuilder, State, TokenKind, PARSER_DEFINITION,
+    };
+    use rustemo::{LRParser, StringLexer, TokenRecognizer};
+
+    // Implements only `recognize_value` and doesn't need the context so the
+    // tokens are recognized lazily.
+    struct Recognizer(TokenKind);
+
+    impl<'i> TokenRecognizer<'i, Context<'i, str>> for Recognizer {
+        fn recognize_value(
+            &self,
+            _context: &Context<'i, str>,
+            input: &'i str,
+            position: usize,
+        ) -> Option<(usize, &'i str)> {
+            let input = &input[position..];
+            let digits = |input: &'i str, radix| {
+                let len = input
+                    .find(|c: char| !c.is_digit(radix))
+                    .unwrap_or(input.len());
+                (len > 0).then(|| &input[..len])
+            };
+            match self.0 {
+                TokenKind::STOP => input.is_empty().then_some((0, input)),
+                TokenKind::Hex => digits(input.strip_prefix("0x")?, 16)
+                    .map(|digits| (digits.len() + 2, digits)),
+                TokenKind::Dec => {
+                    digits(input, 10).map(|digits| (digits.len(), digits))
+                }
+            }
+        }
+    }
+
+    static RECOGNIZERS: [Recognizer; 3] = [
+        Recognizer(TokenKind::STOP),
+        Recognizer(TokenKind::Hex),
+        Recognizer(TokenKind::Dec),
+    ];
+    let result = LRParser::new(
+        &PARSER_DEFINITION,
+        State::default(),
+        false,
+        false,
+        StringLexer::new(true, &RECOGNIZERS),
+        DefaultBuilder::new(),
+    )
+    .parse("0x1F 42")
+    .unwrap();
+    assert!(matches!(
+        &result[..],
+        [Value::Hex(hex), Value::Dec(dec)] if hex == "1F" && dec == "42"
+    ));
+}
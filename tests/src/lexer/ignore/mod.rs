@@ -0,0 +1,24 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::ignore::IgnoreParser;
+
+rustemo_mod!(ignore, "/src/lexer/ignore");
+rustemo_mod!(ignore_actions, "/src/lexer/ignore");
+
+#[test]
+fn ignore() {
+    let result = IgnoreParser::new()
+        .parse("#a a = #b 1; b #c = 2 #d; #e directive #f ; #g")
+        .unwrap();
+    output_cmp!("src/lexer/ignore/ignore.ast", format!("{result:#?}"));
+}
+
+#[test]
+fn ignore_error() {
+    let result = IgnoreParser::new().parse("a = #b ;");
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error at <str>:[1,7]:\n\t...a = #b -->;...\n\tExpected Num."
+    );
+}
@@ -0,0 +1,93 @@
+use rustemo::{IncrementalLexer, Recognized};
+
+use self::incremental::{TokenKind, RECOGNIZERS};
+
+rustemo::rustemo_mod!(incremental, "/src/lexer/incremental");
+rustemo::rustemo_mod!(incremental_actions, "/src/lexer/incremental");
+
+fn token(
+    recognized: Recognized<rustemo::Token<'_, str, TokenKind>>,
+) -> Option<(TokenKind, String)> {
+    match recognized {
+        Recognized::Match(token) => Some((token.kind, token.value.into())),
+        _ => None,
+    }
+}
+
+// ANCHOR: incremental
+#[test]
+fn incremental_byte_at_a_time() {
+    let mut lexer = IncrementalLexer::new(true, &RECOGNIZERS);
+    let expected = [TokenKind::Hello, TokenKind::Ping];
+
+    for byte in ["H", "E", "L", "L"] {
+        lexer.feed(byte);
+        assert!(matches!(
+            lexer.next_token(&expected),
+            Recognized::Incomplete
+        ));
+    }
+    lexer.feed("O");
+    assert_eq!(
+        token(lexer.next_token(&expected)),
+        Some((TokenKind::Hello, "HELLO".into()))
+    );
+
+    // The regex match may continue until a character which is not matched.
+    lexer.feed(" wor");
+    assert!(matches!(
+        lexer.next_token(&[TokenKind::Name]),
+        Recognized::Incomplete
+    ));
+    lexer.feed("ld;");
+    assert_eq!(
+        token(lexer.next_token(&[TokenKind::Name])),
+        Some((TokenKind::Name, "world".into()))
+    );
+    assert_eq!(
+        token(lexer.next_token(&[TokenKind::Semicolon])),
+        Some((TokenKind::Semicolon, ";".into()))
+    );
+
+    // Nothing more arrives.
+    assert!(matches!(
+        lexer.next_token(&[TokenKind::STOP]),
+        Recognized::Incomplete
+    ));
+    lexer.finish();
+    assert_eq!(
+        token(lexer.next_token(&[TokenKind::STOP])),
+        Some((TokenKind::STOP, "".into()))
+    );
+}
+// ANCHOR_END: incremental
+
+#[test]
+fn incremental_no_match() {
+    let mut lexer = IncrementalLexer::new(true, &RECOGNIZERS);
+    lexer.feed("PO");
+    assert!(matches!(
+        lexer.next_token(&[TokenKind::Hello, TokenKind::Ping]),
+        Recognized::NoMatch
+    ));
+    assert_eq!(lexer.rest(), "PO");
+}
+
+#[test]
+fn incremental_location() {
+    let mut lexer = IncrementalLexer::new(true, &RECOGNIZERS);
+    lexer.feed("PING;\n  HEL");
+    lexer.next_token(&[TokenKind::Ping]);
+    lexer.next_token(&[TokenKind::Semicolon]);
+    assert!(matches!(
+        lexer.next_token(&[TokenKind::Hello, TokenKind::Ping]),
+        Recognized::Incomplete
+    ));
+    lexer.feed("LO");
+    match lexer.next_token(&[TokenKind::Hello]) {
+        Recognized::Match(token) => {
+            assert_eq!(format!("{:?}", token.location), "[2,2-2,7]")
+        }
+        _ => panic!("HELLO not recognized."),
+    }
+}
@@ -0,0 +1,29 @@
+use self::keywords::{KeywordsParser, TokenKind};
+
+rustemo::rustemo_mod!(keywords, "/src/lexer/keywords");
+rustemo::rustemo_mod!(keywords_actions, "/src/lexer/keywords");
+
+fn tokens(input: &str) -> Vec<(TokenKind, &str)> {
+    KeywordsParser::tokenize(input)
+        .unwrap()
+        .iter()
+        .map(|token| (token.kind, token.value))
+        .collect()
+}
+
+#[test]
+fn keywords() {
+    assert_eq!(tokens("ifelse"), [(TokenKind::Ident, "ifelse")]);
+    assert_eq!(
+        tokens("if else"),
+        [(TokenKind::If, "if"), (TokenKind::Else, "else")]
+    );
+    assert_eq!(
+        tokens("iff if elsewhere"),
+        [
+            (TokenKind::Ident, "iff"),
+            (TokenKind::If, "if"),
+            (TokenKind::Ident, "elsewhere")
+        ]
+    );
+}
@@ -0,0 +1,38 @@
+use logos::Logos;
+use rustemo::LogosLexer;
+
+use super::calc::TokenKind;
+
+pub type Input = str;
+
+// ANCHOR: logos-lexer
+/// Tokens recognized by the lexer generated by `logos`.
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+#[logos(skip r"[ \t\n]+")]
+pub enum CalcToken {
+    #[token("+")]
+    Plus,
+    #[token("*")]
+    Mul,
+    #[token("(")]
+    LParen,
+    #[token(")")]
+    RParen,
+    #[regex("[0-9]+")]
+    Num,
+}
+
+fn token_kind(token: &CalcToken) -> TokenKind {
+    match token {
+        CalcToken::Plus => TokenKind::Plus,
+        CalcToken::Mul => TokenKind::Mul,
+        CalcToken::LParen => TokenKind::LParen,
+        CalcToken::RParen => TokenKind::RParen,
+        CalcToken::Num => TokenKind::Num,
+    }
+}
+
+pub fn calc_lexer() -> LogosLexer<CalcToken, TokenKind> {
+    LogosLexer::new(token_kind)
+}
+// ANCHOR_END: logos-lexer
@@ -0,0 +1,35 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::calc::CalcParser;
+use self::calc_actions::E;
+use self::calc_lexer::calc_lexer;
+
+mod calc_lexer;
+
+rustemo_mod!(calc, "/src/lexer/logos_lexer");
+rustemo_mod!(calc_actions, "/src/lexer/logos_lexer");
+
+fn eval(e: &E) -> i32 {
+    match e {
+        E::Add(add) => eval(&add.e_1) + eval(&add.e_3),
+        E::Mul(mul) => eval(&mul.e_1) * eval(&mul.e_3),
+        E::Paren(e) => eval(e),
+        E::Num(n) => n.parse().unwrap(),
+    }
+}
+
+#[test]
+fn logos_lexer() {
+    let result = CalcParser::new(calc_lexer()).parse("2 + 3 * (4 + 1)\n");
+    assert_eq!(eval(&result.unwrap()), 17);
+}
+
+#[test]
+fn logos_lexer_error() {
+    let result = CalcParser::new(calc_lexer()).parse("2 + 3 $ 4");
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error at <str>:[1,6]:\n\t...2 + 3 -->$ 4...\n\t\
+         Expected one of STOP, Plus, Mul, RParen."
+    );
+}
@@ -1 +1,11 @@
+mod context_keywords;
 mod custom_lexer;
+mod heredoc;
+mod hex_literal;
+mod ignore;
+mod incremental;
+mod keywords;
+mod logos_lexer;
+mod raw;
+mod rope_input;
+mod token_attr;
@@ -0,0 +1,24 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::raw::RawParser;
+
+rustemo_mod!(raw, "/src/lexer/raw");
+rustemo_mod!(raw_actions, "/src/lexer/raw");
+
+#[test]
+fn raw_nested_block() {
+    let result = RawParser::new().parse(
+        r#"
+        fn first { if a { b } else { { c } } }
+        fn second {}
+        "#,
+    );
+    output_cmp!("src/lexer/raw/raw.ast", format!("{:#?}", result));
+}
+
+#[test]
+fn raw_unbalanced_block() {
+    let result = RawParser::new().parse("fn first { if a { b }");
+    output_cmp!("src/lexer/raw/raw_unbalanced.err", format!("{:#?}", result));
+}
@@ -0,0 +1,28 @@
+use rustemo::{rustemo_mod, Parser, StringLexer};
+use rustemo_compiler::output_cmp;
+
+use self::rope::{RopeBuf, RopeRecognizer};
+use self::rope_calc::RopeCalcParser;
+
+mod rope;
+
+rustemo_mod!(rope_calc, "/src/lexer/rope_input");
+
+static RECOGNIZERS: [RopeRecognizer; 4] = [
+    RopeRecognizer::Stop,
+    RopeRecognizer::Str("+"),
+    RopeRecognizer::Str("*"),
+    RopeRecognizer::Digits,
+];
+
+#[test]
+fn rope_input() {
+    // Number 34 is split across the chunks.
+    let rope = RopeBuf::from_chunks(&["12 + 3", "4 * 5"]);
+    let result =
+        RopeCalcParser::new(StringLexer::new(true, &RECOGNIZERS)).parse(&rope);
+    output_cmp!(
+        "src/lexer/rope_input/rope_calc.ast",
+        format!("{:#?}", result)
+    );
+}
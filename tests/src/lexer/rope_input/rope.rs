@@ -0,0 +1,140 @@
+//! A simple rope whose text is stored in separate chunks.
+use std::{
+    borrow::Borrow,
+    fmt,
+    ops::{Deref, Index, Range},
+    path::Path,
+};
+
+use rustemo::{Input, Location, Position, Result, TokenRecognizer};
+
+/// A view of the rope text. Each element references a byte in one of the
+/// chunks so the text is not contiguous in memory.
+#[repr(transparent)]
+pub struct Rope([&'static u8]);
+
+/// The owned rope.
+pub struct RopeBuf(Vec<&'static u8>);
+
+impl RopeBuf {
+    pub fn from_chunks(chunks: &[&'static str]) -> Self {
+        RopeBuf(chunks.iter().flat_map(|c| c.as_bytes()).collect())
+    }
+}
+
+impl Borrow<Rope> for RopeBuf {
+    fn borrow(&self) -> &Rope {
+        Rope::new(&self.0)
+    }
+}
+
+impl Deref for RopeBuf {
+    type Target = Rope;
+
+    fn deref(&self) -> &Rope {
+        self.borrow()
+    }
+}
+
+impl Rope {
+    fn new<'a>(bytes: &'a [&'static u8]) -> &'a Self {
+        // SAFETY: `Rope` is a transparent wrapper around the slice.
+        unsafe { &*(bytes as *const [&'static u8] as *const Rope) }
+    }
+
+    fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.iter().map(|b| **b)
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.0.len() >= s.len()
+            && self.bytes().zip(s.bytes()).all(|(a, b)| a == b)
+    }
+}
+
+impl ToOwned for Rope {
+    type Owned = RopeBuf;
+
+    fn to_owned(&self) -> RopeBuf {
+        RopeBuf(self.0.to_vec())
+    }
+}
+
+impl Index<Range<usize>> for Rope {
+    type Output = Rope;
+
+    fn index(&self, range: Range<usize>) -> &Rope {
+        Rope::new(&self.0[range])
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?}",
+            String::from_utf8_lossy(&self.bytes().collect::<Vec<_>>())
+        )
+    }
+}
+
+impl Input for Rope {
+    fn context_str(&self, position: usize) -> String {
+        format!(
+            "{:?}-->{:?}",
+            &self[position.saturating_sub(15)..position],
+            &self[position..self.len().min(position + 15)]
+        )
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn whitespace_len(&self) -> usize {
+        self.bytes().take_while(u8::is_ascii_whitespace).count()
+    }
+
+    fn read_file<P: AsRef<Path>>(path: P) -> Result<RopeBuf> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(RopeBuf::from_chunks(&[String::leak(content)]))
+    }
+
+    fn location_after(&self, location: Location) -> Location {
+        let position = match location.start {
+            Position::Position(p) => p,
+            _ => 0,
+        };
+        Location {
+            start: Position::Position(position + self.len()),
+            end: None,
+        }
+    }
+}
+
+/// Recognizers of the rope tokens used by the `StringLexer`.
+pub enum RopeRecognizer {
+    Stop,
+    Str(&'static str),
+    Digits,
+}
+
+impl<'i, C> TokenRecognizer<'i, C, Rope> for RopeRecognizer {
+    fn recognize_value(
+        &self,
+        _context: &C,
+        input: &'i Rope,
+        position: usize,
+    ) -> Option<(usize, &'i Rope)> {
+        let input = &input[position..input.len()];
+        let len = match self {
+            RopeRecognizer::Stop => input.is_empty().then_some(0),
+            RopeRecognizer::Str(s) => input.starts_with(s).then_some(s.len()),
+            RopeRecognizer::Digits => {
+                let len = input.bytes().take_while(u8::is_ascii_digit).count();
+                (len > 0).then_some(len)
+            }
+        }?;
+        Some((len, &input[0..len]))
+    }
+}
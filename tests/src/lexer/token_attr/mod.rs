@@ -0,0 +1,18 @@
+use rustemo::Parser;
+
+use self::token_attr::TokenAttrParser;
+use self::token_attr_lexer::RadixLexer;
+
+mod token_attr_lexer;
+
+rustemo::rustemo_mod!(token_attr, "/src/lexer/token_attr");
+#[rustfmt::skip]
+mod token_attr_actions;
+
+#[test]
+fn token_attr() {
+    let result = TokenAttrParser::new(RadixLexer::new())
+        .parse("10 0x10 0xff 7")
+        .unwrap();
+    assert_eq!(result, vec![10, 16, 255, 7]);
+}
@@ -0,0 +1,27 @@
+/// This file is maintained by rustemo but can be modified manually.
+/// All manual changes will be preserved except non-doc comments.
+use rustemo::Token as RustemoToken;
+use super::token_attr::{TokenKind, Context};
+use super::token_attr_lexer::{Input, Radix};
+pub type Ctx<'i> = Context<'i, Input>;
+#[allow(dead_code)]
+pub type Token<'i> = RustemoToken<'i, Input, TokenKind, super::token_attr_lexer::Radix>;
+pub type Number = u32;
+pub fn number(_ctx: &Ctx, token: Token) -> Number {
+    match token.attr {
+        Some(Radix::Hex) => u32::from_str_radix(&token.value[2..], 16).unwrap(),
+        _ => token.value.parse().unwrap(),
+    }
+}
+pub type Numbers = Number1;
+pub fn numbers_number1(_ctx: &Ctx, number1: Number1) -> Numbers {
+    number1
+}
+pub type Number1 = Vec<Number>;
+pub fn number1_c1(_ctx: &Ctx, mut number1: Number1, number: Number) -> Number1 {
+    number1.push(number);
+    number1
+}
+pub fn number1_number(_ctx: &Ctx, number: Number) -> Number1 {
+    vec![number]
+}
@@ -0,0 +1,69 @@
+use super::token_attr::{State, TokenKind};
+use rustemo::{Context, LRContext, Lexer, Location, Position, Token};
+use std::iter;
+
+pub type Input = str;
+pub type Ctx<'i> = LRContext<'i, Input, State, TokenKind>;
+
+// ANCHOR: token-attr
+/// The radix of the recognized number. Attached to each number token.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Radix {
+    Decimal,
+    Hex,
+}
+
+/// Recognizes decimal and hexadecimal (`0x` prefixed) numbers and tags each
+/// number token with its radix.
+pub struct RadixLexer();
+
+impl RadixLexer {
+    pub fn new() -> Self {
+        RadixLexer()
+    }
+}
+
+impl<'i> Lexer<'i, Ctx<'i>, State, TokenKind, Radix> for RadixLexer {
+    type Input = Input;
+
+    fn next_tokens(
+        &self,
+        context: &mut Ctx<'i>,
+        input: &'i Self::Input,
+        _token_kinds: Vec<(TokenKind, bool)>,
+    ) -> Box<dyn Iterator<Item = Token<'i, Self::Input, TokenKind, Radix>> + 'i>
+    {
+        let skipped = input[context.position()..]
+            .len()
+            .saturating_sub(input[context.position()..].trim_start().len());
+        context.set_position(context.position() + skipped);
+        let rest = &input[context.position()..];
+
+        let (kind, len, attr) = if rest.is_empty() {
+            (TokenKind::STOP, 0, None)
+        } else if let Some(hex) = rest.strip_prefix("0x") {
+            let digits =
+                hex.chars().take_while(|c| c.is_ascii_hexdigit()).count();
+            (TokenKind::Number, 2 + digits, Some(Radix::Hex))
+        } else {
+            let digits =
+                rest.chars().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 {
+                return Box::new(iter::empty());
+            }
+            (TokenKind::Number, digits, Some(Radix::Decimal))
+        };
+
+        Box::new(iter::once(Token {
+            kind,
+            value: &rest[..len],
+            location: Location {
+                start: Position::Position(context.position()),
+                end: Some(Position::Position(context.position() + len)),
+            },
+            attr,
+            consumed: None,
+        }))
+    }
+}
+// ANCHOR_END: token-attr
@@ -1,16 +1,62 @@
 #![allow(unused_imports)]
 #![cfg(test)]
+mod actions_code;
 mod ambiguity;
+mod async_stream;
+mod bare_prod_kinds;
+mod automaton;
+mod borrowed_terminals;
 mod builder;
+mod columns;
+mod completions;
+mod copy_symbols;
+mod derive_hash_eq;
+mod doc_comments;
 mod errors;
+mod fallible_actions;
 mod fancy_regex;
+mod file_header;
 mod from_file;
+mod from_source;
+mod grammar_extension;
+mod impl_fromstr;
+mod labels;
 mod layout;
+mod lex_budget;
 mod lexer;
 mod lexical_ambiguity;
+mod max_depth;
+mod no_panic;
+mod normalize_nfc;
 mod output_dir;
+mod parse_all;
+mod parse_driver;
+mod parse_opts;
 mod partial;
+mod predicates;
+mod preprocess;
+mod prod_kind;
+mod profile;
+mod reduce_dispatch;
+mod regex_set;
+mod rename;
+mod rr_by_order;
+mod rule_parsers;
 mod rule_patterns;
 mod special;
+mod standalone_crate;
+mod start;
+mod streaming;
+mod string_alternatives;
+mod string_terminals;
 mod sugar;
+mod synthetic;
+mod terminal_type;
+mod terminal_type_fallible;
+mod terminals_include;
+mod text_annotation;
+mod tokenize;
+mod tracing_events;
 mod unicode;
+mod visibility;
+mod watch;
@@ -0,0 +1,22 @@
+use rustemo::Parser;
+
+use self::nested::NestedParser;
+
+rustemo::rustemo_mod!(nested, "/src/max_depth");
+rustemo::rustemo_mod!(nested_actions, "/src/max_depth");
+
+#[test]
+fn max_depth_within_limit() {
+    let input = format!("{}x{}", "(".repeat(100), ")".repeat(100));
+    assert!(NestedParser::new().max_depth(1000).parse(&input).is_ok());
+}
+
+#[test]
+fn max_depth_exceeded() {
+    // ANCHOR: max-depth
+    let input = format!("{}x{}", "(".repeat(100_000), ")".repeat(100_000));
+    let result = NestedParser::new().max_depth(1000).parse(&input);
+    // ANCHOR_END: max-depth
+    assert!(matches!(result, Err(rustemo::Error::Error { message, .. })
+                     if message == "Maximum parse stack depth of 1000 exceeded."));
+}
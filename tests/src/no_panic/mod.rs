@@ -0,0 +1,43 @@
+use rustemo::rustemo_mod;
+
+use self::calc::CalcParser;
+
+rustemo_mod!(calc, "/src/no_panic");
+rustemo_mod!(calc_actions, "/src/no_panic");
+
+#[test]
+fn no_panic_parse_bytes_lossy() {
+    // ANCHOR: no-panic
+    assert!(CalcParser::parse_bytes_lossy(b"(1 + 2.5) * 3").is_ok());
+    assert!(CalcParser::parse_bytes_lossy(b"(1 + \xff) * 3").is_err());
+    // ANCHOR_END: no-panic
+}
+
+/// Feeds pseudo-random bytes to the parser. Parsing must finish without
+/// panicking regardless of the result.
+#[test]
+fn no_panic_random_bytes() {
+    // Simple xorshift generator to keep the test deterministic.
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+    let alphabet = b"0123456789.+*() \n\xf0\x9f\xff";
+    for _ in 0..2000 {
+        let len = (next() % 32) as usize;
+        let input: Vec<u8> = (0..len)
+            .map(|_| {
+                let r = next();
+                if r % 4 == 0 {
+                    r as u8
+                } else {
+                    alphabet[(r % alphabet.len() as u64) as usize]
+                }
+            })
+            .collect();
+        let _ = CalcParser::parse_bytes_lossy(&input);
+    }
+}
@@ -0,0 +1,27 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::defs::DefsParser;
+
+rustemo_mod!(defs, "/src/normalize_nfc");
+rustemo_mod!(defs_actions, "/src/normalize_nfc");
+
+#[test]
+fn normalize_nfc_identifiers() {
+    // ANCHOR: normalize-nfc
+    // The first name is in NFD ("e" followed by a combining acute accent) and
+    // the second is in NFC ("é").
+    let input = "cafe\u{301} = caf\u{e9};";
+    let mut parser = DefsParser::new();
+    let (result, offsets) = parser.parse_normalized(input).unwrap();
+    assert_eq!(result[0].name_1, result[0].name_3);
+    // Positions in the normalized input are mapped back to the input.
+    assert_eq!(offsets.original_range(8..13), 9..14);
+    // ANCHOR_END: normalize-nfc
+}
+
+#[test]
+fn normalize_nfc_not_normalized() {
+    let input = "cafe\u{301} = caf\u{e9};";
+    let result = DefsParser::new().parse(input).unwrap();
+    assert_ne!(result[0].name_1, result[0].name_3);
+}
@@ -2,12 +2,12 @@
 use std::fmt::Debug;
 use std::hash::Hash;
 use rustemo::{
-    Result, Input as InputT, Lexer, Token, TokenRecognizer as TokenRecognizerT, Parser,
-    ParserDefinition, State as StateT, Builder,
+    Result, Input as InputT, Lexer, Token, Parser, ParserDefinition, State as StateT,
+    Builder,
 };
 use regex::Regex;
 use once_cell::sync::Lazy;
-use rustemo::StringLexer;
+use rustemo::{StringLexer, TokenRecognizer as TokenRecognizerT};
 use rustemo::LRBuilder;
 use super::output_dir_actions;
 use rustemo::{LRParser, LRContext};
@@ -76,6 +76,33 @@ impl From<ProdKind> for NonTermKind {
         }
     }
 }
+#[allow(dead_code)]
+impl ProdKind {
+    /// The non-terminal this production reduces to.
+    pub fn nonterminal(&self) -> NonTermKind {
+        (*self).into()
+    }
+    /// The number of symbols on the right-hand side of this
+    /// production.
+    pub fn rhs_len(&self) -> usize {
+        match self {
+            ProdKind::AP1 => 2usize,
+            ProdKind::B1P1 => 2usize,
+            ProdKind::B1P2 => 1usize,
+            ProdKind::BP1 => 1usize,
+        }
+    }
+    /// The line and the column in the grammar file where this
+    /// production is defined.
+    pub fn source_location(&self) -> (u32, u32) {
+        match self {
+            ProdKind::AP1 => (1u32, 3u32),
+            ProdKind::B1P1 => (1u32, 3u32),
+            ProdKind::B1P2 => (1u32, 3u32),
+            ProdKind::BP1 => (2u32, 3u32),
+        }
+    }
+}
 #[allow(clippy::enum_variant_names)]
 #[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum State {
@@ -113,15 +140,32 @@ impl std::fmt::Debug for State {
     }
 }
 #[derive(Debug)]
-pub enum Symbol {
-    Terminal(Terminal),
+pub enum Symbol<'i> {
+    Terminal(Terminal<'i>),
     NonTerminal(NonTerminal),
 }
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
-pub enum Terminal {
-    Tb,
-    Num(output_dir_actions::Num),
+pub enum Terminal<'i> {
+    Tb(&'i Input),
+    Num(&'i Input, output_dir_actions::Num),
+}
+#[allow(dead_code)]
+impl<'i> Terminal<'i> {
+    /// The input matched by this terminal.
+    pub fn text(&self) -> &'i Input {
+        match *self {
+            Terminal::Tb(text) => text,
+            Terminal::Num(text, _) => text,
+        }
+    }
+    /// The kind of this terminal.
+    pub fn kind(&self) -> TokenKind {
+        match *self {
+            Terminal::Tb(..) => TokenKind::Tb,
+            Terminal::Num(..) => TokenKind::Num,
+        }
+    }
 }
 #[derive(Debug)]
 pub enum NonTerminal {
@@ -129,6 +173,44 @@ pub enum NonTerminal {
     B1(output_dir_actions::B1),
     B(output_dir_actions::B),
 }
+#[allow(dead_code)]
+impl NonTerminal {
+    /// The kind of this non-terminal.
+    pub fn kind(&self) -> NonTermKind {
+        match *self {
+            NonTerminal::A(_) => NonTermKind::A,
+            NonTerminal::B1(_) => NonTermKind::B1,
+            NonTerminal::B(_) => NonTermKind::B,
+        }
+    }
+}
+#[allow(dead_code)]
+impl<'i> Symbol<'i> {
+    /// The name of the grammar symbol of this symbol.
+    pub fn kind_name(&self) -> &'static str {
+        match *self {
+            Symbol::Terminal(Terminal::Tb(..)) => "Tb",
+            Symbol::Terminal(Terminal::Num(..)) => "Num",
+            Symbol::NonTerminal(NonTerminal::A(_)) => "A",
+            Symbol::NonTerminal(NonTerminal::B1(_)) => "B1",
+            Symbol::NonTerminal(NonTerminal::B(_)) => "B",
+        }
+    }
+    /// The kind of this symbol if it is a terminal.
+    pub fn token_kind(&self) -> Option<TokenKind> {
+        match self {
+            Symbol::Terminal(t) => Some(t.kind()),
+            Symbol::NonTerminal(_) => None,
+        }
+    }
+    /// The kind of this symbol if it is a non-terminal.
+    pub fn nonterm_kind(&self) -> Option<NonTermKind> {
+        match self {
+            Symbol::Terminal(_) => None,
+            Symbol::NonTerminal(nt) => Some(nt.kind()),
+        }
+    }
+}
 type ActionFn = fn(token: TokenKind) -> Vec<Action<State, ProdKind>>;
 pub struct OutputDirParserDefinition {
     actions: [ActionFn; STATE_COUNT],
@@ -181,6 +263,7 @@ fn action_b_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         _ => vec![],
     }
 }
+#[allow(unreachable_patterns)]
 fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
         NonTermKind::A => State::AS2,
@@ -194,6 +277,7 @@ fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
         }
     }
 }
+#[allow(unreachable_patterns)]
 fn goto_b1_s3(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
         NonTermKind::B => State::BS6,
@@ -282,7 +366,7 @@ impl<
     'i,
     Input,
     StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
-    DefaultBuilder,
+    DefaultBuilder<'i>,
 > {
     pub fn new() -> Self {
         Self(
@@ -296,6 +380,78 @@ impl<
             ),
         )
     }
+    pub fn parse_all(
+        &self,
+        input: &'i Input,
+    ) -> Result<Vec<<DefaultBuilder<'i> as Builder>::Output>> {
+        self.0.parse(input).map(|output| vec![output])
+    }
+    pub fn parse_str(
+        &self,
+        input: &'i str,
+    ) -> Result<
+        <Self as Parser<'i, Input, Context<'i, Input>, State, TokenKind>>::Output,
+    > {
+        self.parse(input)
+    }
+    pub fn tokenize(input: &'i Input) -> Result<Vec<Token<'i, Input, TokenKind>>> {
+        LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                false,
+                StringLexer::new(true, &RECOGNIZERS),
+                rustemo::TokensBuilder::new(),
+            )
+            .parse(input)
+    }
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self(self.0.max_depth(max_depth))
+    }
+    pub fn parse_opts(
+        &self,
+        input: &'i Input,
+        opts: rustemo::ParseOpts,
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        self.0.parse_opts(input, opts)
+    }
+    pub fn parse_with_driver(
+        &self,
+        input: &'i Input,
+        driver: &mut impl rustemo::ParseDriver<
+            'i,
+            Context<'i, Input>,
+            ProdKind,
+            Input,
+            TokenKind,
+        >,
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        self.0.parse_with_driver(input, driver)
+    }
+    pub fn completions(&self, prefix: &'i Input) -> Vec<TokenKind> {
+        self.0.completions(prefix).unwrap_or_default()
+    }
+    pub fn max_lex_bytes(self, max_lex_bytes: usize) -> Self {
+        Self(self.0.max_lex_bytes(max_lex_bytes))
+    }
+    pub fn lex_timeout(self, lex_timeout: std::time::Duration) -> Self {
+        Self(self.0.lex_timeout(lex_timeout))
+    }
+    pub fn parse_tokens(
+        &self,
+        tokens: &[Token<'_, str, TokenKind>],
+    ) -> Result<<DefaultBuilder<'i> as Builder>::Output> {
+        let input = rustemo::TokensLexer::input(tokens);
+        LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                false,
+                rustemo::TokensLexer::new(tokens),
+                DefaultBuilder::new(),
+            )
+            .parse(&input)
+    }
 }
 #[allow(dead_code)]
 impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind>
@@ -336,9 +492,17 @@ pub enum Recognizer {
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct TokenRecognizer(TokenKind, Recognizer);
-impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
-    fn recognize(&self, input: &'i str) -> Option<&'i str> {
-        match &self {
+impl<'i> TokenRecognizerT<'i, Context<'i, Input>> for TokenRecognizer {
+    #[allow(unused_variables, clippy::only_used_in_recursion)]
+    fn recognize_value(
+        &self,
+        context: &Context<'i, Input>,
+        input: &'i str,
+        position: usize,
+    ) -> Option<(usize, &'i str)> {
+        let whole_input = input;
+        let input = &input[position..];
+        let recognized: Option<&'i str> = match &self {
             #[allow(unused_variables)]
             TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
                 logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
@@ -376,7 +540,8 @@ impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
                     None
                 }
             }
-        }
+        };
+        recognized.map(|x_str| (x_str.len(), x_str))
     }
 }
 pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
@@ -389,16 +554,27 @@ pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
         ),
     ),
 ];
-pub struct DefaultBuilder {
-    res_stack: Vec<Symbol>,
+pub struct DefaultBuilder<'i> {
+    res_stack: Vec<Symbol<'i>>,
 }
-impl DefaultBuilder {
+impl<'i> DefaultBuilder<'i> {
     #[allow(dead_code)]
     pub fn new() -> Self {
         Self { res_stack: vec![] }
     }
+    /// The symbols on the result stack. Used to inspect the
+    /// parsing progress, e.g. from a builder wrapping this one.
+    #[allow(dead_code)]
+    pub fn stack(&self) -> &[Symbol<'i>] {
+        &self.res_stack
+    }
 }
-impl Builder for DefaultBuilder {
+impl Default for DefaultBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Builder for DefaultBuilder<'_> {
     type Output = output_dir_actions::A;
     fn get_result(&mut self) -> Self::Output {
         match self.res_stack.pop().unwrap() {
@@ -408,7 +584,7 @@ impl Builder for DefaultBuilder {
     }
 }
 impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
-for DefaultBuilder {
+for DefaultBuilder<'i> {
     #![allow(unused_variables)]
     fn shift_action(
         &mut self,
@@ -417,8 +593,10 @@ for DefaultBuilder {
     ) {
         let val = match token.kind {
             TokenKind::STOP => panic!("Cannot shift STOP token!"),
-            TokenKind::Tb => Terminal::Tb,
-            TokenKind::Num => Terminal::Num(output_dir_actions::num(&*context, token)),
+            TokenKind::Tb => Terminal::Tb(token.value),
+            TokenKind::Num => {
+                Terminal::Num(token.value, output_dir_actions::num(&*context, token))
+            }
         };
         self.res_stack.push(Symbol::Terminal(val));
     }
@@ -430,23 +608,17 @@ for DefaultBuilder {
     ) {
         let prod = match prod {
             ProdKind::AP1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::B1(p0)),
-                        Symbol::Terminal(Terminal::Num(p1)),
+                        Symbol::Terminal(Terminal::Num(_, p1)),
                     ) => NonTerminal::A(output_dir_actions::a_c1(&*context, p0, p1)),
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
             ProdKind::B1P1 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 2usize..);
                 match (i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::B1(p0)),
@@ -456,10 +628,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::B1P2 => {
-                let mut i = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                let mut i = self.res_stack.drain(self.res_stack.len() - 1usize..);
                 match i.next().unwrap() {
                     Symbol::NonTerminal(NonTerminal::B(p0)) => {
                         NonTerminal::B1(output_dir_actions::b1_b(&*context, p0))
@@ -468,10 +637,7 @@ for DefaultBuilder {
                 }
             }
             ProdKind::BP1 => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
+                self.res_stack.truncate(self.res_stack.len() - 1usize);
                 NonTerminal::B(output_dir_actions::b_tb(context))
             }
         };
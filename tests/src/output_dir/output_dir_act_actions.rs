@@ -15,6 +15,12 @@ pub struct A {
     pub b1: B1,
     pub num: Num,
 }
+#[allow(dead_code)]
+impl A {
+    pub fn new(b1: B1, num: Num) -> Self {
+        Self { b1, num }
+    }
+}
 pub fn a_c1(_ctx: &Ctx, b1: B1, num: Num) -> A {
     A { b1, num }
 }
@@ -30,6 +36,12 @@ pub fn b1_b(_ctx: &Ctx, b: B) -> B1 {
 pub enum B {
     Tb,
 }
+#[allow(dead_code)]
+impl B {
+    pub fn new_tb() -> Self {
+        Self::Tb
+    }
+}
 pub fn b_tb(_ctx: &Ctx) -> B {
     B::Tb
 }
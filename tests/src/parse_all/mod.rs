@@ -0,0 +1,26 @@
+use rustemo::rustemo_mod;
+use rustemo_compiler::output_cmp;
+
+use self::ambig::AmbigParser;
+use self::calc::CalcParser;
+
+rustemo_mod!(calc, "/src/parse_all");
+rustemo_mod!(calc_actions, "/src/parse_all");
+rustemo_mod!(ambig, "/src/parse_all");
+rustemo_mod!(ambig_actions, "/src/parse_all");
+
+#[test]
+fn parse_all_deterministic() {
+    let result = CalcParser::new().parse_all("1 + 2 * 3").unwrap();
+    assert_eq!(result.len(), 1);
+    output_cmp!("src/parse_all/calc.ast", format!("{:#?}", result));
+}
+
+// ANCHOR: parse-all
+#[test]
+fn parse_all_ambiguous() {
+    let result = AmbigParser::new().parse_all("1 + 2 * 3").unwrap();
+    assert_eq!(result.len(), 2);
+    output_cmp!("src/parse_all/ambig.ast", format!("{:#?}", result));
+}
+// ANCHOR_END: parse-all
@@ -0,0 +1,134 @@
+use rustemo::{ParseDriver, Parser, Recovery, Token};
+
+use self::calc::{CalcParser, Context, ProdKind, TokenKind};
+
+rustemo::rustemo_mod!(calc, "/src/parse_driver");
+rustemo::rustemo_mod!(calc_actions, "/src/parse_driver");
+
+// ANCHOR: parse-driver
+/// Inserts missing closing parentheses and records the actions taken.
+#[derive(Default)]
+struct CloseParens {
+    actions: Vec<String>,
+    inserted: usize,
+}
+
+impl<'i> ParseDriver<'i, Context<'i, str>, ProdKind, str, TokenKind>
+    for CloseParens
+{
+    fn shift(
+        &mut self,
+        _context: &Context<'i, str>,
+        token: &Token<'i, str, TokenKind>,
+    ) {
+        self.actions.push(format!("shift {:?}", token.kind));
+    }
+
+    fn reduce(
+        &mut self,
+        _context: &Context<'i, str>,
+        prod: ProdKind,
+        _prod_len: usize,
+    ) {
+        self.actions.push(format!("reduce {prod:?}"));
+    }
+
+    fn error(
+        &mut self,
+        _context: &Context<'i, str>,
+        expected: &[TokenKind],
+        _error: &rustemo::Error,
+    ) -> Recovery<TokenKind> {
+        if expected.contains(&TokenKind::RParen) {
+            self.inserted += 1;
+            Recovery::Insert(TokenKind::RParen)
+        } else {
+            Recovery::Fail
+        }
+    }
+}
+
+#[test]
+fn parse_driver_insert_token() {
+    let mut driver = CloseParens::default();
+    let result = CalcParser::new()
+        .parse_with_driver("2 * (3 + (4", &mut driver)
+        .unwrap();
+    assert_eq!(driver.inserted, 2);
+    assert_eq!(
+        format!("{result:?}"),
+        format!("{:?}", CalcParser::new().parse("2 * (3 + (4))").unwrap())
+    );
+}
+// ANCHOR_END: parse-driver
+
+#[test]
+fn parse_driver_actions() {
+    let mut driver = CloseParens::default();
+    CalcParser::new()
+        .parse_with_driver("(1)", &mut driver)
+        .unwrap();
+    assert_eq!(
+        driver.actions,
+        [
+            "shift LParen",
+            "shift Num",
+            "reduce F: Num",
+            "reduce T: F",
+            "reduce E: T",
+            "shift RParen",
+            "reduce F: LParen E RParen",
+            "reduce T: F",
+            "reduce E: T",
+        ]
+    );
+    assert_eq!(driver.inserted, 0);
+}
+
+/// Skips the input that can't be recognized.
+struct SkipUnknown;
+
+impl<'i> ParseDriver<'i, Context<'i, str>, ProdKind, str, TokenKind>
+    for SkipUnknown
+{
+    fn error(
+        &mut self,
+        _context: &Context<'i, str>,
+        _expected: &[TokenKind],
+        _error: &rustemo::Error,
+    ) -> Recovery<TokenKind> {
+        Recovery::Skip(1)
+    }
+}
+
+#[test]
+fn parse_driver_skip_input() {
+    let result = CalcParser::new()
+        .parse_with_driver("2 + # 3 ?", &mut SkipUnknown)
+        .unwrap();
+    assert_eq!(
+        format!("{result:?}"),
+        format!("{:?}", CalcParser::new().parse("2 + 3").unwrap())
+    );
+}
+
+#[test]
+fn parse_driver_skip_multibyte_input() {
+    // Skipping a byte of a multibyte char skips the whole char.
+    let result = CalcParser::new()
+        .parse_with_driver("2 + é 3 ü", &mut SkipUnknown)
+        .unwrap();
+    assert_eq!(
+        format!("{result:?}"),
+        format!("{:?}", CalcParser::new().parse("2 + 3").unwrap())
+    );
+}
+
+#[test]
+fn parse_driver_fail() {
+    let result = CalcParser::new().parse_with_driver("2 + * 3", &mut ());
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        CalcParser::new().parse("2 + * 3").unwrap_err().to_string()
+    );
+}
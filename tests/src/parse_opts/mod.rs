@@ -0,0 +1,29 @@
+use rustemo::{ParseOpts, Parser};
+
+use self::calc::CalcParser;
+
+rustemo::rustemo_mod!(calc, "/src/parse_opts");
+rustemo::rustemo_mod!(calc_actions, "/src/parse_opts");
+
+#[test]
+fn parse_opts_require_eof() {
+    let parser = CalcParser::new();
+    let result = parser.parse_opts("1+2 3", ParseOpts { require_eof: true });
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error at <str>:[1,4]:\n\t...1+2 -->3...\n\tExpected one of STOP, Plus."
+    );
+    assert!(parser.parse_opts("1+2", ParseOpts::default()).is_ok());
+}
+
+#[test]
+fn parse_opts_partial() {
+    // ANCHOR: parse-opts
+    let parser = CalcParser::new();
+    let result = parser.parse_opts("1+2 3", ParseOpts { require_eof: false });
+    // ANCHOR_END: parse-opts
+    assert!(result.is_ok());
+
+    // The configuration of the parser is restored after the call.
+    assert!(parser.parse("1+2 3").is_err());
+}
@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use rustemo::Parser;
+
+use self::type_names::{Context, TypeNamesParser};
+use self::type_names_actions::Statement;
+use self::type_names_predicates::TypeNames;
+
+mod type_names_predicates;
+
+rustemo::rustemo_mod!(type_names, "/src/predicates");
+rustemo::rustemo_mod!(type_names_actions, "/src/predicates");
+
+fn parse(type_names: &[&str], input: &'static str) -> Vec<Statement> {
+    let mut context = Context::default();
+    context.set_user_state(Rc::new(
+        type_names
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<TypeNames>(),
+    ));
+    TypeNamesParser::new()
+        .parse_with_context(&mut context, input)
+        .unwrap()
+}
+
+#[test]
+fn predicate_type_names() {
+    let statements = parse(&["foo"], "foo * a; bar * b;");
+    assert!(matches!(
+        &statements[..],
+        [Statement::Declaration(decl), Statement::Expression(expr)]
+            if decl.type_name == "foo" && expr.left == "bar"
+    ));
+
+    let statements = parse(&["bar"], "foo * a; bar * b;");
+    assert!(matches!(
+        &statements[..],
+        [Statement::Expression(_), Statement::Declaration(_)]
+    ));
+}
+
+#[test]
+fn predicate_no_user_state() {
+    let statements = TypeNamesParser::new().parse("foo * a;").unwrap();
+    assert!(matches!(&statements[..], [Statement::Expression(_)]));
+}
@@ -0,0 +1,20 @@
+use super::type_names::{Context, Input, TokenKind};
+use rustemo::{Context as _, Token};
+use std::collections::BTreeSet;
+
+/// Type names known to the parser. Kept as the user state of the context.
+pub type TypeNames = BTreeSet<String>;
+
+// ANCHOR: predicate
+/// Reduces `Ident` to `TypeName` only if it is a known type name. The range of
+/// the context is the range of the reduction.
+pub fn is_type_name<'i>(
+    context: &Context<'i, Input>,
+    input: &'i Input,
+    _token: &Token<'i, Input, TokenKind>,
+) -> bool {
+    context
+        .user_state::<TypeNames>()
+        .is_some_and(|names| names.contains(&input[context.range()]))
+}
+// ANCHOR_END: predicate
@@ -0,0 +1,17 @@
+use rustemo::rustemo_mod;
+
+use self::lines::LinesParser;
+
+rustemo_mod!(lines, "/src/preprocess");
+rustemo_mod!(lines_actions, "/src/preprocess");
+
+#[test]
+fn preprocess_bom_crlf() {
+    // ANCHOR: preprocess
+    let mut parser = LinesParser::new();
+    let result = parser
+        .parse_preprocessed("\u{feff}first line\r\nsecond line\r\n")
+        .unwrap();
+    assert_eq!(result, vec!["first line\n", "second line\n"]);
+    // ANCHOR_END: preprocess
+}
@@ -0,0 +1,22 @@
+use rustemo::rustemo_mod;
+
+use self::calc::{NonTermKind, ProdKind};
+
+rustemo_mod!(calc, "/src/prod_kind");
+rustemo_mod!(calc_actions, "/src/prod_kind");
+
+#[test]
+fn prod_kind_queries() {
+    assert_eq!(ProdKind::EP1.rhs_len(), 3);
+    assert!(matches!(ProdKind::EP1.nonterminal(), NonTermKind::E));
+    assert_eq!(ProdKind::EP3.rhs_len(), 1);
+    assert!(matches!(ProdKind::EP3.nonterminal(), NonTermKind::E));
+}
+
+#[test]
+fn prod_kind_source_location() {
+    assert_eq!(ProdKind::EP1.source_location(), (1, 3));
+    assert_eq!(ProdKind::EP3.source_location(), (3, 3));
+    assert_eq!(ProdKind::AtomP1.source_location(), (6, 6));
+    assert_eq!(ProdKind::AtomP2.source_location(), (7, 6));
+}
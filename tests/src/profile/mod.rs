@@ -0,0 +1,30 @@
+use rustemo::rustemo_mod;
+
+use self::calc::CalcParser;
+
+rustemo_mod!(calc, "/src/profile");
+rustemo_mod!(calc_actions, "/src/profile");
+
+#[test]
+fn profile_reduction_counts() {
+    // ANCHOR: profile
+    let (_, stats) =
+        CalcParser::new().parse_with_stats("1 + 2 * 3 + 4").unwrap();
+
+    assert_eq!(stats.productions["E: E Plus E"], 2);
+    assert_eq!(stats.productions["E: E Mul E"], 1);
+    assert_eq!(stats.productions["E: Num"], 4);
+    assert_eq!(stats.terminals["Num"], 4);
+    assert_eq!(stats.terminals["Plus"], 2);
+    assert_eq!(stats.terminals["Mul"], 1);
+    // ANCHOR_END: profile
+    // Start state is entered once, plus once for each shift and reduction.
+    assert_eq!(stats.states.values().sum::<usize>(), 1 + 7 + 7);
+
+    // Statistics are reset on each parse.
+    let parser = CalcParser::new();
+    parser.parse_with_stats("1 + 2").unwrap();
+    let (_, stats) = parser.parse_with_stats("1").unwrap();
+    assert_eq!(stats.productions.len(), 1);
+    assert_eq!(stats.terminals["Num"], 1);
+}
@@ -0,0 +1,54 @@
+use std::time::Instant;
+
+use rustemo::Parser;
+
+use self::dispatch_match::KeywordsParser as MatchParser;
+use self::keywords::KeywordsParser;
+
+rustemo::rustemo_mod!(keywords, "/src/reduce_dispatch");
+rustemo::rustemo_mod!(keywords_actions, "/src/reduce_dispatch");
+
+mod dispatch_match {
+    pub use self::keywords::KeywordsParser;
+
+    rustemo::rustemo_mod!(keywords, "/src/reduce_dispatch/match");
+    rustemo::rustemo_mod!(keywords_actions, "/src/reduce_dispatch/match");
+}
+
+fn input(statements: usize) -> String {
+    (0..statements)
+        .map(|i| format!("a{:02} {i};", i % 60 + 1))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn reduce_dispatch_table() {
+    let input = input(120);
+    let result = KeywordsParser::new().parse(&input).unwrap();
+    let expected = MatchParser::new().parse(&input).unwrap();
+    assert_eq!(result.len(), 120);
+    assert_eq!(format!("{result:?}"), format!("{expected:?}"));
+}
+
+/// Compares the cost of reductions dispatched through the table and by
+/// `match`. Run with `cargo test --release -- --ignored reduce_dispatch`.
+#[test]
+#[ignore]
+fn reduce_dispatch_benchmark() {
+    let input = input(10_000);
+
+    let start = Instant::now();
+    for _ in 0..10 {
+        KeywordsParser::new().parse(&input).unwrap();
+    }
+    let table = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..10 {
+        MatchParser::new().parse(&input).unwrap();
+    }
+    let matched = start.elapsed();
+
+    println!("Dispatch table: {table:?}, match: {matched:?}");
+}
@@ -0,0 +1,89 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rustemo::{
+    rustemo_mod, Context as _, LRContext, Lexer, Parser, StringLexer,
+    TokenRecognizer as _,
+};
+use rustemo_compiler::output_cmp;
+
+use self::regex_set::{
+    regex_prefilter, Context, RegexSetParser, TokenKind, TokenRecognizer,
+    RECOGNIZERS,
+};
+
+rustemo_mod!(regex_set, "/src/regex_set");
+rustemo_mod!(regex_set_actions, "/src/regex_set");
+
+const INPUT: &str = r#"42 abc XYZ "str" 'c' #00ff00 $var @me %15 ~3.14"#;
+
+#[test]
+fn regex_set() {
+    let result = RegexSetParser::new().parse(INPUT);
+    output_cmp!("src/regex_set/regex_set.ast", format!("{:#?}", result));
+}
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts the calls of the wrapped recognizer.
+struct Counting(&'static TokenRecognizer);
+
+impl<'i> rustemo::TokenRecognizer<'i, Context<'i, str>> for Counting {
+    fn recognize_value(
+        &self,
+        context: &Context<'i, str>,
+        input: &'i str,
+        position: usize,
+    ) -> Option<(usize, &'i str)> {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        self.0.recognize_value(context, input, position)
+    }
+}
+
+/// Lexes the whole input trying all terminals at each position. Returns the
+/// recognized tokens and the number of recognizer calls.
+fn lex<const N: usize>(
+    lexer: &StringLexer<
+        Context<'static, str>,
+        regex_set::State,
+        TokenKind,
+        Counting,
+        N,
+    >,
+) -> (Vec<(TokenKind, &'static str)>, usize) {
+    use TokenKind::*;
+    let expected = [
+        Int, Ident, Upper, Str, Char, Color, Var, Mention, Percent, Float,
+    ];
+    CALLS.store(0, Ordering::Relaxed);
+    let mut context = LRContext::new(0);
+    let mut tokens = vec![];
+    while let Some(token) = lexer
+        .next_tokens(
+            &mut context,
+            INPUT,
+            expected.iter().map(|&kind| (kind, true)).collect(),
+        )
+        .next()
+    {
+        context.set_position(context.position() + token.value.len());
+        tokens.push((token.kind, token.value));
+    }
+    (tokens, CALLS.load(Ordering::Relaxed))
+}
+
+#[test]
+fn regex_set_fewer_evaluations() {
+    let counting = &*Box::leak(Box::new(std::array::from_fn(|idx| {
+        Counting(&RECOGNIZERS[idx])
+    })));
+
+    let (tokens, calls) = lex(&StringLexer::new(true, counting));
+    let (prefiltered_tokens, prefiltered_calls) =
+        lex(&StringLexer::new(true, counting).prefilter(regex_prefilter));
+
+    // Each regex is evaluated only for the terminal matched by the regex set.
+    assert_eq!(tokens.len(), 10);
+    assert_eq!(tokens, prefiltered_tokens);
+    assert_eq!(prefiltered_calls, tokens.len());
+    assert!(calls > 4 * prefiltered_calls);
+}
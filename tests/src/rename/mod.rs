@@ -0,0 +1,28 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::rename::RenameParser;
+use self::rename_actions::{Decl, Type_};
+
+// `Type_` is not in upper camel case.
+rustemo_mod!(
+    #[allow(nonstandard_style)]
+    rename,
+    "/src/rename"
+);
+rustemo_mod!(
+    #[allow(nonstandard_style)]
+    rename_actions,
+    "/src/rename"
+);
+
+#[test]
+fn rename_keyword_symbol() {
+    let result = RenameParser::new().parse("bool flag").unwrap();
+    assert!(matches!(
+        result,
+        Decl {
+            type_: Type_::Bool,
+            name,
+        } if name == "flag"
+    ));
+}
@@ -0,0 +1,13 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::rr_by_order::RrByOrderParser;
+
+rustemo_mod!(rr_by_order, "/src/rr_by_order");
+rustemo_mod!(rr_by_order_actions, "/src/rr_by_order");
+
+#[test]
+fn rr_by_order() {
+    let result = RrByOrderParser::new().parse("a; b;");
+    output_cmp!("src/rr_by_order/rr_by_order.ast", format!("{:#?}", result));
+}
@@ -0,0 +1,28 @@
+use rustemo::Parser;
+use rustemo_compiler::output_cmp;
+
+use self::statements::StatementsParser;
+
+rustemo::rustemo_mod!(statements, "/src/rule_parsers");
+rustemo::rustemo_mod!(statements_actions, "/src/rule_parsers");
+
+#[test]
+fn rule_parsers() {
+    // ANCHOR: rule-parsers
+    let expr = StatementsParser::parse_expr("1 + (2 + 3)");
+    // ANCHOR_END: rule-parsers
+    output_cmp!("src/rule_parsers/expr.ast", format!("{expr:#?}"));
+
+    let statement = StatementsParser::parse_statement("a = 1 + 2;").unwrap();
+    assert_eq!(statement.name, "a");
+
+    // The whole input must be parsed as the rule.
+    let result = StatementsParser::parse_term("1 + 2");
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error at <str>:[1,2]:\n\t...1 -->+ 2...\n\tExpected STOP."
+    );
+
+    // The start rule is still used by `parse`.
+    assert!(StatementsParser::new().parse("a = 1; b = (2);").is_ok());
+}
@@ -17,6 +17,10 @@ rustemo_mod!(optional, "/src/rule_patterns");
 rustemo_mod!(optional_actions, "/src/rule_patterns");
 use self::optional::OptionalParser;
 
+rustemo_mod!(empty_chain, "/src/rule_patterns");
+rustemo_mod!(empty_chain_actions, "/src/rule_patterns");
+use self::empty_chain::EmptyChainParser;
+
 #[test]
 fn zero_or_more_1() {
     let result = ZeroOrMore1Parser::new().parse("1 2 3");
@@ -50,3 +54,20 @@ fn optional() {
 
     output_cmp!("src/rule_patterns/optional.ast", format!("{result:#?}"));
 }
+
+#[test]
+fn empty_chain() {
+    let result = EmptyChainParser::new().parse("  1");
+
+    output_cmp!("src/rule_patterns/empty_chain.ast", format!("{result:#?}"));
+}
+
+#[test]
+fn empty_chain_empty_input() {
+    let result = EmptyChainParser::new().parse("");
+
+    output_cmp!(
+        "src/rule_patterns/empty_chain_empty_input.ast",
+        format!("{result:#?}")
+    );
+}
@@ -0,0 +1,52 @@
+use std::{env, fs, path::Path, process::Command};
+
+use rustemo_compiler::Settings;
+
+/// The generated crate is compiled against the local rustemo and the
+/// dependencies already available to the workspace.
+#[test]
+fn standalone_crate() {
+    let crate_dir = env::temp_dir().join("rustemo_standalone_crate");
+    let _ = fs::remove_dir_all(&crate_dir);
+    rustemo_compiler::generate_parser_from_source(
+        include_str!("calc.rustemo"),
+        "calc",
+        &crate_dir,
+        &Settings::new().standalone_crate(true),
+    )
+    .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(crate_dir.join("lib.rs")).unwrap(),
+        "//! Generated by rustemo. Do not edit manually!\n\
+         pub mod calc;\n\
+         pub mod calc_actions;\n"
+    );
+
+    let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut manifest =
+        fs::read_to_string(crate_dir.join("Cargo.toml")).unwrap();
+    manifest.push_str(&format!(
+        "\n[patch.crates-io]\nrustemo = {{ path = {:?} }}\n",
+        workspace_dir.join("rustemo")
+    ));
+    fs::write(crate_dir.join("Cargo.toml"), manifest).unwrap();
+    fs::copy(
+        workspace_dir.join("Cargo.lock"),
+        crate_dir.join("Cargo.lock"),
+    )
+    .unwrap();
+
+    let status =
+        Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+            .args(["check", "--offline", "--quiet"])
+            .current_dir(&crate_dir)
+            .env("RUSTFLAGS", "-A warnings")
+            .env(
+                "CARGO_TARGET_DIR",
+                env::temp_dir().join("rustemo_standalone_crate_target"),
+            )
+            .status()
+            .unwrap();
+    assert!(status.success());
+}
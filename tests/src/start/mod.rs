@@ -0,0 +1,17 @@
+use rustemo::Parser;
+
+use self::start::StartParser;
+
+rustemo::rustemo_mod!(start, "/src/start");
+rustemo::rustemo_mod!(start_actions, "/src/start");
+
+#[test]
+fn start_directive() {
+    // `Program` is the start rule even though `Statement` is given first.
+    let program = StartParser::new().parse("a = 1; b = 2;").unwrap();
+    let program = program.unwrap();
+    assert_eq!(program.len(), 2);
+    assert_eq!(program[1].name, "b");
+
+    assert!(StartParser::new().parse("").unwrap().is_none());
+}
@@ -0,0 +1,73 @@
+use rustemo::{rustemo_mod, LRParser, Parser, StringLexer};
+
+use self::records::{
+    DefaultBuilder, DefaultStreamBuilder, RecordsParser, State,
+    PARSER_DEFINITION, RECOGNIZERS,
+};
+
+rustemo_mod!(records, "/src/streaming");
+rustemo_mod!(records_actions, "/src/streaming");
+
+// ANCHOR: streaming
+#[test]
+fn streaming() {
+    let input = (0..1000).map(|i| format!("r: {i};\n")).collect::<String>();
+    let mut count = 0;
+    for record in RecordsParser::parse_iter(&input) {
+        let record = record.unwrap();
+        assert_eq!(record.value, count.to_string());
+        count += 1;
+    }
+    assert_eq!(count, 1000);
+}
+// ANCHOR_END: streaming
+
+#[test]
+fn streaming_error() {
+    let mut records = RecordsParser::parse_iter("a: 1; b: 2; c 3;");
+    assert_eq!(records.next().unwrap().unwrap().name, "a");
+    assert_eq!(records.next().unwrap().unwrap().name, "b");
+    assert!(records.next().unwrap().is_err());
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn streaming_empty() {
+    assert_eq!(RecordsParser::parse_iter("").count(), 0);
+}
+
+#[test]
+fn streaming_builders_default() {
+    let input = "a: 1; b: 2;";
+    let lexer = || StringLexer::new(true, &RECOGNIZERS);
+    let parser = |builder: DefaultBuilder<'static>| {
+        LRParser::new(
+            &PARSER_DEFINITION,
+            State::default(),
+            false,
+            false,
+            lexer(),
+            builder,
+        )
+    };
+    assert_eq!(
+        format!("{:?}", parser(DefaultBuilder::new()).parse(input)),
+        format!("{:?}", parser(DefaultBuilder::default()).parse(input)),
+    );
+
+    let stream_parser = |builder: DefaultStreamBuilder<'static>| {
+        LRParser::new(
+            &PARSER_DEFINITION,
+            State::default(),
+            false,
+            false,
+            lexer(),
+            builder,
+        )
+        .parse_iter(input)
+        .map(|record| record.unwrap().name)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(stream_parser(DefaultStreamBuilder::new()), ["a", "b"]);
+    assert_eq!(stream_parser(DefaultStreamBuilder::default()), ["a", "b"]);
+}
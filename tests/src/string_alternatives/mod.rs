@@ -0,0 +1,41 @@
+use rustemo::Parser;
+
+use self::compare::{CompareParser, TokenKind};
+
+rustemo::rustemo_mod!(compare, "/src/string_alternatives");
+rustemo::rustemo_mod!(compare_actions, "/src/string_alternatives");
+
+// ANCHOR: string_alternatives
+#[test]
+fn string_alternatives_tokenize() {
+    let tokens = CompareParser::tokenize("true <= false false<true").unwrap();
+    assert_eq!(
+        tokens
+            .iter()
+            .map(|token| (token.kind, token.value))
+            .collect::<Vec<_>>(),
+        [
+            (TokenKind::Bool, "true"),
+            (TokenKind::Op, "<="),
+            (TokenKind::Bool, "false"),
+            (TokenKind::Bool, "false"),
+            (TokenKind::Op, "<"),
+            (TokenKind::Bool, "true"),
+        ]
+    );
+}
+// ANCHOR_END: string_alternatives
+
+#[test]
+fn string_alternatives_parse() {
+    let comparisons = CompareParser::new().parse("true == false").unwrap();
+    assert_eq!(comparisons.len(), 1);
+    assert_eq!(comparisons[0].left, "true");
+    assert_eq!(comparisons[0].op, "==");
+    assert_eq!(comparisons[0].right, "false");
+}
+
+#[test]
+fn string_alternatives_error() {
+    assert!(CompareParser::tokenize("true = false").is_err());
+}
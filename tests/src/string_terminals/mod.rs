@@ -0,0 +1,24 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::commands::CommandsParser;
+use self::commands_actions::Command;
+
+rustemo_mod!(commands, "/src/string_terminals");
+rustemo_mod!(commands_actions, "/src/string_terminals");
+
+#[test]
+fn string_terminals() {
+    let commands = CommandsParser::new().parse("up up left down").unwrap();
+    assert!(matches!(
+        commands[..],
+        [Command::Up, Command::Up, Command::Left, Command::Down]
+    ));
+
+    // The regex crates are not used if there are no regex terminals.
+    let parser = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/src/string_terminals/commands.rs"
+    ));
+    assert!(!parser.to_lowercase().contains("regex"));
+    assert!(!parser.contains("Lazy"));
+}
@@ -0,0 +1,34 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::iterate::IterateParser;
+
+rustemo_mod!(iterate, "/src/sugar/iterate");
+rustemo_mod!(iterate_actions, "/src/sugar/iterate");
+
+#[test]
+fn iterate_one_or_more() {
+    // ANCHOR: iterate
+    let model = IterateParser::new().parse("one 1 2 3 zero").unwrap();
+    let mut nums = vec![];
+    for num in model.one_or_more {
+        nums.push(num);
+    }
+    // ANCHOR_END: iterate
+    assert_eq!(nums, ["1", "2", "3"]);
+}
+
+#[test]
+fn iterate_zero_or_more() {
+    let model = IterateParser::new().parse("one 1 zero 4 5").unwrap();
+    assert_eq!(model.zero_or_more.len(), 2);
+    assert_eq!(model.zero_or_more[0], "4");
+    let mut nums = vec![];
+    for num in &model.zero_or_more {
+        nums.push(num.as_str());
+    }
+    assert_eq!(nums, ["4", "5"]);
+
+    let model = IterateParser::new().parse("one 1 zero").unwrap();
+    assert!(model.zero_or_more.is_empty());
+    assert_eq!(model.zero_or_more.into_iter().count(), 0);
+}
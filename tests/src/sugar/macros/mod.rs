@@ -0,0 +1,13 @@
+use rustemo::{rustemo_mod, Parser};
+use rustemo_compiler::output_cmp;
+
+use self::macros::MacrosParser;
+
+rustemo_mod!(macros, "/src/sugar/macros");
+rustemo_mod!(macros_actions, "/src/sugar/macros");
+
+#[test]
+fn rule_macros() {
+    let result = MacrosParser::new().parse("io.print(1, 2, 3); exit();");
+    output_cmp!("src/sugar/macros/macros.ast", format!("{result:#?}"));
+}
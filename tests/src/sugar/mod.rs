@@ -1,3 +1,6 @@
+mod iterate;
+mod macros;
 mod one_or_more;
 mod optional;
+mod right_recursive;
 mod zero_or_more;
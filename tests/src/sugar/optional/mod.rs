@@ -3,6 +3,7 @@ use rustemo_compiler::output_cmp;
 
 use self::optional_1::Optional1Parser;
 use self::optional_2::Optional2Parser;
+use self::optional_3::Optional3Parser;
 
 rustemo_mod!(optional_1, "/src/sugar/optional");
 rustemo_mod!(optional_1_actions, "/src/sugar/optional");
@@ -10,6 +11,9 @@ rustemo_mod!(optional_1_actions, "/src/sugar/optional");
 rustemo_mod!(optional_2, "/src/sugar/optional");
 rustemo_mod!(optional_2_actions, "/src/sugar/optional");
 
+rustemo_mod!(optional_3, "/src/sugar/optional");
+rustemo_mod!(optional_3_actions, "/src/sugar/optional");
+
 // ANCHOR: optional1
 #[test]
 fn optional_1_1() {
@@ -101,3 +105,13 @@ fn optional_2_2_error() {
         result.unwrap_err().to_string()
     );
 }
+
+#[test]
+fn optional_no_content() {
+    // Optional terminal without content is not passed to the action.
+    let _: fn(&optional_3_actions::Ctx, optional_3_actions::Expr) -> _ =
+        optional_3_actions::stmt_expr;
+
+    assert_eq!(Optional3Parser::new().parse("42;").unwrap(), "42");
+    assert_eq!(Optional3Parser::new().parse("42").unwrap(), "42");
+}
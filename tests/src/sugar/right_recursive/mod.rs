@@ -0,0 +1,15 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::right_recursive::RightRecursiveParser;
+
+rustemo_mod!(right_recursive, "/src/sugar/right_recursive");
+rustemo_mod!(right_recursive_actions, "/src/sugar/right_recursive");
+
+#[test]
+fn right_recursive_long_list() {
+    let nums: Vec<String> = (0..10_000).map(|n| n.to_string()).collect();
+    let input = format!("{} sep {}", nums.join(" "), nums.join(", "));
+    let model = RightRecursiveParser::new().parse(&input).unwrap();
+    assert_eq!(model.plain, nums);
+    assert_eq!(model.separated, nums);
+}
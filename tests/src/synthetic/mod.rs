@@ -0,0 +1,20 @@
+use rustemo::Parser;
+
+use self::calc::{CalcParser, NonTermKind, ProdKind};
+
+rustemo::rustemo_mod!(calc, "/src/synthetic");
+rustemo::rustemo_mod!(calc_actions, "/src/synthetic");
+
+#[test]
+fn exclude_synthetic() {
+    // Matches are exhaustive, thus synthetic kinds would fail compilation.
+    let nonterm = |nonterm: NonTermKind| match nonterm {
+        NonTermKind::E => "E",
+    };
+    let prod = |prod: ProdKind| match prod {
+        ProdKind::EAdd | ProdKind::EMul | ProdKind::EP3 => nonterm(prod.into()),
+    };
+    assert_eq!(prod(ProdKind::EAdd), "E");
+
+    assert!(CalcParser::new().parse("1 + 2 * 3").is_ok());
+}
@@ -0,0 +1,72 @@
+use rustemo::{
+    rustemo_mod, Builder, LRBuilder, LRParser, Parser, StringLexer, Token,
+};
+
+use self::sum::{
+    Context, DefaultBuilder, ProdKind, State, SumParser, Symbol, TokenKind,
+    PARSER_DEFINITION, RECOGNIZERS,
+};
+
+rustemo_mod!(sum, "/src/terminal_type");
+rustemo_mod!(sum_actions, "/src/terminal_type");
+
+#[test]
+fn terminal_inline_type() {
+    let result = SumParser::new().parse("12 + 3 + 100").unwrap();
+    let total: u32 = result.iter().sum();
+    assert_eq!(total, 115);
+}
+
+/// Collects the text of the terminals shifted by the default builder.
+struct TextBuilder<'i> {
+    builder: DefaultBuilder<'i>,
+    texts: Vec<&'i str>,
+}
+
+impl<'i> Builder for TextBuilder<'i> {
+    type Output = Vec<&'i str>;
+
+    fn get_result(&mut self) -> Self::Output {
+        std::mem::take(&mut self.texts)
+    }
+}
+
+impl<'i> LRBuilder<'i, str, Context<'i, str>, State, ProdKind, TokenKind>
+    for TextBuilder<'i>
+{
+    fn shift_action(
+        &mut self,
+        context: &mut Context<'i, str>,
+        token: Token<'i, str, TokenKind>,
+    ) {
+        self.builder.shift_action(context, token);
+        if let Some(Symbol::Terminal(terminal)) = self.builder.stack().last() {
+            self.texts.push(terminal.text());
+        }
+    }
+
+    fn reduce_action(
+        &mut self,
+        context: &mut Context<'i, str>,
+        prod: ProdKind,
+        prod_len: usize,
+    ) {
+        self.builder.reduce_action(context, prod, prod_len);
+    }
+}
+
+#[test]
+fn terminal_text() {
+    let parser = LRParser::new(
+        &PARSER_DEFINITION,
+        State::default(),
+        false,
+        false,
+        StringLexer::new(true, &RECOGNIZERS),
+        TextBuilder {
+            builder: DefaultBuilder::new(),
+            texts: vec![],
+        },
+    );
+    assert_eq!(parser.parse("12 + 3").unwrap(), ["12", "+", "3"]);
+}
@@ -0,0 +1,22 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::bytes::BytesParser;
+
+rustemo_mod!(bytes, "/src/terminal_type_fallible");
+rustemo_mod!(bytes_actions, "/src/terminal_type_fallible");
+
+#[test]
+fn terminal_type_fallible() {
+    let result = BytesParser::new().parse("1 200 3").unwrap();
+    assert_eq!(
+        result.iter().map(|b| b.get()).collect::<Vec<_>>(),
+        [1, 200, 3]
+    );
+
+    // Values which can't be converted are rejected at their location.
+    let result = BytesParser::new().parse("1 300 3");
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error at [1,2-1,5]:\n\tInvalid Byte value."
+    );
+}
@@ -0,0 +1,30 @@
+use rustemo::Parser;
+
+use self::assign::{AssignParser, TokenKind as AssignTK};
+use self::calc::{CalcParser, TokenKind as CalcTK};
+
+rustemo::rustemo_mod!(calc, "/src/terminals_include");
+rustemo::rustemo_mod!(calc_actions, "/src/terminals_include");
+rustemo::rustemo_mod!(assign, "/src/terminals_include");
+rustemo::rustemo_mod!(assign_actions, "/src/terminals_include");
+
+#[test]
+fn terminals_include_parse() {
+    assert!(CalcParser::new().parse("1 + a + 2").is_ok());
+    assert!(AssignParser::new().parse("a = 1; b = a;").is_ok());
+}
+
+#[test]
+fn terminals_include_indexes() {
+    // Both grammars get the same terminal indexes from the included file.
+    let kinds = [
+        (CalcTK::Num, AssignTK::Num),
+        (CalcTK::Ident, AssignTK::Ident),
+        (CalcTK::Plus, AssignTK::Plus),
+        (CalcTK::Equals, AssignTK::Equals),
+        (CalcTK::SemiColon, AssignTK::SemiColon),
+    ];
+    for (calc_kind, assign_kind) in kinds {
+        assert_eq!(usize::from(calc_kind), usize::from(assign_kind));
+    }
+}
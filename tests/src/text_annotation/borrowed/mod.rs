@@ -0,0 +1,20 @@
+use rustemo::Parser;
+
+use self::assignments::AssignmentsParser;
+
+rustemo::rustemo_mod!(assignments, "/src/text_annotation/borrowed");
+rustemo::rustemo_mod!(assignments_actions, "/src/text_annotation/borrowed");
+
+#[test]
+fn text_annotation_borrowed() {
+    let input = "a = b + (1 +2); c = 3;";
+    let assignments = AssignmentsParser::new().parse(input).unwrap();
+    let values: Vec<&str> = assignments.iter().map(|a| a.value).collect();
+    assert_eq!(values, ["b + (1 +2)", "3"]);
+
+    // The values are slices of the input.
+    let input_range = input.as_bytes().as_ptr_range();
+    for value in values {
+        assert!(input_range.contains(&value.as_ptr()));
+    }
+}
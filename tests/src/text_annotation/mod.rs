@@ -0,0 +1,2 @@
+mod borrowed;
+mod owned;
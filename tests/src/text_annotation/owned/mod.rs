@@ -0,0 +1,19 @@
+use rustemo::Parser;
+
+use self::assignments::AssignmentsParser;
+
+rustemo::rustemo_mod!(assignments, "/src/text_annotation/owned");
+rustemo::rustemo_mod!(assignments_actions, "/src/text_annotation/owned");
+
+// ANCHOR: text-annotation
+#[test]
+fn text_annotation() {
+    let assignments = AssignmentsParser::new()
+        .parse("a = b + (1 +2); c = 3;")
+        .unwrap();
+    let values: Vec<&str> =
+        assignments.iter().map(|a| a.value.as_str()).collect();
+    // The values are the exact source text of the expressions.
+    assert_eq!(values, ["b + (1 +2)", "3"]);
+}
+// ANCHOR_END: text-annotation
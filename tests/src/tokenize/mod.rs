@@ -0,0 +1,98 @@
+use self::calc::{CalcParser, TokenKind};
+use rustemo::{Location, Parser, Position, Token};
+
+rustemo::rustemo_mod!(calc, "/src/tokenize");
+rustemo::rustemo_mod!(calc_actions, "/src/tokenize");
+
+// ANCHOR: tokenize
+#[test]
+fn tokenize_expression() {
+    let tokens = CalcParser::tokenize("2 + (3 * 4)").unwrap();
+    assert_eq!(
+        tokens
+            .iter()
+            .map(|token| (token.kind, token.value))
+            .collect::<Vec<_>>(),
+        [
+            (TokenKind::Num, "2"),
+            (TokenKind::Plus, "+"),
+            (TokenKind::LParen, "("),
+            (TokenKind::Num, "3"),
+            (TokenKind::Mul, "*"),
+            (TokenKind::Num, "4"),
+            (TokenKind::RParen, ")"),
+        ]
+    );
+    assert_eq!(format!("{:?}", tokens[3].location), "[1,5-1,6]");
+}
+// ANCHOR_END: tokenize
+
+#[test]
+fn tokenize_error() {
+    let result = CalcParser::tokenize("2 + * 4");
+    assert!(result.is_err());
+}
+
+fn token(
+    kind: TokenKind,
+    value: &str,
+    column: usize,
+) -> Token<'_, str, TokenKind> {
+    Token {
+        kind,
+        value,
+        location: Location {
+            start: Position::from_lc(1, column),
+            end: Some(Position::from_lc(1, column + value.len())),
+        },
+        attr: None,
+        consumed: None,
+    }
+}
+
+// ANCHOR: parse_tokens
+#[test]
+fn parse_tokens() {
+    // Tokens as produced by an external tokenizer for `2 + (3 * 4)`.
+    let tokens = [
+        token(TokenKind::Num, "2", 0),
+        token(TokenKind::Plus, "+", 2),
+        token(TokenKind::LParen, "(", 4),
+        token(TokenKind::Num, "3", 5),
+        token(TokenKind::Mul, "*", 7),
+        token(TokenKind::Num, "4", 9),
+        token(TokenKind::RParen, ")", 10),
+    ];
+    let result = CalcParser::new().parse_tokens(&tokens).unwrap();
+    assert_eq!(
+        format!("{result:?}"),
+        format!("{:?}", CalcParser::new().parse("2 + (3 * 4)").unwrap())
+    );
+}
+// ANCHOR_END: parse_tokens
+
+#[test]
+fn parse_tokens_from_tokenize() {
+    let tokens = CalcParser::tokenize("1 * 2 + 3").unwrap();
+    let result = CalcParser::new().parse_tokens(&tokens).unwrap();
+    assert_eq!(
+        format!("{result:?}"),
+        format!("{:?}", CalcParser::new().parse("1 * 2 + 3").unwrap())
+    );
+}
+
+#[test]
+fn parse_tokens_error() {
+    let tokens = [
+        token(TokenKind::Num, "2", 0),
+        token(TokenKind::Plus, "+", 2),
+        token(TokenKind::Mul, "*", 4),
+        token(TokenKind::Num, "4", 6),
+    ];
+    let result = CalcParser::new().parse_tokens(&tokens);
+    // The error is reported at the location of the offending token.
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Error at <str>:[1,4-1,5]:\n\t...2+-->*4...\n\tExpected one of LParen, Num."
+    );
+}
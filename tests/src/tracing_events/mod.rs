@@ -0,0 +1,73 @@
+use std::sync::{Arc, Mutex};
+
+use rustemo::{rustemo_mod, Parser};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+use self::calc::CalcParser;
+
+rustemo_mod!(calc, "/src/tracing_events");
+rustemo_mod!(calc_actions, "/src/tracing_events");
+
+/// Collects the messages and the fields of the events as strings.
+#[derive(Clone, Default)]
+struct Collector(Arc<Mutex<Vec<String>>>);
+
+struct EventVisitor(String);
+
+impl Visit for EventVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.insert_str(0, &format!("{value:?}"));
+        } else {
+            self.0.push_str(&format!(" {}={value:?}", field.name()));
+        }
+    }
+}
+
+impl Subscriber for Collector {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = EventVisitor(String::new());
+        event.record(&mut visitor);
+        self.0.lock().unwrap().push(visitor.0);
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+// ANCHOR: tracing-events
+#[test]
+fn tracing_reduce_event() {
+    let collector = Collector::default();
+    let result = tracing::subscriber::with_default(collector.clone(), || {
+        CalcParser::new().parse("1 + 2")
+    });
+    assert!(result.is_ok());
+
+    let events = collector.0.lock().unwrap();
+    for event in [
+        "token kind=Num position=0",
+        "shift state=1:Num token=Num",
+        "reduce production=E: E Plus T len=3 goto=2:E",
+    ] {
+        assert!(events.iter().any(|e| e == event), "{event} not emitted");
+    }
+    assert_eq!(events.last().unwrap(), "accept");
+}
+// ANCHOR_END: tracing-events
@@ -0,0 +1,28 @@
+use rustemo::{rustemo_mod, Parser};
+
+use self::calc::CalcParser;
+
+rustemo_mod!(pub calc, "/src/visibility");
+rustemo_mod!(pub calc_actions, "/src/visibility");
+
+#[test]
+fn visibility_pub_crate() {
+    // The parser is usable inside the crate.
+    let result = CalcParser::new().parse("1 + 2 * 3");
+    assert!(result.is_ok());
+
+    // But no generated item is a part of the public API even if the parser
+    // module is public.
+    let parser =
+        include_str!(concat!(env!("OUT_DIR"), "/src/visibility/calc.rs"));
+    assert!(!parser.lines().any(|l| l.starts_with("pub ")));
+    assert!(parser.contains("pub(crate) struct CalcParser"));
+
+    // Actions referencing the parser types follow the parser visibility.
+    let actions = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/src/visibility/calc_actions.rs"
+    ));
+    assert!(!actions.lines().any(|l| l.starts_with("pub fn")));
+    assert!(actions.contains("pub(crate) fn num("));
+}
@@ -0,0 +1,83 @@
+use std::{
+    fs,
+    ops::ControlFlow,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use rustemo_compiler::Settings;
+
+#[test]
+fn watch_regenerates_changed_grammar() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("watch");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let first = dir.join("first.rustemo");
+    let second = dir.join("second.rustemo");
+    fs::write(&first, "A: Ta;\nterminals\nTa: 'a';").unwrap();
+    fs::write(&second, "B: Tb;\nterminals\nTb: 'b';").unwrap();
+
+    let settings = Settings::new().root_dir(dir.clone()).in_source_tree();
+    settings.process_dir().unwrap();
+    let second_parser = fs::read_to_string(dir.join("second.rs")).unwrap();
+    let second_modified = fs::metadata(dir.join("second.rs"))
+        .unwrap()
+        .modified()
+        .unwrap();
+
+    // The grammar is changed repeatedly until the change is noticed as the
+    // watching starts at some point after the thread is spawned. The grammar
+    // is replaced atomically so that it is never read half-written.
+    let done = Arc::new(AtomicBool::new(false));
+    let editor = {
+        let done = Arc::clone(&done);
+        let first = first.clone();
+        let edited = dir.join("first.edited");
+        thread::spawn(move || {
+            while !done.load(Ordering::SeqCst) {
+                fs::write(
+                    &edited,
+                    "A: Ta Second;\nterminals\nTa: 'a';\nSecond: 'x';",
+                )
+                .unwrap();
+                fs::rename(&edited, &first).unwrap();
+                thread::sleep(Duration::from_millis(100));
+            }
+        })
+    };
+
+    // ANCHOR: watch
+    let mut regenerated = vec![];
+    settings
+        .watch_dir(Duration::from_millis(50), |grammar, result| {
+            result.unwrap();
+            regenerated.push(grammar.to_path_buf());
+            done.store(true, Ordering::SeqCst);
+            ControlFlow::Break(())
+        })
+        .unwrap();
+    // ANCHOR_END: watch
+    editor.join().unwrap();
+
+    assert_eq!(regenerated, [first]);
+    assert!(fs::read_to_string(dir.join("first.rs"))
+        .unwrap()
+        .contains("Second"));
+    // The parser of the unchanged grammar is not regenerated.
+    assert_eq!(
+        fs::read_to_string(dir.join("second.rs")).unwrap(),
+        second_parser
+    );
+    assert_eq!(
+        fs::metadata(dir.join("second.rs"))
+            .unwrap()
+            .modified()
+            .unwrap(),
+        second_modified
+    );
+}